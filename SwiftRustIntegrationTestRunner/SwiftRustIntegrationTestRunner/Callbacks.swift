@@ -54,4 +54,19 @@ class SwiftMethodCallbackTester {
     func method_with_fnonce_callback_primitive(callback: (UInt16) -> UInt16) -> UInt16 {
         callback(5)
     }
+
+    /// Reports each step's progress and then throws if `fail` is true.
+    func method_with_fn_progress_callback(
+        steps: UInt8,
+        fail: Bool,
+        progress: (UInt8) -> ()
+    ) throws -> UInt8 {
+        for step in 1...steps {
+            progress(step)
+        }
+        if fail {
+            throw CallbackTestProcessError.Failed
+        }
+        return steps
+    }
 }
@@ -47,6 +47,17 @@ func swift_reflect_option_bool(arg: Optional<Bool>) -> Optional<Bool> {
     arg
 }
 
+func swift_reflect_option_duration(arg: Optional<TimeInterval>) -> Optional<TimeInterval> {
+    arg
+}
+func swift_reflect_option_system_time(arg: Optional<Date>) -> Optional<Date> {
+    arg
+}
+
+func swift_reflect_option_char(arg: Optional<Character>) -> Optional<Character> {
+    arg
+}
+
 
 func swift_reflect_option_string(arg: Optional<RustString>) -> Optional<RustString> {
     arg
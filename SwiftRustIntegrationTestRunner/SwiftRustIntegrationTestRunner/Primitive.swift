@@ -52,6 +52,10 @@ func swift_negate_bool(arg: Bool) -> Bool {
     !arg
 }
 
+func swift_reflect_saturating_u8(arg: UInt8) -> UInt8 {
+    arg
+}
+
 func swift_reflect_null(arg: ()) -> () {
     arg
 }
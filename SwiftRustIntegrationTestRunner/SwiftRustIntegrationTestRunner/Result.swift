@@ -23,6 +23,33 @@ public class ResultTestOpaqueSwiftType {
     }
 }
 
+func swift_func_halve_even_number(value: UInt32) throws -> UInt32 {
+    if value % 2 != 0 {
+        throw ResultTestSwiftError.Odd(value)
+    }
+    return value / 2
+}
+
+func swift_async_halve_even_number(value: UInt32) async throws -> UInt32 {
+    await Task.yield()
+    if value % 2 != 0 {
+        throw AsyncResultErrEnum.NamedFields(value: value)
+    }
+    return value / 2
+}
+
+func swift_func_check_non_zero(value: UInt32) throws {
+    if value == 0 {
+        throw ResultTestSwiftError.Zero
+    }
+}
+
+func swift_func_check_fits_in_u8(value: UInt32) throws {
+    if value > UInt32(UInt8.max) {
+        throw ResultTestParseError.Overflow
+    }
+}
+
 extension ResultTestOpaqueRustType: @unchecked Sendable {}
 extension ResultTestOpaqueRustType: Error {}
 
@@ -41,5 +68,41 @@ extension ResultTransparentStruct: Error {}
 extension SameEnum: @unchecked Sendable {}
 extension SameEnum: Error {}
 
+/// `Box<dyn Error + Send + Sync>` errors are thrown as a `RustString` holding the error's message.
+extension RustString: @unchecked Sendable {}
+extension RustString: Error {}
+
 extension AsyncResultErrEnum: @unchecked Sendable {}
 extension AsyncResultErrEnum: Error {}
+
+extension ResultTestSessionError: @unchecked Sendable {}
+extension ResultTestSessionError: Error {}
+
+extension ResultTestLazyError: @unchecked Sendable {}
+extension ResultTestLazyError: Error {}
+
+extension ResultTestIoError: @unchecked Sendable {}
+extension ResultTestIoError: Error {}
+
+extension ResultTestParseError: @unchecked Sendable {}
+extension ResultTestParseError: Error {}
+
+extension ResultTestSyntaxError: @unchecked Sendable {}
+extension ResultTestSyntaxError: Error {}
+
+extension ResultTestDbError: @unchecked Sendable {}
+extension ResultTestDbError: Error {}
+
+extension ResultTestQueryError: @unchecked Sendable {}
+extension ResultTestQueryError: Error {}
+
+extension ResultTestDomainError: @unchecked Sendable {}
+
+extension ErrorChainTestError: @unchecked Sendable {}
+extension ErrorChainTestError: Error {}
+
+extension LocalizedErrorTestError: @unchecked Sendable {}
+
+extension ResultTestSwiftError: Error {}
+
+extension CallbackTestProcessError: Error {}
@@ -10,3 +10,9 @@ import Foundation
 func increment_some_owned_opaque_rust_type(arg: SomeRustType, amount: UInt32) {
     arg.increment_counter(amount)
 }
+
+/// Implemented by `ConformsToTestType`'s bridged `value()` method, via
+/// `#[swift_bridge(conforms_to = "ConformsToTestHasValue")]`.
+protocol ConformsToTestHasValue {
+    func value() -> UInt32
+}
@@ -177,6 +177,56 @@ class AsyncTests: XCTestCase {
         }
     }
     
+    /// Verify that awaiting an async Rust function that returns a Result<u32, TransparentEnum>
+    /// either returns the `Ok` value or throws the `Err` value.
+    func testSwiftCallsRustAsyncFnReturnResultPrimitiveTransparentEnum() async throws {
+        let value: UInt32 = try await rust_async_func_return_result_u32_and_transparent_enum(true)
+        XCTAssertEqual(value, 123)
+
+        do {
+            let _ = try await rust_async_func_return_result_u32_and_transparent_enum(false)
+            XCTFail()
+        } catch AsyncResultErrEnum.NamedFields(let value) {
+            XCTAssertEqual(value, 456)
+        } catch {
+            XCTFail()
+        }
+    }
+
+    /// Verify that an async Rust function that returns a `Box<dyn Error + Send + Sync>` error
+    /// throws the error's `Display` message.
+    func testSwiftCallsRustAsyncFnReturnResultBoxedError() async throws {
+        let value: UInt32 = try await rust_async_func_return_result_u32_and_boxed_error(true)
+        XCTAssertEqual(value, 123)
+
+        do {
+            let _ = try await rust_async_func_return_result_u32_and_boxed_error(false)
+            XCTFail()
+        } catch let error as RustString {
+            XCTAssertEqual(error.toString(), "invalid digit found in string")
+        } catch {
+            XCTFail()
+        }
+    }
+
+    /// Verify that an async Rust method that returns a Result throws its `Err` value.
+    func testSwiftCallsRustAsyncMethodReturnResult() async throws {
+        let test = TestRustAsyncSelf()
+
+        let quotient = try await test.checked_divide_u16(10, 2)
+        XCTAssertEqual(quotient, 5)
+
+        do {
+            let _ = try await test.checked_divide_u16(10, 0)
+            XCTFail()
+        } catch AsyncResultErrEnum.UnnamedFields(let message, let dividend) {
+            XCTAssertEqual(message.toString(), "division by zero")
+            XCTAssertEqual(dividend, 10)
+        } catch {
+            XCTFail()
+        }
+    }
+
     func testSwiftCallsRustAsyncFnRetStruct() async throws {
         let _: AsyncRustFnReturnStruct = await rust_async_return_struct()
     }
@@ -191,5 +241,21 @@ class AsyncTests: XCTestCase {
             XCTAssertEqual(error.val(), 111)
         }
     }
+
+    /// Verify that Rust can await a Swift `async throws` function and receive either its returned
+    /// value or its thrown error.
+    func testRustAwaitsSwiftAsyncThrowingFn() async throws {
+        let half = try await rust_async_awaits_swift_async_throwing_fn(10)
+        XCTAssertEqual(half, 5)
+
+        do {
+            let _ = try await rust_async_awaits_swift_async_throwing_fn(7)
+            XCTFail()
+        } catch AsyncResultErrEnum.NamedFields(let value) {
+            XCTAssertEqual(value, 7)
+        } catch {
+            XCTFail()
+        }
+    }
 }
 
@@ -26,5 +26,22 @@ class FunctionAttributeGetTests: XCTestCase {
         let val = SomeTypeGet()
         XCTAssertEqual(val.my_opt_static_str()!.toString(), "world")
     }
+
+    /// Verify that a `&str` borrowed from `self` can be read inside of the closure overload, which
+    /// keeps the Rust value alive until the closure returns.
+    func testGetStrReferenceClosure() throws {
+        let string = SomeTypeGet().my_string_reference { $0.toString() }
+        XCTAssertEqual(string, "Hello")
+    }
+    
+    /// Verify that the `swift_bridge(get_cell(...))` and `swift_bridge(set_cell(...))` attributes
+    /// can be used to mutate a `Cell<i32>` field and then read it back.
+    func testGetCellSetCell() throws {
+        let val = SomeTypeGetCell()
+        XCTAssertEqual(val.my_cell_i32(), 5)
+        
+        val.set_my_cell_i32(-10)
+        XCTAssertEqual(val.my_cell_i32(), -10)
+    }
 }
 
@@ -8,7 +8,7 @@
 import Foundation
 
 import XCTest
-@testable import SwiftRustIntegrationTestRunner
+@_spi(SpiTests) @testable import SwiftRustIntegrationTestRunner
 
 /// Tests the #[swift_bridge(swift_name = "x")] attribute.
 class FunctionAttributeTests: XCTestCase {
@@ -24,4 +24,26 @@ class FunctionAttributeTests: XCTestCase {
     func testSwiftNameAttribute() throws {
         XCTAssertEqual(testCallRustFromSwiftByNameAttribute().toString(), "StringFromRust")
     }
+
+    /// Verify that we can call a function that has a `swift_bridge(available = "...")`
+    /// attribute on a platform version that it is available on.
+    func testAvailableAttribute() throws {
+        XCTAssertEqual(test_available_attribute_double(21), 42)
+    }
+
+    /// Verify that we can call a function that has a `swift_bridge(spi = "...")` attribute from
+    /// a module that imports its SPI group.
+    func testSpiAttribute() throws {
+        XCTAssertEqual(test_spi_attribute_triple(14), 42)
+    }
+
+    /// Verify that a `swift_bridge(shared)` function is exposed as a `static let` that always
+    /// refers to the same Rust singleton.
+    func testSharedAttribute() throws {
+        let first = SharedInstanceCounter.shared.increment()
+        let second = SharedInstanceCounter.shared.increment()
+
+        XCTAssertEqual(second, first + 1)
+        XCTAssert(SharedInstanceCounter.shared === SharedInstanceCounter.shared)
+    }
 }
@@ -0,0 +1,109 @@
+//
+//  HashMapTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for passing `HashMap<String, T>` and integer keyed maps such as `HashMap<u32, T>` between
+/// Swift and Rust as `[String: T]` and `[UInt32: T]`.
+/// See crates/swift-integration-tests/src/hash_map.rs
+class HashMapTests: XCTestCase {
+    /// Verify that a dictionary passed to Rust and returned back to Swift is unchanged, including
+    /// empty and non-ASCII keys.
+    func testReflectStringMap() throws {
+        let dictionary: [String: UInt32] = [
+            "one": 1,
+            "": 0,
+            "héllo wörld": 2,
+            "🦀🐦": UInt32.max,
+        ]
+
+        XCTAssertEqual(rust_reflect_string_map_u32(dictionary), dictionary)
+        XCTAssertEqual(rust_reflect_string_map_u32([:]), [:])
+    }
+
+    /// Verify that Rust can look up the keys of a dictionary that was passed from Swift.
+    func testRustReadsStringMap() throws {
+        let dictionary: [String: Int64] = ["negative": -5, "positive": 10]
+
+        XCTAssertEqual(rust_string_map_get(dictionary, "negative"), -5)
+        XCTAssertEqual(rust_string_map_get(dictionary, "positive"), 10)
+        XCTAssertNil(rust_string_map_get(dictionary, "missing"))
+    }
+
+    /// Verify that large maps can be passed in both directions.
+    func testLargeStringMap() throws {
+        let len: UInt32 = 100_000
+
+        let fromRust = rust_make_string_map(len)
+        XCTAssertEqual(fromRust.count, Int(len))
+        for idx in stride(from: UInt32(0), to: len, by: 997) {
+            XCTAssertEqual(fromRust["key-\(idx)"], idx)
+        }
+
+        XCTAssertEqual(rust_reflect_string_map_u32(fromRust), fromRust)
+
+        var toRust: [String: UInt64] = [:]
+        for idx in 0..<UInt64(len) {
+            toRust["key-\(idx)"] = idx
+        }
+        XCTAssertEqual(rust_sum_string_map_values(toRust), toRust.values.reduce(0, +))
+    }
+
+    /// Verify that a dictionary of opaque Rust types can be passed to Rust and returned back to
+    /// Swift, with Swift owning each of the returned values.
+    func testReflectStringMapOfOpaqueRustType() throws {
+        let dictionary = [
+            "first": StringMapValue("one"),
+            "second": StringMapValue("two"),
+        ]
+
+        let reflected = rust_reflect_string_map_opaque(dictionary)
+        XCTAssertEqual(reflected.count, 2)
+        XCTAssertEqual(reflected["first"]!.name().toString(), "one")
+        XCTAssertEqual(reflected["second"]!.name().toString(), "two")
+
+        XCTAssertEqual(rust_reflect_string_map_opaque([:]).count, 0)
+    }
+
+    /// Verify that Rust can create a map of opaque Rust types that Swift then owns.
+    func testMakeStringMapOfOpaqueRustType() throws {
+        let dictionary = rust_make_string_map_opaque(3)
+        XCTAssertEqual(dictionary.count, 3)
+        for idx in 0..<3 {
+            XCTAssertEqual(dictionary["key-\(idx)"]!.name().toString(), "value-\(idx)")
+        }
+    }
+
+    /// Verify that an integer keyed dictionary passed to Rust and returned back to Swift is
+    /// unchanged, even though neither side preserves the order of its entries.
+    func testReflectIntMap() throws {
+        let dictionary: [UInt32: UInt32] = [0: 1, 5: 10, UInt32.max: 0]
+        XCTAssertEqual(rust_reflect_int_map_u32(dictionary), dictionary)
+        XCTAssertEqual(rust_reflect_int_map_u32([:]), [:])
+
+        let negativeKeys: [Int64: Double] = [Int64.min: -1.5, -1: 0.25, 42: Double.infinity]
+        XCTAssertEqual(rust_reflect_int_map_i64_f64(negativeKeys), negativeKeys)
+    }
+
+    /// Verify that an integer keyed dictionary of opaque Rust types can round trip through Rust.
+    func testReflectIntMapOfOpaqueRustType() throws {
+        let dictionary: [UInt32: IntMapRecord] = [
+            1: IntMapRecord("one"),
+            2: IntMapRecord("two"),
+        ]
+
+        let reflected = rust_reflect_int_map_opaque(dictionary)
+        XCTAssertEqual(reflected.count, 2)
+        XCTAssertEqual(reflected[1]!.name().toString(), "one")
+        XCTAssertEqual(reflected[2]!.name().toString(), "two")
+
+        let fromRust = rust_make_int_map_opaque(3)
+        XCTAssertEqual(fromRust.count, 3)
+        for idx in UInt32(0)..<3 {
+            XCTAssertEqual(fromRust[idx]!.name().toString(), "record-\(idx)")
+        }
+    }
+}
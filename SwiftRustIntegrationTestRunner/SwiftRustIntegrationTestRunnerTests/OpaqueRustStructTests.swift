@@ -69,6 +69,21 @@ class OpaqueRustStructTests: XCTestCase {
         XCTAssert(val.eq(val2))
     }
 
+    /// Verify that calling a method that takes `self` by value marks the Swift handle as consumed,
+    /// so that it does not get freed again when it is deinitialized.
+    /// Calling another consuming method on the handle would trap.
+    func testOpaqueRustTypeConsumingMethod() throws {
+        let stack = ARustStack()
+        stack.push(5)
+        stack.push(10)
+
+        let (len, last) = stack.into_parts()
+        XCTAssertEqual(len, 2)
+        XCTAssertEqual(last, 10)
+
+        XCTAssertFalse(stack.isOwned)
+    }
+
     func testOpaqueRustTypeImplEquatable() throws {
         XCTContext.runActivity(named: "Should be equal"){
             _ in
@@ -130,5 +145,41 @@ class OpaqueRustStructTests: XCTestCase {
             }
         }
     }
+
+    /// Verify that two distinct instances of a `#[swift_bridge(HashableByGetters)]` type whose
+    /// getters return equal values are equal and have the same hash.
+    func testOpaqueRustTypeHashableByGetters() throws {
+        let val1 = HashableByGettersType(1, "a")
+        let val2 = HashableByGettersType(1, "a")
+        let val3 = HashableByGettersType(1, "b")
+
+        XCTAssertNotEqual(val1.ptr, val2.ptr)
+        XCTAssertEqual(val1, val2)
+        XCTAssertEqual(val1.hashValue, val2.hashValue)
+        XCTAssertNotEqual(val1, val3)
+
+        let set: Set<HashableByGettersType> = [val1, val2, val3]
+        XCTAssertEqual(set.count, 2)
+    }
+
+    /// Verify that we can use the functions and types of a bridge module annotated with
+    /// `#[swift_bridge(ffi_visibility = "crate")]`.
+    /// See crates/swift-integration-tests/src/ffi_visibility.rs
+    func testCrateVisibleFfiFunctions() throws {
+        let counter = CrateVisibleFfiCounter(21)
+
+        XCTAssertEqual(counter.count(), 21)
+        XCTAssertEqual(crate_visible_ffi_double(counter.count()), 42)
+    }
+
+    /// Verify that we can use the functions and types of a bridge module annotated with
+    /// `#[swift_bridge(rust_ffi_file = "...")]`.
+    /// See crates/swift-integration-tests/src/rust_ffi_file.rs
+    func testRustFfiFileFunctions() throws {
+        let counter = RustFfiFileCounter(1)
+
+        XCTAssertEqual(counter.increment(), 2)
+        XCTAssertEqual(rust_ffi_file_triple(counter.increment()), 9)
+    }
 }
 
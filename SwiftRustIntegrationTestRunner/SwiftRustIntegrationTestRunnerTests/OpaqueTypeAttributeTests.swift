@@ -42,7 +42,182 @@ class OpaqueTypeAttributeTests: XCTestCase {
         XCTAssert(AlreadyDeclaredCopyTypeTest.an_associated_function())
     }
 
-    
+    /// Verify that an opaque Rust type with a `#[doc(alias = "...")]` attribute can be used.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/doc_alias.rs
+    func testExternRustDocAliasType() throws {
+        XCTAssertEqual(RustDocAliasType().value(), 7)
+    }
+
+    /// Verify that an opaque Rust type with a `#[swift_bridge(dispatch_queue = "...")]` attribute
+    /// can be used on its dispatch queue.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/dispatch_queue.rs
+    func testExternRustDispatchQueueType() throws {
+        XCTAssertEqual(RustDispatchQueueType().value(), 9)
+    }
+
+    /// Verify that we can call the methods of the type that a
+    /// `#[swift_bridge(deref = "...")]` opaque Rust type derefs to.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/deref.rs
+    func testExternRustDerefType() throws {
+        let wrapper = DerefWrapper(10)
+
+        XCTAssertEqual(wrapper.inner_value(), 10)
+        XCTAssertEqual(wrapper.add_to_inner_value(5), 15)
+    }
+
+    /// Verify that we can subscript a `#[swift_bridge(index(Idx, Output))]` opaque Rust type.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/index.rs
+    func testExternRustIndexSubscript() throws {
+        let samples = IndexSamples()
+
+        XCTAssertEqual(samples[0], 10)
+        XCTAssertEqual(samples[1], -20)
+        XCTAssertEqual(samples[2], 30)
+    }
+
+    /// Verify that a thrown `#[swift_bridge(error_chain)]` error's description includes the
+    /// Rust error's `source()` chain.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/error_chain.rs
+    func testExternRustErrorChainDescription() throws {
+        do {
+            try rust_func_return_error_chain_error()
+            XCTFail()
+        } catch let error {
+            XCTAssertEqual(
+                "\(error)",
+                "failed to start server: invalid port: invalid digit found in string"
+            )
+        }
+    }
+
+    /// Verify that we can use `+=` and `-=` on a `#[swift_bridge(AddAssign, SubAssign)]` opaque
+    /// Rust type.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/compound_assignment.rs
+    func testExternRustCompoundAssignment() throws {
+        var counter = CompoundAssignCounter(10)
+
+        counter += CompoundAssignCounter(5)
+        XCTAssertEqual(counter.count(), 15)
+
+        counter -= CompoundAssignCounter(20)
+        XCTAssertEqual(counter.count(), -5)
+
+        // The type is `Clone`, so the right hand side gets cloned when it is the same value.
+        counter += counter
+        XCTAssertEqual(counter.count(), -10)
+    }
+
+    /// Verify that we can negate a `#[swift_bridge(Neg)]` opaque Rust type with a prefix `-`.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/neg.rs
+    func testExternRustNeg() throws {
+        let v = NegVector2(1.5, -2.0)
+
+        let negated = -v
+        XCTAssertEqual(negated.x(), -1.5)
+        XCTAssertEqual(negated.y(), 2.0)
+
+        // The original value is left untouched.
+        XCTAssertEqual(v.x(), 1.5)
+        XCTAssertEqual(v.y(), -2.0)
+    }
+
+    /// Verify that we can copy a `#[swift_bridge(Clone)]` opaque Rust type using
+    /// `init(copying:)`, and that the copy is independent of the original.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/clone.rs
+    func testExternRustCloneInitCopying() throws {
+        let original = CloneNameList()
+        original.push("a")
+
+        let copy = CloneNameList(copying: original)
+        XCTAssertEqual(copy.len(), 1)
+
+        copy.push("b")
+        XCTAssertEqual(copy.len(), 2)
+        XCTAssertEqual(original.len(), 1)
+
+        original.push("c")
+        original.push("d")
+        XCTAssertEqual(original.len(), 3)
+        XCTAssertEqual(copy.len(), 2)
+    }
+
+    /// Verify that we can call the methods of a `#[swift_bridge(main_actor)]` opaque Rust type
+    /// from the main actor.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/main_actor.rs
+    @MainActor
+    func testExternRustMainActor() throws {
+        let counter = MainActorCounter()
+
+        counter.increment()
+        counter.increment()
+        XCTAssertEqual(counter.count(), 2)
+    }
+
+    /// Verify that we can call a `#[swift_bridge(callable)]` opaque Rust type as a function.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/callable.rs
+    func testExternRustCallable() throws {
+        let triple = CallableMultiplier(3)
+
+        XCTAssertEqual(triple(5), 15)
+        XCTAssertEqual(triple(-2), -6)
+    }
+
+    /// Verify that we can query a range of a `#[swift_bridge(btree_map(K, V))]` opaque Rust type.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/btree_map.rs
+    func testExternRustBTreeMapRange() throws {
+        let scores = BTreeMapScores()
+        scores.insert(30, 300)
+        scores.insert(10, 100)
+        scores.insert(20, 200)
+        scores.insert(40, 400)
+
+        let entries = scores.range(15, 40)
+        XCTAssertEqual(entries.map { $0.key }, [20, 30])
+        XCTAssertEqual(entries.map { $0.value }, [200, 300])
+
+        XCTAssertTrue(scores.range(40, 10).isEmpty)
+    }
+
+    /// Verify that we can increment and decrement a `#[swift_bridge(counter(u32))]` opaque Rust
+    /// type and read its value.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/counter.rs
+    func testExternRustCounter() throws {
+        let visits = PageVisitCounter()
+        XCTAssertEqual(visits.value, 0)
+
+        visits.increment()
+        visits.increment()
+        visits.decrement()
+
+        XCTAssertEqual(visits.value, 1)
+    }
+
+    /// Verify that two Swift handles to a `#[swift_bridge(arc_mutex)]` opaque Rust type share
+    /// the same underlying value.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/arc_mutex.rs
+    func testExternRustArcMutexType() throws {
+        let first = ArcMutexCounter(1)
+        let second = first.share()
+
+        first.increment()
+        second.increment()
+        XCTAssertEqual(first.value(), 3)
+        XCTAssertEqual(second.value(), 3)
+
+        let third = ArcMutexCounter(10)
+        first.add_from(third)
+        XCTAssertEqual(second.value(), 13)
+
+        XCTAssertEqual(arc_mutex_counter_strong_count(second.share()), 3)
+    }
+
+    /// Verify that an opaque Rust type conforms to the protocols listed in its
+    /// `#[swift_bridge(conforms_to = "...")]` attribute.
+    func testExternRustTypeConformsTo() throws {
+        let hasValue: ConformsToTestHasValue = ConformsToTestType(5)
+        XCTAssertEqual(hasValue.value(), 5)
+    }
+
     func testPerformanceExample() throws {
         // This is an example of a performance test case.
         self.measure {
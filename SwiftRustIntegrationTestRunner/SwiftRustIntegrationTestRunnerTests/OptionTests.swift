@@ -48,6 +48,29 @@ class OptionTests: XCTestCase {
         XCTAssertEqual(rust_reflect_option_bool(nil), nil)
     }
 
+    /// Verify that `Option<Duration>` and `Option<SystemTime>` are bridged as `TimeInterval?`
+    /// and `Date?`.
+    func testSwiftCallRustOptionDurationAndSystemTime() throws {
+        XCTAssertEqual(rust_reflect_option_duration(1.5), 1.5)
+        XCTAssertEqual(rust_reflect_option_duration(nil), nil)
+
+        let date = Date(timeIntervalSince1970: 1_700_000_000)
+        XCTAssertEqual(rust_reflect_option_system_time(date), date)
+        XCTAssertEqual(rust_reflect_option_system_time(nil), nil)
+
+        let beforeEpoch = Date(timeIntervalSince1970: -1_000)
+        XCTAssertEqual(rust_reflect_option_system_time(beforeEpoch), beforeEpoch)
+    }
+
+    /// Verify that `Option<char>` is bridged as `Character?`, with `None` distinct from any
+    /// scalar value.
+    func testSwiftCallRustOptionChar() throws {
+        XCTAssertEqual(rust_reflect_option_char(nil), nil)
+        XCTAssertEqual(rust_reflect_option_char("a"), "a")
+        XCTAssertEqual(rust_reflect_option_char("🎉"), "🎉")
+        XCTAssertEqual(rust_reflect_option_char("\0"), "\0")
+    }
+
     /// Verify that Rust can call Swift functions that accept and return Option<T>.
     func testRustCallSwiftOptionPrimitive() throws {
         test_rust_calls_swift_option_primitive()
@@ -90,6 +113,25 @@ class OptionTests: XCTestCase {
         XCTAssertNil(rust_reflect_option_vector_rust_type(nil))
     }
 
+    /// Verify that we can pass and receive an `Option<Vec<OpaqueRustType>>`, and that an empty
+    /// `Some` vector is distinct from `None`.
+    func testSwiftCallRustWithOptionVecOfOpaqueRustType() throws {
+        let vec = RustVec<OptTestOpaqueRustType>()
+        vec.push(value: OptTestOpaqueRustType(123))
+        vec.push(value: OptTestOpaqueRustType(221))
+
+        let reflected = rust_reflect_option_vector_opaque_rust_type(vec)!
+        XCTAssertEqual(reflected.len(), 2)
+        XCTAssertEqual(reflected.get(index: 0)!.field(), 123)
+        XCTAssertEqual(reflected.get(index: 1)!.field(), 221)
+
+        let empty = rust_reflect_option_vector_opaque_rust_type(RustVec<OptTestOpaqueRustType>())
+        XCTAssertNotNil(empty)
+        XCTAssertEqual(empty!.len(), 0)
+
+        XCTAssertNil(rust_reflect_option_vector_opaque_rust_type(nil))
+    }
+
     func testSwiftCallRustWithOptionOpaqueRustType() throws {
         let val = OptTestOpaqueRustType(123)
         let reflect = rust_reflect_option_opaque_rust_type(val)
@@ -98,6 +140,21 @@ class OptionTests: XCTestCase {
         XCTAssertNil(rust_reflect_option_opaque_rust_type(nil))
     }
 
+    /// Verify that an `Option<OpaqueRustType>` return value is a plain Swift `Optional`, so that
+    /// `map` and `flatMap` can be chained over it.
+    func testSwiftCallRustOptionOpaqueRustTypeMap() throws {
+        let doubled = rust_reflect_option_opaque_rust_type(OptTestOpaqueRustType(21))
+            .flatMap { rust_reflect_option_opaque_rust_type($0) }
+            .map { $0.field() }
+            .map { $0 * 2 }
+        XCTAssertEqual(doubled, 42)
+
+        let none = rust_reflect_option_opaque_rust_type(nil)
+            .flatMap { rust_reflect_option_opaque_rust_type($0) }
+            .map { $0.field() }
+        XCTAssertNil(none)
+    }
+
     /// Verify that we can bridge options of opaque Swift types.
     func testSwiftCallRustWithOptionOpaqueSwiftType() throws {
         let val = OptTestOpaqueSwiftType(val: 727)
@@ -124,6 +181,31 @@ class OptionTests: XCTestCase {
         XCTAssertEqual(reflect!.field(), 123)
     }
 
+    /// Verify that we can mutate through an `Option<&mut OpaqueRustType>` using the closure
+    /// taking accessor, and that the closure is not called when Rust returns `None`.
+    func testSwiftCallRustWithOptionRefMutOpaqueRustType() throws {
+        let val = OptTestOpaqueRefRustType.new(123)
+
+        let updated = val.field_mut { field -> UInt8 in
+            field.set_field(200)
+            return field.field()
+        }
+        XCTAssertEqual(updated, 200)
+        XCTAssertEqual(val.field_ref()!.field(), 200)
+
+        val.clear_field()
+
+        var closureCalled = false
+        let missing = val.field_mut { field -> UInt8 in
+            closureCalled = true
+            field.set_field(50)
+            return field.field()
+        }
+        XCTAssertNil(missing)
+        XCTAssertFalse(closureCalled)
+        XCTAssertNil(val.field_ref())
+    }
+
     func testSwiftCallRustWithOptionOpaqueRustCopyType() throws {
         let val = new_opaque_rust_copy_type(123)
         let _: OptTestOpaqueRustCopyType? = rust_reflect_option_opaque_rust_copy_type(val)
@@ -197,6 +279,24 @@ class OptionTests: XCTestCase {
         XCTAssertEqual(reflected.boolean, nil)
     }
 
+    /// Verify that optional struct fields default to `nil` so that they can be omitted.
+    func testStructWithOptionFieldsOmitted() {
+        let val = StructWithOptionFields(u8: 5)
+        XCTAssertEqual(val.u8, 5)
+        XCTAssertNil(val.i8)
+        XCTAssertNil(val.boolean)
+
+        let reflected = rust_reflect_struct_with_option_fields(StructWithOptionFields())
+        XCTAssertNil(reflected.u8)
+        XCTAssertNil(reflected.f64)
+        XCTAssertNil(reflected.boolean)
+
+        let leaf = OptionBoxTreeNode(value: 9)
+        XCTAssertNil(leaf.left)
+        XCTAssertNil(leaf.right)
+        XCTAssertEqual(rust_sum_option_box_tree(leaf), 9)
+    }
+
     func testEnumWhereVariantsHaveNoData() {
         let val = OptionEnumWithNoData.Variant2
         let reflectedSome = rust_reflect_option_enum_with_no_data(val)
@@ -220,6 +320,38 @@ class OptionTests: XCTestCase {
         XCTAssertEqual(reflectedSome!.field, 123)
         XCTAssertNil(reflectedNone)
     }
+
+    /// Verify that we can pass a tree of structs with `Option<Box<Self>>` children between Rust and Swift.
+    func testOptionBoxTree() {
+        let tree = rust_create_option_box_tree()
+
+        XCTAssertEqual(tree.value, 1)
+        XCTAssertEqual(tree.left!.value.value, 2)
+        XCTAssertEqual(tree.left!.value.left!.value.value, 4)
+        XCTAssertNil(tree.left!.value.left!.value.left)
+        XCTAssertNil(tree.left!.value.right)
+        XCTAssertEqual(tree.right!.value.value, 3)
+        XCTAssertNil(tree.right!.value.left)
+
+        func sum(_ node: OptionBoxTreeNode?) -> UInt32 {
+            guard let node = node else {
+                return 0
+            }
+            return node.value + sum(node.left?.value) + sum(node.right?.value)
+        }
+        XCTAssertEqual(sum(tree), 10)
+
+        let swiftTree = OptionBoxTreeNode(
+            value: 5,
+            left: RustBox(OptionBoxTreeNode(value: 6, left: nil, right: nil)),
+            right: RustBox(OptionBoxTreeNode(
+                value: 7,
+                left: nil,
+                right: RustBox(OptionBoxTreeNode(value: 8, left: nil, right: nil))
+            ))
+        )
+        XCTAssertEqual(rust_sum_option_box_tree(swiftTree), 26)
+    }
     
     /// Verify that we can use failable initializers defined on the Rust side.
     func testFailableInitializer() {
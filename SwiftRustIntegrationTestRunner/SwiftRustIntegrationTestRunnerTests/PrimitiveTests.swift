@@ -30,5 +30,26 @@ class PrimitiveTests: XCTestCase {
         XCTAssertEqual(rust_negate_bool(true), false);
         XCTAssertEqual(rust_negate_bool(false), true);
     }
-}
 
+    /// Verify that `Saturating<u8>` is bridged as a `UInt8` and that Rust saturates instead of
+    /// overflowing.
+    func testSwiftCallsRustSaturating() throws {
+        XCTAssertEqual(rust_double_saturating_u8(10), 20)
+        XCTAssertEqual(rust_double_saturating_u8(200), UInt8.max)
+    }
+
+    /// Verify that `Duration`s bridged as `TimeInterval`s can be added, subtracted and compared
+    /// in Swift and that the results agree with Rust's `Duration` arithmetic.
+    func testDurationArithmetic() throws {
+        let a: TimeInterval = 1.5
+        let b: TimeInterval = 2.25
+
+        XCTAssertEqual(rust_add_durations(a, b), a + b)
+        XCTAssertEqual(rust_saturating_sub_durations(b, a), b - a)
+        XCTAssertEqual(rust_saturating_sub_durations(a, b), 0)
+
+        XCTAssertEqual(rust_duration_is_shorter(a, b), a < b)
+        XCTAssertEqual(rust_duration_is_shorter(b, a), b < a)
+        XCTAssertTrue(rust_add_durations(a, b) > b)
+    }
+}
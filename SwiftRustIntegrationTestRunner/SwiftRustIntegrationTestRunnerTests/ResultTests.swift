@@ -41,6 +41,59 @@ class ResultTests: XCTestCase {
         )
     }
 
+    /// Verify that we can receive a Result<OpaqueRust, OpaqueRust> where the `Ok` and `Err`
+    /// types are different, and that both values are freed once Swift is done with them.
+    func testSwiftCallRustResultDifferentOpaqueRustTypes() throws {
+        let liveCount = result_test_session_live_count()
+
+        do {
+            let session = try rust_func_return_result_session(true)
+            XCTAssertEqual(session.id(), 7)
+            XCTAssertEqual(result_test_session_live_count(), liveCount + 1)
+        }
+        XCTAssertEqual(result_test_session_live_count(), liveCount)
+
+        do {
+            let _ = try rust_func_return_result_session(false)
+            XCTFail("The function should have returned an error.")
+        } catch let error as ResultTestSessionError {
+            XCTAssertEqual(error.code(), 500)
+            XCTAssertEqual(result_test_session_live_count(), liveCount + 1)
+        }
+        XCTAssertEqual(result_test_session_live_count(), liveCount)
+    }
+
+    /// Verify that a Rust function that returns a `Result<(), Vec<E>>` throws a `RustVecErr`
+    /// that holds every one of the errors.
+    func testSwiftCallRustResultUnitVecOfErrors() throws {
+        try rust_func_validate_username("ferris")
+
+        do {
+            try rust_func_validate_username("ferris the crab")
+            XCTFail("The function should have returned an error.")
+        } catch let error as RustVecErr<ResultTestValidationError> {
+            XCTAssertEqual(error.errors, [.TooLong(15), .ContainsWhitespace])
+        }
+    }
+
+    /// Verify that returning `Ok` many times never constructs the `Err` type, and that the error
+    /// is only constructed once Rust actually returns an `Err`.
+    func testSwiftCallRustResultOkNeverConstructsError() throws {
+        let constructedCount = result_test_lazy_error_constructed_count()
+
+        for _ in 0..<10_000 {
+            XCTAssertEqual(try rust_func_return_result_lazy_error(true), 123)
+        }
+        XCTAssertEqual(result_test_lazy_error_constructed_count(), constructedCount)
+
+        do {
+            let _ = try rust_func_return_result_lazy_error(false)
+            XCTFail("The function should have returned an error.")
+        } catch _ as ResultTestLazyError {
+            XCTAssertEqual(result_test_lazy_error_constructed_count(), constructedCount + 1)
+        }
+    }
+
     /// Verify that we can receive a Result<(), OpaqueRust> from Rust
     func testSwiftCallRustResultNullOpaqueRust() throws {
         try! rust_func_return_result_null_opaque_rust(true)
@@ -249,4 +302,222 @@ class ResultTests: XCTestCase {
             XCTAssertEqual(UInt32(i), value.val())
         }
     }
+
+    /// Verify that we can receive a Result<Vec<T>, E> from Rust, where E is a transparent enum.
+    func testSwiftCallRustResultVecOpaqueRustTransparentEnum() throws {
+        let vec = try! rust_func_return_result_of_vec_opaque_transparent_enum(3)
+        XCTAssertEqual(vec.len(), 3)
+        for (i, value) in vec.enumerated() {
+            XCTAssertEqual(UInt32(i), value.val())
+        }
+
+        do {
+            let _ = try rust_func_return_result_of_vec_opaque_transparent_enum(0)
+            XCTFail("The function should have returned an error.")
+        } catch ResultTestQueryError.NotFound {
+        }
+
+        do {
+            let _ = try rust_func_return_result_of_vec_opaque_transparent_enum(101)
+            XCTFail("The function should have returned an error.")
+        } catch ResultTestQueryError.InvalidLimit(let limit) {
+            XCTAssertEqual(limit, 101)
+        }
+    }
+
+    /// Verify that we can receive a Result<(), ()> from Rust, and that the Err branch throws.
+    func testSwiftCallRustResultUnitUnit() throws {
+        try! rust_func_return_result_unit_unit(true)
+
+        do {
+            try rust_func_return_result_unit_unit(false)
+            XCTFail("The function should have returned an error.")
+        } catch is RustResultUnitErr {
+        }
+    }
+
+    /// Verify that we can pass a Result<(), ()> from Swift -> Rust
+    func testSwiftCallRustTakesResultUnitUnit() throws {
+        XCTAssertTrue(rust_func_takes_result_unit_unit(.Ok(())))
+        XCTAssertFalse(rust_func_takes_result_unit_unit(.Err(())))
+    }
+
+    /// Verify that an error that a Rust function propagates from an inner fallible call using `?`
+    /// is thrown in Swift.
+    func testSwiftCallRustResultPropagatedError() throws {
+        XCTAssertEqual(try rust_func_add_parsed_numbers("1", "2"), 3)
+
+        do {
+            let _ = try rust_func_add_parsed_numbers("1", "not a number")
+            XCTFail("The function should have returned an error.")
+        } catch let error as ResultTestParseError {
+            switch error {
+            case .InvalidNumber:
+                break
+            case .Overflow:
+                XCTFail()
+            }
+        }
+
+        do {
+            let _ = try rust_func_add_parsed_numbers("200", "100")
+            XCTFail("The function should have returned an error.")
+        } catch let error as ResultTestParseError {
+            switch error {
+            case .InvalidNumber:
+                XCTFail()
+            case .Overflow:
+                break
+            }
+        }
+    }
+
+    /// Verify that `#[swift_bridge(return_into)]` converts a different Rust error type into the
+    /// bridged error type, which gets thrown.
+    func testSwiftCallRustResultReturnIntoError() throws {
+        XCTAssertEqual(try rust_func_parse_u8_into_error("12"), 12)
+
+        do {
+            let _ = try rust_func_parse_u8_into_error("256")
+            XCTFail("The function should have returned an error.")
+        } catch let error as ResultTestParseError {
+            switch error {
+            case .InvalidNumber:
+                break
+            case .Overflow:
+                XCTFail()
+            }
+        }
+    }
+
+    /// Verify that an error that borrows from a Rust function's arguments is copied into its
+    /// owned form, so that the thrown error outlives the arguments.
+    func testSwiftCallRustResultBorrowedError() throws {
+        XCTAssertEqual(try rust_func_parse_keyword("two"), 2)
+
+        do {
+            let _ = try rust_func_parse_keyword("  three")
+            XCTFail("The function should have returned an error.")
+        } catch let error as ResultTestSyntaxError {
+            XCTAssertEqual(error.token.toString(), "three")
+            XCTAssertEqual(error.position, 2)
+        }
+    }
+
+    /// Verify that a `Result<Option<T>, E>` returns the value for `Ok(Some)`, returns `nil` for
+    /// `Ok(None)` and throws for `Err`.
+    func testSwiftCallRustResultOptionOpaqueRust() throws {
+        let user = try rust_func_find_user(true, 2)
+        XCTAssertEqual(user?.id(), 2)
+
+        XCTAssertNil(try rust_func_find_user(true, 100))
+
+        do {
+            let _ = try rust_func_find_user(false, 2)
+            XCTFail("The function should have returned an error.")
+        } catch ResultTestDbError.NotConnected {
+        }
+    }
+
+    /// Verify that Rust receives a Result from a Swift function, with an `Err` when the Swift
+    /// function throws.
+    func testRustCallsSwiftThrowingFunctions() throws {
+        test_rust_calls_swift_throwing_functions()
+    }
+
+    /// Verify that Rust can fill an `inout` Swift buffer while also returning a Result.
+    func testSwiftCallRustReadIntoMutableSlice() throws {
+        let reader = ResultTestReader()
+        var buf: [UInt8] = [0, 0, 0]
+
+        XCTAssertEqual(try reader.read(&buf), 3)
+        XCTAssertEqual(buf, [1, 2, 3])
+
+        XCTAssertEqual(try reader.read(&buf), 2)
+        XCTAssertEqual(buf, [4, 5, 3])
+
+        do {
+            let _ = try reader.read(&buf)
+            XCTFail("The function should have returned an error.")
+        } catch ResultTestIoError.Eof {
+        }
+    }
+
+    /// Verify that an `error_domain` enum thrown from Rust bridges to an `NSError` with the
+    /// given domain and the variant's code.
+    func testSwiftCallRustResultWithErrorDomain() throws {
+        try rust_func_return_result_with_error_domain(200)
+
+        do {
+            try rust_func_return_result_with_error_domain(404)
+            XCTFail("The function should have returned an error.")
+        } catch let error as NSError {
+            XCTAssertEqual(error.domain, "com.swift-bridge.result-tests")
+            XCTAssertEqual(error.code, 404)
+        }
+
+        do {
+            try rust_func_return_result_with_error_domain(405)
+            XCTFail("The function should have returned an error.")
+        } catch let error as NSError {
+            XCTAssertEqual(ResultTestDomainError.errorDomain, error.domain)
+            XCTAssertEqual(error.code, 405)
+        }
+    }
+
+    /// Verify that we can iterate over items that each carry their own `Result`, and that an
+    /// `Err` item does not end the iteration.
+    func testSwiftIteratesItemsWithPerItemResult() throws {
+        let ids = RustVec<UInt32>()
+        for id: UInt32 in [1, 2, 3, 4] {
+            ids.push(value: id)
+        }
+        let stream = ResultTestItemStream(ids)
+
+        var oks: [UInt32: String] = [:]
+        var errs: [UInt32: String] = [:]
+        while let item = stream.next() {
+            do {
+                oks[item.id()] = try item.result().toString()
+            } catch let error as RustString {
+                errs[item.id()] = error.toString()
+            }
+        }
+
+        XCTAssertEqual(oks, [2: "item 2", 4: "item 4"])
+        XCTAssertEqual(errs, [1: "item 1 failed", 3: "item 3 failed"])
+    }
+
+    /// Verify that Rust can write into an `inout` primitive argument of a function that returns
+    /// a `Result`, and that the written value is visible whether the function returns or throws.
+    func testSwiftCallRustResultWithInoutAttempts() throws {
+        var attempts: UInt32 = 0
+
+        XCTAssertEqual(try ResultTestRetryingClient(2).call(&attempts), 200)
+        XCTAssertEqual(attempts, 3)
+
+        do {
+            let _ = try ResultTestRetryingClient(5).call(&attempts)
+            XCTFail("The function should have returned an error.")
+        } catch let error as RustString {
+            XCTAssertEqual(error.toString(), "Gave up after 3 attempts")
+        }
+        XCTAssertEqual(attempts, 3)
+
+        XCTAssertEqual(try ResultTestRetryingClient(0).call(&attempts), 200)
+        XCTAssertEqual(attempts, 1)
+    }
+
+    /// Verify that a `#[swift_bridge(return_data)]` function that returns a
+    /// `Result<Vec<u8>, E>` returns a `Data` on success and throws on error.
+    func testSwiftCallRustReturnResultOfData() throws {
+        let data = try rust_func_return_result_of_data(true)
+        XCTAssertEqual(data, Data([1, 2, 3, 255]))
+
+        do {
+            let _ = try rust_func_return_result_of_data(false)
+            XCTFail("The function should have returned an error.")
+        } catch ResultTestIoError.Eof {
+        }
+    }
 }
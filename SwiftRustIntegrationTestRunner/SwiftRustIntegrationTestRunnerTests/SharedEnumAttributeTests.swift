@@ -41,6 +41,108 @@ class SharedEnumAttributeTests: XCTestCase {
         let debugString = String(reflecting: DeriveDebugEnum.Variant)
         XCTAssertEqual(debugString, "Variant")
     }
+    
+    /// Verify that we can compare enums with data using the generated Equatable conformance.
+    func testSharedEnumDerivePartialEq() throws {
+        XCTAssertEqual(DerivePartialEqEnum.Number(5), DerivePartialEqEnum.Number(5))
+        XCTAssertNotEqual(DerivePartialEqEnum.Number(5), DerivePartialEqEnum.Number(6))
+
+        XCTAssertEqual(DerivePartialEqEnum.Point(x: 1, y: 2), DerivePartialEqEnum.Point(x: 1, y: 2))
+        XCTAssertNotEqual(DerivePartialEqEnum.Point(x: 1, y: 2), DerivePartialEqEnum.Point(x: 1, y: 3))
+
+        XCTAssertEqual(
+            rust_make_derive_partial_eq_enum_text("hello"),
+            rust_make_derive_partial_eq_enum_text("hello")
+        )
+        XCTAssertNotEqual(
+            rust_make_derive_partial_eq_enum_text("hello"),
+            rust_make_derive_partial_eq_enum_text("world")
+        )
+
+        XCTAssertEqual(DerivePartialEqEnum.Empty, DerivePartialEqEnum.Empty)
+        XCTAssertNotEqual(DerivePartialEqEnum.Number(0), DerivePartialEqEnum.Empty)
+    }
+
+    /// Verify that we can create an option set from a raw value with combined bits, and that
+    /// bits that do not correspond to a variant are preserved.
+    func testSharedEnumOptionSetInitRawValue() throws {
+        let readAndWrite = OptionSetPermissionsOptions(rawValue: option_set_read_and_write_raw_value())
+        XCTAssertTrue(readAndWrite.contains(.Read))
+        XCTAssertTrue(readAndWrite.contains(.Write))
+        XCTAssertFalse(readAndWrite.contains(.Execute))
+        XCTAssertEqual(readAndWrite, [.Read, .Write])
+        
+        let withUnknownBits = OptionSetPermissionsOptions(rawValue: 0b1_0101)
+        XCTAssertEqual(withUnknownBits.rawValue, 0b1_0101)
+        XCTAssertTrue(withUnknownBits.contains(OptionSetPermissionsOptions(OptionSetPermissions.Execute)))
+    }
+    
+    /// Verify that a `case_iterable` enum's `allCases` lists its variants in Rust declaration order.
+    func testSharedEnumCaseIterable() throws {
+        XCTAssertEqual(CaseIterableWeekday.allCases, [.Wednesday, .Monday, .Friday, .Tuesday])
+        XCTAssertEqual(CaseIterableWeekday.allCases.map(case_iterable_weekday_index), [0, 1, 2, 3])
+    }
+
+    /// Verify that an `Identifiable` enum can be used where SwiftUI's `ForEach` or `List` expect
+    /// identifiable elements, with each case serving as its own `id`.
+    func testSharedEnumIdentifiable() throws {
+        func labelsById<Element: Identifiable>(
+            _ elements: [Element],
+            label: (Element) -> String
+        ) -> [Element.ID: String] {
+            Dictionary(uniqueKeysWithValues: elements.map { ($0.id, label($0)) })
+        }
+
+        let orders: [IdentifiableSortOrder] = [.Newest, .Oldest, .MostPopular]
+        let labels = labelsById(orders) { identifiable_sort_order_label($0).toString() }
+
+        XCTAssertEqual(IdentifiableSortOrder.Oldest.id, .Oldest)
+        XCTAssertEqual(labels.count, 3)
+        XCTAssertEqual(labels[.MostPopular], "Most popular")
+    }
+
+    /// Verify that we can sort a comparable enum by its raw values.
+    func testSharedEnumComparable() throws {
+        let priorities: [ComparablePriority] = [.High, .Low, comparable_priority_highest(), .Medium]
+        XCTAssertEqual(priorities.sorted(), [.Low, .Medium, .High, .High])
+        XCTAssertEqual(ComparablePriority.Low.rawValue, -1)
+        XCTAssertTrue(ComparablePriority.Medium < ComparablePriority.High)
+    }
+
+    /// Verify that we can construct a struct from the raw value of its comparable enum field.
+    func testSharedStructFromComparableEnumRawValue() throws {
+        let task = PrioritizedTask(id: 1, priority: 10)
+        XCTAssertEqual(task?.priority, .High)
+
+        XCTAssertNil(PrioritizedTask(id: 2, priority: 3))
+    }
+
+    /// Verify that an enum annotated with `#[swift_bridge(custom_string_convertible)]` describes its variant's fields.
+    func testSharedEnumCustomStringConvertible() throws {
+        XCTAssertEqual(described_shape_circle(2.0).description, "Circle(radius: 2.0)")
+        XCTAssertEqual(DescribedShape.Rectangle(3, 4).description, "Rectangle(3, 4)")
+        XCTAssertEqual(DescribedShape.Labeled("hi".intoRustString()).description, "Labeled(\"hi\")")
+        XCTAssertEqual("\(DescribedShape.Empty)", "Empty")
+    }
+
+    /// Verify that we can construct an enum annotated with `#[swift_bridge(from_str)]` from a string.
+    func testSharedEnumFromStr() throws {
+        XCTAssertEqual(FromStrColor("green"), .Green)
+        XCTAssertNil(FromStrColor("purple"))
+    }
+
+    /// Verify that an enum annotated with `#[swift_bridge(objc)]` is an `@objc` enum with `Int` raw values.
+    func testSharedEnumObjc() throws {
+        XCTAssertEqual(objc_direction_reverse(.Up), .Down)
+        XCTAssertEqual(ObjcDirection.Down.rawValue, 2)
+        XCTAssertEqual(ObjcDirectionHolder().rawValue(of: .Up), 1)
+    }
 
 }
 
+/// Only `@objc` enums can be used in the signature of an `@objc` method.
+private class ObjcDirectionHolder: NSObject {
+    @objc func rawValue(of direction: ObjcDirection) -> Int {
+        direction.rawValue
+    }
+}
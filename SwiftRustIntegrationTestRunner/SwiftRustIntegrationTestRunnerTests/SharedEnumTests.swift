@@ -128,4 +128,59 @@ class SharedEnumTests: XCTestCase {
         }
     }
 
+    /// Verify that a recursive enum, whose variants hold a `Box<Self>`, round-trips between
+    /// Swift and Rust.
+    func testRecursiveEnum() {
+        // -(1 + (2 + 3))
+        let expr = RecursiveExpr.Negate(
+            RecursiveExpr.Add(
+                left: RecursiveExpr.Number(1),
+                right: RecursiveExpr.Add(left: RecursiveExpr.Number(2), right: RecursiveExpr.Number(3))
+            )
+        )
+
+        XCTAssertEqual(evaluate_recursive_expr(expr), -6)
+
+        switch reflect_recursive_expr(expr) {
+        case .Negate(.Add(left: .Number(let left), right: .Add(left: .Number(let middle), right: .Number(let right)))):
+            XCTAssertEqual(left, 1)
+            XCTAssertEqual(middle, 2)
+            XCTAssertEqual(right, 3)
+        default:
+            XCTFail()
+        }
+
+        XCTAssertEqual(evaluate_recursive_expr(reflect_recursive_expr(expr)), -6)
+    }
+
+    /// Verify that an enum that mixes unit variants that have discriminants with variants that
+    /// hold data has raw values for its unit cases, and that its payload cases can still be
+    /// matched.
+    func testEnumWithHybridRawValues() {
+        XCTAssertEqual(EnumWithHybridRawValues.Idle.rawValue, 10)
+        XCTAssertEqual(EnumWithHybridRawValues.Done.rawValue, 12)
+        XCTAssertNil(EnumWithHybridRawValues.Running(5).rawValue)
+
+        switch EnumWithHybridRawValues(rawValue: 12) {
+        case .Done:
+            break
+        default:
+            XCTFail()
+        }
+        XCTAssertNil(EnumWithHybridRawValues(rawValue: 11))
+
+        switch reflect_enum_with_hybrid_raw_values(EnumWithHybridRawValues.Running(5)) {
+        case .Running(let value):
+            XCTAssertEqual(value, 5)
+        default:
+            XCTFail()
+        }
+
+        switch reflect_enum_with_hybrid_raw_values(EnumWithHybridRawValues.Idle) {
+        case .Idle:
+            break
+        default:
+            XCTFail()
+        }
+    }
 }
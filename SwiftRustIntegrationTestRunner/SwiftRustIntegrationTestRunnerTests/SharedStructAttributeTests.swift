@@ -34,5 +34,145 @@ class SharedStructAttributeTests: XCTestCase {
     func testSharedStructAlreadyDeclared() throws {
         test_rust_calls_swift_already_declared_struct()
     }
-}
 
+    /// Verify that the `customMirror` of a `custom_reflectable` struct lists its fields.
+    /// See crates/swift-integration-tests/src/struct_attributes/custom_reflectable.rs
+    func testSharedStructCustomReflectable() throws {
+        let val = rust_reflect_custom_reflectable_struct(
+            CustomReflectableStruct(field1: 10, field2: true)
+        )
+        let mirror = Mirror(reflecting: val)
+
+        XCTAssertEqual(mirror.children.map { $0.label }, ["field1", "field2"])
+        XCTAssertEqual(mirror.children.first!.value as! UInt8, 10)
+        XCTAssertEqual(mirror.children.dropFirst().first!.value as! Bool, true)
+    }
+
+    /// Verify that the `customMirror` of a `custom_reflectable` tuple struct lists its fields.
+    func testSharedTupleStructCustomReflectable() throws {
+        let mirror = Mirror(reflecting: CustomReflectableTupleStruct(_0: 5, _1: 6))
+
+        XCTAssertEqual(mirror.children.map { $0.label }, ["_0", "_1"])
+    }
+
+    /// Verify that a tuple struct's `positional_names` are used as its Swift property names.
+    /// See crates/swift-integration-tests/src/struct_attributes/positional_names.rs
+    func testSharedTupleStructPositionalNames() throws {
+        let point = rust_swap_positional_names_point(PositionalNamesPoint(x: 1.5, y: 2.5))
+
+        XCTAssertEqual(point.x, 2.5)
+        XCTAssertEqual(point.y, 1.5)
+    }
+
+    /// Verify that a struct that derives `Hash` is `Hashable`, with equal structs having equal
+    /// hashes and structs that differ in any field having different hashes.
+    /// See crates/swift-integration-tests/src/struct_attributes/derive.rs
+    func testSharedStructDeriveHash() throws {
+        let val = StructDeriveHash(id: 1, enabled: true, score: 50)
+        let equal = StructDeriveHash(id: 1, enabled: true, score: 50)
+
+        XCTAssertEqual(val, equal)
+        XCTAssertEqual(val.hashValue, equal.hashValue)
+
+        for different in [
+            StructDeriveHash(id: 2, enabled: true, score: 50),
+            StructDeriveHash(id: 1, enabled: false, score: 50),
+            StructDeriveHash(id: 1, enabled: true, score: nil),
+        ] {
+            XCTAssertNotEqual(val, different)
+            XCTAssertNotEqual(val.hashValue, different.hashValue)
+        }
+
+        let set: Set<StructDeriveHash> = [val, equal, StructDeriveHash(id: 2, enabled: true, score: nil)]
+        XCTAssertEqual(set.count, 2)
+    }
+
+    /// Verify that a tuple struct that derives `Hash` compares and hashes its fields by position.
+    /// See crates/swift-integration-tests/src/struct_attributes/derive.rs
+    func testSharedTupleStructDeriveHash() throws {
+        let val = StructDeriveHashTuple(_0: 1, _1: 2)
+        let equal = StructDeriveHashTuple(_0: 1, _1: 2)
+        let swapped = StructDeriveHashTuple(_0: 2, _1: 1)
+
+        XCTAssertEqual(val, equal)
+        XCTAssertEqual(val.hashValue, equal.hashValue)
+        XCTAssertNotEqual(val, swapped)
+
+        let set: Set<StructDeriveHashTuple> = [val, equal, swapped]
+        XCTAssertEqual(set.count, 2)
+        XCTAssertTrue(set.contains(StructDeriveHashTuple(_0: 2, _1: 1)))
+    }
+
+    /// Verify that a struct with `LocalizedError` field attributes provides its
+    /// `errorDescription`, `failureReason` and `recoverySuggestion` using those fields.
+    /// See crates/swift-integration-tests/src/struct_attributes/localized_error.rs
+    func testSharedStructLocalizedError() throws {
+        do {
+            try rust_func_return_localized_error(5)
+            XCTFail("Expected an error to be thrown")
+        } catch let error as LocalizedErrorTestError {
+            XCTAssertEqual(error.code, 5)
+            XCTAssertEqual(error.errorDescription, "The file could not be saved.")
+            XCTAssertEqual(error.failureReason, "The disk is full.")
+            XCTAssertEqual(error.recoverySuggestion, "Free up some space and try again.")
+            XCTAssertEqual(error.localizedDescription, "The file could not be saved.")
+        }
+    }
+
+    /// Verify that a struct with the `bytes` attribute round-trips through `toBytes()` and
+    /// `init?(bytes:)`.
+    /// See crates/swift-integration-tests/src/struct_attributes/bytes.rs
+    func testSharedStructBytes() throws {
+        let entry = BytesTestCacheEntry(id: 42, score: 1.5, pinned: true)
+
+        let bytes = entry.toBytes()
+        let decoded = BytesTestCacheEntry(bytes: bytes)!
+        XCTAssertEqual(decoded.id, 42)
+        XCTAssertEqual(decoded.score, 1.5)
+        XCTAssertEqual(decoded.pinned, true)
+
+        let reflected = rust_reflect_bytes_test_cache_entry(decoded)
+        let reflectedDecoded = BytesTestCacheEntry(bytes: reflected.toBytes())!
+        XCTAssertEqual(reflectedDecoded.id, 42)
+        XCTAssertEqual(reflectedDecoded.score, 1.5)
+        XCTAssertEqual(reflectedDecoded.pinned, true)
+
+        XCTAssertNil(BytesTestCacheEntry(bytes: Data([1, 2, 3])))
+
+        // A `Bool` can only be decoded from a `0` or `1` byte.
+        var invalidBool = bytes
+        invalidBool[invalidBool.endIndex - 1] = 2
+        XCTAssertNil(BytesTestCacheEntry(bytes: invalidBool))
+    }
+
+    /// Verify that sorting structs with the `comparable` attribute orders them by their fields in
+    /// declaration order.
+    /// See crates/swift-integration-tests/src/struct_attributes/comparable.rs
+    func testSharedStructComparable() throws {
+        let versions = [
+            ComparableTestVersion(major: 2, minor: 0, channel: .Beta),
+            ComparableTestVersion(major: 1, minor: 10, channel: .Stable),
+            ComparableTestVersion(major: 1, minor: 2, channel: .Stable),
+            ComparableTestVersion(major: 1, minor: 10, channel: .Beta),
+            ComparableTestVersion(major: 1, minor: 2, channel: .Beta),
+        ]
+
+        let sorted = versions.sorted().map { [$0.major, $0.minor, UInt32($0.channel.rawValue)] }
+        XCTAssertEqual(sorted, [
+            [1, 2, 0],
+            [1, 2, 1],
+            [1, 10, 0],
+            [1, 10, 1],
+            [2, 0, 0],
+        ])
+
+        XCTAssertEqual(
+            ComparableTestVersion(major: 1, minor: 2, channel: .Beta),
+            ComparableTestVersion(major: 1, minor: 2, channel: .Beta)
+        )
+        XCTAssertFalse(
+            ComparableTestVersion(major: 1, minor: 2, channel: .Beta)
+                < ComparableTestVersion(major: 1, minor: 2, channel: .Beta)
+        )
+    }
+}
@@ -52,4 +52,91 @@ class SharedStructTests: XCTestCase {
         XCTAssertEqual(val._0, reflected._0)
         XCTAssertEqual(val._1, reflected._1)
     }
+
+    /// Verify that a transparent struct with an `Option<String>` field can make a round trip
+    /// Swift -> Rust -> Swift.
+    func testStructReprStructWithOptionalStringFieldRoundTrip() {
+        let some = swift_calls_rust_struct_repr_struct_optional_string_field(
+            StructReprStructWithOptionalStringField(
+                id: 1,
+                name: "hello".intoRustString(),
+                nickname: "world".intoRustString()
+            )
+        )
+        XCTAssertEqual(some.id, 2)
+        XCTAssertEqual(some.name.toString(), "hello")
+        XCTAssertEqual(some.nickname!.toString(), "world")
+
+        let none = swift_calls_rust_struct_repr_struct_optional_string_field(
+            StructReprStructWithOptionalStringField(
+                id: 5,
+                name: "hello".intoRustString(),
+                nickname: nil
+            )
+        )
+        XCTAssertEqual(none.id, 6)
+        XCTAssertEqual(none.name.toString(), "hello")
+        XCTAssertNil(none.nickname)
+    }
+
+    /// Verify that a struct's `PhantomData` field is left out of the Swift struct and that the
+    /// struct can make a round trip Swift -> Rust -> Swift.
+    func testStructReprStructWithPhantomDataField() {
+        let val = swift_calls_rust_struct_repr_struct_phantom_data_field(
+            StructReprStructWithPhantomDataField(value: 21)
+        )
+        XCTAssertEqual(val.value, 42)
+    }
+
+    /// Verify that we can bridge a struct whose fields are other shared structs.
+    func testStructReprStructNestedStruct() {
+        let line = swift_calls_rust_make_line(3)
+        XCTAssertEqual(line.start.x, 0)
+        XCTAssertEqual(line.start.y, 0)
+        XCTAssertEqual(line.end.x, 3)
+        XCTAssertEqual(line.end.y, 0)
+
+        let translated = swift_calls_rust_translate_line(line, 1, 2)
+        XCTAssertEqual(translated.start.x, 1)
+        XCTAssertEqual(translated.start.y, 2)
+        XCTAssertEqual(translated.end.x, 4)
+        XCTAssertEqual(translated.end.y, 2)
+    }
+
+    /// Verify that a `self: &mut SomeStruct` method mutates the Swift struct in place.
+    func testStructReprStructMutatingMethods() {
+        var val = StructReprStructWithMutatingMethods(count: 1, label: "hello".intoRustString())
+
+        XCTAssertEqual(val.increment(2), 3)
+        XCTAssertEqual(val.count, 3)
+        XCTAssertEqual(val.increment(4), 7)
+        XCTAssertEqual(val.count, 7)
+
+        val.append_to_label(" world")
+        XCTAssertEqual(val.label.toString(), "hello world")
+        XCTAssertEqual(val.count, 7)
+    }
+
+    /// Verify that fallible methods that take a shared struct by value or by reference work on
+    /// both the success and error paths, and that the Swift struct is not changed by either.
+    func testStructReprStructFallibleValueMethods() throws {
+        let budget = StructReprStructBudget(remaining: 10, name: "groceries".intoRustString())
+
+        let spent = try budget.spend(4)
+        XCTAssertEqual(spent.remaining, 6)
+        XCTAssertEqual(spent.name.toString(), "groceries")
+        XCTAssertEqual(budget.remaining, 10)
+
+        XCTAssertThrowsError(try budget.spend(15)) { error in
+            XCTAssertEqual((error as! RustString).toString(), "short by 5")
+        }
+        XCTAssertEqual(budget.remaining, 10)
+        XCTAssertEqual(budget.name.toString(), "groceries")
+
+        XCTAssertEqual(try budget.remaining_after(3), 7)
+        XCTAssertThrowsError(try budget.remaining_after(11)) { error in
+            XCTAssertEqual((error as! RustString).toString(), "groceries cannot cover 11")
+        }
+        XCTAssertEqual(budget.remaining, 10)
+    }
 }
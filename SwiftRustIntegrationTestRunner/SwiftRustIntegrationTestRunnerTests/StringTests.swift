@@ -67,5 +67,48 @@ class StringTests: XCTestCase {
             XCTAssertNotEqual(foo.as_str(), bar.as_str())
         }
     }
-}
 
+    /// Verify that a `&'static str` returned from Rust is copied into a Swift `String`.
+    func testRustReturnsStaticStr() throws {
+        let red: String = rust_static_str_color_name(0)
+        XCTAssertEqual(red, "red")
+        XCTAssertEqual(rust_static_str_color_name(1), "green")
+        XCTAssertEqual(rust_static_str_color_name(9), "unknown")
+    }
+
+    /// Verify that a `&str` that Rust returns from one of the Swift strings that it was passed is
+    /// copied into a Swift `String` that outlives the call.
+    func testRustReturnsStrBorrowedFromArgs() throws {
+        let picker = StringTestPicker(3)
+
+        let longest: String = picker.longest("hi", "hello")
+        XCTAssertEqual(longest, "hello")
+        XCTAssertEqual(picker.longest("hey", "yo"), "hey")
+        XCTAssertEqual(picker.longest("a", "bc"), "a")
+
+        let trimmed: String = rust_trim_str("  padded  ")
+        XCTAssertEqual(trimmed, "padded")
+    }
+
+    /// Verify that Rust can append to a `&mut String` argument, and that Swift sees the appended
+    /// text in its `RustString` afterwards.
+    func testRustAppendsToMutableStringArgument() throws {
+        let summary = RustString("Picker ")
+
+        StringTestPicker(3).append_summary(summary)
+        XCTAssertEqual(summary.toString(), "Picker picks strings of at least 3 bytes")
+    }
+
+    /// Verify that a `Box<str>` returned from Rust is copied into a Swift `String`.
+    func testRustReturnsBoxedStr() throws {
+        let boxed: String = rust_make_boxed_str(3)
+        XCTAssertEqual(boxed, "ababab")
+        XCTAssertEqual(rust_make_boxed_str(0), "")
+    }
+
+    /// Verify that we can pass a `Box<str>` Swift -> Rust -> Swift.
+    func testReflectBoxedStr() throws {
+        XCTAssertEqual(rust_reflect_boxed_str("hello"), "hello")
+        XCTAssertEqual(rust_reflect_boxed_str("hello world".intoRustString()), "hello world")
+    }
+}
@@ -36,6 +36,33 @@ final class TupleTest: XCTestCase {
         }
     }
     
+    /// Verify that a Rust function can return a partial result along with a non-fatal error.
+    func testSwiftCallsRustTupleWithOptionalError() throws {
+        let (sum, error) = rust_sum_valid_numbers("1,2,x,3")
+        XCTAssertEqual(sum, 6)
+        switch error {
+        case .InvalidNumber(let number):
+            XCTAssertEqual(number.toString(), "x")
+        case nil:
+            XCTFail()
+        }
+
+        let (allValidSum, noError) = rust_sum_valid_numbers("4,5")
+        XCTAssertEqual(allValidSum, 9)
+        XCTAssertNil(noError)
+    }
+
+    /// Verify that a Rust function can return a value along with the warnings that it produced.
+    func testSwiftCallsRustReturnWarnings() throws {
+        let result = rust_sum_numbers_with_warnings("1,x,2,y,3")
+        XCTAssertEqual(result.value, 6)
+        XCTAssertEqual(result.warnings.map { $0.position }, [1, 3])
+
+        let noWarnings = rust_sum_numbers_with_warnings("4,5")
+        XCTAssertEqual(noWarnings.value, 9)
+        XCTAssertTrue(noWarnings.warnings.isEmpty)
+    }
+
     /// Verify that Rust can call Swift functions that accept and return Tuples.
     func testRustCallsSwiftTuple() throws {
         test_rust_calls_swift_tuples()
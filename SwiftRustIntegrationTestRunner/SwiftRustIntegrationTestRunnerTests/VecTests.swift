@@ -42,6 +42,40 @@ class VecTests: XCTestCase {
         let ptr = vec.as_ptr()
         XCTAssertEqual(ptr.pointee, 10)
     }
+    /// Verify that `RustVec.toArray()` reserves the Rust `Vec`'s capacity before copying the
+    /// elements into the array.
+    func testRustVecToArrayReservesCapacity() throws {
+        let vec = rust_make_vec_u32_with_capacity(3, 100)
+        XCTAssertEqual(vec.len(), 3)
+        XCTAssertGreaterThanOrEqual(vec.capacity(), 100)
+
+        let array = vec.toArray()
+        XCTAssertGreaterThanOrEqual(array.capacity, vec.capacity())
+        XCTAssertEqual(array, [0, 1, 2])
+    }
+    /// Verify that the bulk copying `RustVec.toArray()` for primitives produces the same array as
+    /// iterating over the vector one element at a time.
+    func testRustVecBulkToArrayMatchesIteration() throws {
+        let vec = rust_make_vec_u32_with_capacity(1000, 2000)
+
+        var elementWise: [UInt32] = []
+        for value in vec {
+            elementWise.append(value)
+        }
+
+        let bulk = vec.toArray()
+        XCTAssertEqual(bulk, elementWise)
+        XCTAssertEqual(bulk.count, 1000)
+        XCTAssertGreaterThanOrEqual(bulk.capacity, vec.capacity())
+
+        let doubles = RustVec<Double>()
+        for value in [1.5, -2.25, 0] {
+            doubles.push(value: value)
+        }
+        XCTAssertEqual(doubles.toArray(), [1.5, -2.25, 0])
+
+        XCTAssertEqual(RustVec<Bool>().toArray(), [])
+    }
     func testRustVecU8Iterator() throws {
         let vec = RustVec<UInt8>()
         vec.push(value: 111)
@@ -55,6 +89,80 @@ class VecTests: XCTestCase {
         XCTAssertEqual(iterations, 2)
     }
     
+    /// Verify that we can lazily map, filter and reduce a `RustVec`.
+    func testRustVecLazyMapFilterReduce() throws {
+        let vec = RustVec<UInt32>()
+        for value: UInt32 in 1...6 {
+            vec.push(value: value)
+        }
+
+        var transformed = 0
+        let doubledEvens = vec
+            .lazyFilter { $0 % 2 == 0 }
+            .map { value -> UInt32 in
+                transformed += 1
+                return value * 2
+            }
+        XCTAssertEqual(transformed, 0)
+
+        XCTAssertEqual(Array(doubledEvens), [4, 8, 12])
+        XCTAssertEqual(transformed, 3)
+
+        XCTAssertEqual(vec.lazyMap { UInt64($0) }.reduce(0, +), 21)
+    }
+
+    /// Verify that we can slice a `RustVec` with a range and iterate over the slice.
+    func testRustVecRangeSubscript() throws {
+        let vec = RustVec<UInt32>()
+        for value: UInt32 in [10, 20, 30, 40, 50] {
+            vec.push(value: value)
+        }
+
+        let slice = vec[1..<4]
+        XCTAssertEqual(slice.count, 3)
+        XCTAssertEqual(slice.startIndex, 1)
+        XCTAssertEqual(slice.first, 20)
+
+        var iterated: [UInt32] = []
+        for value in slice {
+            iterated.append(value)
+        }
+        XCTAssertEqual(iterated, [20, 30, 40])
+
+        XCTAssertEqual(Array(vec[0..<0]), [])
+        XCTAssertEqual(Array(vec[3..<5]), [40, 50])
+    }
+
+    /// Verify that standard library `Collection` algorithms work on a `RustVec`.
+    func testRustVecCollectionAlgorithms() throws {
+        let vec = RustVec<UInt32>()
+        XCTAssertNil(vec.first)
+        XCTAssertNil(vec.last)
+        XCTAssertEqual(vec.startIndex, vec.endIndex)
+
+        for value: UInt32 in [3, 8, 5, 12] {
+            vec.push(value: value)
+        }
+
+        XCTAssertEqual(vec.startIndex, 0)
+        XCTAssertEqual(vec.endIndex, 4)
+        XCTAssertEqual(vec.first, 3)
+        XCTAssertEqual(vec.last, 12)
+        XCTAssertEqual(vec.first(where: { $0 % 2 == 0 }), 8)
+        XCTAssertEqual(vec.firstIndex(where: { $0 > 4 }), 1)
+        XCTAssertNil(vec.first(where: { $0 > 100 }))
+        XCTAssertTrue(vec.contains(5))
+        XCTAssertFalse(vec.contains(4))
+
+        let opaqueVec = RustVec<ARustTypeInsideVecT>()
+        opaqueVec.push(value: ARustTypeInsideVecT("first"))
+        opaqueVec.push(value: ARustTypeInsideVecT("second"))
+        XCTAssertEqual(
+            opaqueVec.first(where: { $0.text().toString().hasPrefix("s") })?.text().toString(),
+            "second"
+        )
+    }
+
     func testVecOfOpaqueRustTypeLen() throws {
         let vec = RustVec<ARustTypeInsideVecT>()
         XCTAssertEqual(vec.len(), 0)
@@ -87,6 +195,39 @@ class VecTests: XCTestCase {
         XCTAssertEqual(reflected.get(index: 0)!.text().toString(), "hello world")
     }
     
+    /// Verify that Rust can push onto a `&mut Vec<T>` argument, and that Swift sees the pushed
+    /// values in its `RustVec` afterwards.
+    func testRustAppendsToMutableVecArgument() throws {
+        let numbers = RustVec<UInt32>()
+        numbers.push(value: 100)
+
+        rust_append_to_vec_u32(numbers, 3)
+        XCTAssertEqual(Array(numbers), [100, 0, 1, 2])
+
+        let opaqueVec: RustVec<ARustTypeInsideVecT> = RustVec()
+        ARustTypeInsideVecT("copy").append_copies(opaqueVec, 2)
+        XCTAssertEqual(opaqueVec.len(), 2)
+        XCTAssertEqual(opaqueVec.get(index: 1)!.text().toString(), "copy")
+    }
+
+    /// Verify that a Vec<Option<T>> of opaque Rust types can be used as an argument and return
+    /// type for extern "Rust" functions.
+    func testReflectVecOfOptionalOpaqueRustType() throws {
+        let vec: [ARustTypeInsideVecT?] = [
+            ARustTypeInsideVecT("first"),
+            nil,
+            ARustTypeInsideVecT("third"),
+            nil
+        ]
+
+        let reflected = rust_reflect_vec_option_opaque_rust_type(vec)
+        XCTAssertEqual(reflected.count, 4)
+        XCTAssertEqual(reflected[0]!.text().toString(), "first")
+        XCTAssertNil(reflected[1])
+        XCTAssertEqual(reflected[2]!.text().toString(), "third")
+        XCTAssertNil(reflected[3])
+    }
+    
     /// Verify that a Vec<T> of transparent enums can be used as an argument and return
     /// type for extern "Rust" functions.
     func testReflectVecOfTransparentEnum() throws {
@@ -99,6 +240,106 @@ class VecTests: XCTestCase {
         XCTAssertEqual(reflected.pop()!, TransparentEnumInsideVecT.VariantB)
     }
     
+    /// Verify that a Vec<T> of transparent enums whose variants contain data can be used as an
+    /// argument and return type for extern "Rust" functions, and that each element keeps its
+    /// variant and data.
+    func testReflectVecOfDataEnum() throws {
+        let vec: RustVec<DataEnumInsideVecT> = RustVec()
+        vec.push(value: DataEnumInsideVecT.Circle(1.5))
+        vec.push(value: DataEnumInsideVecT.Rectangle(width: 2, height: 3))
+        vec.push(value: DataEnumInsideVecT.Label(RustString("hello")))
+        vec.push(value: DataEnumInsideVecT.Empty)
+
+        let reflected = rust_reflect_vec_data_enum(vec)
+        XCTAssertEqual(reflected.len(), 4)
+
+        switch reflected.get(index: 0)! {
+        case .Circle(let radius):
+            XCTAssertEqual(radius, 1.5)
+        default:
+            XCTFail()
+        }
+        switch reflected.get(index: 1)! {
+        case .Rectangle(let width, let height):
+            XCTAssertEqual(width, 2)
+            XCTAssertEqual(height, 3)
+        default:
+            XCTFail()
+        }
+        switch reflected.get(index: 2)! {
+        case .Label(let label):
+            XCTAssertEqual(label.toString(), "hello")
+        default:
+            XCTFail()
+        }
+
+        switch reflected.pop()! {
+        case .Empty:
+            break
+        default:
+            XCTFail()
+        }
+        XCTAssertEqual(reflected.len(), 3)
+    }
+
+    /// Verify that a Vec<T> of a `Codable` shared struct can be turned into a Swift array that is
+    /// encoded to JSON and decoded back, using the struct's field names as the JSON keys.
+    func testVecOfCodableSharedStructJsonRoundTrip() throws {
+        let records: [CodableRecordInsideVecT] = rust_make_vec_codable_records(3).toArray()
+
+        let json = try JSONEncoder().encode(records)
+
+        let objects = try JSONSerialization.jsonObject(with: json) as! [[String: Any]]
+        XCTAssertEqual(objects.count, 3)
+        XCTAssertEqual(Set(objects[1].keys), ["id", "score", "active"])
+        XCTAssertEqual(objects[1]["id"] as! UInt32, 1)
+        XCTAssertEqual(objects[1]["score"] as! Double, 1.5)
+        XCTAssertEqual(objects[1]["active"] as! Bool, false)
+
+        let decoded = try JSONDecoder().decode([CodableRecordInsideVecT].self, from: json)
+        let vec: RustVec<CodableRecordInsideVecT> = RustVec()
+        for record in decoded {
+            vec.push(value: record)
+        }
+
+        let reflected = rust_reflect_vec_codable_records(vec)
+        XCTAssertEqual(reflected.len(), 3)
+        for (idx, record) in reflected.enumerated() {
+            XCTAssertEqual(record.id, UInt32(idx))
+            XCTAssertEqual(record.score, Double(idx) * 1.5)
+            XCTAssertEqual(record.active, idx % 2 == 0)
+        }
+    }
+
+    /// Verify that a `Vec<Duration>` is passed to and returned from Rust as a `[TimeInterval]`.
+    /// See crates/swift-integration-tests/src/vec.rs
+    func testReflectVecOfDuration() throws {
+        let durations: [TimeInterval] = [0, 1.5, 3600.25]
+
+        XCTAssertEqual(rust_reflect_vec_duration(durations), durations)
+        XCTAssertEqual(rust_reflect_vec_duration([]), [])
+    }
+
+    /// Verify that a `Vec<SystemTime>` is passed to and returned from Rust as a `[Date]`,
+    /// including dates from before the Unix epoch.
+    /// See crates/swift-integration-tests/src/vec.rs
+    func testReflectVecOfSystemTime() throws {
+        let dates = [
+            Date(timeIntervalSince1970: 1_700_000_000.5),
+            Date(timeIntervalSince1970: 0),
+            Date(timeIntervalSince1970: -86_400.25),
+        ]
+
+        let reflected = rust_reflect_vec_system_time(dates)
+        XCTAssertEqual(reflected.count, dates.count)
+        for (reflected, date) in zip(reflected, dates) {
+            XCTAssertEqual(
+                reflected.timeIntervalSince1970, date.timeIntervalSince1970, accuracy: 0.000_001
+            )
+        }
+        XCTAssertEqual(rust_reflect_vec_system_time([]), [])
+    }
+
     /// Verify that we can construct a RustVec of every primitive type.
     /// We tested all of the methods on  two different primitives above to be sure that our
     /// functions that generate the pieces of the RustVec support aren't accidentally hard coded to
@@ -128,6 +369,104 @@ class VecTests: XCTestCase {
     func testRustCallsSwiftRustVecFunctions() {
         run_vec_tests()
     }
-}
 
+    /// Verify that we can pass a jagged `[[Int32]]` to a Rust function that takes a `&[&[i32]]`.
+    /// See crates/swift-integration-tests/src/slice.rs
+    func testJaggedSliceArgument() {
+        XCTAssertEqual(rust_sum_jagged_slice([[1, 2, 3], [4], [], [5, 6]]), 21)
+        XCTAssertEqual(
+            Array(rust_jagged_slice_inner_lengths([[1, 2, 3], [4], [], [5, 6]])),
+            [3, 1, 0, 2]
+        )
+    }
+
+    /// Verify that we can pass empty inner and outer jagged slices to Rust.
+    func testEmptyJaggedSliceArgument() {
+        XCTAssertEqual(rust_sum_jagged_slice([]), 0)
+        XCTAssertEqual(rust_sum_jagged_slice([[], []]), 0)
+        XCTAssertEqual(rust_jagged_slice_inner_lengths([]).len(), 0)
+    }
+
+    /// Verify that we can pass a `[SlicePoint]` to a Rust function that takes a `&[SlicePoint]`.
+    /// See crates/swift-integration-tests/src/slice.rs
+    func testSharedStructSliceArgument() {
+        let sum = rust_sum_slice_points([
+            SlicePoint(x: 1, y: 10),
+            SlicePoint(x: 2, y: 20),
+            SlicePoint(x: -3, y: 30),
+        ])
+        XCTAssertEqual(sum.x, 0)
+        XCTAssertEqual(sum.y, 60)
 
+        let empty = rust_sum_slice_points([])
+        XCTAssertEqual(empty.x, 0)
+        XCTAssertEqual(empty.y, 0)
+    }
+
+    /// Verify that Rust takes ownership of the fields of each struct in a `&[SharedStruct]`
+    /// argument.
+    /// See crates/swift-integration-tests/src/slice.rs
+    func testSharedStructSliceArgumentWithOwnedFields() {
+        let joined = rust_join_slice_labels([
+            SliceLabel(text: RustString("hello")),
+            SliceLabel(text: RustString("world")),
+        ])
+        XCTAssertEqual(joined.toString(), "hello, world")
+    }
+
+    /// Verify that we can pass a `Data` to a Rust function that takes a `&[u8]`.
+    /// See crates/swift-integration-tests/src/slice.rs
+    func testDataPassedAsByteSlice() {
+        let bytes = (0..<1_000_000).map { UInt8($0 % 251) }
+        let data = Data(bytes)
+
+        XCTAssertEqual(rust_sum_bytes(data), bytes.reduce(0) { $0 + UInt64($1) })
+        XCTAssertEqual(rust_sum_bytes(Data()), 0)
+    }
+
+    /// Verify that we can pass a `Data` to a Rust function that takes a `bytes::Bytes`.
+    /// See crates/swift-integration-tests/src/slice.rs
+    func testDataPassedAsBytes() {
+        let bytes = (0..<1_000_000).map { UInt8($0 % 251) }
+
+        XCTAssertEqual(rust_sum_bytes_from_data(Data(bytes)), bytes.reduce(0) { $0 + UInt64($1) })
+        XCTAssertEqual(rust_sum_bytes_from_data(Data([1, 2, 3] as [UInt8])), 6)
+        XCTAssertEqual(rust_sum_bytes_from_data(Data()), 0)
+    }
+
+    /// Verify that a `bytes::Bytes` and its slices keep sharing the Swift buffer after the
+    /// `Data` that it came from goes out of scope in Swift.
+    /// See crates/swift-integration-tests/src/slice.rs
+    func testBytesKeepsDataAlive() {
+        let holder: SharedBytesHolder
+        do {
+            let data = Data([1, 2, 3, 4] as [UInt8])
+            holder = rust_hold_bytes_tail(data)
+        }
+
+        XCTAssertEqual(holder.sum(), 9)
+        XCTAssertTrue(holder.shares_buffer())
+    }
+
+    /// Verify that borrowed and owned `Cow<[u8]>`s are both returned to Swift as the same `[UInt8]`.
+    /// See crates/swift-integration-tests/src/slice.rs
+    func testCowSliceReturnedAsArray() {
+        let holder = CowSliceHolder()
+
+        let borrowed: [UInt8] = holder.borrowed_bytes()
+        let owned: [UInt8] = holder.owned_bytes()
+
+        XCTAssertEqual(borrowed, [1, 2, 3])
+        XCTAssertEqual(borrowed, owned)
+    }
+
+    /// Verify that a `Vec<&str>` that borrows from `&self` is copied into a `[String]`, which
+    /// outlives the Rust value that it was borrowed from.
+    func testVecOfStrReturnedAsStringArray() {
+        var sentence: VecTestSentence? = VecTestSentence("hello  from rust")
+        let words: [String] = sentence!.words()
+        sentence = nil
+
+        XCTAssertEqual(words, ["hello", "from", "rust"])
+    }
+}
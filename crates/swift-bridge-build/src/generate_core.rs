@@ -4,29 +4,47 @@ use crate::generate_core::boxed_fn_support::{
 use crate::generate_core::option_support::{
     swift_option_primitive_support, C_OPTION_PRIMITIVE_SUPPORT,
 };
-use crate::generate_core::result_support::{C_RESULT_SUPPORT, SWIFT_RUST_RESULT};
+use crate::generate_core::result_support::{
+    swift_rust_vec_err, C_RESULT_SUPPORT, SWIFT_RUST_RESULT,
+};
 use std::path::Path;
+use swift_bridge_ir::SwiftVersion;
 
 const RUST_STRING_SWIFT: &'static str = include_str!("./generate_core/rust_string.swift");
 const RUST_STRING_C: &'static str = include_str!("./generate_core/rust_string.c.h");
 
 const STRING_SWIFT: &'static str = include_str!("./generate_core/string.swift");
 const RUST_VEC_SWIFT: &'static str = include_str!("./generate_core/rust_vec.swift");
+const RUST_BOX_SWIFT: &'static str = include_str!("./generate_core/rust_box.swift");
+const STRING_MAP_SWIFT: &'static str = include_str!("./generate_core/string_map.swift");
+const INT_MAP_SWIFT: &'static str = include_str!("./generate_core/int_map.swift");
+const VALUE_WITH_WARNINGS_SWIFT: &'static str =
+    include_str!("./generate_core/value_with_warnings.swift");
 
 mod boxed_fn_support;
 mod option_support;
 mod result_support;
 
-pub(super) fn write_core_swift_and_c(out_dir: &Path) {
+pub(super) fn write_core_swift_and_c(out_dir: &Path, target_swift_version: Option<SwiftVersion>) {
     let core_swift_out = out_dir.join("SwiftBridgeCore.swift");
     let mut swift = core_swift();
     swift += "\n";
     swift += &RUST_STRING_SWIFT;
     swift += "\n";
+    swift += &RUST_BOX_SWIFT;
+    swift += "\n";
+    swift += &VALUE_WITH_WARNINGS_SWIFT;
+    swift += "\n";
     swift += &SWIFT_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN;
     swift += "\n";
     swift += &SWIFT_RUST_RESULT;
     swift += "\n";
+    swift += &swift_rust_vec_err(
+        target_swift_version
+            .map(|version| version.supports_sendable())
+            .unwrap_or(true),
+    );
+    swift += "\n";
     swift += &swift_option_primitive_support();
 
     std::fs::write(core_swift_out, swift).unwrap();
@@ -48,6 +66,8 @@ fn core_swift() -> String {
 
     core_swift += STRING_SWIFT;
     core_swift += RUST_VEC_SWIFT;
+    core_swift += STRING_MAP_SWIFT;
+    core_swift += INT_MAP_SWIFT;
 
     for (swift_ty, rust_ty) in vec![
         ("UInt8", "u8"),
@@ -68,6 +88,7 @@ fn core_swift() -> String {
         ("Double", "f64"),
     ] {
         core_swift += &conform_to_vectorizable(swift_ty, rust_ty);
+        core_swift += &format!("extension {swift_ty}: BulkCopyableVectorizable {{}}\n");
     }
 
     core_swift += &generic_freer();
@@ -82,6 +103,12 @@ fn core_c_header() -> String {
 typedef struct RustStr { uint8_t* const start; uintptr_t len; } RustStr;
 typedef struct __private__FfiSlice { void* const start; uintptr_t len; } __private__FfiSlice;
 void* __swift_bridge__null_pointer(void);
+void __swift_bridge__$free_ffi_slice_of_pointers(__private__FfiSlice slice);
+typedef struct __private__FfiStringMap { __private__FfiSlice key_bytes; __private__FfiSlice key_lengths; __private__FfiSlice values; } __private__FfiStringMap;
+void __swift_bridge__$FfiStringMap$free_pointers(__private__FfiStringMap map);
+typedef struct __private__FfiIntMap { __private__FfiSlice keys; __private__FfiSlice values; } __private__FfiIntMap;
+void __swift_bridge__$FfiIntMap$free_values_pointers(__private__FfiSlice values);
+typedef struct __private__SwiftBytes { void* owner; const uint8_t* start; uintptr_t len; void (*release)(void*); } __private__SwiftBytes;
 
 "#
     .to_string();
@@ -106,6 +133,17 @@ void* __swift_bridge__null_pointer(void);
         ("f64", "double"),
     ] {
         header += &vec_of_primitive_headers(rust_ty, c_ty);
+        header += &format!(
+            "void __swift_bridge__$FfiStringMap$free_{rust_ty}(__private__FfiStringMap map);\n"
+        );
+        if !matches!(rust_ty, "bool" | "f32" | "f64") {
+            header += &format!(
+                "void __swift_bridge__$FfiIntMap$free_keys_{rust_ty}(__private__FfiSlice keys);\n"
+            );
+        }
+        header += &format!(
+            "void __swift_bridge__$FfiIntMap$free_values_{rust_ty}(__private__FfiSlice values);\n"
+        );
     }
 
     header
@@ -127,6 +165,7 @@ fn vec_of_primitive_headers(rust_ty: &str, c_ty: &str) -> String {
 void* __swift_bridge__$Vec_{rust_ty}$new();
 void __swift_bridge__$Vec_{rust_ty}$_free(void* const vec);
 uintptr_t __swift_bridge__$Vec_{rust_ty}$len(void* const vec);
+uintptr_t __swift_bridge__$Vec_{rust_ty}$capacity(void* const vec);
 void __swift_bridge__$Vec_{rust_ty}$push(void* const vec, {c_ty} val);
 {option_ty} __swift_bridge__$Vec_{rust_ty}$pop(void* const vec);
 {option_ty} __swift_bridge__$Vec_{rust_ty}$get(void* const vec, uintptr_t index);
@@ -189,6 +228,10 @@ extension {swift_ty}: Vectorizable {{
     public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {{
         __swift_bridge__$Vec_{rust_ty}$len(vecPtr)
     }}
+
+    public static func vecOfSelfCapacity(vecPtr: UnsafeMutableRawPointer) -> UInt {{
+        __swift_bridge__$Vec_{rust_ty}$capacity(vecPtr)
+    }}
 }}
     "#,
         rust_ty = rust_ty,
@@ -0,0 +1,45 @@
+extension Dictionary where Key: FixedWidthInteger {
+    /// Copy the dictionary's keys and values into contiguous buffers and then call the callback
+    /// with an __private__FfiIntMap that points to those buffers.
+    ///
+    /// The value at each index belongs to the key at the same index.
+    /// The buffers are freed after the callback returns.
+    func toFfiIntMap<T>(_ withFfiIntMap: (__private__FfiIntMap) throws -> T) rethrows -> T {
+        var keys: [Key] = []
+        var values: [Value] = []
+        keys.reserveCapacity(self.count)
+        values.reserveCapacity(self.count)
+
+        for (key, value) in self {
+            keys.append(key)
+            values.append(value)
+        }
+
+        return try keys.withUnsafeBufferPointer({ keys in
+            try values.withUnsafeBufferPointer({ values in
+                try withFfiIntMap(__private__FfiIntMap(
+                    keys: keys.toFfiSlice(),
+                    values: values.toFfiSlice()
+                ))
+            })
+        })
+    }
+}
+
+extension __private__FfiIntMap {
+    /// Copy the map's keys and values into a Dictionary.
+    ///
+    /// This does not free the map's buffers.
+    func toSwiftDictionary<K: FixedWidthInteger, V>(_ keyType: K.Type, _ valueType: V.Type) -> [K: V] {
+        let keys = UnsafeBufferPointer(
+            start: self.keys.start?.assumingMemoryBound(to: K.self),
+            count: Int(self.keys.len)
+        )
+        let values = UnsafeBufferPointer(
+            start: self.values.start?.assumingMemoryBound(to: V.self),
+            count: Int(self.values.len)
+        )
+
+        return Dictionary(uniqueKeysWithValues: zip(keys, values))
+    }
+}
@@ -33,8 +33,40 @@ extension RustResult {
         }
     }
 }
+
+/// Thrown when a Rust function that returns `Result<(), ()>` returns `Err(())`.
+public struct RustResultUnitErr: Error {
+    public init() {}
+}
+
 "#;
 
+/// `RustVecErr` only conforms to `Sendable` when the target Swift version supports it.
+pub fn swift_rust_vec_err(supports_sendable: bool) -> String {
+    let maybe_sendable = if supports_sendable {
+        ", @unchecked Sendable"
+    } else {
+        ""
+    };
+
+    format!(
+        r#"
+/// Thrown when a Rust function that returns `Result<T, Vec<E>>` returns an `Err`, so that Swift
+/// receives every one of the errors at once.
+public struct RustVecErr<E: Vectorizable>: Error{maybe_sendable} {{
+    /// Owns the errors. `errors` points into this `Vec` when `E` is an opaque Rust type.
+    public let vec: RustVec<E>
+    public let errors: [E.SelfRef]
+
+    public init(_ vec: RustVec<E>) {{
+        self.vec = vec
+        self.errors = vec.toArray()
+    }}
+}}
+"#
+    )
+}
+
 pub const C_RESULT_SUPPORT: &'static str = r#"
 struct __private__ResultPtrAndPtr { bool is_ok; void* ok_or_err; };
 "#;
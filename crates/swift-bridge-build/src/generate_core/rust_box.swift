@@ -0,0 +1,11 @@
+/// Holds a value that Rust stores in a `Box<T>`.
+///
+/// Swift structs cannot directly contain themselves, so a recursive Rust struct such as
+/// `struct TreeNode { left: Option<Box<TreeNode>> }` has its boxed fields bridged as a `RustBox`.
+public final class RustBox<T> {
+    public var value: T
+
+    public init(_ value: T) {
+        self.value = value
+    }
+}
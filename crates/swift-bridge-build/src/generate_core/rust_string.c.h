@@ -9,6 +9,7 @@ void* __swift_bridge__$Vec_RustString$pop(void* vec_ptr);
 void* __swift_bridge__$Vec_RustString$get(void* vec_ptr, uintptr_t index);
 void* __swift_bridge__$Vec_RustString$get_mut(void* vec_ptr, uintptr_t index);
 uintptr_t __swift_bridge__$Vec_RustString$len(void* vec_ptr);
+uintptr_t __swift_bridge__$Vec_RustString$capacity(void* vec_ptr);
 void* __swift_bridge__$Vec_RustString$as_ptr(void* vec_ptr);
 
 void* __swift_bridge__$RustString$new(void);
@@ -94,4 +94,8 @@ extension RustString: Vectorizable {
     public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {
         __swift_bridge__$Vec_RustString$len(vecPtr)
     }
+
+    public static func vecOfSelfCapacity(vecPtr: UnsafeMutableRawPointer) -> UInt {
+        __swift_bridge__$Vec_RustString$capacity(vecPtr)
+    }
 }
\ No newline at end of file
@@ -33,6 +33,21 @@ public class RustVec<T: Vectorizable> {
         Int(T.vecOfSelfLen(vecPtr: ptr))
     }
 
+    /// The number of elements that the Rust `Vec` can hold without reallocating.
+    public func capacity() -> Int {
+        Int(T.vecOfSelfCapacity(vecPtr: ptr))
+    }
+
+    /// Copy the vector's elements into a Swift `Array` that reserves the Rust `Vec`'s capacity up
+    /// front, so that filling the array and then appending up to that many elements does not
+    /// need to reallocate.
+    public func toArray() -> [T.SelfRef] {
+        var array: [T.SelfRef] = []
+        array.reserveCapacity(Swift.max(self.capacity(), self.len()))
+        array.append(contentsOf: self)
+        return array
+    }
+
     deinit {
         if isOwned {
             T.vecOfSelfFree(vecPtr: ptr)
@@ -72,6 +87,18 @@ extension RustVec: Collection {
         self.get(index: UInt(position))!
     }
 
+    /// A view of the elements in the range, such as `vec[1..<3]`.
+    ///
+    /// The elements are not copied. They are read from the Rust `Vec` as the slice is accessed.
+    public subscript(bounds: Range<Int>) -> Slice<RustVec<T>> {
+        let len = self.len()
+        precondition(
+            bounds.lowerBound >= 0 && bounds.upperBound <= len,
+            "Range \(bounds) is out of bounds for a RustVec of length \(len)"
+        )
+        return Slice(base: self, bounds: bounds)
+    }
+
     public var startIndex: Int {
         0
     }
@@ -83,12 +110,101 @@ extension RustVec: Collection {
 
 extension RustVec: RandomAccessCollection {}
 
+extension RustVec {
+    /// Lazily transform the vector's elements as they are iterated over, without first copying
+    /// the vector into an `Array`.
+    public func lazyMap<U>(_ transform: @escaping (T.SelfRef) -> U) -> LazyMapSequence<RustVec<T>, U> {
+        self.lazy.map(transform)
+    }
+
+    /// Lazily skip the vector's elements that do not satisfy the predicate as they are iterated
+    /// over, without first copying the vector into an `Array`.
+    public func lazyFilter(_ isIncluded: @escaping (T.SelfRef) -> Bool) -> LazyFilterSequence<RustVec<T>> {
+        self.lazy.filter(isIncluded)
+    }
+}
+
+extension RustVec where T: BulkCopyableVectorizable {
+    /// Copy the vector's elements into a Swift `Array` with a single bulk copy of the Rust `Vec`'s
+    /// buffer, instead of calling into Rust once per element.
+    ///
+    /// Like the general `toArray()`, the array reserves the Rust `Vec`'s capacity up front.
+    public func toArray() -> [T] {
+        let len = self.len()
+
+        var array: [T] = []
+        array.reserveCapacity(Swift.max(self.capacity(), len))
+        array.append(contentsOf: UnsafeBufferPointer(start: self.as_ptr(), count: len))
+        return array
+    }
+}
+
 extension UnsafeBufferPointer {
     func toFfiSlice () -> __private__FfiSlice {
         __private__FfiSlice(start: UnsafeMutablePointer(mutating: self.baseAddress), len: UInt(self.count))
     }
 }
 
+extension UnsafeMutableBufferPointer {
+    func toFfiSlice () -> __private__FfiSlice {
+        __private__FfiSlice(start: self.baseAddress, len: UInt(self.count))
+    }
+}
+
+extension Array {
+    /// Call the callback with an __private__FfiSlice that points to this array's elements,
+    /// allowing Rust to mutate them in place.
+    mutating func toFfiMutableSlice<T>(_ withFfiSlice: (__private__FfiSlice) throws -> T) rethrows -> T {
+        try self.withUnsafeMutableBufferPointer({ buffer in
+            try withFfiSlice(buffer.toFfiSlice())
+        })
+    }
+
+    /// Call the callback with an __private__FfiSlice that points to this array's elements.
+    func toFfiSlice<T>(_ withFfiSlice: (__private__FfiSlice) throws -> T) rethrows -> T {
+        try self.withUnsafeBufferPointer({ buffer in
+            try withFfiSlice(buffer.toFfiSlice())
+        })
+    }
+
+    /// Call the callback with an __private__FfiSlice that points to this array's pointers.
+    func toFfiPointerSlice<T>(_ withFfiSlice: (__private__FfiSlice) throws -> T) rethrows -> T where Element == Optional<UnsafeMutableRawPointer> {
+        try self.withUnsafeBufferPointer({ buffer in
+            try withFfiSlice(buffer.toFfiSlice())
+        })
+    }
+}
+
+extension Array {
+    /// Copy each inner array into a temporary buffer and then call the callback with an
+    /// __private__FfiSlice of __private__FfiSlice's that point to those buffers.
+    ///
+    /// The buffers are freed after the callback returns.
+    func toFfiJaggedSlice<E, T> (_ withUnsafeFfiSlice: (__private__FfiSlice) -> T) -> T where Element == [E] {
+        let buffers = self.map({ inner -> UnsafeMutableBufferPointer<E> in
+            let buffer = UnsafeMutableBufferPointer<E>.allocate(capacity: inner.count)
+            _ = buffer.initialize(from: inner)
+            return buffer
+        })
+        defer {
+            for buffer in buffers {
+                buffer.deallocate()
+            }
+        }
+
+        let slices = buffers.map({ buffer in
+            __private__FfiSlice(start: buffer.baseAddress, len: UInt(buffer.count))
+        })
+        return slices.withUnsafeBufferPointer({ slicesPtr in
+            withUnsafeFfiSlice(slicesPtr.toFfiSlice())
+        })
+    }
+}
+
+/// A `Vectorizable` type whose Swift and Rust memory layouts are the same, such as a `UInt32`, so a
+/// `RustVec` of it can be copied into a Swift `Array` straight from the Rust `Vec`'s buffer.
+public protocol BulkCopyableVectorizable: Vectorizable where SelfRef == Self {}
+
 public protocol Vectorizable {
     associatedtype SelfRef
     associatedtype SelfRefMut
@@ -108,4 +224,6 @@ public protocol Vectorizable {
     static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<SelfRef>
 
     static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt
+
+    static func vecOfSelfCapacity(vecPtr: UnsafeMutableRawPointer) -> UInt
 }
@@ -1,4 +1,6 @@
+#if canImport(Foundation)
 import Foundation
+#endif
 
 extension RustString {
     public func toString() -> String {
@@ -17,7 +19,7 @@ extension RustStr {
 
     public func toString() -> String {
         let bytes = self.toBufferPointer()
-        return String(bytes: bytes, encoding: .utf8)!
+        return String(decoding: bytes, as: UTF8.self)
     }
 }
 extension RustStr: Identifiable {
@@ -0,0 +1,66 @@
+extension Dictionary where Key == String {
+    /// Copy the dictionary's keys and values into contiguous buffers and then call the callback
+    /// with an __private__FfiStringMap that points to those buffers.
+    ///
+    /// This lets the whole dictionary be passed to Rust without an FFI call for each entry.
+    /// The buffers are freed after the callback returns.
+    func toFfiStringMap<T>(_ withFfiStringMap: (__private__FfiStringMap) throws -> T) rethrows -> T {
+        var keyBytes: [UInt8] = []
+        var keyLengths: [UInt] = []
+        var values: [Value] = []
+        keyLengths.reserveCapacity(self.count)
+        values.reserveCapacity(self.count)
+
+        for (key, value) in self {
+            let utf8 = key.utf8
+            keyBytes.append(contentsOf: utf8)
+            keyLengths.append(UInt(utf8.count))
+            values.append(value)
+        }
+
+        return try keyBytes.withUnsafeBufferPointer({ keyBytes in
+            try keyLengths.withUnsafeBufferPointer({ keyLengths in
+                try values.withUnsafeBufferPointer({ values in
+                    try withFfiStringMap(__private__FfiStringMap(
+                        key_bytes: keyBytes.toFfiSlice(),
+                        key_lengths: keyLengths.toFfiSlice(),
+                        values: values.toFfiSlice()
+                    ))
+                })
+            })
+        })
+    }
+}
+
+extension __private__FfiStringMap {
+    /// Copy the map's keys and values into a Dictionary.
+    ///
+    /// This does not free the map's buffers.
+    func toSwiftDictionary<V>(_ valueType: V.Type) -> [String: V] {
+        let keyBytes = UnsafeBufferPointer(
+            start: self.key_bytes.start?.assumingMemoryBound(to: UInt8.self),
+            count: Int(self.key_bytes.len)
+        )
+        let keyLengths = UnsafeBufferPointer(
+            start: self.key_lengths.start?.assumingMemoryBound(to: UInt.self),
+            count: Int(self.key_lengths.len)
+        )
+        let values = UnsafeBufferPointer(
+            start: self.values.start?.assumingMemoryBound(to: V.self),
+            count: Int(self.values.len)
+        )
+
+        var dictionary: [String: V] = [:]
+        dictionary.reserveCapacity(values.count)
+
+        var offset = 0
+        for (keyLength, value) in zip(keyLengths, values) {
+            let end = offset + Int(keyLength)
+            let key = String(decoding: UnsafeBufferPointer(rebasing: keyBytes[offset..<end]), as: UTF8.self)
+            dictionary[key] = value
+            offset = end
+        }
+
+        return dictionary
+    }
+}
@@ -0,0 +1,13 @@
+/// A value that a Rust function returned along with the warnings that it produced.
+///
+/// Returned by functions that use `#[swift_bridge(return_warnings)]` to return a `(T, Vec<W>)`.
+/// Warnings do not mean that the function failed, so they are kept apart from the error path.
+public struct ValueWithWarnings<Value, Warning> {
+    public var value: Value
+    public var warnings: [Warning]
+
+    public init(value: Value, warnings: [Warning]) {
+        self.value = value
+        self.warnings = warnings
+    }
+}
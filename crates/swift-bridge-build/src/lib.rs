@@ -7,24 +7,40 @@ mod package;
 use crate::generate_core::write_core_swift_and_c;
 pub use package::*;
 use std::path::Path;
-use swift_bridge_ir::{CodegenConfig, SwiftBridgeModule};
+use swift_bridge_ir::CodegenConfig;
+pub use swift_symbols::{SwiftSymbol, SwiftSymbolKind};
+
+pub use swift_bridge_ir::{
+    CamelCaseSwiftNames, GetterProperties, NameCasing, NamingConvention, SwiftBridgeModule,
+    SwiftResultFunctions, SwiftVersion,
+};
 use syn::__private::ToTokens;
 use syn::{File, Item};
 
 mod generate_core;
+mod swift_symbols;
 
 /// Parse rust sources files for `#\[swift_bridge::bridge\]` headers and generate the corresponding
 /// Swift files.
 pub fn parse_bridges(
     rust_source_files: impl IntoIterator<Item = impl AsRef<Path>>,
 ) -> GeneratedCode {
-    let mut generated_code = GeneratedCode::new();
+    parse_bridges_with_config(rust_source_files, ParseBridgesConfig::default())
+}
+
+/// Parse rust sources files for `#\[swift_bridge::bridge\]` headers and generate the corresponding
+/// Swift files, using the provided config.
+pub fn parse_bridges_with_config(
+    rust_source_files: impl IntoIterator<Item = impl AsRef<Path>>,
+    config: ParseBridgesConfig,
+) -> GeneratedCode {
+    let mut generated_code = GeneratedCode::new(config.target_swift_version);
 
     for rust_file in rust_source_files.into_iter() {
         let rust_file: &Path = rust_file.as_ref();
 
         let file = std::fs::read_to_string(rust_file).unwrap();
-        let gen = match parse_file_contents(&file) {
+        let gen = match parse_file_contents(&file, &config) {
             Ok(generated) => generated,
             Err(e) => {
                 // TODO: Return an error...
@@ -38,26 +54,136 @@ Error while parsing {:?}
             }
         };
 
+        for warning in &gen.warnings {
+            // Cargo displays these lines as warnings when we're running inside of a build script.
+            println!("cargo:warning={}: {}", rust_file.display(), warning);
+        }
+
         generated_code.generated.push(gen);
     }
 
     generated_code
 }
 
+/// Generate the Swift code for a bridge module that has already been parsed, returning it as a
+/// `String` instead of writing it to disk.
+///
+/// This is useful for build systems that want to post-process or embed the generated Swift
+/// themselves.
+/// The returned Swift does not include the core Swift support code that
+/// [`GeneratedCode::write_all_concatenated`] writes out.
+pub fn generate_swift(module: &SwiftBridgeModule, config: &ParseBridgesConfig) -> String {
+    module.generate_swift_code(codegen_config(config))
+}
+
+/// Config for parsing bridge modules and generating code for them.
+#[derive(Default)]
+pub struct ParseBridgesConfig {
+    /// The Swift version that the generated Swift code will be compiled with.
+    /// Language features that were introduced after this version will not be used.
+    /// Defaults to `None`, meaning that the latest Swift version can be targeted.
+    pub target_swift_version: Option<SwiftVersion>,
+    /// Whether or not to log from the `deinit` of generated Swift classes for opaque Rust types.
+    /// The logging is only compiled into `DEBUG` Swift builds.
+    /// Pair this with the `swift-bridge` crate's `debug-log-frees` feature to match up
+    /// allocations and frees when debugging memory leaks.
+    pub log_deinit: bool,
+    /// Convert `snake_case` Rust function and method names into idiomatic `camelCase` Swift names,
+    /// such as `to_json` -> `toJSON`.
+    /// Functions that use `#[swift_bridge(swift_name = "...")]` keep the name that they were given.
+    /// Defaults to `None`, meaning that Swift functions keep their Rust names.
+    pub camel_case_swift_names: Option<CamelCaseSwiftNames>,
+    /// Generate Swift computed properties for methods that use `#[swift_bridge(get(...))]`,
+    /// such as `get_width` -> `var width`.
+    /// Defaults to `None`, meaning that getters are generated as Swift methods.
+    pub getter_properties: Option<GetterProperties>,
+    /// Which Swift functions to generate for Rust functions that return a `Result`.
+    /// Defaults to [`SwiftResultFunctions::Throwing`], meaning that only a throwing Swift
+    /// function is generated.
+    pub swift_result_functions: SwiftResultFunctions,
+    /// Print a `cargo:warning` for bridged functions that take more than this many arguments,
+    /// since calls with many arguments can spill out of registers and onto the stack.
+    /// Defaults to `None`, meaning that the number of arguments is not checked.
+    pub max_function_arguments: Option<usize>,
+    /// Print a `cargo:warning` for bridged types and functions whose Swift names do not follow
+    /// this naming convention, along with a suggested name.
+    /// Defaults to `None`, meaning that the casing of Swift names is not checked.
+    pub naming_convention: Option<NamingConvention>,
+    /// Swift names that generated types and functions are not allowed to have, in addition to
+    /// Swift's keywords. For example, `vec!["T".to_string()]` forbids a bridged type that would
+    /// shadow a Swift generic parameter named `T`.
+    /// Parsing fails with an error that points at the offending name.
+    /// Defaults to an empty list.
+    pub reserved_swift_names: Vec<String>,
+    /// Mark generated Swift functions, methods and getter properties `@inlinable` when they only
+    /// pass primitive values to and from Rust, so that calls from other Swift modules can be
+    /// optimized.
+    /// Only useful when the generated Swift code is in a library that other Swift modules use.
+    /// Defaults to `false`.
+    pub inlinable_accessors: bool,
+    /// Declare the generated Swift classes for opaque Rust types, and their instance methods,
+    /// `open` instead of `public`, so that other Swift modules can subclass them and override
+    /// their methods.
+    /// Only useful when the generated Swift code is in a library that other Swift modules use.
+    /// Defaults to `false`.
+    pub open_classes: bool,
+    /// Write an XCTest file with an empty test method for each extern "Rust" function, to help
+    /// bootstrap a test suite for the bridged API.
+    /// Each test method calls its function with Xcode placeholder arguments that are meant to be
+    /// filled in by hand.
+    /// See [`GeneratedCode::write_all_concatenated`] for where the file is written.
+    /// Defaults to `false`.
+    pub swift_test_stubs: bool,
+    /// Emit a doc comment on each generated Swift type that describes the type's FFI
+    /// representation, such as the order and widths of a shared struct's C fields.
+    /// Useful when debugging layout issues or when writing interop code by hand.
+    /// Defaults to `false`.
+    pub ffi_layout_docs: bool,
+    /// Emit a doc comment on each generated Swift function that links to the bridged types that
+    /// it takes and returns, so that Xcode's Quick Help can navigate between related types.
+    /// Defaults to `false`.
+    pub doc_comment_type_links: bool,
+    /// Generate Swift code that is compatible with Embedded Swift, which has no Foundation.
+    /// The generated code does not `import Foundation` and omits Foundation-only conveniences,
+    /// such as `Data` overloads for `&[u8]` arguments, `toBytes()`/`init(bytes:)` for `bytes`
+    /// structs and `LocalizedError`/`CustomNSError` conformances.
+    /// See the book for the bridged types that are unavailable in Embedded Swift.
+    /// Defaults to `false`.
+    pub embedded_swift: bool,
+    /// Write a manifest that lists the symbol of every `extern "C"` function that the generated
+    /// Rust code exports, one per line.
+    /// Linker exports lists, such as a `.def` file or a list for `-exported_symbols_list`, can be
+    /// built from it in order to hide every other symbol in the Rust library.
+    /// See [`GeneratedCode::write_all_concatenated`] for where the file is written.
+    /// Defaults to `false`.
+    pub ffi_symbols_manifest: bool,
+}
+
 /// Generated Swift files and C headers.
 pub struct GeneratedCode {
     generated: Vec<GeneratedFromSwiftBridgeModule>,
+    /// See [`ParseBridgesConfig::target_swift_version`].
+    target_swift_version: Option<SwiftVersion>,
 }
 
 impl GeneratedCode {
-    fn new() -> Self {
-        GeneratedCode { generated: vec![] }
+    fn new(target_swift_version: Option<SwiftVersion>) -> Self {
+        GeneratedCode {
+            generated: vec![],
+            target_swift_version,
+        }
     }
 }
 
 impl GeneratedCode {
     /// Write all of the generated Swift to a single Swift file and all of the generated C headers
     /// to a single header file.
+    ///
+    /// When [`ParseBridgesConfig::swift_test_stubs`] is enabled, the test stubs are written to
+    /// `{swift_bridge_out_dir}/{CrateName}Tests.swift`.
+    ///
+    /// When [`ParseBridgesConfig::ffi_symbols_manifest`] is enabled, the FFI symbols are written
+    /// to `{swift_bridge_out_dir}/{crate_name}/{crate_name}.symbols`.
     pub fn write_all_concatenated(&self, swift_bridge_out_dir: impl AsRef<Path>, crate_name: &str) {
         let swift_bridge_out_dir = swift_bridge_out_dir.as_ref();
 
@@ -82,7 +208,34 @@ impl GeneratedCode {
         )
         .unwrap();
 
-        write_core_swift_and_c(swift_bridge_out_dir.as_ref());
+        if let Some(ffi_symbols) = self.concat_ffi_symbols() {
+            std::fs::write(out.join(format!("{}.symbols", crate_name)), ffi_symbols).unwrap();
+        }
+
+        if let Some(test_stubs) = self.concat_swift_test_stubs(crate_name) {
+            std::fs::write(
+                swift_bridge_out_dir.join(format!("{}.swift", test_class_name(crate_name))),
+                test_stubs,
+            )
+            .unwrap();
+        }
+
+        write_core_swift_and_c(swift_bridge_out_dir.as_ref(), self.target_swift_version);
+    }
+
+    /// Write the `extern "C"` functions of every bridge module that uses
+    /// `#[swift_bridge(rust_ffi_file = "...")]` to their files in the given directory.
+    ///
+    /// The bridge modules `include!` these files from `OUT_DIR`, so call this from your build
+    /// script with `std::env::var("OUT_DIR")`.
+    pub fn write_rust_ffi_files(&self, out_dir: impl AsRef<Path>) {
+        let out_dir = out_dir.as_ref();
+
+        for gen in &self.generated {
+            for (file_name, contents) in &gen.rust_ffi_files {
+                std::fs::write(out_dir.join(file_name), contents).unwrap();
+            }
+        }
     }
 
     /// Concatenate all of the generated Swift code into one file.
@@ -106,14 +259,78 @@ impl GeneratedCode {
 
         c_header
     }
+
+    /// List the symbol of every `extern "C"` function that the generated Rust code exports, one
+    /// per line, or `None` if [`ParseBridgesConfig::ffi_symbols_manifest`] is not enabled.
+    pub fn concat_ffi_symbols(&self) -> Option<String> {
+        let mut ffi_symbols = "".to_string();
+
+        for gen in &self.generated {
+            for symbol in gen.ffi_symbols.as_ref()? {
+                ffi_symbols += symbol;
+                ffi_symbols += "\n";
+            }
+        }
+
+        Some(ffi_symbols)
+    }
+
+    /// Concatenate all of the generated Swift test stubs into an XCTest file, or `None` if
+    /// [`ParseBridgesConfig::swift_test_stubs`] is not enabled.
+    pub fn concat_swift_test_stubs(&self, crate_name: &str) -> Option<String> {
+        let mut test_stubs = vec![];
+
+        for gen in &self.generated {
+            let module_test_stubs = gen.swift_test_stubs.as_ref()?;
+            if !module_test_stubs.is_empty() {
+                test_stubs.push(module_test_stubs.as_str());
+            }
+        }
+
+        Some(format!(
+            r#"// File automatically generated by swift-bridge.
+// Move this file into a test target and fill in the placeholders.
+import XCTest
+
+final class {class_name}: XCTestCase {{
+{test_stubs}
+}}
+"#,
+            class_name = test_class_name(crate_name),
+            test_stubs = test_stubs.join("\n\n"),
+        ))
+    }
 }
 
-fn parse_file_contents(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule> {
+/// "my-crate" -> "MyCrateTests"
+fn test_class_name(crate_name: &str) -> String {
+    format!(
+        "{}Tests",
+        NameCasing::PascalCase.convert(&crate_name.replace('-', "_"))
+    )
+}
+
+fn parse_file_contents(
+    file: &str,
+    parse_config: &ParseBridgesConfig,
+) -> syn::Result<GeneratedFromSwiftBridgeModule> {
     let file: File = syn::parse_str(file)?;
 
     let mut generated = GeneratedFromSwiftBridgeModule {
         c_header: "".to_string(),
         swift: "".to_string(),
+        swift_test_stubs: if parse_config.swift_test_stubs {
+            Some("".to_string())
+        } else {
+            None
+        },
+        ffi_symbols: if parse_config.ffi_symbols_manifest {
+            Some(vec![])
+        } else {
+            None
+        },
+        rust_ffi_files: vec![],
+        warnings: vec![],
     };
 
     for item in file.items {
@@ -127,15 +344,33 @@ fn parse_file_contents(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule
                 }) {
                     let module: SwiftBridgeModule = syn::parse2(module.to_token_stream())?;
 
-                    let config = CodegenConfig {
-                        crate_feature_lookup: Box::new(|feature_name| {
-                            let normalized_feature_name = feature_name.replace("-", "_");
-                            let normalized_feature_name = normalized_feature_name.to_uppercase();
+                    let config = codegen_config(parse_config);
+                    module.check_reserved_swift_names(&config)?;
+                    module.check_duplicate_swift_member_names(&config)?;
+                    module.check_target_swift_version(&config)?;
+                    module.check_embedded_swift(&config)?;
+                    generated.warnings.extend(
+                        module
+                            .parse_warnings(&config)
+                            .into_iter()
+                            .map(|warning| warning.to_string()),
+                    );
+                    if let Some(swift_test_stubs) = generated.swift_test_stubs.as_mut() {
+                        let module_test_stubs = module.generate_swift_test_stubs(&config);
+                        if !swift_test_stubs.is_empty() && !module_test_stubs.is_empty() {
+                            *swift_test_stubs += "\n\n";
+                        }
+                        *swift_test_stubs += &module_test_stubs;
+                    }
+
+                    if let Some(ffi_symbols) = generated.ffi_symbols.as_mut() {
+                        ffi_symbols.extend(module.generate_ffi_symbols(&config));
+                    }
+
+                    if let Some(rust_ffi_file) = module.generate_rust_ffi_file() {
+                        generated.rust_ffi_files.push(rust_ffi_file);
+                    }
 
-                            let env_var_name = format!("CARGO_FEATURE_{}", normalized_feature_name);
-                            std::env::var(env_var_name).is_ok()
-                        }),
-                    };
                     let swift_and_c = module.generate_swift_code_and_c_header(config);
 
                     generated.c_header += &swift_and_c.c_header;
@@ -153,8 +388,221 @@ fn parse_file_contents(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule
     Ok(generated)
 }
 
+fn codegen_config(parse_config: &ParseBridgesConfig) -> CodegenConfig {
+    CodegenConfig {
+        crate_feature_lookup: Box::new(|feature_name| {
+            let normalized_feature_name = feature_name.replace("-", "_");
+            let normalized_feature_name = normalized_feature_name.to_uppercase();
+
+            let env_var_name = format!("CARGO_FEATURE_{}", normalized_feature_name);
+            std::env::var(env_var_name).is_ok()
+        }),
+        target_swift_version: parse_config.target_swift_version,
+        log_deinit: parse_config.log_deinit,
+        camel_case_swift_names: parse_config.camel_case_swift_names.clone(),
+        getter_properties: parse_config.getter_properties.clone(),
+        swift_result_functions: parse_config.swift_result_functions,
+        max_function_arguments: parse_config.max_function_arguments,
+        naming_convention: parse_config.naming_convention.clone(),
+        reserved_swift_names: parse_config.reserved_swift_names.clone(),
+        inlinable_accessors: parse_config.inlinable_accessors,
+        open_classes: parse_config.open_classes,
+        ffi_layout_docs: parse_config.ffi_layout_docs,
+        doc_comment_type_links: parse_config.doc_comment_type_links,
+        embedded_swift: parse_config.embedded_swift,
+    }
+}
+
 #[derive(Debug)]
 struct GeneratedFromSwiftBridgeModule {
     c_header: String,
     swift: String,
+    /// `None` if [`ParseBridgesConfig::swift_test_stubs`] is not enabled.
+    swift_test_stubs: Option<String>,
+    /// `None` if [`ParseBridgesConfig::ffi_symbols_manifest`] is not enabled.
+    ffi_symbols: Option<Vec<String>>,
+    /// The names and contents of the files that hold the `extern "C"` functions of modules that
+    /// use `#[swift_bridge(rust_ffi_file = "...")]`.
+    rust_ffi_files: Vec<(String, String)>,
+    warnings: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we can generate the Swift code for an already parsed bridge module as a
+    /// `String`.
+    #[test]
+    fn generates_swift_for_parsed_module() {
+        let module: SwiftBridgeModule = syn::parse_str(
+            r#"
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type Counter;
+
+        fn increment(&mut self);
+        fn make_counter() -> Counter;
+    }
+}
+"#,
+        )
+        .unwrap();
+
+        let swift = generate_swift(&module, &ParseBridgesConfig::default());
+
+        assert!(swift.contains("public class Counter: CounterRefMut {"));
+        assert!(swift.contains("public func increment() {"));
+        assert!(swift.contains("public func make_counter() -> Counter {"));
+        assert!(!swift.contains("#include"));
+    }
+
+    /// Verify that the test stub file contains a test method for each bridged function.
+    #[test]
+    fn generates_test_stub_per_function() {
+        let file = r#"
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type Counter;
+
+        #[swift_bridge(init)]
+        fn new() -> Counter;
+        fn increment(&mut self, amount: u32);
+        fn make_counter() -> Counter;
+    }
+}
+"#;
+
+        let config = ParseBridgesConfig {
+            swift_test_stubs: true,
+            ..Default::default()
+        };
+        let generated = GeneratedCode {
+            generated: vec![parse_file_contents(file, &config).unwrap()],
+            target_swift_version: None,
+        };
+
+        let test_stubs = generated.concat_swift_test_stubs("my-crate").unwrap();
+
+        assert!(test_stubs.contains("import XCTest"));
+        assert!(test_stubs.contains("final class MyCrateTests: XCTestCase {"));
+        assert_eq!(test_stubs.matches("    func test").count(), 3);
+        assert!(test_stubs.contains("    func testCounterInit() {"));
+        assert!(test_stubs.contains("    func testCounterIncrement() {"));
+        assert!(test_stubs.contains("        _ = make_counter()"));
+    }
+
+    /// Verify that no test stubs are generated by default.
+    #[test]
+    fn no_test_stubs_by_default() {
+        let file = r#"
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn some_function();
+    }
+}
+"#;
+
+        let generated = GeneratedCode {
+            generated: vec![parse_file_contents(file, &ParseBridgesConfig::default()).unwrap()],
+            target_swift_version: None,
+        };
+
+        assert!(generated.concat_swift_test_stubs("my-crate").is_none());
+    }
+
+    /// Verify that the FFI symbols manifest lists the symbol of every `extern "C"` function that
+    /// the generated Rust code exports.
+    #[test]
+    fn ffi_symbols_manifest() {
+        let file = r#"
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct Point {
+        x: f64,
+        y: f64,
+    }
+
+    extern "Rust" {
+        fn distance(a: Point, b: Point) -> f64;
+        fn greet(name: &str) -> String;
+    }
+
+    extern "Swift" {
+        fn log_message(message: &str);
+    }
+}
+"#;
+
+        let config = ParseBridgesConfig {
+            ffi_symbols_manifest: true,
+            ..Default::default()
+        };
+        let generated = GeneratedCode {
+            generated: vec![parse_file_contents(file, &config).unwrap()],
+            target_swift_version: None,
+        };
+
+        assert_eq!(
+            generated.concat_ffi_symbols().unwrap(),
+            "__swift_bridge__$distance\n__swift_bridge__$greet\n"
+        );
+    }
+
+    /// Verify that no FFI symbols manifest is generated by default.
+    #[test]
+    fn no_ffi_symbols_manifest_by_default() {
+        let file = r#"
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn some_function();
+    }
+}
+"#;
+
+        let generated = GeneratedCode {
+            generated: vec![parse_file_contents(file, &ParseBridgesConfig::default()).unwrap()],
+            target_swift_version: None,
+        };
+
+        assert!(generated.concat_ffi_symbols().is_none());
+    }
+
+    /// Verify that we write the `extern "C"` functions of a module that uses the `rust_ffi_file`
+    /// attribute to their own Rust file.
+    #[test]
+    fn writes_rust_ffi_file() {
+        let file = r#"
+#[swift_bridge::bridge]
+#[swift_bridge(rust_ffi_file = "counter_ffi.rs")]
+mod ffi {
+    extern "Rust" {
+        type Counter;
+
+        fn increment(&mut self, amount: u32);
+        fn make_counter() -> Counter;
+    }
+}
+"#;
+
+        let generated = GeneratedCode {
+            generated: vec![parse_file_contents(file, &ParseBridgesConfig::default()).unwrap()],
+            target_swift_version: None,
+        };
+
+        let out_dir = std::env::temp_dir().join("swift-bridge-build-writes-rust-ffi-file");
+        std::fs::create_dir_all(&out_dir).unwrap();
+        generated.write_rust_ffi_files(&out_dir);
+
+        let rust_ffi = std::fs::read_to_string(out_dir.join("counter_ffi.rs")).unwrap();
+        assert!(rust_ffi.contains(r#"# [export_name = "__swift_bridge__$Counter$increment"]"#));
+        assert!(rust_ffi.contains("pub extern \"C\" fn __swift_bridge__Counter_increment"));
+        assert!(rust_ffi.contains("pub extern \"C\" fn __swift_bridge__make_counter"));
+        assert!(rust_ffi.contains("pub extern \"C\" fn __swift_bridge__Counter__free"));
+    }
 }
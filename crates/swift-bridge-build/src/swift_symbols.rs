@@ -0,0 +1,282 @@
+//! Lists the public Swift symbols in generated Swift code, so that changes to the Swift API
+//! surface can be caught during review.
+
+use crate::GeneratedCode;
+
+/// A public symbol in the generated Swift code.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SwiftSymbol {
+    /// What kind of symbol this is.
+    pub kind: SwiftSymbolKind,
+    /// The symbol's name, such as `Counter` or `increment`.
+    /// Initializers are named `init`.
+    pub name: String,
+    /// The type or extension that the symbol is declared in, or `None` for top level symbols.
+    pub parent: Option<String>,
+    /// The symbol's declaration, such as `public func increment(_ amount: UInt32) -> UInt32`.
+    pub declaration: String,
+}
+
+/// The kind of a [`SwiftSymbol`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SwiftSymbolKind {
+    /// `public class Name`
+    Class,
+    /// `public struct Name`
+    Struct,
+    /// `public enum Name`
+    Enum,
+    /// `public protocol Name`
+    Protocol,
+    /// `public typealias Name`
+    TypeAlias,
+    /// A top level `public func name`.
+    Function,
+    /// A `public func name` inside of a type or an extension.
+    Method,
+    /// A `public init`.
+    Initializer,
+    /// A `public var name` or `public let name`.
+    Property,
+}
+
+impl GeneratedCode {
+    /// List every public symbol in the generated Swift code, in the order that they were
+    /// generated.
+    ///
+    /// Useful for reviewing changes to a library's Swift API.
+    pub fn swift_symbols(&self) -> Vec<SwiftSymbol> {
+        swift_symbols(&self.concat_swift())
+    }
+}
+
+/// The modifiers that can appear before a declaration's keyword.
+const MODIFIERS: &[&str] = &[
+    "public",
+    "open",
+    "static",
+    "final",
+    "override",
+    "convenience",
+    "required",
+    "mutating",
+    "nonmutating",
+    "nonisolated",
+];
+
+fn swift_symbols(swift: &str) -> Vec<SwiftSymbol> {
+    let mut symbols = vec![];
+
+    // The types and extensions that we are inside of, along with the brace depth that they
+    // were declared at.
+    let mut containers: Vec<(String, usize)> = vec![];
+    let mut depth = 0;
+
+    for line in swift.lines() {
+        let line = line.trim();
+
+        if let Some((symbol, opens_container)) = parse_declaration(line) {
+            let parent = containers.last().map(|(name, _)| name.clone());
+
+            if opens_container {
+                containers.push((symbol.name.clone(), depth));
+            }
+
+            if let Some(kind) = symbol.kind {
+                let kind = match (kind, &parent) {
+                    (SwiftSymbolKind::Function, Some(_)) => SwiftSymbolKind::Method,
+                    (kind, _) => kind,
+                };
+
+                symbols.push(SwiftSymbol {
+                    kind,
+                    name: symbol.name,
+                    parent,
+                    declaration: line.trim_end_matches('{').trim_end().to_string(),
+                });
+            }
+        }
+
+        let mut in_string = false;
+        for c in line.chars() {
+            match c {
+                '"' => in_string = !in_string,
+                '{' if !in_string => depth += 1,
+                '}' if !in_string => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+
+        while containers
+            .last()
+            .map(|(_, container_depth)| depth <= *container_depth)
+            .unwrap_or(false)
+        {
+            containers.pop();
+        }
+    }
+
+    symbols
+}
+
+struct Declaration {
+    /// `None` for declarations that are not symbols, such as extensions.
+    kind: Option<SwiftSymbolKind>,
+    name: String,
+}
+
+/// Parse a line such as `public func foo() {`.
+///
+/// Returns the declaration and whether or not it opens a type or extension that other symbols
+/// can be declared in.
+fn parse_declaration(line: &str) -> Option<(Declaration, bool)> {
+    let mut tokens = line
+        .split_whitespace()
+        .skip_while(|token| token.starts_with('@'))
+        .peekable();
+
+    let mut is_public = false;
+    let keyword = loop {
+        let token = tokens.next()?;
+
+        // `class` is a modifier in `public class func foo()`.
+        let is_class_modifier = token == "class"
+            && matches!(tokens.peek(), Some(&"func") | Some(&"var") | Some(&"let"));
+
+        if MODIFIERS.contains(&token) || is_class_modifier {
+            is_public |= token == "public" || token == "open";
+        } else {
+            break token;
+        }
+    };
+
+    if keyword == "extension" {
+        let name = identifier(tokens.next()?);
+        return Some((Declaration { kind: None, name }, true));
+    }
+
+    if !is_public {
+        return None;
+    }
+
+    if keyword.starts_with("init(") || keyword.starts_with("init<") || keyword == "init?" {
+        return Some((
+            Declaration {
+                kind: Some(SwiftSymbolKind::Initializer),
+                name: "init".to_string(),
+            },
+            false,
+        ));
+    }
+
+    let (kind, opens_container) = match keyword {
+        "class" => (SwiftSymbolKind::Class, true),
+        "struct" => (SwiftSymbolKind::Struct, true),
+        "enum" => (SwiftSymbolKind::Enum, true),
+        "protocol" => (SwiftSymbolKind::Protocol, true),
+        "typealias" => (SwiftSymbolKind::TypeAlias, false),
+        "func" => (SwiftSymbolKind::Function, false),
+        "var" | "let" => (SwiftSymbolKind::Property, false),
+        _ => return None,
+    };
+
+    let name = identifier(tokens.next()?);
+    if name.is_empty() {
+        return None;
+    }
+
+    Some((
+        Declaration {
+            kind: Some(kind),
+            name,
+        },
+        opens_container,
+    ))
+}
+
+/// `foo(_ a: UInt8)` -> `foo`, `Counter:` -> `Counter`
+fn identifier(token: &str) -> String {
+    token
+        .split(|c| matches!(c, '(' | '<' | ':' | '{' | '='))
+        .next()
+        .unwrap()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_file_contents, ParseBridgesConfig};
+
+    /// Verify that we list the public Swift symbols that are generated for a bridge module.
+    #[test]
+    fn lists_generated_swift_symbols() {
+        let file = r#"
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct Point {
+        x: u8,
+    }
+
+    enum Color {
+        Red,
+    }
+
+    extern "Rust" {
+        type Counter;
+
+        #[swift_bridge(init)]
+        fn new() -> Counter;
+        fn increment(&mut self);
+        fn value(&self) -> u32;
+
+        fn free_fn(a: u8) -> u8;
+    }
+
+    extern "Swift" {
+        fn swift_fn();
+    }
+}
+"#;
+        let generated = GeneratedCode {
+            generated: vec![parse_file_contents(file, &ParseBridgesConfig::default()).unwrap()],
+            target_swift_version: None,
+        };
+
+        let symbols = generated.swift_symbols();
+        let symbols: Vec<(SwiftSymbolKind, Option<&str>, &str)> = symbols
+            .iter()
+            .filter(|symbol| !symbol.name.starts_with("vecOfSelf"))
+            .map(|symbol| (symbol.kind, symbol.parent.as_deref(), symbol.name.as_str()))
+            .collect();
+
+        use SwiftSymbolKind::*;
+        assert_eq!(
+            symbols,
+            vec![
+                (Function, None, "free_fn"),
+                (Struct, None, "Point"),
+                (Property, Some("Point"), "x"),
+                (Initializer, Some("Point"), "init"),
+                (Enum, None, "Color"),
+                (Class, None, "Counter"),
+                (Initializer, Some("Counter"), "init"),
+                (Initializer, Some("Counter"), "init"),
+                (Class, None, "CounterRefMut"),
+                (Initializer, Some("CounterRefMut"), "init"),
+                (Method, Some("CounterRefMut"), "increment"),
+                (Class, None, "CounterRef"),
+                (Initializer, Some("CounterRef"), "init"),
+                (Method, Some("CounterRef"), "value"),
+            ]
+        );
+
+        let value = generated
+            .swift_symbols()
+            .into_iter()
+            .find(|symbol| symbol.name == "value")
+            .unwrap();
+        assert_eq!(value.declaration, "public func value() -> UInt32");
+    }
+}
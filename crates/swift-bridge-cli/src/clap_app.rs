@@ -1,4 +1,5 @@
-use clap::{Arg, ArgAction, Command};
+use clap::{value_parser, Arg, ArgAction, Command};
+use swift_bridge_build::{SwiftResultFunctions, SwiftVersion};
 
 /// The CLI application
 pub fn cli() -> Command<'static> {
@@ -133,4 +134,168 @@ fn create_bridges_command() -> Command<'static> {
                 .value_name("PATH")
                 .required(true),
         )
+        .arg(
+            Arg::new("swift-version")
+                .action(ArgAction::Set)
+                .help(
+                    "The Swift version that the generated code will be compiled with (e.g. 5.4). \
+                          Newer Swift language features will not be used.",
+                )
+                .long("swift-version")
+                .value_name("VERSION")
+                .value_parser(|version: &str| version.parse::<SwiftVersion>()),
+        )
+        .arg(
+            Arg::new("log-deinit")
+                .action(ArgAction::SetTrue)
+                .help(
+                    "Log from the deinit of generated Swift classes in DEBUG builds. \
+                          Useful for debugging memory leaks.",
+                )
+                .long("log-deinit"),
+        )
+        .arg(
+            Arg::new("camel-case-names")
+                .action(ArgAction::SetTrue)
+                .help(
+                    "Convert snake_case Rust function and method names into camelCase Swift \
+                          names, such as to_json -> toJSON.",
+                )
+                .long("camel-case-names"),
+        )
+        .arg(
+            Arg::new("acronym")
+                .action(ArgAction::Append)
+                .help(
+                    "An additional word to write in all caps when converting names to camelCase \
+                          (e.g. SQL). Can be passed multiple times.",
+                )
+                .long("acronym")
+                .value_name("ACRONYM")
+                .requires("camel-case-names"),
+        )
+        .arg(
+            Arg::new("getter-properties")
+                .action(ArgAction::SetTrue)
+                .help(
+                    "Generate Swift computed properties for methods that use \
+                          #[swift_bridge(get(...))], such as get_width -> var width.",
+                )
+                .long("getter-properties"),
+        )
+        .arg(
+            Arg::new("getter-prefix")
+                .action(ArgAction::Set)
+                .help(
+                    "The prefix to strip from getter names when generating getter properties. \
+                          Defaults to get_.",
+                )
+                .long("getter-prefix")
+                .value_name("PREFIX")
+                .requires("getter-properties"),
+        )
+        .arg(
+            Arg::new("result-functions")
+                .action(ArgAction::Set)
+                .help(
+                    "Which Swift functions to generate for Rust functions that return a Result: \
+                          throwing (default), result or both.",
+                )
+                .long("result-functions")
+                .value_name("STYLE")
+                .value_parser(|style: &str| style.parse::<SwiftResultFunctions>()),
+        )
+        .arg(
+            Arg::new("max-function-arguments")
+                .action(ArgAction::Set)
+                .help(
+                    "Warn about bridged functions that take more than this many arguments, \
+                          including self.",
+                )
+                .long("max-function-arguments")
+                .value_name("COUNT")
+                .value_parser(value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("lint-naming-convention")
+                .action(ArgAction::SetTrue)
+                .help(
+                    "Warn about bridged types that are not PascalCase and bridged functions that \
+                          are not camelCase in Swift.",
+                )
+                .long("lint-naming-convention"),
+        )
+        .arg(
+            Arg::new("reserved-swift-name")
+                .action(ArgAction::Append)
+                .help(
+                    "A Swift name that generated types and functions are not allowed to have \
+                          (e.g. T). Can be passed multiple times.",
+                )
+                .long("reserved-swift-name")
+                .value_name("NAME"),
+        )
+        .arg(
+            Arg::new("inlinable-accessors")
+                .action(ArgAction::SetTrue)
+                .help(
+                    "Mark generated Swift functions, methods and getter properties that only \
+                          pass primitive values to and from Rust @inlinable.",
+                )
+                .long("inlinable-accessors"),
+        )
+        .arg(
+            Arg::new("open-classes")
+                .action(ArgAction::SetTrue)
+                .help(
+                    "Declare the generated Swift classes for opaque Rust types open, so that \
+                          other Swift modules can subclass them.",
+                )
+                .long("open-classes"),
+        )
+        .arg(
+            Arg::new("swift-test-stubs")
+                .action(ArgAction::SetTrue)
+                .help(
+                    "Also write an XCTest file with an empty test method for each extern \"Rust\" \
+                          function.",
+                )
+                .long("swift-test-stubs"),
+        )
+        .arg(
+            Arg::new("ffi-layout-docs")
+                .action(ArgAction::SetTrue)
+                .help(
+                    "Document the FFI representation of each generated Swift type, such as the \
+                          order and widths of a shared struct's C fields.",
+                )
+                .long("ffi-layout-docs"),
+        )
+        .arg(
+            Arg::new("doc-comment-type-links")
+                .action(ArgAction::SetTrue)
+                .help(
+                    "Link the bridged types that each generated Swift function takes and returns \
+                          from its doc comment.",
+                )
+                .long("doc-comment-type-links"),
+        )
+        .arg(
+            Arg::new("embedded-swift")
+                .action(ArgAction::SetTrue)
+                .help(
+                    "Generate Swift code that is compatible with Embedded Swift by not importing \
+                          Foundation and omitting Foundation-only conveniences.",
+                )
+                .long("embedded-swift"),
+        )
+        .arg(
+            Arg::new("ffi-symbols-manifest")
+                .action(ArgAction::SetTrue)
+                .help(
+                    "Also write a file that lists the symbol of every extern \"C\" function that \
+                          the generated Rust code exports, for use in linker exports lists.",
+                )
+                .long("ffi-symbols-manifest"),
+        )
 }
@@ -1,7 +1,11 @@
 use clap::ArgMatches;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use swift_bridge_build::{create_package, parse_bridges, ApplePlatform, CreatePackageConfig};
+use swift_bridge_build::{
+    create_package, parse_bridges_with_config, ApplePlatform, CamelCaseSwiftNames,
+    CreatePackageConfig, GetterProperties, NamingConvention, ParseBridgesConfig,
+    SwiftResultFunctions, SwiftVersion,
+};
 
 /// Executes the correct function depending on the cli input
 pub fn handle_matches(matches: ArgMatches) {
@@ -43,6 +47,80 @@ fn handle_parse_bridges(matches: &ArgMatches) {
     let crate_name = matches.get_one::<String>("crate-name").unwrap(); // required
     let source_files: Vec<&String> = matches.get_many("source-file").unwrap().collect(); // required
     let output = matches.get_one::<String>("output").map(Path::new).unwrap(); // required
+    let target_swift_version = matches.get_one::<SwiftVersion>("swift-version").copied();
 
-    parse_bridges(source_files.iter().map(Path::new)).write_all_concatenated(output, crate_name);
+    let log_deinit = matches.get_flag("log-deinit");
+
+    let camel_case_swift_names = if matches.get_flag("camel-case-names") {
+        let mut camel_case = CamelCaseSwiftNames::default();
+        if let Some(acronyms) = matches.get_many::<String>("acronym") {
+            camel_case.acronyms.extend(acronyms.cloned());
+        }
+        Some(camel_case)
+    } else {
+        None
+    };
+
+    let getter_properties = if matches.get_flag("getter-properties") {
+        let mut getter_properties = GetterProperties::default();
+        if let Some(prefix) = matches.get_one::<String>("getter-prefix") {
+            getter_properties.strip_prefix = prefix.clone();
+        }
+        Some(getter_properties)
+    } else {
+        None
+    };
+
+    let swift_result_functions = matches
+        .get_one::<SwiftResultFunctions>("result-functions")
+        .copied()
+        .unwrap_or_default();
+
+    let max_function_arguments = matches.get_one::<usize>("max-function-arguments").copied();
+
+    let naming_convention = if matches.get_flag("lint-naming-convention") {
+        Some(NamingConvention::default())
+    } else {
+        None
+    };
+
+    let reserved_swift_names = matches
+        .get_many::<String>("reserved-swift-name")
+        .map(|names| names.cloned().collect())
+        .unwrap_or_default();
+
+    let inlinable_accessors = matches.get_flag("inlinable-accessors");
+
+    let open_classes = matches.get_flag("open-classes");
+
+    let swift_test_stubs = matches.get_flag("swift-test-stubs");
+
+    let ffi_layout_docs = matches.get_flag("ffi-layout-docs");
+
+    let doc_comment_type_links = matches.get_flag("doc-comment-type-links");
+
+    let embedded_swift = matches.get_flag("embedded-swift");
+
+    let ffi_symbols_manifest = matches.get_flag("ffi-symbols-manifest");
+
+    let config = ParseBridgesConfig {
+        target_swift_version,
+        log_deinit,
+        camel_case_swift_names,
+        getter_properties,
+        swift_result_functions,
+        max_function_arguments,
+        naming_convention,
+        reserved_swift_names,
+        inlinable_accessors,
+        open_classes,
+        swift_test_stubs,
+        ffi_layout_docs,
+        doc_comment_type_links,
+        embedded_swift,
+        ffi_symbols_manifest,
+    };
+
+    parse_bridges_with_config(source_files.iter().map(Path::new), config)
+        .write_all_concatenated(output, crate_name);
 }
@@ -1,4 +1,5 @@
-use proc_macro2::Ident;
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
 use syn::parse::{Parse, ParseStream};
 use syn::LitStr;
 use syn::Token;
@@ -34,3 +35,83 @@ impl Parse for CfgAttr {
         }
     }
 }
+
+/// A `#[swift_bridge(...)]` attribute on a bridge module.
+///
+/// ```no_run,ignore
+/// #[swift_bridge::bridge]
+/// #[swift_bridge(auto_suffix_swift_names)]
+/// mod ffi {
+/// }
+/// ```
+pub enum ModuleSwiftBridgeAttr {
+    /// Give colliding Swift declarations distinct names, such as `make` and `make2`, instead of
+    /// failing to compile.
+    AutoSuffixSwiftNames,
+    /// `#[swift_bridge(ffi_visibility = "crate")]`
+    /// The Rust visibility of the generated `extern "C"` functions.
+    FfiVisibility(FfiVisibility),
+    /// `#[swift_bridge(rust_ffi_file = "some_ffi.rs")]`
+    /// Include the generated `extern "C"` functions from a file in `OUT_DIR` that the build
+    /// script writes, instead of expanding them inline.
+    RustFfiFile(LitStr),
+}
+
+/// The Rust visibility of the `extern "C"` functions that a bridge module generates.
+///
+/// This does not change whether or not the functions' symbols are exported, since Swift needs to
+/// link against them. It only controls whether other Rust crates can call them.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub enum FfiVisibility {
+    /// `pub extern "C" fn`
+    #[default]
+    Pub,
+    /// `pub(crate) extern "C" fn`
+    Crate,
+}
+
+impl ToTokens for FfiVisibility {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let vis = match self {
+            FfiVisibility::Pub => quote! { pub },
+            FfiVisibility::Crate => quote! { pub(crate) },
+        };
+        vis.to_tokens(tokens);
+    }
+}
+
+impl Parse for ModuleSwiftBridgeAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        syn::parenthesized!(content in input);
+        let ident: Ident = content.parse()?;
+
+        if &ident == "auto_suffix_swift_names" {
+            Ok(ModuleSwiftBridgeAttr::AutoSuffixSwiftNames)
+        } else if &ident == "ffi_visibility" {
+            content.parse::<Token![=]>()?;
+
+            let visibility = content.parse::<LitStr>()?;
+            match visibility.value().as_str() {
+                "pub" => Ok(ModuleSwiftBridgeAttr::FfiVisibility(FfiVisibility::Pub)),
+                "crate" => Ok(ModuleSwiftBridgeAttr::FfiVisibility(FfiVisibility::Crate)),
+                other => Err(syn::Error::new(
+                    visibility.span(),
+                    format!(
+                        r#"Unknown ffi_visibility "{}". Expected "pub" or "crate"."#,
+                        other
+                    ),
+                )),
+            }
+        } else if &ident == "rust_ffi_file" {
+            content.parse::<Token![=]>()?;
+
+            Ok(ModuleSwiftBridgeAttr::RustFfiFile(content.parse()?))
+        } else {
+            Err(syn::Error::new(
+                ident.span(),
+                format!("Unknown bridge module attribute {}.", ident),
+            ))
+        }
+    }
+}
@@ -9,22 +9,46 @@ use syn::{FnArg, Pat, PatType, Path, ReturnType, Type};
 
 pub(crate) use self::bridged_opaque_type::OpaqueForeignType;
 use crate::bridged_type::boxed_fn::BridgeableBoxedFnOnce;
+use crate::bridged_type::bridgeable_boxed_enum::BridgeableBoxedSharedEnum;
+use crate::bridged_type::bridgeable_boxed_error::BridgeableBoxedError;
+use crate::bridged_type::bridgeable_boxed_str::BridgeableBoxedStr;
+use crate::bridged_type::bridgeable_boxed_struct::BridgeableBoxedSharedStruct;
+use crate::bridged_type::bridgeable_bytes::BridgeableBytes;
+use crate::bridged_type::bridgeable_char::BridgeableChar;
+use crate::bridged_type::bridgeable_cow_slice::BridgeableCowSlice;
+use crate::bridged_type::bridgeable_int_map::BridgeableIntMap;
 use crate::bridged_type::bridgeable_pointer::{BuiltInPointer, Pointee, PointerKind};
+use crate::bridged_type::bridgeable_ref_mut_string::BridgeableRefMutString;
 use crate::bridged_type::bridgeable_result::BuiltInResult;
+use crate::bridged_type::bridgeable_saturating::BridgeableSaturating;
 use crate::bridged_type::bridgeable_string::BridgedString;
+use crate::bridged_type::bridgeable_string_map::BridgeableStringMap;
+use crate::bridged_type::bridgeable_time::BridgeableTime;
 use crate::bridged_type::built_in_tuple::BuiltInTuple;
 
 use crate::parse::{HostLang, TypeDeclaration, TypeDeclarations};
 
 use self::bridged_option::BridgedOption;
-pub(crate) use self::shared_enum::{DeriveAttrs, EnumVariant, SharedEnum};
+pub(crate) use self::shared_enum::{swift_field_equality, DeriveAttrs, EnumVariant, SharedEnum};
 pub(crate) use self::shared_struct::{SharedStruct, StructFields, StructSwiftRepr};
 
 pub(crate) mod boxed_fn;
+pub(crate) mod bridgeable_boxed_enum;
+mod bridgeable_boxed_error;
+mod bridgeable_boxed_str;
+pub(crate) mod bridgeable_boxed_struct;
+mod bridgeable_bytes;
+mod bridgeable_char;
+pub(crate) mod bridgeable_cow_slice;
+mod bridgeable_int_map;
 mod bridgeable_pointer;
+mod bridgeable_ref_mut_string;
 mod bridgeable_result;
+mod bridgeable_saturating;
 pub mod bridgeable_str;
 pub mod bridgeable_string;
+mod bridgeable_string_map;
+mod bridgeable_time;
 pub mod bridged_opaque_type;
 mod bridged_option;
 mod built_in_primitive;
@@ -74,6 +98,40 @@ pub(crate) trait BridgeableType: Debug {
         self.only_encoding().is_some()
     }
 
+    /// Whether or not this type's Swift representation comes from Foundation, such as `Date`,
+    /// meaning that the generated Swift code needs to `import Foundation`.
+    fn uses_swift_foundation(&self) -> bool {
+        false
+    }
+
+    /// Some if this is a `HashMap<String, T>`, which Swift passes to Rust by copying the
+    /// dictionary into buffers that only live for the duration of the call.
+    fn as_string_map(&self) -> Option<&BridgeableStringMap> {
+        None
+    }
+
+    /// Some if this is a `HashMap<K, V>` with integer keys, which Swift passes to Rust by copying
+    /// the dictionary into buffers that only live for the duration of the call.
+    fn as_int_map(&self) -> Option<&BridgeableIntMap> {
+        None
+    }
+
+    /// Whether or not this is a `Vec<T>`.
+    fn is_vec(&self) -> bool {
+        false
+    }
+
+    /// Whether or not this is a `&mut T` where `T` is an opaque Rust type.
+    fn is_opaque_rust_type_ref_mut(&self) -> bool {
+        false
+    }
+
+    /// Some if this is a shared enum that does not have any variants with data, such as
+    /// `enum ParseError { Empty, TooLong }`.
+    fn as_unit_only_shared_enum(&self) -> Option<&SharedEnum> {
+        None
+    }
+
     /// Some if this type can be encoded to exactly one representation.
     /// For example `()` and `struct Foo;` can have exactly one representation,
     /// but `u8` does not since there are 255 possible `u8`s.
@@ -338,6 +396,58 @@ pub(crate) fn bridgeable_type_from_token_stream_str(
         return BridgedString::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
     }
 
+    if BridgeableBoxedError::can_parse_token_stream_str(tokens) {
+        return BridgeableBoxedError::parse_token_stream_str(tokens, types)
+            .map(|o| Box::new(o) as _);
+    }
+
+    if BridgeableBoxedStr::can_parse_token_stream_str(tokens) {
+        return BridgeableBoxedStr::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+
+    if BridgeableBoxedSharedEnum::can_parse_token_stream_str(tokens) {
+        if let Some(boxed_enum) = BridgeableBoxedSharedEnum::parse_token_stream_str(tokens, types) {
+            return Some(Box::new(boxed_enum));
+        }
+    }
+
+    if BridgeableBoxedSharedStruct::can_parse_token_stream_str(tokens) {
+        return BridgeableBoxedSharedStruct::parse_token_stream_str(tokens, types)
+            .map(|o| Box::new(o) as _);
+    }
+
+    if BridgeableCowSlice::can_parse_token_stream_str(tokens) {
+        return BridgeableCowSlice::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+
+    if BridgeableSaturating::can_parse_token_stream_str(tokens) {
+        return BridgeableSaturating::parse_token_stream_str(tokens, types)
+            .map(|o| Box::new(o) as _);
+    }
+
+    if BridgeableChar::can_parse_token_stream_str(tokens) {
+        return BridgeableChar::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+
+    if BridgeableTime::can_parse_token_stream_str(tokens) {
+        return BridgeableTime::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+
+    if BridgeableBytes::can_parse_token_stream_str(tokens) {
+        if let Some(bytes) = BridgeableBytes::parse_token_stream_str(tokens, types) {
+            return Some(Box::new(bytes));
+        }
+    }
+
+    if BridgeableIntMap::can_parse_token_stream_str(tokens) {
+        return BridgeableIntMap::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+
+    if BridgeableStringMap::can_parse_token_stream_str(tokens) {
+        return BridgeableStringMap::parse_token_stream_str(tokens, types)
+            .map(|o| Box::new(o) as _);
+    }
+
     OpaqueForeignType::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _)
 }
 
@@ -417,12 +527,81 @@ pub(crate) enum TypePosition {
 #[derive(Debug)]
 pub(crate) struct BuiltInRefSlice {
     pub ty: Box<BridgedType>,
+    /// Whether or not this is a `&mut [T]`.
+    pub mutable: bool,
+}
+
+impl BuiltInRefSlice {
+    /// Whether or not this is a slice of slices, such as `&[&[i32]]`.
+    pub fn is_jagged(&self) -> bool {
+        matches!(
+            self.ty.deref(),
+            BridgedType::StdLib(StdLibType::RefSlice(_))
+        )
+    }
+
+    /// Whether or not this is a `&[u8]`.
+    pub fn is_byte_slice(&self) -> bool {
+        !self.mutable && matches!(self.ty.deref(), BridgedType::StdLib(StdLibType::U8))
+    }
+
+    /// Whether or not this is a slice of shared structs, such as `&[Point]`.
+    pub fn is_shared_struct_slice(&self) -> bool {
+        !self.mutable
+            && matches!(
+                self.ty.deref(),
+                BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(_)))
+            )
+    }
+
+    /// Whether or not this is a slice of shared structs that Swift passes to Rust, such as
+    /// `fn sum(points: &[Point])`.
+    /// On the Swift side these are passed as an array, and Rust reconstructs each struct from
+    /// its FFI representation.
+    pub fn is_shared_struct_fn_arg_from_swift(&self, type_pos: TypePosition) -> bool {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) => {
+                self.is_shared_struct_slice() && func_host_lang.is_rust()
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether or not this is a mutable slice that Swift passes to Rust, such as
+    /// `fn read(buf: &mut [u8])`.
+    /// On the Swift side these are passed as an `inout` array.
+    pub fn is_mutable_fn_arg_from_swift(&self, type_pos: TypePosition) -> bool {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) => self.mutable && func_host_lang.is_rust(),
+            _ => false,
+        }
+    }
 }
 
 /// Vec<T>
 #[derive(Debug)]
 pub(crate) struct BuiltInVec {
     pub ty: Box<BridgedType>,
+    /// Whether or not this is a `Vec<Option<SomeOpaqueRustType>>`.
+    ///
+    /// These are passed over FFI as a slice of pointers to the opaque Rust types, where `None` is
+    /// a null pointer, and are represented in Swift as a `[SomeOpaqueRustType?]`.
+    pub optional_opaque_rust_type: bool,
+    /// Set when this is a `Vec<Duration>` or a `Vec<SystemTime>`.
+    ///
+    /// These are passed over FFI as a `Vec<f64>` of seconds and are represented in Swift as a
+    /// `[TimeInterval]` or a `[Date]`.
+    pub time_elements: Option<BridgeableTime>,
+    /// Whether or not this is a `Vec<&str>`.
+    ///
+    /// The borrowed strings are copied into a `Vec<String>` before being passed over FFI, so none
+    /// of the references escape, and are represented in Swift as a `[String]`.
+    pub str_elements: bool,
+    /// Whether or not this is a `&mut Vec<T>` argument.
+    ///
+    /// Rust borrows the Swift `RustVec<T>` for the duration of the call, so anything that Rust
+    /// pushes onto it is visible to Swift afterwards.
+    pub mutable_reference: bool,
 }
 
 impl BridgedType {
@@ -466,6 +645,40 @@ impl BridgeableType for BridgedType {
         !self.is_custom_type()
     }
 
+    fn uses_swift_foundation(&self) -> bool {
+        match self {
+            BridgedType::Bridgeable(b) => b.uses_swift_foundation(),
+            BridgedType::StdLib(StdLibType::Option(opt)) => opt.ty.uses_swift_foundation(),
+            BridgedType::StdLib(StdLibType::Vec(vec)) => vec.ty.uses_swift_foundation(),
+            BridgedType::StdLib(StdLibType::Result(result)) => {
+                result.ok_ty.uses_swift_foundation() || result.err_ty.uses_swift_foundation()
+            }
+            _ => false,
+        }
+    }
+
+    fn is_vec(&self) -> bool {
+        matches!(self, BridgedType::StdLib(StdLibType::Vec(_)))
+    }
+
+    fn is_opaque_rust_type_ref_mut(&self) -> bool {
+        match self {
+            BridgedType::Bridgeable(b) => b.is_opaque_rust_type_ref_mut(),
+            _ => false,
+        }
+    }
+
+    fn as_unit_only_shared_enum(&self) -> Option<&SharedEnum> {
+        match self {
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum)))
+                if shared_enum.all_variants_empty() && !shared_enum.variants.is_empty() =>
+            {
+                Some(shared_enum)
+            }
+            _ => None,
+        }
+    }
+
     fn only_encoding(&self) -> Option<OnlyEncoding> {
         match self {
             BridgedType::StdLib(StdLibType::Null) => Some(OnlyEncoding {
@@ -633,12 +846,12 @@ impl BridgeableType for BridgedType {
 
     fn convert_ffi_expression_to_rust_type(
         &self,
-        _expression: &TokenStream,
-        _span: Span,
-        _swift_bridge_path: &Path,
-        _types: &TypeDeclarations,
+        expression: &TokenStream,
+        span: Span,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
     ) -> TokenStream {
-        todo!()
+        self.convert_ffi_expression_to_rust_type(expression, span, swift_bridge_path, types)
     }
 
     fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
@@ -776,11 +989,64 @@ impl BridgedType {
                             return Some(BridgedType::StdLib(StdLibType::Str));
                         }
 
+                        // `&mut u32`
+                        if ty_ref.mutability.is_some() {
+                            let ty = Self::new_with_type(&ty_ref.elem, types);
+                            if let Some(BridgedType::StdLib(
+                                primitive @ (StdLibType::U8
+                                | StdLibType::I8
+                                | StdLibType::U16
+                                | StdLibType::I16
+                                | StdLibType::U32
+                                | StdLibType::I32
+                                | StdLibType::U64
+                                | StdLibType::I64
+                                | StdLibType::Usize
+                                | StdLibType::Isize
+                                | StdLibType::F32
+                                | StdLibType::F64
+                                | StdLibType::Bool),
+                            )) = ty
+                            {
+                                return Some(BridgedType::StdLib(StdLibType::Pointer(
+                                    BuiltInPointer {
+                                        kind: PointerKind::RefMut,
+                                        pointee: Pointee::BuiltIn(Box::new(BridgedType::StdLib(
+                                            primitive,
+                                        ))),
+                                    },
+                                )));
+                            }
+                        }
+
+                        // `&mut String`
+                        if let Some(ref_mut_string) = BridgeableRefMutString::from_type(ty, types) {
+                            return Some(BridgedType::Bridgeable(Box::new(ref_mut_string)));
+                        }
+
+                        // `&mut Vec<T>`
+                        if ty_ref.mutability.is_some() {
+                            if let Some(BridgedType::StdLib(StdLibType::Vec(mut vec))) =
+                                Self::new_with_type(&ty_ref.elem, types)
+                            {
+                                if !vec.optional_opaque_rust_type
+                                    && vec.time_elements.is_none()
+                                    && !vec.str_elements
+                                {
+                                    vec.mutable_reference = true;
+                                    return Some(BridgedType::StdLib(StdLibType::Vec(vec)));
+                                }
+                            }
+                        }
+
                         None
                     }
                 }
                 Type::Slice(slice) => Self::new_with_type(&slice.elem, types).map(|ty| {
-                    BridgedType::StdLib(StdLibType::RefSlice(BuiltInRefSlice { ty: Box::new(ty) }))
+                    BridgedType::StdLib(StdLibType::RefSlice(BuiltInRefSlice {
+                        ty: Box::new(ty),
+                        mutable: ty_ref.mutability.is_some(),
+                    }))
                 }),
                 _ => None,
             },
@@ -817,7 +1083,21 @@ impl BridgedType {
         let tokens = tokens.as_str();
         if tokens.starts_with("Vec < ") {
             let inner = tokens.trim_start_matches("Vec < ");
-            let inner = inner.trim_end_matches(" >");
+            let inner = inner.strip_suffix(" >").unwrap_or(inner);
+
+            let optional_opaque_rust_type = inner
+                .strip_prefix("Option < ")
+                .and_then(|opt_inner| opt_inner.strip_suffix(" >"))
+                .and_then(|opt_inner| types.get(opt_inner))
+                .map(|declared_ty| match declared_ty {
+                    TypeDeclaration::Opaque(opaque) => {
+                        opaque.host_lang.is_rust() && opaque.attributes.copy.is_none()
+                    }
+                    _ => false,
+                })
+                .unwrap_or(false);
+
+            let time_elements = BridgeableTime::parse_token_stream_str(inner, types);
 
             let inner = if let Some(declared_ty) = types.get(inner) {
                 declared_ty.to_bridged_type(false, false)
@@ -826,8 +1106,14 @@ impl BridgedType {
                 BridgedType::new_with_type(&inner, types)?
             };
 
+            let str_elements = matches!(inner, BridgedType::StdLib(StdLibType::Str));
+
             return Some(BridgedType::StdLib(StdLibType::Vec(BuiltInVec {
                 ty: Box::new(inner),
+                optional_opaque_rust_type,
+                time_elements,
+                str_elements,
+                mutable_reference: false,
             })));
         } else if tokens.starts_with("Option < ") {
             let last_bracket = tokens.rfind(">")?;
@@ -852,7 +1138,7 @@ impl BridgedType {
             return Some(BridgedType::StdLib(StdLibType::Result(
                 BuiltInResult::from_str_tokens(&tokens, types)?,
             )));
-        } else if tokens.starts_with("Box < dyn FnOnce") {
+        } else if tokens.starts_with("Box < dyn Fn") {
             return Some(BridgedType::StdLib(StdLibType::BoxedFnOnce(
                 BridgeableBoxedFnOnce::from_str_tokens(&tokens, types)?,
             )));
@@ -916,12 +1202,20 @@ impl BridgedType {
                 StdLibType::Pointer(ptr) => ptr.to_rust_type_path(types),
                 StdLibType::RefSlice(ref_slice) => {
                     let ty = ref_slice.ty.to_rust_type_path(types);
-                    quote! { &[#ty]}
+                    if ref_slice.mutable {
+                        quote! { &mut [#ty]}
+                    } else {
+                        quote! { &[#ty]}
+                    }
                 }
                 StdLibType::Str => quote! { &str },
                 StdLibType::Vec(v) => {
                     let ty = v.ty.to_rust_type_path(types);
-                    quote! { Vec<#ty> }
+                    if v.mutable_reference {
+                        quote! { &mut Vec<#ty> }
+                    } else {
+                        quote! { Vec<#ty> }
+                    }
                 }
                 StdLibType::Option(opt) => {
                     let ty = opt.ty.to_rust_type_path(types);
@@ -999,6 +1293,16 @@ impl BridgedType {
                 StdLibType::Null => {
                     quote! { () }
                 }
+                StdLibType::Vec(ty) if ty.optional_opaque_rust_type => {
+                    let ty = ty.ty.to_ffi_compatible_rust_type(swift_bridge_path, types);
+                    quote! { #swift_bridge_path::FfiSlice<#ty> }
+                }
+                StdLibType::Vec(ty) if ty.time_elements.is_some() => {
+                    quote! { *mut Vec<f64> }
+                }
+                StdLibType::Vec(ty) if ty.str_elements => {
+                    quote! { *mut Vec<String> }
+                }
                 StdLibType::Vec(ty) => {
                     let ty = ty.ty.to_rust_type_path(types);
                     quote! { *mut Vec<#ty> }
@@ -1141,10 +1445,16 @@ impl BridgedType {
                 StdLibType::Pointer(ptr) => {
                     let maybe_mutable = match ptr.kind {
                         PointerKind::Const => "",
-                        PointerKind::Mut => "Mutable",
+                        PointerKind::Mut | PointerKind::RefMut => "Mutable",
                     };
 
                     match &ptr.pointee {
+                        Pointee::BuiltIn(ty) if ptr.is_inout_fn_arg_from_swift(type_pos) => {
+                            format!(
+                                "inout {}",
+                                ty.to_swift_type(type_pos, types, swift_bridge_path)
+                            )
+                        }
                         Pointee::BuiltIn(ty) => {
                             format!(
                                 "Unsafe{}Pointer<{}>",
@@ -1163,6 +1473,25 @@ impl BridgedType {
                         | TypePosition::FnReturn(func_host_lang) => {
                             if func_host_lang.is_swift() {
                                 "__private__FfiSlice".to_string()
+                            } else if let (
+                                TypePosition::FnArg(_, _),
+                                BridgedType::StdLib(StdLibType::RefSlice(inner)),
+                            ) = (type_pos, slice.ty.deref())
+                            {
+                                format!(
+                                    "[[{}]]",
+                                    inner.ty.to_swift_type(type_pos, types, swift_bridge_path)
+                                )
+                            } else if slice.is_mutable_fn_arg_from_swift(type_pos) {
+                                format!(
+                                    "inout [{}]",
+                                    slice.ty.to_swift_type(type_pos, types, swift_bridge_path)
+                                )
+                            } else if slice.is_shared_struct_fn_arg_from_swift(type_pos) {
+                                format!(
+                                    "[{}]",
+                                    slice.ty.to_swift_type(type_pos, types, swift_bridge_path)
+                                )
                             } else {
                                 format!(
                                     "UnsafeBufferPointer<{}>",
@@ -1194,6 +1523,53 @@ impl BridgedType {
                         unimplemented!()
                     }
                 },
+                StdLibType::Vec(ty) if ty.optional_opaque_rust_type => match type_pos {
+                    TypePosition::FnArg(func_host_lang, _)
+                    | TypePosition::FnReturn(func_host_lang) => {
+                        if func_host_lang.is_rust() {
+                            format!(
+                                "[{}]",
+                                ty.ty.to_swift_type(type_pos, types, swift_bridge_path)
+                            )
+                        } else {
+                            "__private__FfiSlice".to_string()
+                        }
+                    }
+                    _ => {
+                        format!(
+                            "[{}]",
+                            ty.ty.to_swift_type(type_pos, types, swift_bridge_path)
+                        )
+                    }
+                },
+                StdLibType::Vec(ty) if ty.str_elements => match type_pos {
+                    TypePosition::FnArg(func_host_lang, _)
+                    | TypePosition::FnReturn(func_host_lang)
+                        if func_host_lang.is_swift() =>
+                    {
+                        "UnsafeMutableRawPointer".to_string()
+                    }
+                    _ => "[String]".to_string(),
+                },
+                StdLibType::Vec(ty) if ty.time_elements.is_some() => match type_pos {
+                    TypePosition::FnArg(func_host_lang, _)
+                    | TypePosition::FnReturn(func_host_lang) => {
+                        if func_host_lang.is_rust() {
+                            format!(
+                                "[{}]",
+                                ty.ty.to_swift_type(type_pos, types, swift_bridge_path)
+                            )
+                        } else {
+                            "UnsafeMutableRawPointer".to_string()
+                        }
+                    }
+                    _ => {
+                        format!(
+                            "[{}]",
+                            ty.ty.to_swift_type(type_pos, types, swift_bridge_path)
+                        )
+                    }
+                },
                 StdLibType::Vec(ty) => match type_pos {
                     TypePosition::FnArg(func_host_lang, _) => {
                         if func_host_lang.is_rust() {
@@ -1284,7 +1660,7 @@ impl BridgedType {
                 StdLibType::Pointer(ptr) => {
                     let maybe_const = match ptr.kind {
                         PointerKind::Const => " const ",
-                        PointerKind::Mut => "",
+                        PointerKind::Mut | PointerKind::RefMut => "",
                     };
 
                     match &ptr.pointee {
@@ -1297,6 +1673,9 @@ impl BridgedType {
                 StdLibType::RefSlice(_slice) => "struct __private__FfiSlice".to_string(),
                 StdLibType::Str => "struct RustStr".to_string(),
                 StdLibType::Null => "void".to_string(),
+                StdLibType::Vec(ty) if ty.optional_opaque_rust_type => {
+                    "struct __private__FfiSlice".to_string()
+                }
                 StdLibType::Vec(_) => "void*".to_string(),
                 StdLibType::Option(opt) => opt.to_c(),
                 StdLibType::Result(result) => result.to_c(types).to_string(),
@@ -1376,6 +1755,11 @@ impl BridgedType {
                 | StdLibType::Bool => {
                     quote! { #expression }
                 }
+                StdLibType::Pointer(ptr) if ptr.kind == PointerKind::RefMut => {
+                    quote! {
+                        #expression as *mut _
+                    }
+                }
                 StdLibType::Pointer(_) => {
                     quote! {
                         #expression
@@ -1391,6 +1775,47 @@ impl BridgedType {
                         #swift_bridge_path::string::RustStr::from_str( #expression )
                     }
                 }
+                StdLibType::Vec(ty) if ty.optional_opaque_rust_type => {
+                    let ptr_ty = ty.ty.to_ffi_compatible_rust_type(swift_bridge_path, types);
+                    quote! {
+                        #swift_bridge_path::FfiSlice::from_vec(
+                            #expression
+                                .into_iter()
+                                .map(|val| {
+                                    if let Some(val) = val {
+                                        Box::into_raw(Box::new(val))
+                                    } else {
+                                        std::ptr::null_mut()
+                                    }
+                                })
+                                .collect::<Vec<#ptr_ty>>()
+                        )
+                    }
+                }
+                StdLibType::Vec(vec) if vec.str_elements => {
+                    quote! {
+                        Box::into_raw(Box::new(
+                            #expression
+                                .into_iter()
+                                .map(|val| val.to_string())
+                                .collect::<Vec<String>>()
+                        ))
+                    }
+                }
+                StdLibType::Vec(vec) if vec.time_elements.is_some() => {
+                    let to_secs = vec.time_elements.unwrap().rust_to_secs(quote! { val });
+                    quote! {
+                        Box::into_raw(Box::new(
+                            #expression
+                                .into_iter()
+                                .map(|val| #to_secs)
+                                .collect::<Vec<f64>>()
+                        ))
+                    }
+                }
+                StdLibType::Vec(vec) if vec.mutable_reference => {
+                    todo!("Support passing a &mut Vec<T> from Rust to Swift")
+                }
                 StdLibType::Vec(_) => {
                     quote! { Box::into_raw(Box::new( #expression )) }
                 }
@@ -1466,15 +1891,72 @@ impl BridgedType {
                 | StdLibType::Bool => {
                     quote_spanned! {span=> #value }
                 }
+                StdLibType::Pointer(ptr) if ptr.kind == PointerKind::RefMut => {
+                    quote_spanned! {span=> unsafe { &mut *#value } }
+                }
                 StdLibType::Pointer(_) => {
                     quote_spanned! {span=> #value }
                 }
-                StdLibType::RefSlice(_reference) => {
-                    quote_spanned! {span=> #value.as_slice() }
+                StdLibType::RefSlice(reference) => {
+                    if reference.is_jagged() {
+                        quote_spanned! {span=> &#value.as_jagged_slices() }
+                    } else if reference.is_shared_struct_slice() {
+                        // Swift gave up ownership of each struct's FFI representation, so we
+                        // move them out of the buffer.
+                        let element = reference.ty.convert_ffi_expression_to_rust_type(
+                            &quote! { unsafe { std::ptr::read(val) } },
+                            span,
+                            swift_bridge_path,
+                            types,
+                        );
+                        quote_spanned! {span=>
+                            &#value
+                                .as_slice()
+                                .iter()
+                                .map(|val| #element)
+                                .collect::<Vec<_>>()
+                        }
+                    } else if reference.mutable {
+                        quote_spanned! {span=> #value.as_mut_slice() }
+                    } else {
+                        quote_spanned! {span=> #value.as_slice() }
+                    }
                 }
                 StdLibType::Str => {
                     quote_spanned! {span=> #value.to_str() }
                 }
+                StdLibType::Vec(ty) if ty.optional_opaque_rust_type => {
+                    quote_spanned! {span=>
+                        #value
+                            .as_slice()
+                            .iter()
+                            .map(|ptr| {
+                                if ptr.is_null() {
+                                    None
+                                } else {
+                                    Some(unsafe { *Box::from_raw(*ptr) })
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                    }
+                }
+                StdLibType::Vec(vec) if vec.str_elements => {
+                    todo!("Support passing Vec<&str> from Swift to Rust")
+                }
+                StdLibType::Vec(vec) if vec.time_elements.is_some() => {
+                    let from_secs = vec.time_elements.unwrap().rust_from_secs(quote! { secs });
+                    quote_spanned! {span=>
+                        unsafe { * Box::from_raw(#value) }
+                            .into_iter()
+                            .map(|secs| #from_secs)
+                            .collect::<Vec<_>>()
+                    }
+                }
+                StdLibType::Vec(vec) if vec.mutable_reference => {
+                    quote_spanned! {span=>
+                        unsafe { &mut * #value }
+                    }
+                }
                 StdLibType::Vec(_) => {
                     quote_spanned! {span=>
                         unsafe { * Box::from_raw(#value) }
@@ -1532,73 +2014,105 @@ impl BridgedType {
                 types,
                 swift_bridge_path,
             ),
-            BridgedType::StdLib(stdlib_type) => match stdlib_type {
-                StdLibType::Null
-                | StdLibType::U8
-                | StdLibType::I8
-                | StdLibType::U16
-                | StdLibType::I16
-                | StdLibType::U32
-                | StdLibType::I32
-                | StdLibType::U64
-                | StdLibType::I64
-                | StdLibType::Usize
-                | StdLibType::Isize
-                | StdLibType::F32
-                | StdLibType::F64
-                | StdLibType::Bool => expression.to_string(),
-                StdLibType::Pointer(ptr) => match &ptr.pointee {
-                    Pointee::BuiltIn(_) => expression.to_string(),
-                    Pointee::Void(_ty) => match ptr.kind {
-                        PointerKind::Const => match type_pos {
-                            TypePosition::FnArg(func_host_lang, _) => {
-                                if func_host_lang.is_rust() {
+            BridgedType::StdLib(stdlib_type) => {
+                match stdlib_type {
+                    StdLibType::Null
+                    | StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+                    | StdLibType::F32
+                    | StdLibType::F64
+                    | StdLibType::Bool => expression.to_string(),
+                    StdLibType::Pointer(ptr) => match &ptr.pointee {
+                        Pointee::BuiltIn(_) => expression.to_string(),
+                        Pointee::Void(_ty) => match ptr.kind {
+                            PointerKind::Const => match type_pos {
+                                TypePosition::FnArg(func_host_lang, _) => {
+                                    if func_host_lang.is_rust() {
+                                        format!("UnsafeRawPointer({}!)", expression)
+                                    } else {
+                                        expression.to_string()
+                                    }
+                                }
+                                TypePosition::FnReturn(_) => {
                                     format!("UnsafeRawPointer({}!)", expression)
-                                } else {
-                                    expression.to_string()
                                 }
-                            }
-                            TypePosition::FnReturn(_) => {
-                                format!("UnsafeRawPointer({}!)", expression)
-                            }
-                            TypePosition::SharedStructField => {
-                                format!("UnsafeRawPointer({}!)", expression)
-                            }
-                            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
-                                unimplemented!()
-                            }
+                                TypePosition::SharedStructField => {
+                                    format!("UnsafeRawPointer({}!)", expression)
+                                }
+                                TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                                    unimplemented!()
+                                }
+                            },
+                            PointerKind::Mut | PointerKind::RefMut => expression.to_string(),
                         },
-                        PointerKind::Mut => expression.to_string(),
                     },
-                },
-                StdLibType::RefSlice(ty) => {
-                    format!(
+                    StdLibType::RefSlice(ty) => {
+                        format!(
                         "let slice = {value}; return UnsafeBufferPointer(start: slice.start.assumingMemoryBound(to: {ty}.self), count: Int(slice.len));",
                         value = expression,
                         ty = ty.ty.to_swift_type(type_pos,types,swift_bridge_path)
                        )
+                    }
+                    StdLibType::Str => expression.to_string(),
+                    StdLibType::Vec(ty) if ty.optional_opaque_rust_type => {
+                        // The owned Swift class, no matter which side of the FFI boundary the
+                        // function is on.
+                        let opaque_ty = match ty.ty.deref() {
+                            BridgedType::StdLib(StdLibType::Option(opt)) => opt.ty.to_swift_type(
+                                TypePosition::SharedStructField,
+                                types,
+                                swift_bridge_path,
+                            ),
+                            _ => unreachable!(),
+                        };
+                        format!(
+                        "{{ let slice = {expression}; let vec = UnsafeBufferPointer(start: slice.start.assumingMemoryBound(to: Optional<UnsafeMutableRawPointer>.self), count: Int(slice.len)).map {{ ptr -> Optional<{opaque_ty}> in if let ptr = ptr {{ return {opaque_ty}(ptr: ptr) }} else {{ return nil }} }}; __swift_bridge__$free_ffi_slice_of_pointers(slice); return vec }}()",
+                        expression = expression,
+                        opaque_ty = opaque_ty
+                    )
+                    }
+                    StdLibType::Vec(ty) if ty.str_elements => {
+                        format!("RustVec<RustString>(ptr: {expression}).map {{ $0.as_str().toString() }}")
+                    }
+                    StdLibType::Vec(ty) if ty.time_elements.is_some() => {
+                        let from_secs = ty.time_elements.unwrap().swift_from_secs("$0");
+                        let array = format!("RustVec<Double>(ptr: {expression}).toArray()");
+                        match ty.time_elements {
+                            Some(BridgeableTime::SystemTime) => {
+                                format!("{array}.map {{ {from_secs} }}")
+                            }
+                            _ => array,
+                        }
+                    }
+                    StdLibType::Vec(_ty) => {
+                        format!("RustVec(ptr: {})", expression)
+                    }
+                    StdLibType::Option(opt) => opt.convert_ffi_expression_to_swift_type(expression),
+                    StdLibType::Result(result) => result.convert_ffi_value_to_swift_value(
+                        expression,
+                        type_pos,
+                        types,
+                        swift_bridge_path,
+                    ),
+                    StdLibType::BoxedFnOnce(fn_once) => {
+                        fn_once.convert_ffi_value_to_swift_value(type_pos)
+                    }
+                    StdLibType::Tuple(tuple) => tuple.convert_ffi_expression_to_swift_type(
+                        expression,
+                        type_pos,
+                        types,
+                        swift_bridge_path,
+                    ),
                 }
-                StdLibType::Str => expression.to_string(),
-                StdLibType::Vec(_ty) => {
-                    format!("RustVec(ptr: {})", expression)
-                }
-                StdLibType::Option(opt) => opt.convert_ffi_expression_to_swift_type(expression),
-                StdLibType::Result(result) => result.convert_ffi_value_to_swift_value(
-                    expression,
-                    type_pos,
-                    types,
-                    swift_bridge_path,
-                ),
-                StdLibType::BoxedFnOnce(fn_once) => {
-                    fn_once.convert_ffi_value_to_swift_value(type_pos)
-                }
-                StdLibType::Tuple(tuple) => tuple.convert_ffi_expression_to_swift_type(
-                    expression,
-                    type_pos,
-                    types,
-                    swift_bridge_path,
-                ),
-            },
+            }
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 shared_struct.convert_ffi_expression_to_swift_type(expression)
             }
@@ -1640,10 +2154,26 @@ impl BridgedType {
                 | StdLibType::F32
                 | StdLibType::F64
                 | StdLibType::Bool => expression.to_string(),
-                StdLibType::RefSlice(_) => {
-                    format!("{}.toFfiSlice()", expression)
-                }
+                StdLibType::RefSlice(slice) => match type_pos {
+                    TypePosition::FnArg(func_host_lang, _) if slice.is_jagged() => {
+                        if func_host_lang.is_rust() {
+                            format!("{}AsFfiSlice", expression)
+                        } else {
+                            format!("{}.toFfiSlice()", expression)
+                        }
+                    }
+                    TypePosition::FnArg(_, _)
+                        if slice.is_mutable_fn_arg_from_swift(type_pos)
+                            || slice.is_shared_struct_fn_arg_from_swift(type_pos) =>
+                    {
+                        format!("{}AsFfiSlice", expression)
+                    }
+                    _ => format!("{}.toFfiSlice()", expression),
+                },
                 StdLibType::Pointer(ptr) => match &ptr.pointee {
+                    Pointee::BuiltIn(_) if ptr.is_inout_fn_arg_from_swift(type_pos) => {
+                        format!("&{}", expression)
+                    }
                     Pointee::BuiltIn(_) => expression.to_string(),
                     Pointee::Void(_ty) => match type_pos {
                         TypePosition::FnArg(func_host_lang, _)
@@ -1678,6 +2208,27 @@ impl BridgedType {
                         unimplemented!()
                     }
                 },
+                StdLibType::Vec(ty) if ty.optional_opaque_rust_type => match type_pos {
+                    TypePosition::FnArg(func_host_lang, _) if func_host_lang.is_rust() => {
+                        format!("{}AsFfiSlice", expression)
+                    }
+                    _ => {
+                        todo!("Support passing Vec<Option<SomeOpaqueRustType>> from Swift to Rust in this position")
+                    }
+                },
+                StdLibType::Vec(vec) if vec.str_elements => {
+                    todo!("Support passing Vec<&str> from Swift to Rust")
+                }
+                StdLibType::Vec(vec) if vec.time_elements.is_some() => {
+                    let secs = vec.time_elements.unwrap().swift_to_secs("val");
+                    format!(
+                        "{{ let vec = RustVec<Double>(); for val in {expression} {{ vec.push(value: {secs}) }}; vec.isOwned = false; return vec.ptr }}()"
+                    )
+                }
+                StdLibType::Vec(vec) if vec.mutable_reference => {
+                    // Rust borrows the vector, so Swift keeps ownership of it.
+                    format!("{}.ptr", expression)
+                }
                 StdLibType::Vec(_) => {
                     format!(
                         "{{ let val = {value}; val.isOwned = false; return val.ptr }}()",
@@ -1758,6 +2309,7 @@ impl BridgedType {
                 StdLibType::RefSlice(slice) => slice.ty.to_c_include(types),
                 StdLibType::Vec(_vec) => Some(vec!["stdint.h"]),
                 StdLibType::Tuple(tuple) => tuple.to_c_include(types),
+                StdLibType::Result(result) => result.to_c_include(),
                 _ => None,
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(_shared_struct))) => {
@@ -1899,8 +2451,15 @@ impl BridgedType {
     }
 
     /// Convert a rust expression into this type using
-    pub fn rust_expression_into(&self, expression: &TokenStream) -> TokenStream {
+    pub fn rust_expression_into(
+        &self,
+        expression: &TokenStream,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
         match self {
+            BridgedType::StdLib(StdLibType::Result(result)) => {
+                result.rust_expression_map_err_into(expression, types)
+            }
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 let struct_name = &shared_struct.name;
 
@@ -1952,6 +2511,12 @@ impl BridgedType {
                 StdLibType::F32 => "F32".to_string(),
                 StdLibType::F64 => "F64".to_string(),
                 StdLibType::Tuple(ty) => ty.to_alpha_numeric_underscore_name(types),
+                StdLibType::Vec(v) => {
+                    format!("Vec_{}", v.ty.to_alpha_numeric_underscore_name(types))
+                }
+                StdLibType::Option(opt) => {
+                    format!("Option{}", opt.ty.to_alpha_numeric_underscore_name(types))
+                }
                 _ => todo!(),
             },
             BridgedType::Foreign(ty) => match ty {
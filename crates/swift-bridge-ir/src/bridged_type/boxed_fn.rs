@@ -10,13 +10,36 @@ use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::{Path, Type};
 
-/// Box<dyn FnOnce(A, B, C) -> ()>
+/// Box<dyn FnOnce(A, B, C) -> ()> or Box<dyn Fn(A, B, C) -> ()>
 #[derive(Debug)]
 pub(crate) struct BridgeableBoxedFnOnce {
     /// The functions parameters.
     pub params: Vec<BridgedType>,
     /// The functions return type.
     pub ret: Box<BridgedType>,
+    /// Whether the boxed function is an `FnOnce` or an `Fn`.
+    pub fn_trait: BoxedFnTrait,
+}
+
+/// The trait that a boxed function implements.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum BoxedFnTrait {
+    /// `Box<dyn FnOnce(A, B) -> C>`
+    /// Freed after Swift calls it, so Swift can call it at most once.
+    FnOnce,
+    /// `Box<dyn Fn(A, B) -> C>`
+    /// Borrowed each time Swift calls it and freed once Swift no longer holds on to it, so
+    /// Swift can call it any number of times.
+    Fn,
+}
+
+impl ToTokens for BoxedFnTrait {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            BoxedFnTrait::FnOnce => quote! { FnOnce }.to_tokens(tokens),
+            BoxedFnTrait::Fn => quote! { Fn }.to_tokens(tokens),
+        }
+    }
 }
 
 /// example: Vec<SomeType, AnotherType, u32>
@@ -33,8 +56,50 @@ impl BridgeableBoxedFnOnce {
         self.params.is_empty() && self.ret.is_null()
     }
 
+    /// Whether we use the pre-defined `__private__RustFnOnceCallbackNoArgsNoRet` Swift class for
+    /// this callback instead of generating a class for it.
+    pub fn uses_no_args_no_ret_fn_once_class(&self) -> bool {
+        self.fn_trait == BoxedFnTrait::FnOnce && self.does_not_have_params_or_return()
+    }
+
+    /// The name of the Swift class that holds on to the boxed function.
+    ///
+    /// `__private__RustFnOnceCallback$SomeType$some_method$param1`
+    pub fn swift_callback_class_name(
+        &self,
+        maybe_associated_ty: &str,
+        fn_name: &str,
+        idx: usize,
+    ) -> String {
+        if self.uses_no_args_no_ret_fn_once_class() {
+            return "__private__RustFnOnceCallbackNoArgsNoRet".to_string();
+        }
+
+        let callback_kind = match self.fn_trait {
+            BoxedFnTrait::FnOnce => "RustFnOnceCallback",
+            BoxedFnTrait::Fn => "RustFnCallback",
+        };
+        format!("__private__{callback_kind}{maybe_associated_ty}${fn_name}$param{idx}")
+    }
+
+    /// Call the boxed function that Swift passed back to Rust.
+    ///
+    /// An `FnOnce` is consumed by the call, while an `Fn` is only borrowed since Swift may call
+    /// it again.
+    pub fn call_ffi_boxed_fn(&self, boxed_fn: &Ident, call_args: &[TokenStream]) -> TokenStream {
+        match self.fn_trait {
+            BoxedFnTrait::FnOnce => quote! {
+                unsafe { Box::from_raw(#boxed_fn)(#(#call_args),*) }
+            },
+            BoxedFnTrait::Fn => quote! {
+                unsafe { (*#boxed_fn)(#(#call_args),*) }
+            },
+        }
+    }
+
     /// Box<dyn FnOnce(A, B) -> C>
     pub fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let fn_trait = self.fn_trait;
         let args: Vec<TokenStream> = self
             .params
             .iter()
@@ -42,7 +107,7 @@ impl BridgeableBoxedFnOnce {
             .collect();
         let ret = &self.ret.to_rust_type_path(types);
         quote! {
-            Box<dyn FnOnce(#(#args),*) -> #ret>
+            Box<dyn #fn_trait(#(#args),*) -> #ret>
         }
     }
 
@@ -57,9 +122,10 @@ impl BridgeableBoxedFnOnce {
             .map(|a| a.to_rust_type_path(types))
             .collect();
         let ret = &self.ret.to_rust_type_path(types);
+        let fn_trait = self.fn_trait;
 
         quote! {
-            Box::into_raw(Box::new(#expression)) as *mut Box<dyn FnOnce(#(#args),*) -> #ret>
+            Box::into_raw(Box::new(#expression)) as *mut Box<dyn #fn_trait(#(#args),*) -> #ret>
         }
     }
 
@@ -70,8 +136,9 @@ impl BridgeableBoxedFnOnce {
             .map(|a| a.to_rust_type_path(types))
             .collect();
         let ret = &self.ret.to_rust_type_path(types);
+        let fn_trait = self.fn_trait;
         quote! {
-            *mut Box<dyn FnOnce(#(#params),*) -> #ret>
+            *mut Box<dyn #fn_trait(#(#params),*) -> #ret>
         }
     }
 
@@ -203,7 +270,7 @@ impl BridgeableBoxedFnOnce {
     pub fn convert_ffi_value_to_swift_value(&self, type_pos: TypePosition) -> String {
         match type_pos {
             TypePosition::FnArg(_, param_idx) => {
-                if self.does_not_have_params_or_return() {
+                if self.uses_no_args_no_ret_fn_once_class() {
                     format!("{{ cb{param_idx}.call() }}")
                 } else if self.params.len() > 0 {
                     let args = self.to_swift_call_args();
@@ -253,7 +320,15 @@ impl BridgeableBoxedFnOnce {
         // ( A , B , C ) -> D >
         //   OR
         // ( A , B , C ) >
-        let signature = string.trim_start_matches("Box < dyn FnOnce");
+        let (signature, fn_trait) = if let Some(signature) = string.strip_prefix("Box < dyn FnOnce")
+        {
+            (signature, BoxedFnTrait::FnOnce)
+        } else {
+            (string.strip_prefix("Box < dyn Fn")?, BoxedFnTrait::Fn)
+        };
+        if !signature.trim_start().starts_with('(') {
+            return None;
+        }
 
         let open_parens = signature.find("(").unwrap();
         let closing_parens = signature.find(")").unwrap();
@@ -287,6 +362,7 @@ impl BridgeableBoxedFnOnce {
         return Some(BridgeableBoxedFnOnce {
             params: args_bridged_tys,
             ret: Box::new(ret),
+            fn_trait,
         });
     }
 }
@@ -354,6 +430,28 @@ mod tests {
         );
     }
 
+    /// Verify that we can parse a `Box<dyn Fn>`, and that we do not parse a `Box<dyn FnMut>`.
+    #[test]
+    fn boxed_fn_from_string() {
+        let tokens = quote! {Box<dyn Fn(u8) -> u16>}
+            .to_token_stream()
+            .to_string();
+        let boxed_fn =
+            BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default()).unwrap();
+        assert_eq!(boxed_fn.fn_trait, BoxedFnTrait::Fn);
+        assert_eq!(boxed_fn.params.len(), 1);
+
+        let tokens = quote! {Box<dyn FnOnce(u8)>}.to_token_stream().to_string();
+        let boxed_fn =
+            BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default()).unwrap();
+        assert_eq!(boxed_fn.fn_trait, BoxedFnTrait::FnOnce);
+
+        let tokens = quote! {Box<dyn FnMut(u8)>}.to_token_stream().to_string();
+        assert!(
+            BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default()).is_none()
+        );
+    }
+
     /// Verify that we can parse a boxed fn that has a comma after the FnOnce.
     /// rustfmt adds a trailing comma when it puts a long function signature on its own line.
     #[test]
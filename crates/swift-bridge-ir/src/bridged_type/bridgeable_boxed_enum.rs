@@ -0,0 +1,315 @@
+//! `Box<SomeSharedEnum>`, which allows shared enums to be recursive.
+//! For example `enum Expr { Number(i64), Add(Box<Expr>, Box<Expr>) }`.
+//!
+//! On the Swift side a boxed enum is represented as the enum itself, and the variants that hold
+//! it are generated as `indirect` cases so that the Swift enum can be recursive.
+//!
+//! Over FFI a `Box<SomeSharedEnum>` is a pointer to a heap allocated
+//! `__swift_bridge__SomeSharedEnum`.
+
+use crate::bridged_type::{
+    BridgeableType, CFfiStruct, OnlyEncoding, SharedEnum, TypePosition, UnusedOptionNoneValue,
+};
+use crate::parse::{SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{Path, Type};
+
+#[derive(Debug)]
+pub(crate) struct BridgeableBoxedSharedEnum {
+    pub shared_enum: SharedEnum,
+}
+
+impl BridgeableBoxedSharedEnum {
+    /// __swift_bridge__$SomeEnum$_box
+    fn box_func_name(&self) -> String {
+        format!("{}$_box", self.shared_enum.ffi_name_string())
+    }
+
+    /// __swift_bridge__$SomeEnum$_unbox
+    fn unbox_func_name(&self) -> String {
+        format!("{}$_unbox", self.shared_enum.ffi_name_string())
+    }
+}
+
+impl BridgeableType for BridgeableBoxedSharedEnum {
+    fn is_built_in_type(&self) -> bool {
+        false
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&super::bridgeable_result::BuiltInResult> {
+        None
+    }
+
+    fn as_option(&self) -> Option<&super::bridged_option::BridgedOption> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        let ty_name = &self.shared_enum.name;
+
+        if self.shared_enum.already_declared {
+            quote! { Box<super::#ty_name> }
+        } else {
+            quote! { Box<#ty_name> }
+        }
+    }
+
+    fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang)
+                if !func_host_lang.is_rust() =>
+            {
+                "UnsafeMutableRawPointer".to_string()
+            }
+            _ => self.shared_enum.swift_name_string(),
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty_name = &self.shared_enum.name;
+
+        if self.shared_enum.already_declared {
+            quote! { *mut <super:: #ty_name as #swift_bridge_path::SharedEnum>::FfiRepr }
+        } else {
+            let ffi_ty_name = self.shared_enum.ffi_name_tokens();
+            quote! { *mut #ffi_ty_name }
+        }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Option<Box<SharedEnum>> is not yet supported")
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        todo!("Option<Box<SharedEnum>> is not yet supported")
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        todo!("Option<Box<SharedEnum>> is not yet supported")
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        quote! {
+            Box::into_raw(Box::new((*#expression).into_ffi_repr()))
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        todo!("Option<Box<SharedEnum>> is not yet supported")
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{box_func}({expression}.intoFfiRepr())",
+            box_func = self.box_func_name()
+        )
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        todo!("Option<Box<SharedEnum>> is not yet supported")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! {
+            Box::new(unsafe { *Box::from_raw(#expression) }.into_rust_repr())
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+        todo!("Option<Box<SharedEnum>> is not yet supported")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> String {
+        format!(
+            "{unbox_func}({expression}).intoSwiftRepr()",
+            unbox_func = self.unbox_func_name()
+        )
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        todo!("Option<Box<SharedEnum>> is not yet supported")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!()
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!()
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        todo!("Option<Box<SharedEnum>> is not yet supported")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("Box < ") && !tokens.starts_with("Box < dyn")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let inner = tokens.trim_start_matches("Box < ").trim_end_matches(" >");
+
+        match types.get(inner)? {
+            TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
+                Some(BridgeableBoxedSharedEnum {
+                    shared_enum: shared_enum.clone(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        format!("Box_{}", self.shared_enum.swift_name_string())
+    }
+}
+
+/// Whether or not any shared enum in the module has a variant with a `Box<shared_enum>` field.
+///
+/// If so we need to generate the functions that Swift uses to move the enum's FFI
+/// representation onto and off of the Rust heap.
+pub(crate) fn shared_enum_is_boxed(shared_enum: &SharedEnum, types: &TypeDeclarations) -> bool {
+    let boxed = format!("Box<{}>", shared_enum.name);
+
+    types.types().into_iter().any(|ty| match ty {
+        TypeDeclaration::Shared(SharedTypeDeclaration::Enum(e)) => {
+            e.variants.iter().any(|variant| {
+                variant.fields.normalized_fields().iter().any(|norm_field| {
+                    norm_field.ty.to_token_stream().to_string().replace(" ", "") == boxed
+                })
+            })
+        }
+        _ => false,
+    })
+}
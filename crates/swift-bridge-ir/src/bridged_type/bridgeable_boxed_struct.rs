@@ -0,0 +1,390 @@
+//! `Box<SomeSharedStruct>`, which allows shared structs to be recursive.
+//! For example `struct TreeNode { left: Option<Box<TreeNode>> }`.
+//!
+//! On the Swift side a boxed struct is represented as a `RustBox<SomeSharedStruct>`, since Swift
+//! structs cannot directly contain themselves.
+//!
+//! Over FFI an `Option<Box<SomeSharedStruct>>` is a pointer to a heap allocated
+//! `__swift_bridge__SomeSharedStruct`, where `None` is a null pointer.
+
+use crate::bridged_type::{
+    BridgeableType, CFfiStruct, OnlyEncoding, SharedStruct, TypePosition, UnusedOptionNoneValue,
+};
+use crate::parse::{SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{GenericArgument, Path, PathArguments, Type};
+
+#[derive(Debug)]
+pub(crate) struct BridgeableBoxedSharedStruct {
+    pub shared_struct: SharedStruct,
+}
+
+impl BridgeableBoxedSharedStruct {
+    /// __swift_bridge__$SomeStruct$_box
+    fn box_func_name(&self) -> String {
+        format!("{}$_box", self.shared_struct.ffi_name_string())
+    }
+
+    /// __swift_bridge__$SomeStruct$_unbox
+    fn unbox_func_name(&self) -> String {
+        format!("{}$_unbox", self.shared_struct.ffi_name_string())
+    }
+}
+
+impl BridgeableType for BridgeableBoxedSharedStruct {
+    fn is_built_in_type(&self) -> bool {
+        false
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&super::bridgeable_result::BuiltInResult> {
+        None
+    }
+
+    fn as_option(&self) -> Option<&super::bridged_option::BridgedOption> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        let ty_name = &self.shared_struct.name;
+
+        if self.shared_struct.already_declared {
+            quote! { Box<super::#ty_name> }
+        } else {
+            quote! { Box<#ty_name> }
+        }
+    }
+
+    fn to_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> String {
+        format!("RustBox<{}>", self.shared_struct.swift_name_string())
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self
+            .shared_struct
+            .type_name_with_swift_bridge_prefix(swift_bridge_path);
+        quote! { *mut #ty }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.to_ffi_compatible_rust_type(swift_bridge_path, types)
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        "UnsafeMutableRawPointer?".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "void*".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        unreachable!("Box<SharedStruct> outside of an Option is rejected during parsing")
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        quote! {
+            if let Some(val) = #expression {
+                Box::into_raw(Box::new((*val).into_ffi_repr()))
+            } else {
+                std::ptr::null_mut()
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        unreachable!("Box<SharedStruct> outside of an Option is rejected during parsing")
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ if let boxed = {expression} {{ return {box_func}(boxed.value.intoFfiRepr()) }} else {{ return nil }} }}()",
+            expression = expression,
+            box_func = self.box_func_name()
+        )
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Box<SharedStruct> outside of an Option is rejected during parsing")
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        quote! {
+            {
+                let val = #expression;
+
+                if val.is_null() {
+                    None
+                } else {
+                    Some(Box::new(unsafe { *Box::from_raw(val) }.into_rust_repr()))
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> String {
+        unreachable!("Box<SharedStruct> outside of an Option is rejected during parsing")
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!(
+            "{{ if let ptr = {expression} {{ return RustBox({unbox_func}(ptr).intoSwiftRepr()) }} else {{ return nil }} }}()",
+            expression = expression,
+            unbox_func = self.unbox_func_name()
+        )
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Box<SharedStruct> outside of an Option is rejected during parsing")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Box<SharedStruct> outside of an Option is rejected during parsing")
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! { std::ptr::null_mut() },
+            swift: "nil".to_string(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("Box < ") && !tokens.starts_with("Box < dyn")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let inner = tokens.trim_start_matches("Box < ").trim_end_matches(" >");
+
+        match types.get(inner)? {
+            TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                Some(BridgeableBoxedSharedStruct {
+                    shared_struct: shared_struct.clone(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        format!("Box_{}", self.shared_struct.swift_name_string())
+    }
+}
+
+/// Whether or not any shared struct in the module has an `Option<Box<shared_struct>>` field.
+///
+/// If so we need to generate the functions that Swift uses to move the struct's FFI
+/// representation onto and off of the Rust heap.
+pub(crate) fn shared_struct_is_boxed(
+    shared_struct: &SharedStruct,
+    types: &TypeDeclarations,
+) -> bool {
+    types.types().into_iter().any(|ty| match ty {
+        TypeDeclaration::Shared(SharedTypeDeclaration::Struct(s)) => {
+            s.fields.normalized_fields().iter().any(|norm_field| {
+                let boxed = generic_arg(&norm_field.ty, "Option")
+                    .and_then(|inner| generic_arg(inner, "Box"));
+                match boxed {
+                    Some(Type::Path(path)) => path.path.is_ident(&shared_struct.name),
+                    _ => false,
+                }
+            })
+        }
+        _ => false,
+    })
+}
+
+/// Find a `Box<SomeSharedStruct>` within the type that is not directly wrapped in an `Option`.
+///
+/// Only `Option<Box<SomeSharedStruct>>` can currently be bridged.
+pub(crate) fn find_unsupported_boxed_shared_struct<'a>(
+    ty: &'a Type,
+    types: &TypeDeclarations,
+) -> Option<&'a Type> {
+    match ty {
+        Type::Path(path) => {
+            if let Some(boxed) =
+                generic_arg(ty, "Option").filter(|t| generic_arg(t, "Box").is_some())
+            {
+                return generic_arg(boxed, "Box")
+                    .and_then(|inner| find_unsupported_boxed_shared_struct(inner, types));
+            }
+
+            if let Some(Type::Path(inner)) = generic_arg(ty, "Box") {
+                let inner_name = inner.path.to_token_stream().to_string();
+                if let Some(TypeDeclaration::Shared(SharedTypeDeclaration::Struct(_))) =
+                    types.get(&inner_name)
+                {
+                    return Some(ty);
+                }
+            }
+
+            let last = path.path.segments.last()?;
+            match &last.arguments {
+                PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+                    GenericArgument::Type(inner) => {
+                        find_unsupported_boxed_shared_struct(inner, types)
+                    }
+                    _ => None,
+                }),
+                _ => None,
+            }
+        }
+        Type::Reference(reference) => find_unsupported_boxed_shared_struct(&reference.elem, types),
+        Type::Tuple(tuple) => tuple
+            .elems
+            .iter()
+            .find_map(|elem| find_unsupported_boxed_shared_struct(elem, types)),
+        _ => None,
+    }
+}
+
+/// `Wrapper<T>` -> `Some(T)`, where `Wrapper` is the given `wrapper` identifier.
+fn generic_arg<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let path = match ty {
+        Type::Path(path) => path,
+        _ => return None,
+    };
+    let last = path.path.segments.last()?;
+    if last.ident != wrapper {
+        return None;
+    }
+
+    match &last.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,334 @@
+//! `Cow<[T]>`, where `T` is a primitive such as `u8`.
+//!
+//! A `Cow<[T]>` that gets returned from Rust is seen on the Swift side as an owned `[T]`.
+//! Over FFI it is passed as a `*mut Vec<T>`, so borrowed and owned `Cow`s both end up being
+//! copied into a Swift `Array`, after which the Rust `Vec` gets freed.
+
+use crate::bridged_type::{
+    BridgeableType, BridgedType, CFfiStruct, OnlyEncoding, StdLibType, TypePosition,
+    UnusedOptionNoneValue,
+};
+use crate::parse::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{GenericArgument, Path, PathArguments, Type};
+
+#[derive(Debug)]
+pub(crate) struct BridgeableCowSlice {
+    /// The slice's element type, such as the `u8` in `Cow<[u8]>`.
+    pub elem: BridgedType,
+}
+
+impl BridgeableCowSlice {
+    const PREFIXES: [&'static str; 3] = ["Cow<", "borrow::Cow<", "std::borrow::Cow<"];
+
+    /// Remove whitespace so that "Cow < 'a , [u8] >" and "Cow<'a, [u8]>" are handled the same.
+    fn normalize(tokens: &str) -> String {
+        tokens.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+}
+
+impl BridgeableType for BridgeableCowSlice {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&super::bridgeable_result::BuiltInResult> {
+        None
+    }
+
+    fn as_option(&self) -> Option<&super::bridged_option::BridgedOption> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let elem = self.elem.to_rust_type_path(types);
+        quote! { std::borrow::Cow<[#elem]> }
+    }
+
+    fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnReturn(host_lang) if host_lang.is_rust() => format!(
+                "Array<{}>",
+                self.elem.to_swift_type(type_pos, types, swift_bridge_path)
+            ),
+            _ => "UnsafeMutableRawPointer".to_string(),
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let elem = self.elem.to_rust_type_path(types);
+        quote! { *mut Vec<#elem> }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Option<Cow<[T]>> is not yet supported")
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        todo!("Option<Cow<[T]>> is not yet supported")
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        todo!("Option<Cow<[T]>> is not yet supported")
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        quote! { Box::into_raw(Box::new(#expression.into_owned())) }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        todo!("Option<Cow<[T]>> is not yet supported")
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        unreachable!("Cow<[T]> outside of a Rust function's return type is rejected during parsing")
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        todo!("Option<Cow<[T]>> is not yet supported")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Cow<[T]> outside of a Rust function's return type is rejected during parsing")
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+        todo!("Option<Cow<[T]>> is not yet supported")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        format!(
+            "Array(RustVec<{elem}>(ptr: {expression}))",
+            elem = self.elem.to_swift_type(type_pos, types, swift_bridge_path),
+            expression = expression
+        )
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        todo!("Option<Cow<[T]>> is not yet supported")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Cow<[T]> outside of a Rust function's return type is rejected during parsing")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Cow<[T]> outside of a Rust function's return type is rejected during parsing")
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! { std::ptr::null_mut() },
+            swift: "nil".to_string(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        let tokens = Self::normalize(tokens);
+        Self::PREFIXES
+            .iter()
+            .any(|prefix| tokens.starts_with(prefix))
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    /// "Cow < [u8] >" or "Cow < 'a , [u8] >"
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let tokens = Self::normalize(tokens);
+        let generics = Self::PREFIXES
+            .iter()
+            .find_map(|prefix| tokens.strip_prefix(prefix))?
+            .strip_suffix(">")?;
+
+        let slice = match generics.split_once(',') {
+            Some((lifetime, slice)) if lifetime.starts_with('\'') => slice,
+            _ => generics,
+        };
+        let elem = slice.strip_prefix("[")?.strip_suffix("]")?;
+
+        let elem = BridgedType::new_with_str(elem, types)?;
+        match &elem {
+            BridgedType::StdLib(
+                StdLibType::U8
+                | StdLibType::I8
+                | StdLibType::U16
+                | StdLibType::I16
+                | StdLibType::U32
+                | StdLibType::I32
+                | StdLibType::U64
+                | StdLibType::I64
+                | StdLibType::Usize
+                | StdLibType::Isize
+                | StdLibType::F32
+                | StdLibType::F64
+                | StdLibType::Bool,
+            ) => Some(BridgeableCowSlice { elem }),
+            _ => None,
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "Cow_Slice_{}",
+            self.elem.to_alpha_numeric_underscore_name(types)
+        )
+    }
+}
+
+/// Find a `Cow<[T]>` within the type.
+///
+/// `Cow<[T]>` is currently only supported as the return type of an extern "Rust" function, so
+/// any other `Cow<[T]>` that we find gets reported as a parse error.
+pub(crate) fn find_cow_slice<'a>(ty: &'a Type, types: &TypeDeclarations) -> Option<&'a Type> {
+    match ty {
+        Type::Path(path) => {
+            if BridgeableCowSlice::from_type(ty, types).is_some() {
+                return Some(ty);
+            }
+
+            let last = path.path.segments.last()?;
+            match &last.arguments {
+                PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+                    GenericArgument::Type(inner) => find_cow_slice(inner, types),
+                    _ => None,
+                }),
+                _ => None,
+            }
+        }
+        Type::Reference(reference) => find_cow_slice(&reference.elem, types),
+        Type::Tuple(tuple) => tuple
+            .elems
+            .iter()
+            .find_map(|elem| find_cow_slice(elem, types)),
+        _ => None,
+    }
+}
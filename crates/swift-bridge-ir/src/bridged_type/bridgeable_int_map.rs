@@ -0,0 +1,398 @@
+//! `HashMap<K, V>` where `K` is an integer such as `u32`, and `V` is a primitive or an owned
+//! opaque Rust type.
+//!
+//! In Swift a `HashMap<u32, V>` is a `[UInt32: V]`.
+//!
+//! Over FFI the map is passed as a `__private__FfiIntMap`, which holds a buffer of keys and a
+//! buffer of values. Neither Rust nor Swift maps have an order, so the only guarantee is that the
+//! value at each index belongs to the key at the same index.
+//!
+//! When Swift passes a dictionary to Rust the buffers are borrowed for the duration of the call
+//! and Rust copies them into a `HashMap`.
+//! When Rust passes a map to Swift the buffers are leaked, and Swift copies them into a
+//! `Dictionary` and then calls `__swift_bridge__$FfiIntMap$free_keys_{K}` and
+//! `__swift_bridge__$FfiIntMap$free_values_{V}` to free them.
+//!
+//! Opaque Rust type values are owned by whichever side receives the map, the same as for a
+//! `HashMap<String, T>`.
+
+use crate::bridged_type::bridgeable_string_map::parse_map_value;
+use crate::bridged_type::{
+    BridgeableType, BridgedType, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::parse::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{Path, Type};
+
+#[derive(Debug)]
+pub(crate) struct BridgeableIntMap {
+    /// The map's key type, such as the `u32` in `HashMap<u32, u8>`.
+    pub key: BridgedType,
+    /// The map's value type, such as the `u8` in `HashMap<u32, u8>`.
+    pub value: BridgedType,
+    /// Whether the values are owned opaque Rust types, such as `HashMap<u32, SomeRustType>`.
+    opaque_rust_values: bool,
+}
+
+impl BridgeableIntMap {
+    const PREFIXES: [&'static str; 3] = [
+        "HashMap<",
+        "collections::HashMap<",
+        "std::collections::HashMap<",
+    ];
+
+    const KEYS: [&'static str; 10] = [
+        "u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64", "usize", "isize",
+    ];
+
+    /// Remove whitespace so that "HashMap < u32 , u8 >" and "HashMap<u32, u8>" are handled the
+    /// same.
+    fn normalize(tokens: &str) -> String {
+        tokens.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// "HashMap<u32,u8>" -> ("u32", "u8")
+    fn split_key_and_value(tokens: &str) -> Option<(&str, &str)> {
+        let key_and_value = Self::PREFIXES
+            .iter()
+            .find_map(|prefix| tokens.strip_prefix(prefix))?
+            .strip_suffix(">")?;
+        let (key, value) = key_and_value.split_once(',')?;
+
+        if !Self::KEYS.contains(&key) {
+            return None;
+        }
+
+        Some((key, value))
+    }
+
+    /// Whether the values are owned opaque Rust types that are passed as boxed pointers.
+    pub fn has_opaque_rust_values(&self) -> bool {
+        self.opaque_rust_values
+    }
+
+    /// The names of the Rust functions that free the key and value buffers of a map that was
+    /// passed from Rust to Swift.
+    fn swift_free_fn_names(&self, types: &TypeDeclarations) -> (String, String) {
+        let free_keys = format!(
+            "__swift_bridge__$FfiIntMap$free_keys_{}",
+            self.key.to_rust_type_path(types)
+        );
+
+        let free_values = if self.opaque_rust_values {
+            "__swift_bridge__$FfiIntMap$free_values_pointers".to_string()
+        } else {
+            format!(
+                "__swift_bridge__$FfiIntMap$free_values_{}",
+                self.value.to_rust_type_path(types)
+            )
+        };
+
+        (free_keys, free_values)
+    }
+}
+
+impl BridgeableType for BridgeableIntMap {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&super::bridgeable_result::BuiltInResult> {
+        None
+    }
+
+    fn as_option(&self) -> Option<&super::bridged_option::BridgedOption> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn as_int_map(&self) -> Option<&BridgeableIntMap> {
+        Some(self)
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let key = self.key.to_rust_type_path(types);
+        let value = self.value.to_rust_type_path(types);
+        quote! { std::collections::HashMap<#key, #value> }
+    }
+
+    fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang)
+                if !func_host_lang.is_rust() =>
+            {
+                "__private__FfiIntMap".to_string()
+            }
+            _ => format!(
+                "[{}: {}]",
+                self.key.to_swift_type(type_pos, types, swift_bridge_path),
+                self.value.to_swift_type(type_pos, types, swift_bridge_path)
+            ),
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "struct __private__FfiIntMap".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let key = self
+            .key
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+        let value = self
+            .value
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+        quote! { #swift_bridge_path::hash_map::FfiIntMap<#key, #value> }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Option<HashMap<K, V>> is not yet supported")
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        todo!("Option<HashMap<K, V>> is not yet supported")
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        todo!("Option<HashMap<K, V>> is not yet supported")
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        if self.opaque_rust_values {
+            let value = self.value.convert_rust_expression_to_ffi_type(
+                &quote! { value },
+                swift_bridge_path,
+                types,
+                span,
+            );
+            return quote! {
+                #swift_bridge_path::hash_map::FfiIntMap::from_hash_map_with(#expression, |value| #value)
+            };
+        }
+
+        quote! { #swift_bridge_path::hash_map::FfiIntMap::from_hash_map(#expression) }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        todo!("Option<HashMap<K, V>> is not yet supported")
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        type_pos: TypePosition,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) if func_host_lang.is_rust() => {
+                format!("{expression}AsFfiIntMap")
+            }
+            _ => todo!("Swift can only pass a HashMap<K, V> to Rust as a function argument"),
+        }
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        todo!("Option<HashMap<K, V>> is not yet supported")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        if self.opaque_rust_values {
+            let value = self.value.convert_ffi_expression_to_rust_type(
+                &quote! { value },
+                span,
+                swift_bridge_path,
+                types,
+            );
+            return quote! { #expression.to_hash_map_with(|value| #value) };
+        }
+
+        quote! { #expression.to_hash_map() }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+        todo!("Option<HashMap<K, V>> is not yet supported")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        let key = self.key.to_swift_type(type_pos, types, swift_bridge_path);
+        let value = self.value.to_swift_type(type_pos, types, swift_bridge_path);
+        // Swift takes ownership of each of the opaque values.
+        let (ffi_value, maybe_map_values) = if self.opaque_rust_values {
+            (
+                "UnsafeMutableRawPointer".to_string(),
+                format!(".mapValues({{ {value}(ptr: $0) }})"),
+            )
+        } else {
+            (value, "".to_string())
+        };
+        let (free_keys, free_values) = self.swift_free_fn_names(types);
+
+        format!(
+            "{{ let ffiIntMap = {expression}; let dictionary = ffiIntMap.toSwiftDictionary({key}.self, {ffi_value}.self){maybe_map_values}; {free_keys}(ffiIntMap.keys); {free_values}(ffiIntMap.values); return dictionary }}()",
+        )
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        todo!("Option<HashMap<K, V>> is not yet supported")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!()
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!()
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        todo!("Option<HashMap<K, V>> is not yet supported")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        Self::split_key_and_value(&Self::normalize(tokens)).is_some()
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    /// "HashMap < u32 , u8 >" or "std :: collections :: HashMap < u32 , u8 >"
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let tokens = Self::normalize(tokens);
+        let (key, value) = Self::split_key_and_value(&tokens)?;
+
+        let key = BridgedType::new_with_str(key, types)?;
+        let (value, opaque_rust_values) = parse_map_value(value, types)?;
+
+        Some(BridgeableIntMap {
+            key,
+            value,
+            opaque_rust_values,
+        })
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "HashMap_{}_{}",
+            self.key.to_alpha_numeric_underscore_name(types),
+            self.value.to_alpha_numeric_underscore_name(types)
+        )
+    }
+}
@@ -18,6 +18,24 @@ pub(crate) struct BuiltInPointer {
 pub(crate) enum PointerKind {
     Const,
     Mut,
+    /// A `&mut T` argument where `T` is a primitive, such as `attempts: &mut u32`.
+    ///
+    /// This is passed over FFI as a `*mut T` and is an `inout T` in Swift.
+    RefMut,
+}
+
+impl BuiltInPointer {
+    /// Whether or not this is a `&mut T` argument that Swift passes to Rust, such as
+    /// `fn call(attempts: &mut u32)`.
+    /// On the Swift side these are passed as an `inout` value.
+    pub fn is_inout_fn_arg_from_swift(&self, type_pos: TypePosition) -> bool {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) => {
+                self.kind == PointerKind::RefMut && func_host_lang.is_rust()
+            }
+            _ => false,
+        }
+    }
 }
 
 /// The target of an `*const` or `*mut` pointer.
@@ -63,6 +81,10 @@ impl BridgeableType for BuiltInPointer {
 
     fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
         match &self.pointee {
+            Pointee::BuiltIn(ty) if self.kind == PointerKind::RefMut => {
+                let ty = ty.to_rust_type_path(types);
+                quote! { &mut #ty }
+            }
             Pointee::BuiltIn(ty) => {
                 let pointer_kind = self.kind.to_ffi_compatible_rust_type();
                 let ty = ty.to_rust_type_path(types);
@@ -273,7 +295,7 @@ impl PointerKind {
             PointerKind::Const => {
                 quote! { *const }
             }
-            PointerKind::Mut => {
+            PointerKind::Mut | PointerKind::RefMut => {
                 quote! { *mut }
             }
         }
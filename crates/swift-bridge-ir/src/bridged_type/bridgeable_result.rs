@@ -1,6 +1,7 @@
 use crate::bridged_type::{BridgeableType, BridgedType, CFfiStruct, TypePosition};
+use crate::parse::HostLang;
 use crate::{TypeDeclarations, SWIFT_BRIDGE_PREFIX};
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Literal, Span, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
 use syn::Path;
 
@@ -17,6 +18,9 @@ use syn::Path;
 pub(crate) struct BuiltInResult {
     pub ok_ty: Box<dyn BridgeableType>,
     pub err_ty: Box<dyn BridgeableType>,
+    /// Whether the error type has lifetime arguments, such as `ParseError<'a>`, and needs to be
+    /// converted into the bridged error type using its `into_owned` method.
+    pub converts_err_into_owned: bool,
 }
 
 impl BuiltInResult {
@@ -25,6 +29,14 @@ impl BuiltInResult {
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
     ) -> TokenStream {
+        if self.is_ok_and_err_zero_bytes() {
+            return quote! { bool };
+        }
+
+        if self.is_error_code_only() {
+            return quote! { i32 };
+        }
+
         if self.is_custom_result_type() {
             let ty = format_ident!("{}", self.custom_c_struct_name(types));
             return quote! {
@@ -57,6 +69,30 @@ impl BuiltInResult {
         types: &TypeDeclarations,
         span: Span,
     ) -> TokenStream {
+        if self.is_ok_and_err_zero_bytes() {
+            return quote! {
+                #expression.is_ok()
+            };
+        }
+
+        // An error that borrows data, such as `ParseError<'a>`, gets copied into its owned form
+        // so that the Swift error does not outlive the data that it borrows.
+        let err = if self.converts_err_into_owned {
+            quote! { err.into_owned() }
+        } else {
+            quote! { err }
+        };
+
+        if self.is_error_code_only() {
+            let err_code = self.rust_err_to_error_code(&err, types);
+            return quote! {
+                match #expression {
+                    Ok(_) => 0,
+                    Err(err) => #err_code,
+                }
+            };
+        }
+
         let convert_ok = self.ok_ty.convert_rust_expression_to_ffi_type(
             &quote! { ok },
             swift_bridge_path,
@@ -64,12 +100,9 @@ impl BuiltInResult {
             span,
         );
 
-        let convert_err = self.err_ty.convert_rust_expression_to_ffi_type(
-            &quote! { err },
-            swift_bridge_path,
-            types,
-            span,
-        );
+        let convert_err =
+            self.err_ty
+                .convert_rust_expression_to_ffi_type(&err, swift_bridge_path, types, span);
 
         if self.is_custom_result_type() {
             if self.err_ty.can_be_encoded_with_zero_bytes() {
@@ -78,7 +111,7 @@ impl BuiltInResult {
             if self.ok_ty.can_be_encoded_with_zero_bytes() {
                 let ffi_enum_name = self.to_ffi_compatible_rust_type(swift_bridge_path, types);
                 let err_ffi = self.err_ty.convert_rust_expression_to_ffi_type(
-                    &quote!(err),
+                    &err,
                     swift_bridge_path,
                     types,
                     span,
@@ -97,12 +130,16 @@ impl BuiltInResult {
                 types,
                 span,
             );
-            let err_ffi = self.err_ty.convert_rust_expression_to_ffi_type(
-                &quote!(err),
-                swift_bridge_path,
-                types,
-                span,
-            );
+            let err_ffi = if self.has_error_code() {
+                self.rust_err_to_error_code(&err, types)
+            } else {
+                self.err_ty.convert_rust_expression_to_ffi_type(
+                    &err,
+                    swift_bridge_path,
+                    types,
+                    span,
+                )
+            };
             return quote! {
                 match #expression {
                     Ok(ok) => #ffi_enum_name::Ok(#ok_ffi),
@@ -138,6 +175,23 @@ impl BuiltInResult {
         }
     }
 
+    /// Convert the error of a `Result` returning expression into the bridged error type, the same
+    /// way that `?` would.
+    ///
+    /// Used for `#[swift_bridge(return_into)]`, so that a function can return any error that
+    /// implements `Into<E>`.
+    pub(super) fn rust_expression_map_err_into(
+        &self,
+        expression: &TokenStream,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let err_ty = self.err_ty.to_rust_type_path(types);
+
+        quote! {
+            #expression.map_err(|err| -> #err_ty { err.into() })
+        }
+    }
+
     pub(super) fn convert_ffi_value_to_rust_value(
         &self,
         expression: &TokenStream,
@@ -145,6 +199,81 @@ impl BuiltInResult {
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
     ) -> TokenStream {
+        if self.is_ok_and_err_zero_bytes() {
+            return quote_spanned! {span=>
+                if #expression {
+                    std::result::Result::Ok(())
+                } else {
+                    std::result::Result::Err(())
+                }
+            };
+        }
+
+        if self.is_error_code_only() {
+            let convert_err = self.error_code_to_rust_err(&quote! { code }, types);
+            return quote_spanned! {span=>
+                match #expression {
+                    0 => std::result::Result::Ok(()),
+                    code => std::result::Result::Err(#convert_err),
+                }
+            };
+        }
+
+        if self.is_custom_result_type() {
+            let ffi_enum_name = self.to_ffi_compatible_rust_type(swift_bridge_path, types);
+            let convert_err = if self.has_error_code() {
+                self.error_code_to_rust_err(&quote! { err }, types)
+            } else {
+                self.err_ty.convert_ffi_expression_to_rust_type(
+                    &quote! { err },
+                    span,
+                    swift_bridge_path,
+                    types,
+                )
+            };
+
+            if self.ok_ty.can_be_encoded_with_zero_bytes() {
+                return quote_spanned! {span=>
+                    match #expression {
+                        #ffi_enum_name::Ok => std::result::Result::Ok(()),
+                        #ffi_enum_name::Err(err) => std::result::Result::Err(#convert_err),
+                    }
+                };
+            }
+
+            let convert_ok = self.ok_ty.convert_ffi_expression_to_rust_type(
+                &quote! { ok },
+                span,
+                swift_bridge_path,
+                types,
+            );
+            return quote_spanned! {span=>
+                match #expression {
+                    #ffi_enum_name::Ok(ok) => std::result::Result::Ok(#convert_ok),
+                    #ffi_enum_name::Err(err) => std::result::Result::Err(#convert_err),
+                }
+            };
+        }
+
+        if self.ok_ty.can_be_encoded_with_zero_bytes() {
+            let convert_err = self.err_ty.convert_ffi_expression_to_rust_type(
+                &quote! { err },
+                span,
+                swift_bridge_path,
+                types,
+            );
+            return quote_spanned! {span=>
+                {
+                    let err = #expression;
+                    if err.is_null() {
+                        std::result::Result::Ok(())
+                    } else {
+                        std::result::Result::Err(#convert_err)
+                    }
+                }
+            };
+        }
+
         let convert_ok = self.ok_ty.convert_ffi_result_ok_value_to_rust_value(
             expression,
             swift_bridge_path,
@@ -180,6 +309,9 @@ impl BuiltInResult {
         swift_bridge_path: &Path,
     ) -> String {
         match type_pos {
+            TypePosition::FnReturn(func_host_lang) if !func_host_lang.is_rust() => {
+                self.to_ffi_compatible_swift_type(types)
+            }
             TypePosition::FnReturn(_) => {
                 self.ok_ty.to_swift_type(type_pos, types, swift_bridge_path)
             }
@@ -192,24 +324,35 @@ impl BuiltInResult {
                 )
             }
             TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
-                if self.err_ty.can_be_encoded_with_zero_bytes() {
-                    todo!()
-                }
-                if self.is_custom_result_type() {
-                    return format!(
-                        "{}${}",
-                        SWIFT_BRIDGE_PREFIX,
-                        self.custom_c_struct_name(types)
-                    );
-                }
-                if self.ok_ty.can_be_encoded_with_zero_bytes() {
-                    return "UnsafeMutableRawPointer?".to_string();
-                }
-                "__private__ResultPtrAndPtr".to_string()
+                self.to_ffi_compatible_swift_type(types)
             }
         }
     }
 
+    /// The Swift type of the Result's FFI representation.
+    fn to_ffi_compatible_swift_type(&self, types: &TypeDeclarations) -> String {
+        if self.is_ok_and_err_zero_bytes() {
+            return "Bool".to_string();
+        }
+        if self.is_error_code_only() {
+            return "Int32".to_string();
+        }
+        if self.err_ty.can_be_encoded_with_zero_bytes() {
+            todo!()
+        }
+        if self.is_custom_result_type() {
+            return format!(
+                "{}${}",
+                SWIFT_BRIDGE_PREFIX,
+                self.custom_c_struct_name(types)
+            );
+        }
+        if self.ok_ty.can_be_encoded_with_zero_bytes() {
+            return "UnsafeMutableRawPointer?".to_string();
+        }
+        "__private__ResultPtrAndPtr".to_string()
+    }
+
     pub fn convert_ffi_value_to_swift_value(
         &self,
         expression: &str,
@@ -217,6 +360,20 @@ impl BuiltInResult {
         types: &TypeDeclarations,
         swift_bridge_path: &Path,
     ) -> String {
+        if self.is_ok_and_err_zero_bytes() {
+            return format!(
+                "try {{ let val = {expression}; if val {{ return }} else {{ throw RustResultUnitErr() }} }}()",
+                expression = expression
+            );
+        }
+
+        if self.is_error_code_only() {
+            return format!(
+                "try {{ let val = {expression}; if val == 0 {{ return }} else {{ throw {err} }} }}()",
+                err = self.error_code_to_swift_err("val")
+            );
+        }
+
         if self.is_custom_result_type() {
             if self.err_ty.can_be_encoded_with_zero_bytes() {
                 todo!();
@@ -234,7 +391,7 @@ impl BuiltInResult {
                         swift_bridge_path,
                     )
             };
-            let err_swift_type = self.err_ty.convert_ffi_expression_to_swift_type(
+            let err_swift_type = self.convert_ffi_err_expression_to_swift_type(
                 "val.payload.err",
                 type_pos,
                 types,
@@ -277,7 +434,7 @@ impl BuiltInResult {
             } else {
                 ok = " ".to_string() + &ok;
             }
-            let err = self.err_ty.convert_ffi_expression_to_swift_type(
+            let err = self.convert_ffi_err_expression_to_swift_type(
                 "val!",
                 type_pos,
                 types,
@@ -292,7 +449,7 @@ impl BuiltInResult {
             types,
             swift_bridge_path,
         );
-        let err = self.err_ty.convert_ffi_expression_to_swift_type(
+        let err = self.convert_ffi_err_expression_to_swift_type(
             "val.ok_or_err!",
             type_pos,
             types,
@@ -306,12 +463,58 @@ impl BuiltInResult {
         )
     }
 
+    /// Convert the Result's FFI error value into the Swift value that gets thrown.
+    ///
+    /// A `RustVec` is not a Swift `Error`, so a `Vec<E>` error is thrown as a `RustVecErr<E>` that
+    /// holds every one of the errors.
+    fn convert_ffi_err_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        if self.has_error_code() {
+            return self.error_code_to_swift_err(expression);
+        }
+
+        if self.err_ty.is_vec() {
+            return format!(
+                "RustVecErr({}(ptr: {}))",
+                self.err_ty
+                    .to_swift_type(type_pos, types, swift_bridge_path),
+                expression
+            );
+        }
+
+        self.err_ty.convert_ffi_expression_to_swift_type(
+            expression,
+            type_pos,
+            types,
+            swift_bridge_path,
+        )
+    }
+
     pub fn convert_swift_expression_to_ffi_compatible(
         &self,
         expression: &str,
         types: &TypeDeclarations,
         type_pos: TypePosition,
     ) -> String {
+        if self.is_ok_and_err_zero_bytes() {
+            return format!(
+                "{{ switch {val} {{ case .Ok(_): return true case .Err(_): return false }} }}()",
+                val = expression
+            );
+        }
+
+        if self.is_error_code_only() {
+            return format!(
+                "{{ () -> Int32 in switch {expression} {{ case .Ok(_): return 0 case .Err(let err): return {err_code} }} }}()",
+                err_code = self.swift_err_to_error_code("err")
+            );
+        }
+
         let convert_ok = self
             .ok_ty
             .convert_swift_expression_to_ffi_type("ok", types, type_pos);
@@ -332,7 +535,95 @@ impl BuiltInResult {
         }
     }
 
+    /// Convert a call to a throwing Swift function into the Result's FFI representation, so that
+    /// Rust receives an `Err` when the Swift function throws.
+    ///
+    /// The Swift function must throw the Swift type of the Result's error.
+    pub fn convert_throwing_swift_expression_to_ffi_compatible(
+        &self,
+        expression: &str,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        let type_pos = TypePosition::FnReturn(HostLang::Swift);
+
+        if self.is_ok_and_err_zero_bytes() {
+            return format!(
+                "{{ do {{ try {expression}; return true }} catch {{ return false }} }}()",
+                expression = expression
+            );
+        }
+
+        let err_ty = self.err_ty.to_swift_type(
+            TypePosition::FnReturn(HostLang::Rust),
+            types,
+            swift_bridge_path,
+        );
+
+        if self.is_error_code_only() {
+            return format!(
+                r#"{{ () -> Int32 in do {{ try {expression}; return 0 }} catch let err as {err_ty} {{ return {err_code} }} catch {{ fatalError("Expected the thrown error to be a {err_ty}, but it was \(error)") }} }}()"#,
+                err_code = self.swift_err_to_error_code("err")
+            );
+        }
+
+        let (try_call, ok_ffi) = if self.ok_ty.can_be_encoded_with_zero_bytes() {
+            (format!("try {expression}"), None)
+        } else {
+            (
+                format!("let ok = try {expression}"),
+                Some(
+                    self.ok_ty
+                        .convert_swift_expression_to_ffi_type("ok", types, type_pos),
+                ),
+            )
+        };
+        let err_ffi = if self.has_error_code() {
+            self.swift_err_to_error_code("err")
+        } else {
+            self.err_ty
+                .convert_swift_expression_to_ffi_type("err", types, type_pos)
+        };
+
+        let (return_ok, return_err) = if self.is_custom_result_type() {
+            let ffi_name = self.to_ffi_compatible_swift_type(types);
+            let c_ok_name = self.c_ok_tag_name(types);
+            let c_err_name = self.c_err_tag_name(types);
+
+            let return_ok = match ok_ffi {
+                Some(ok_ffi) => format!(
+                    "{ffi_name}(tag: {c_ok_name}, payload: {ffi_name}$Fields(ok: {ok_ffi}))"
+                ),
+                None => {
+                    format!("{{ var val = {ffi_name}(); val.tag = {c_ok_name}; return val }}()")
+                }
+            };
+            let return_err = format!(
+                "{ffi_name}(tag: {c_err_name}, payload: {ffi_name}$Fields(err: {err_ffi}))"
+            );
+
+            (return_ok, return_err)
+        } else if let Some(ok_ffi) = ok_ffi {
+            (
+                format!("__private__ResultPtrAndPtr(is_ok: true, ok_or_err: {ok_ffi})"),
+                format!("__private__ResultPtrAndPtr(is_ok: false, ok_or_err: {err_ffi})"),
+            )
+        } else {
+            ("nil".to_string(), err_ffi)
+        };
+
+        format!(
+            r#"{{ do {{ {try_call}; return {return_ok} }} catch let err as {err_ty} {{ return {return_err} }} catch {{ fatalError("Expected the thrown error to be a {err_ty}, but it was \(error)") }} }}()"#
+        )
+    }
+
     pub fn to_c(&self, types: &TypeDeclarations) -> String {
+        if self.is_ok_and_err_zero_bytes() {
+            return "bool".to_string();
+        }
+        if self.is_error_code_only() {
+            return "int32_t".to_string();
+        }
         if self.is_custom_result_type() {
             return format!(
                 "struct {}${}",
@@ -371,9 +662,12 @@ impl BuiltInResult {
             quote! {(#ty)}
         };
 
-        let err = self
-            .err_ty
-            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+        let err = if self.has_error_code() {
+            quote! { i32 }
+        } else {
+            self.err_ty
+                .to_ffi_compatible_rust_type(swift_bridge_path, types)
+        };
         let mut custom_rust_ffi_types = vec![];
         // TODO: remove `#[allow(unused)]` when rustc no longer issues dead code warnings for `#[repr(C)]`
         //  structs or enums: https://github.com/rust-lang/rust/issues/126706
@@ -426,7 +720,11 @@ impl BuiltInResult {
         } else {
             format!("{} ok; ", self.ok_ty.to_c_type(types))
         };
-        let err_c_field_name = self.err_ty.to_c_type(types);
+        let err_c_field_name = if self.has_error_code() {
+            "int32_t".to_string()
+        } else {
+            self.err_ty.to_c_type(types)
+        };
         let ok_c_tag_name = self.c_ok_tag_name(types);
         let err_c_tag_name = self.c_err_tag_name(types);
         let c_ffi_type = format!(
@@ -454,7 +752,24 @@ typedef struct {c_enum_name}{{{c_tag_name} tag; union {c_fields_name} payload;}}
         return Some(custom_c_ffi_type);
     }
 
+    /// `Result<(), ()>` is passed over FFI as a `bool` that is `true` if the result is `Ok`.
+    fn is_ok_and_err_zero_bytes(&self) -> bool {
+        self.ok_ty.can_be_encoded_with_zero_bytes() && self.err_ty.can_be_encoded_with_zero_bytes()
+    }
+
+    pub fn to_c_include(&self) -> Option<Vec<&'static str>> {
+        if self.is_ok_and_err_zero_bytes() {
+            Some(vec!["stdbool.h"])
+        } else {
+            None
+        }
+    }
+
     fn is_custom_result_type(&self) -> bool {
+        if self.is_ok_and_err_zero_bytes() || self.is_error_code_only() {
+            return false;
+        }
+
         // ResultPtrAndPtr
         if self.ok_ty.is_passed_via_pointer() && self.err_ty.is_passed_via_pointer() {
             return false;
@@ -477,6 +792,28 @@ typedef struct {c_enum_name}{{{c_tag_name} tag; union {c_fields_name} payload;}}
         types: &TypeDeclarations,
         swift_bridge_path: &Path,
     ) -> String {
+        if self.is_ok_and_err_zero_bytes() {
+            return format!(
+                r#"if {expression} {{
+        wrapper.cb(.success(()))
+    }} else {{
+        wrapper.cb(.failure(RustResultUnitErr()))
+    }}"#,
+                expression = expression
+            );
+        }
+
+        if self.is_error_code_only() {
+            return format!(
+                r#"if {expression} == 0 {{
+        wrapper.cb(.success(()))
+    }} else {{
+        wrapper.cb(.failure({err}))
+    }}"#,
+                err = self.error_code_to_swift_err(expression)
+            );
+        }
+
         if self.is_custom_result_type() {
             let ok = if self.ok_ty.can_be_encoded_with_zero_bytes() {
                 "()".to_string()
@@ -488,7 +825,7 @@ typedef struct {c_enum_name}{{{c_tag_name} tag; union {c_fields_name} payload;}}
                     swift_bridge_path,
                 )
             };
-            let err = self.err_ty.convert_ffi_expression_to_swift_type(
+            let err = self.convert_ffi_err_expression_to_swift_type(
                 &format!("{expression}.payload.err"),
                 type_pos,
                 types,
@@ -508,7 +845,7 @@ typedef struct {c_enum_name}{{{c_tag_name} tag; union {c_fields_name} payload;}}
             .err_ty
             .to_swift_type(type_pos, types, swift_bridge_path);
 
-        let (ok_val, err_val, condition) = if self.ok_ty.can_be_encoded_with_zero_bytes() {
+        let (ok_val, mut err_val, condition) = if self.ok_ty.can_be_encoded_with_zero_bytes() {
             (
                 ok,
                 format!("{err}(ptr: rustFnRetVal!)"),
@@ -521,6 +858,9 @@ typedef struct {c_enum_name}{{{c_tag_name} tag; union {c_fields_name} payload;}}
                 "rustFnRetVal.is_ok",
             )
         };
+        if self.err_ty.is_vec() {
+            err_val = format!("RustVecErr({err_val})");
+        }
 
         format!(
             r#"if {condition} {{
@@ -535,15 +875,23 @@ typedef struct {c_enum_name}{{{c_tag_name} tag; union {c_fields_name} payload;}}
 impl BuiltInResult {
     /// Go from `Result < A , B >` to a `BuiltInResult`.
     pub fn from_str_tokens(string: &str, types: &TypeDeclarations) -> Option<Self> {
+        // `Result<u8, MyError<'a>>` is printed as `Result < u8 , MyError < 'a >>`.
+        let string = string.replace(">>", "> >");
+
         // A , B >
         let trimmed = string.trim_start_matches("Result < ");
         // A , B
-        let trimmed = trimmed.trim_end_matches(" >");
+        let trimmed = trimmed.strip_suffix(" >").unwrap_or(trimmed);
 
         // [A, B]
-        let ok_and_err = trimmed.rsplit_once(",")?;
-        let ok = ok_and_err.0.trim();
-        let err = ok_and_err.1.trim();
+        let (ok, err) = split_at_top_level_comma(trimmed)?;
+        let ok = ok.trim();
+        let err = err.trim();
+
+        let (err, converts_err_into_owned) = match strip_lifetime_arguments(err) {
+            Some(owned_err) => (owned_err, true),
+            None => (err, false),
+        };
 
         let ok = BridgedType::new_with_str(ok, types)?;
         let err = BridgedType::new_with_str(err, types)?;
@@ -551,10 +899,43 @@ impl BuiltInResult {
         Some(BuiltInResult {
             ok_ty: Box::new(ok),
             err_ty: Box::new(err),
+            converts_err_into_owned,
         })
     }
 }
 
+/// `Vec < u8 > , MyError < 'a , 'b >` -> `(Vec < u8 > , MyError < 'a , 'b >)`
+fn split_at_top_level_comma(tokens: &str) -> Option<(&str, &str)> {
+    let mut depth = 0;
+    let mut prev = ' ';
+    for (idx, c) in tokens.char_indices() {
+        let prev = std::mem::replace(&mut prev, c);
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            // The `>` in a `->` does not close a generic argument list.
+            '>' if prev == '-' => {}
+            '>' | ')' | ']' => depth -= 1,
+            ',' if depth == 0 => return Some((&tokens[..idx], &tokens[idx + 1..])),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// `MyError < 'a >` -> `MyError`
+///
+/// Returns `None` if the type's generic arguments are not all lifetimes.
+fn strip_lifetime_arguments(ty: &str) -> Option<&str> {
+    let (name, args) = ty.split_once(" < ")?;
+    let args = args.strip_suffix(" >")?;
+
+    if args.split(" , ").all(|arg| arg.starts_with('\'')) {
+        Some(name)
+    } else {
+        None
+    }
+}
+
 impl BuiltInResult {
     fn custom_c_struct_name(&self, types: &TypeDeclarations) -> String {
         let ok = &self.ok_ty;
@@ -582,6 +963,115 @@ impl BuiltInResult {
     }
 }
 
+/// Errors that are shared enums without any data are passed over FFI as an `int32_t` error code
+/// instead of as the enum's FFI representation.
+///
+/// `0` means that there is no error, and each variant's error code is one more than its index.
+/// So, `enum ParseError { Empty, TooLong }` is passed as `1` for `Empty` and `2` for `TooLong`.
+///
+/// A `Result<(), E>` is passed as just the error code. Other `Result<T, E>`s hold the error code
+/// in their payload.
+impl BuiltInResult {
+    fn has_error_code(&self) -> bool {
+        self.err_ty.as_unit_only_shared_enum().is_some()
+    }
+
+    fn is_error_code_only(&self) -> bool {
+        self.ok_ty.can_be_encoded_with_zero_bytes() && self.has_error_code()
+    }
+
+    /// `match err { ParseError::Empty => 1, ParseError::TooLong => 2 }`
+    fn rust_err_to_error_code(
+        &self,
+        expression: &TokenStream,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let shared_enum = self.err_ty.as_unit_only_shared_enum().unwrap();
+        let enum_path = self.err_ty.to_rust_type_path(types);
+
+        let arms = shared_enum
+            .variants
+            .iter()
+            .enumerate()
+            .map(|(idx, variant)| {
+                let variant_name = &variant.name;
+                let error_code = Literal::i32_unsuffixed(idx as i32 + 1);
+                quote! { #enum_path::#variant_name => #error_code }
+            });
+
+        quote! {
+            match #expression {
+                #(#arms),*
+            }
+        }
+    }
+
+    /// `match code { 1 => ParseError::Empty, 2 => ParseError::TooLong, _ => unreachable!() }`
+    fn error_code_to_rust_err(
+        &self,
+        expression: &TokenStream,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let shared_enum = self.err_ty.as_unit_only_shared_enum().unwrap();
+        let enum_path = self.err_ty.to_rust_type_path(types);
+
+        let arms = shared_enum
+            .variants
+            .iter()
+            .enumerate()
+            .map(|(idx, variant)| {
+                let variant_name = &variant.name;
+                let error_code = Literal::i32_unsuffixed(idx as i32 + 1);
+                quote! { #error_code => #enum_path::#variant_name }
+            });
+
+        quote! {
+            match #expression {
+                #(#arms,)*
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    /// `{ () -> Int32 in switch err { case ParseError.Empty: return 1 } }()`
+    fn swift_err_to_error_code(&self, expression: &str) -> String {
+        let shared_enum = self.err_ty.as_unit_only_shared_enum().unwrap();
+        let enum_name = shared_enum.swift_name_string();
+
+        let cases = shared_enum
+            .variants
+            .iter()
+            .enumerate()
+            .map(|(idx, variant)| {
+                format!("case {}.{}: return {}", enum_name, variant.name, idx + 1)
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        format!("{{ () -> Int32 in switch {expression} {{ {cases} }} }}()")
+    }
+
+    /// `{ () -> ParseError in switch val { case 1: return ParseError.Empty default: fatalError() } }()`
+    fn error_code_to_swift_err(&self, expression: &str) -> String {
+        let shared_enum = self.err_ty.as_unit_only_shared_enum().unwrap();
+        let enum_name = shared_enum.swift_name_string();
+
+        let cases = shared_enum
+            .variants
+            .iter()
+            .enumerate()
+            .map(|(idx, variant)| {
+                format!("case {}: return {}.{}", idx + 1, enum_name, variant.name)
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        format!(
+            "{{ () -> {enum_name} in switch {expression} {{ {cases} default: fatalError() }} }}()"
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -597,4 +1087,24 @@ mod tests {
         assert!(result.ok_ty.is_null());
         assert!(result.err_ty.is_null());
     }
+
+    /// Verify that we look up an error type that has lifetime arguments by its name, and convert
+    /// it into its owned form.
+    #[test]
+    fn result_with_borrowed_error() {
+        let tokens = quote! { Result<Vec<(u8, u16)>, ()> }
+            .to_token_stream()
+            .to_string();
+        let result = BuiltInResult::from_str_tokens(&tokens, &TypeDeclarations::default()).unwrap();
+        assert!(!result.converts_err_into_owned);
+
+        let tokens = quote! { Result<u8, String<'a, 'b>> }
+            .to_token_stream()
+            .to_string();
+        let result = BuiltInResult::from_str_tokens(&tokens, &TypeDeclarations::default()).unwrap();
+        assert!(result.converts_err_into_owned);
+        assert!(result
+            .err_ty
+            .contains_owned_string_recursive(&TypeDeclarations::default()));
+    }
 }
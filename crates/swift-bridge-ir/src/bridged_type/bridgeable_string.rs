@@ -183,7 +183,9 @@ impl BridgeableType for BridgedString {
         type_pos: TypePosition,
     ) -> String {
         match type_pos {
-            TypePosition::FnArg(_func_host_lang, _) => {
+            // A shared struct's `intoFfiRepr` hands its `RustString` over to Rust, just like an
+            // argument does.
+            TypePosition::FnArg(_, _) | TypePosition::SharedStructField => {
                 format!(
                     "{{ if let rustString = optionalStringIntoRustString({expression}) {{ rustString.isOwned = false; return rustString.ptr }} else {{ return nil }} }}()",
                     expression = expression
@@ -199,9 +201,6 @@ impl BridgeableType for BridgedString {
                     )
                 }
             }
-            TypePosition::SharedStructField => {
-                todo!("Option<String> fields in structs are not yet supported.")
-            }
             TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                 unimplemented!()
             }
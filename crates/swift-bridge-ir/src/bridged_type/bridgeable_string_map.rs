@@ -0,0 +1,405 @@
+//! `HashMap<String, T>`, where `T` is a primitive such as `u32` or an owned opaque Rust type.
+//!
+//! In Swift a `HashMap<String, T>` is a `[String: T]`.
+//!
+//! Over FFI the map is passed as a `__private__FfiStringMap`, which holds three buffers: the
+//! UTF-8 bytes of all of the keys, the length of each key and the values. This lets the whole
+//! map cross the FFI boundary at once instead of needing an FFI call for each entry.
+//!
+//! When Swift passes a dictionary to Rust the buffers are borrowed for the duration of the call
+//! and Rust copies them into a `HashMap`.
+//! When Rust passes a map to Swift the buffers are leaked, and Swift copies them into a
+//! `Dictionary` and then calls `__swift_bridge__$FfiStringMap$free_{T}` to free them.
+//!
+//! Opaque Rust type values are passed as one boxed pointer per entry, and whichever side receives
+//! the map takes ownership of them. So when Rust passes a map to Swift each value is freed when
+//! its Swift class instance deinits, and when Swift passes a dictionary to Rust it gives up
+//! ownership of each of its values.
+
+use crate::bridged_type::{
+    BridgeableType, BridgedType, CFfiStruct, OnlyEncoding, OpaqueForeignType, StdLibType,
+    TypePosition, UnusedOptionNoneValue,
+};
+use crate::parse::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{Path, Type};
+
+#[derive(Debug)]
+pub(crate) struct BridgeableStringMap {
+    /// The map's value type, such as the `u32` in `HashMap<String, u32>`.
+    pub value: BridgedType,
+    /// Whether the values are owned opaque Rust types, such as `HashMap<String, SomeRustType>`.
+    opaque_rust_values: bool,
+}
+
+impl BridgeableStringMap {
+    const PREFIXES: [&'static str; 3] = [
+        "HashMap<",
+        "collections::HashMap<",
+        "std::collections::HashMap<",
+    ];
+
+    /// Remove whitespace so that "HashMap < String , u32 >" and "HashMap<String, u32>" are
+    /// handled the same.
+    fn normalize(tokens: &str) -> String {
+        tokens.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// Whether the values are owned opaque Rust types that are passed as boxed pointers.
+    pub fn has_opaque_rust_values(&self) -> bool {
+        self.opaque_rust_values
+    }
+
+    /// The name of the Rust function that frees a map that was passed from Rust to Swift.
+    fn swift_free_fn_name(&self, types: &TypeDeclarations) -> String {
+        if self.opaque_rust_values {
+            return "__swift_bridge__$FfiStringMap$free_pointers".to_string();
+        }
+
+        format!(
+            "__swift_bridge__$FfiStringMap$free_{}",
+            self.value.to_rust_type_path(types)
+        )
+    }
+}
+
+impl BridgeableType for BridgeableStringMap {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&super::bridgeable_result::BuiltInResult> {
+        None
+    }
+
+    fn as_option(&self) -> Option<&super::bridged_option::BridgedOption> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn as_string_map(&self) -> Option<&BridgeableStringMap> {
+        Some(self)
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let value = self.value.to_rust_type_path(types);
+        quote! { std::collections::HashMap<String, #value> }
+    }
+
+    fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang)
+                if !func_host_lang.is_rust() =>
+            {
+                "__private__FfiStringMap".to_string()
+            }
+            _ => format!(
+                "[String: {}]",
+                self.value.to_swift_type(type_pos, types, swift_bridge_path)
+            ),
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "struct __private__FfiStringMap".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let value = self
+            .value
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+        quote! { #swift_bridge_path::hash_map::FfiStringMap<#value> }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Option<HashMap<String, T>> is not yet supported")
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        todo!("Option<HashMap<String, T>> is not yet supported")
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        todo!("Option<HashMap<String, T>> is not yet supported")
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        if self.opaque_rust_values {
+            let value = self.value.convert_rust_expression_to_ffi_type(
+                &quote! { value },
+                swift_bridge_path,
+                types,
+                span,
+            );
+            return quote! {
+                #swift_bridge_path::hash_map::FfiStringMap::from_hash_map_with(#expression, |value| #value)
+            };
+        }
+
+        quote! { #swift_bridge_path::hash_map::FfiStringMap::from_hash_map(#expression) }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        todo!("Option<HashMap<String, T>> is not yet supported")
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        type_pos: TypePosition,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) if func_host_lang.is_rust() => {
+                format!("{expression}AsFfiStringMap")
+            }
+            _ => todo!("Swift can only pass a HashMap<String, T> to Rust as a function argument"),
+        }
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        todo!("Option<HashMap<String, T>> is not yet supported")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        if self.opaque_rust_values {
+            let value = self.value.convert_ffi_expression_to_rust_type(
+                &quote! { value },
+                span,
+                swift_bridge_path,
+                types,
+            );
+            return quote! { #expression.to_hash_map_with(|value| #value) };
+        }
+
+        quote! { #expression.to_hash_map() }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+        todo!("Option<HashMap<String, T>> is not yet supported")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        let value = self.value.to_swift_type(type_pos, types, swift_bridge_path);
+        // Swift takes ownership of each of the opaque values.
+        let (ffi_value, maybe_map_values) = if self.opaque_rust_values {
+            (
+                "UnsafeMutableRawPointer".to_string(),
+                format!(".mapValues({{ {value}(ptr: $0) }})"),
+            )
+        } else {
+            (value, "".to_string())
+        };
+
+        format!(
+            "{{ let ffiStringMap = {expression}; let dictionary = ffiStringMap.toSwiftDictionary({ffi_value}.self){maybe_map_values}; {free}(ffiStringMap); return dictionary }}()",
+            free = self.swift_free_fn_name(types),
+        )
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        todo!("Option<HashMap<String, T>> is not yet supported")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!()
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!()
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        todo!("Option<HashMap<String, T>> is not yet supported")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        let tokens = Self::normalize(tokens);
+        Self::PREFIXES
+            .iter()
+            .any(|prefix| tokens.starts_with(prefix))
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    /// "HashMap < String , u32 >" or "std :: collections :: HashMap < String , u32 >"
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let tokens = Self::normalize(tokens);
+        let value = Self::PREFIXES
+            .iter()
+            .find_map(|prefix| tokens.strip_prefix(prefix))?
+            .strip_suffix(">")?
+            .strip_prefix("String,")?;
+
+        let (value, opaque_rust_values) = parse_map_value(value, types)?;
+        Some(BridgeableStringMap {
+            value,
+            opaque_rust_values,
+        })
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "HashMap_String_{}",
+            self.value.to_alpha_numeric_underscore_name(types)
+        )
+    }
+}
+
+/// Parse a map's value type, which is either a primitive or an owned opaque Rust type.
+///
+/// Returns the value type along with whether or not it is an opaque Rust type.
+pub(super) fn parse_map_value(
+    value: &str,
+    types: &TypeDeclarations,
+) -> Option<(BridgedType, bool)> {
+    if let Some(opaque) = OpaqueForeignType::parse_token_stream_str(value, types) {
+        let is_owned_opaque_rust_type = opaque.host_lang.is_rust()
+            && !opaque.has_swift_bridge_copy_annotation
+            && !opaque.arc_mutex;
+        if !is_owned_opaque_rust_type {
+            return None;
+        }
+
+        return Some((BridgedType::Bridgeable(Box::new(opaque)), true));
+    }
+
+    let value = BridgedType::new_with_str(value, types)?;
+    match &value {
+        BridgedType::StdLib(
+            StdLibType::U8
+            | StdLibType::I8
+            | StdLibType::U16
+            | StdLibType::I16
+            | StdLibType::U32
+            | StdLibType::I32
+            | StdLibType::U64
+            | StdLibType::I64
+            | StdLibType::Usize
+            | StdLibType::Isize
+            | StdLibType::F32
+            | StdLibType::F64
+            | StdLibType::Bool,
+        ) => Some((value, false)),
+        _ => None,
+    }
+}
@@ -0,0 +1,361 @@
+//! `std::time::Duration` and `std::time::SystemTime`.
+//!
+//! Over FFI both are represented as an `f64` number of seconds. A `SystemTime` is the number of
+//! seconds since the Unix epoch, which is negative for times before the epoch.
+//!
+//! In Swift a `Duration` is a Foundation `TimeInterval` and a `SystemTime` is a Foundation `Date`.
+//! We don't use Swift's own `Duration` type since it is only available on macOS 13+ and iOS 16+.
+//!
+//! An `Option<Duration>` or `Option<SystemTime>` is passed over FFI as a `__private__OptionF64`.
+
+use crate::bridged_type::{
+    BridgeableType, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::parse::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{Path, Type};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum BridgeableTime {
+    /// `std::time::Duration`
+    Duration,
+    /// `std::time::SystemTime`
+    SystemTime,
+}
+
+impl BridgeableTime {
+    const DURATION: [&'static str; 3] = ["Duration", "time :: Duration", "std :: time :: Duration"];
+    const SYSTEM_TIME: [&'static str; 3] = [
+        "SystemTime",
+        "time :: SystemTime",
+        "std :: time :: SystemTime",
+    ];
+
+    /// Convert an `f64` number of seconds into a `Duration` or `SystemTime`.
+    pub(super) fn rust_from_secs(&self, secs: TokenStream) -> TokenStream {
+        match self {
+            BridgeableTime::Duration => quote! { std::time::Duration::from_secs_f64(#secs) },
+            BridgeableTime::SystemTime => quote! {
+                {
+                    let secs: f64 = #secs;
+                    if secs >= 0. {
+                        std::time::UNIX_EPOCH + std::time::Duration::from_secs_f64(secs)
+                    } else {
+                        std::time::UNIX_EPOCH - std::time::Duration::from_secs_f64(-secs)
+                    }
+                }
+            },
+        }
+    }
+
+    /// Convert a `Duration` or `SystemTime` into an `f64` number of seconds.
+    pub(super) fn rust_to_secs(&self, expression: TokenStream) -> TokenStream {
+        match self {
+            BridgeableTime::Duration => quote! { #expression.as_secs_f64() },
+            BridgeableTime::SystemTime => quote! {
+                match #expression.duration_since(std::time::UNIX_EPOCH) {
+                    Ok(after_epoch) => after_epoch.as_secs_f64(),
+                    Err(before_epoch) => -before_epoch.duration().as_secs_f64(),
+                }
+            },
+        }
+    }
+
+    /// Convert a Swift `TimeInterval` or `Date` into a `Double` number of seconds.
+    pub(super) fn swift_to_secs(&self, expression: &str) -> String {
+        match self {
+            BridgeableTime::Duration => expression.to_string(),
+            BridgeableTime::SystemTime => format!("{expression}.timeIntervalSince1970"),
+        }
+    }
+
+    /// Convert a Swift `Double` number of seconds into a `TimeInterval` or `Date`.
+    pub(super) fn swift_from_secs(&self, secs: &str) -> String {
+        match self {
+            BridgeableTime::Duration => secs.to_string(),
+            BridgeableTime::SystemTime => format!("Date(timeIntervalSince1970: {secs})"),
+        }
+    }
+}
+
+impl BridgeableType for BridgeableTime {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn uses_swift_foundation(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&super::bridgeable_result::BuiltInResult> {
+        None
+    }
+
+    fn as_option(&self) -> Option<&super::bridged_option::BridgedOption> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        match self {
+            BridgeableTime::Duration => quote! { std::time::Duration },
+            BridgeableTime::SystemTime => quote! { std::time::SystemTime },
+        }
+    }
+
+    fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> String {
+        match (self, type_pos) {
+            (BridgeableTime::SystemTime, TypePosition::FnArg(func_host_lang, _))
+            | (BridgeableTime::SystemTime, TypePosition::FnReturn(func_host_lang))
+                if !func_host_lang.is_rust() =>
+            {
+                "Double".to_string()
+            }
+            (BridgeableTime::Duration, _) => "TimeInterval".to_string(),
+            (BridgeableTime::SystemTime, _) => "Date".to_string(),
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "double".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { f64 }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { #swift_bridge_path::option::OptionF64 }
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        "__private__OptionF64".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "struct __private__OptionF64".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        self.rust_to_secs(expression.clone())
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let secs = self.rust_to_secs(quote! { val });
+
+        quote! {
+            if let Some(val) = #expression {
+                #swift_bridge_path::option::OptionF64 { val: #secs, is_some: true }
+            } else {
+                #swift_bridge_path::option::OptionF64 { val: 123.4, is_some: false }
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        self.swift_to_secs(expression)
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        match self {
+            BridgeableTime::Duration => format!("{expression}.intoFfiRepr()"),
+            BridgeableTime::SystemTime => {
+                format!("{expression}.map {{ $0.timeIntervalSince1970 }}.intoFfiRepr()")
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.rust_from_secs(expression.clone())
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        let val = self.rust_from_secs(quote! { val.val });
+
+        quote! {
+            {
+                let val = #expression;
+                if val.is_some {
+                    Some(#val)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> String {
+        self.swift_from_secs(expression)
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        match self {
+            BridgeableTime::Duration => format!("{expression}.intoSwiftRepr()"),
+            BridgeableTime::SystemTime => {
+                format!("{expression}.intoSwiftRepr().map {{ Date(timeIntervalSince1970: $0) }}")
+            }
+        }
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!()
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!()
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! { 123.4 },
+            swift: "123.4".to_string(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        Self::DURATION.contains(&tokens) || Self::SYSTEM_TIME.contains(&tokens)
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::DURATION.contains(&tokens) {
+            Some(BridgeableTime::Duration)
+        } else if Self::SYSTEM_TIME.contains(&tokens) {
+            Some(BridgeableTime::SystemTime)
+        } else {
+            None
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        match self {
+            BridgeableTime::Duration => "Duration".to_string(),
+            BridgeableTime::SystemTime => "SystemTime".to_string(),
+        }
+    }
+}
@@ -16,6 +16,8 @@ pub(crate) struct OpaqueForeignType {
     pub reference: bool,
     pub mutable: bool,
     pub has_swift_bridge_copy_annotation: bool,
+    /// `#[swift_bridge(arc_mutex)]`
+    pub arc_mutex: bool,
     pub generics: OpaqueRustTypeGenerics,
 }
 
@@ -176,6 +178,8 @@ impl BridgeableType for OpaqueForeignType {
         if self.has_swift_bridge_copy_annotation {
             let ty = self.copy_rust_repr_type();
             quote! { #ty }
+        } else if self.arc_mutex {
+            quote! { *const std::sync::Mutex<super::#ty_name> }
         } else {
             if self.host_lang.is_rust() {
                 let generics = self
@@ -214,7 +218,7 @@ impl BridgeableType for OpaqueForeignType {
                 .generics
                 .angle_bracketed_concrete_generics_tokens(types);
 
-            if self.reference {
+            if self.reference && !self.mutable {
                 quote! { *const super::#type_name #generics }
             } else {
                 quote! { *mut super::#type_name #generics }
@@ -254,6 +258,19 @@ impl BridgeableType for OpaqueForeignType {
                 quote! {
                     #copy_ty::from_rust_repr(#expression)
                 }
+            } else if self.arc_mutex {
+                if self.reference {
+                    unreachable!(
+                        "Returning references to arc_mutex types is rejected during parsing"
+                    )
+                }
+
+                quote_spanned! {span=>
+                    std::sync::Arc::into_raw({
+                        let val: std::sync::Arc<std::sync::Mutex<super::#ty_name>> = #expression;
+                        val
+                    })
+                }
             } else if self.reference {
                 let ptr = if self.mutable {
                     quote! { *mut }
@@ -307,11 +324,21 @@ impl BridgeableType for OpaqueForeignType {
         } else if self.reference {
             let ty = &self.ty;
 
-            quote! {
-                if let Some(val) = #expression {
-                    val as *const super::#ty
-                } else {
-                    std::ptr::null()
+            if self.mutable {
+                quote! {
+                    if let Some(val) = #expression {
+                        val as *mut super::#ty
+                    } else {
+                        std::ptr::null_mut()
+                    }
+                }
+            } else {
+                quote! {
+                    if let Some(val) = #expression {
+                        val as *const super::#ty
+                    } else {
+                        std::ptr::null()
+                    }
                 }
             }
         } else {
@@ -458,7 +485,7 @@ impl BridgeableType for OpaqueForeignType {
         &self,
         expression: &TokenStream,
         _span: Span,
-        _swift_bridge_path: &Path,
+        swift_bridge_path: &Path,
         _types: &TypeDeclarations,
     ) -> TokenStream {
         if self.host_lang.is_rust() {
@@ -471,6 +498,22 @@ impl BridgeableType for OpaqueForeignType {
                 quote! {
                     #maybe_ref #expression.into_rust_repr()
                 }
+            } else if self.arc_mutex {
+                if self.reference {
+                    let maybe_mut = if self.mutable {
+                        quote! { mut }
+                    } else {
+                        quote! {}
+                    };
+
+                    quote! {
+                        & #maybe_mut * #swift_bridge_path::arc_mutex_support::lock(unsafe { & * #expression })
+                    }
+                } else {
+                    quote! {
+                        unsafe { std::sync::Arc::from_raw( #expression ) }
+                    }
+                }
             } else if self.reference {
                 let maybe_mut = if self.mutable {
                     quote! { mut }
@@ -507,11 +550,21 @@ impl BridgeableType for OpaqueForeignType {
                 }
             }
         } else if self.reference {
-            quote! {
-                if #expression.is_null() {
-                    None
-                } else {
-                    Some(unsafe {& * #expression} )
+            if self.mutable {
+                quote! {
+                    if #expression.is_null() {
+                        None
+                    } else {
+                        Some(unsafe {&mut * #expression} )
+                    }
+                }
+            } else {
+                quote! {
+                    if #expression.is_null() {
+                        None
+                    } else {
+                        Some(unsafe {& * #expression} )
+                    }
                 }
             }
         } else {
@@ -659,6 +712,10 @@ impl BridgeableType for OpaqueForeignType {
         }
     }
 
+    fn is_opaque_rust_type_ref_mut(&self) -> bool {
+        self.host_lang.is_rust() && self.reference && self.mutable
+    }
+
     fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
         let ty_name = &self.ty;
 
@@ -749,7 +806,9 @@ impl BridgeableType for OpaqueForeignType {
 
 impl OpaqueForeignType {
     pub fn swift_name(&self) -> String {
-        if self.reference {
+        if self.reference && self.mutable {
+            format!("{}RefMut", self.ty)
+        } else if self.reference {
             format!("{}Ref", self.ty)
         } else {
             format!("{}", self.ty)
@@ -1,8 +1,10 @@
+use crate::bridged_type::{BridgedType, CustomBridgedType, SharedType, StdLibType};
+use crate::parse::TypeDeclarations;
 use crate::SWIFT_BRIDGE_PREFIX;
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{quote, ToTokens};
 use std::fmt::{Debug, Formatter};
-use syn::LitStr;
+use syn::{LitStr, Type};
 
 mod enum_variant;
 pub(crate) use self::enum_variant::EnumVariant;
@@ -12,6 +14,12 @@ use super::StructFields;
 #[derive(Default, Clone)]
 pub(crate) struct DeriveAttrs {
     pub debug: bool,
+    /// `#[derive(Clone)]`. Enums without data always derive `Clone`, so this only matters for
+    /// enums that have one or more variants with data.
+    pub clone: bool,
+    /// `#[derive(PartialEq)]`. Enums without data are always `Equatable` in Swift, so this only
+    /// changes the generated Swift for enums that have one or more variants with data.
+    pub partial_eq: bool,
 }
 
 #[derive(Clone)]
@@ -21,6 +29,24 @@ pub(crate) struct SharedEnum {
     pub already_declared: bool,
     pub swift_name: Option<LitStr>,
     pub derive: DeriveAttrs,
+    /// `#[swift_bridge(case_iterable)]`
+    pub case_iterable: bool,
+    /// `#[swift_bridge(Identifiable)]`
+    pub identifiable: bool,
+    /// `#[swift_bridge(comparable)]`
+    pub comparable: bool,
+    /// `#[swift_bridge(conforms_to = "...")]`
+    pub conforms_to: Vec<String>,
+    /// `#[swift_bridge(custom_string_convertible)]`
+    pub custom_string_convertible: bool,
+    /// `#[swift_bridge(error_domain = "...")]`
+    pub error_domain: Option<LitStr>,
+    /// `#[swift_bridge(from_str)]`
+    pub from_str: bool,
+    /// `#[swift_bridge(objc)]`
+    pub objc: bool,
+    /// `#[swift_bridge(option_set)]`
+    pub option_set: bool,
 }
 
 impl SharedEnum {
@@ -84,6 +110,11 @@ impl SharedEnum {
         union_fields
     }
 
+    /// SomeEnumOptions
+    pub fn swift_option_set_name_string(&self) -> String {
+        format!("{}Options", self.swift_name_string())
+    }
+
     /// __swift_bridge__$Option$SomeEnum
     pub fn ffi_option_name_string(&self) -> String {
         format!(
@@ -104,6 +135,41 @@ impl SharedEnum {
         self.variants.iter().any(|v| !v.fields.is_empty())
     }
 
+    /// Whether or not the enum can be used in a `Vec<T>`.
+    ///
+    /// Getting an element from a `RustVec` returns a copy of it, so enums with data must
+    /// `#[derive(Clone)]`.
+    pub fn supports_vec(&self) -> bool {
+        !self.has_one_or_more_variants_with_data() || self.derive.clone
+    }
+
+    /// Whether or not the generated Swift enum conforms to `Equatable`.
+    ///
+    /// Swift synthesizes `Equatable` for enums without data, while enums with data must
+    /// `#[derive(PartialEq)]` so that we generate their `==`.
+    pub fn is_swift_equatable(&self) -> bool {
+        !self.has_one_or_more_variants_with_data() || self.derive.partial_eq
+    }
+
+    /// Whether or not the generated Swift enum uses the variants' discriminants as its `Int`
+    /// raw values.
+    pub fn has_swift_raw_values(&self) -> bool {
+        self.comparable || self.objc
+    }
+
+    /// Whether or not the enum mixes variants that hold data with unit variants that have
+    /// discriminants.
+    ///
+    /// Swift enums with associated values can't have raw values, so the generated Swift enum
+    /// gets an optional `rawValue` that is only set for its unit cases.
+    pub fn has_hybrid_raw_values(&self) -> bool {
+        self.has_one_or_more_variants_with_data()
+            && self
+                .variants
+                .iter()
+                .any(|v| v.fields.is_empty() && v.discriminant.is_some())
+    }
+
     /// Returns true if None of the variants are data-carrying.
     ///
     /// `enum { VariantA, VariantB }` would return true.
@@ -113,6 +179,56 @@ impl SharedEnum {
     }
 }
 
+/// A Swift expression that checks whether two values of an enum variant's field are equal, or
+/// `None` if the field's Swift type can't be compared.
+///
+/// `RustString` is not `Equatable`, so we compare `String` fields using their `RustStr`s.
+pub(crate) fn swift_field_equality(
+    ty: &Type,
+    lhs: &str,
+    rhs: &str,
+    types: &TypeDeclarations,
+) -> Option<String> {
+    let is_equatable = match BridgedType::new_with_type(ty, types)? {
+        BridgedType::StdLib(
+            StdLibType::U8
+            | StdLibType::I8
+            | StdLibType::U16
+            | StdLibType::I16
+            | StdLibType::U32
+            | StdLibType::I32
+            | StdLibType::U64
+            | StdLibType::I64
+            | StdLibType::Usize
+            | StdLibType::Isize
+            | StdLibType::F32
+            | StdLibType::F64
+            | StdLibType::Bool,
+        ) => true,
+        BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
+            shared_enum.is_swift_equatable()
+        }
+        BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(_))) => false,
+        BridgedType::StdLib(_) => false,
+        BridgedType::Bridgeable(_) => {
+            if ty.to_token_stream().to_string() == "String" {
+                return Some(format!("{lhs}.as_str() == {rhs}.as_str()"));
+            }
+
+            match types.get_with_type(ty).and_then(|ty| ty.as_opaque()) {
+                Some(opaque) => opaque.host_lang.is_rust() && opaque.attributes.equatable,
+                None => false,
+            }
+        }
+    };
+
+    if is_equatable {
+        Some(format!("{lhs} == {rhs}"))
+    } else {
+        None
+    }
+}
+
 impl PartialEq for SharedEnum {
     fn eq(&self, other: &Self) -> bool {
         self.name.to_string() == other.name.to_string() && self.variants == other.variants
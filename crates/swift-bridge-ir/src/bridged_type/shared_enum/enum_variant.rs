@@ -1,20 +1,58 @@
-use crate::bridged_type::{BridgedType, StructFields, TypePosition};
+use crate::bridged_type::bridgeable_boxed_enum::BridgeableBoxedSharedEnum;
+use crate::bridged_type::{BridgeableType, BridgedType, StructFields, TypePosition};
 use crate::parse::TypeDeclarations;
 use proc_macro2::Ident;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use std::fmt::{Debug, Formatter};
 use syn::spanned::Spanned;
-use syn::Path;
+use syn::{Expr, ExprLit, ExprUnary, Lit, Path, UnOp};
 
 #[derive(Clone)]
 pub(crate) struct EnumVariant {
     pub name: Ident,
     #[allow(unused)]
     pub fields: StructFields,
+    /// `Variant = 4`
+    ///            -
+    pub discriminant: Option<Expr>,
 }
 
 impl EnumVariant {
+    /// `Variant = 4` -> Some(4)
+    /// `Variant = -4` -> Some(-4)
+    /// `Variant` -> None
+    pub(crate) fn integer_discriminant(&self) -> Option<i64> {
+        match self.discriminant.as_ref()? {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(lit_int),
+                ..
+            }) => lit_int.base10_parse::<i64>().ok(),
+            Expr::Unary(ExprUnary {
+                op: UnOp::Neg(_),
+                expr,
+                ..
+            }) => match expr.as_ref() {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Int(lit_int),
+                    ..
+                }) => lit_int.base10_parse::<i64>().ok().map(|val| -val),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Whether or not the variant holds a `Box<SomeEnum>`, which is how a Rust enum refers to
+    /// itself. These variants are generated as `indirect` Swift cases so that the Swift enum
+    /// can be recursive.
+    pub(crate) fn is_indirect(&self, types: &TypeDeclarations) -> bool {
+        self.fields
+            .normalized_fields()
+            .iter()
+            .any(|norm_field| BridgeableBoxedSharedEnum::from_type(&norm_field.ty, types).is_some())
+    }
+
     pub(crate) fn convert_rust_expression_to_ffi_repr(
         &self,
         types: &TypeDeclarations,
@@ -1,3 +1,5 @@
+pub(crate) use self::struct_field::NamedStructField;
+pub(crate) use self::struct_field::NormalizedStructFieldAccessor;
 pub(crate) use self::struct_field::StructField;
 pub(crate) use self::struct_field::StructFields;
 use self::struct_field::UnnamedStructField;
@@ -22,7 +24,11 @@ impl UnnamedStructFields {
         let unnamed_fields = types
             .into_iter()
             .enumerate()
-            .map(|(idx, ty)| UnnamedStructField { ty: ty, idx: idx })
+            .map(|(idx, ty)| UnnamedStructField {
+                ty: ty,
+                idx: idx,
+                swift_name: None,
+            })
             .collect();
         Self(unnamed_fields)
     }
@@ -256,13 +262,51 @@ pub(crate) struct SharedStruct {
     pub fields: StructFields,
     pub swift_name: Option<LitStr>,
     pub already_declared: bool,
+    /// Whether or not to generate a Swift `CustomReflectable` conformance.
+    pub custom_reflectable: bool,
+    /// `#[swift_bridge(bytes)]`, which generates Swift `toBytes()` and `init?(bytes:)` helpers.
+    pub bytes: bool,
+    /// `#[swift_bridge(comparable)]`, which generates a Swift `Comparable` conformance that
+    /// compares the struct's fields in declaration order.
+    pub comparable: bool,
     pub derives: StructDerives,
+    /// The fields that implement the struct's Swift `LocalizedError` conformance.
+    pub localized_error: LocalizedErrorFields,
+    /// `#[swift_bridge(error_domain = "...")]`, which generates a Swift `CustomNSError`
+    /// conformance.
+    pub error_domain: Option<LitStr>,
+    /// `#[swift_bridge(conforms_to = "...")]`, Swift protocols that the user implements for the
+    /// generated Swift struct.
+    pub conforms_to: Vec<String>,
+    /// Named `PhantomData<T>` fields. They are only declared on the Rust struct, and are left
+    /// out of the FFI representation and the Swift struct.
+    pub phantom_data_fields: Vec<NamedStructField>,
+}
+
+/// The indices of the fields that were annotated with `#[swift_bridge(error_description)]`,
+/// `#[swift_bridge(failure_reason)]` and `#[swift_bridge(recovery_suggestion)]`.
+#[derive(Clone, Default)]
+pub(crate) struct LocalizedErrorFields {
+    pub error_description: Option<usize>,
+    pub failure_reason: Option<usize>,
+    pub recovery_suggestion: Option<usize>,
+}
+
+impl LocalizedErrorFields {
+    /// Whether or not the struct should conform to Swift's `LocalizedError` protocol.
+    pub fn is_localized_error(&self) -> bool {
+        self.error_description.is_some()
+            || self.failure_reason.is_some()
+            || self.recovery_suggestion.is_some()
+    }
 }
 
 #[derive(Clone)]
 pub(crate) struct StructDerives {
     pub copy: bool,
     pub clone: bool,
+    /// `#[derive(Hash)]`, which also makes the generated Swift struct `Hashable`.
+    pub hash: bool,
 }
 
 impl SharedStruct {
@@ -305,6 +349,14 @@ impl SharedStruct {
         format!("{}$Option${}", SWIFT_BRIDGE_PREFIX, name,)
     }
 
+    /// Whether or not the struct can be used in a `Vec<T>`.
+    ///
+    /// Getting an element from a `RustVec` returns a copy of it, so the struct must
+    /// `#[derive(Clone)]`.
+    pub fn supports_vec(&self) -> bool {
+        self.derives.clone
+    }
+
     /// Some if the struct has a single variant.
     /// TODO: If all of the struct's fields have an `OnlyEncoding`, then the struct has exactly
     ///  one encoding as well.
@@ -354,6 +406,12 @@ impl SharedStruct {
                     #maybe_name_and_colon #converted_field
                 }
             })
+            .chain(self.phantom_data_fields.iter().map(|field| {
+                let name = &field.name;
+                quote! {
+                    #name: std::marker::PhantomData
+                }
+            }))
             .collect();
 
         let converted_fields = self.wrap_fields(&converted_fields);
@@ -441,7 +499,7 @@ impl SharedStruct {
                 let field_name = norm_field.ffi_field_name();
                 let ty = BridgedType::new_with_type(&norm_field.ty, types).unwrap();
                 let access_field = ty.convert_swift_expression_to_ffi_type(
-                    &format!("val.{field_name}", field_name = field_name),
+                    &format!("val.{swift_name}", swift_name = norm_field.swift_name),
                     types,
                     TypePosition::SharedStructField,
                 );
@@ -492,8 +550,8 @@ impl SharedStruct {
                 );
 
                 format!(
-                    "{field_name}: {access_field}",
-                    field_name = field_name,
+                    "{swift_name}: {access_field}",
+                    swift_name = norm_field.swift_name,
                     access_field = access_field
                 )
             })
@@ -32,6 +32,7 @@ impl StructFields {
                 .map(|n| NormalizedStructField {
                     accessor: NormalizedStructFieldAccessor::Named(n.name.clone()),
                     ty: n.ty.clone(),
+                    swift_name: n.swift_name_string(),
                 })
                 .collect(),
             StructFields::Unnamed(unnamed) => unnamed
@@ -39,6 +40,7 @@ impl StructFields {
                 .map(|u| NormalizedStructField {
                     accessor: NormalizedStructFieldAccessor::Unnamed(u.idx),
                     ty: u.ty.clone(),
+                    swift_name: u.swift_name_string(),
                 })
                 .collect(),
             StructFields::Unit => Vec::new(),
@@ -102,6 +104,7 @@ impl StructFields {
                     let field = UnnamedStructField {
                         ty: field.ty.clone(),
                         idx,
+                        swift_name: None,
                     };
                     fields.push(field);
                 }
@@ -123,6 +126,10 @@ pub(crate) struct NamedStructField {
 pub(crate) struct UnnamedStructField {
     pub ty: Type,
     pub idx: usize,
+    /// The name of the field's Swift property, set using
+    /// `#[swift_bridge(positional_names = [...])]`.
+    /// Defaults to `_0`, `_1`, etc.
+    pub swift_name: Option<String>,
 }
 
 pub(crate) trait StructField {
@@ -146,7 +153,10 @@ impl StructField for UnnamedStructField {
     }
 
     fn swift_name_string(&self) -> String {
-        format!("_{}", self.idx)
+        match &self.swift_name {
+            Some(swift_name) => swift_name.clone(),
+            None => format!("_{}", self.idx),
+        }
     }
 }
 
@@ -170,6 +180,7 @@ impl PartialEq for UnnamedStructField {
     fn eq(&self, other: &Self) -> bool {
         self.ty.to_token_stream().to_string() == other.ty.to_token_stream().to_string()
             && self.idx == other.idx
+            && self.swift_name == other.swift_name
     }
 }
 
@@ -178,6 +189,7 @@ impl Debug for UnnamedStructField {
         f.debug_struct("UnnamedStructField")
             .field("ty", &self.ty.to_token_stream())
             .field("idx", &self.idx)
+            .field("swift_name", &self.swift_name)
             .finish()
     }
 }
@@ -6,6 +6,8 @@ use syn::Type;
 pub(crate) struct NormalizedStructField {
     pub accessor: NormalizedStructFieldAccessor,
     pub ty: Type,
+    /// The name of the field's Swift property.
+    pub swift_name: String,
 }
 
 pub(crate) enum NormalizedStructFieldAccessor {
@@ -1,9 +1,22 @@
 use crate::bridge_module_attributes::CfgAttr;
 use crate::SwiftBridgeModule;
 
+pub use self::camel_case::CamelCaseSwiftNames;
+pub use self::getter_properties::GetterProperties;
+pub use self::naming_convention::{NameCasing, NamingConvention};
+pub use self::swift_result_functions::SwiftResultFunctions;
+pub use self::swift_version::SwiftVersion;
+
+mod camel_case;
 mod generate_c_header;
+mod generate_ffi_symbols;
 mod generate_rust_tokens;
 mod generate_swift;
+mod generate_swift_test_stubs;
+mod getter_properties;
+mod naming_convention;
+mod swift_result_functions;
+mod swift_version;
 
 #[cfg(test)]
 mod codegen_tests;
@@ -22,17 +35,81 @@ pub struct CodegenConfig {
     /// This helps us decide whether or not to generate code for parts of the module
     /// that are annotated with `#[cfg(feature = "some-feature")]`
     pub crate_feature_lookup: Box<dyn Fn(&str) -> bool>,
+    /// The Swift version that the generated Swift code will be compiled with.
+    /// Language features that were introduced after this version will not be used.
+    /// `None` means that the latest Swift version can be targeted.
+    pub target_swift_version: Option<SwiftVersion>,
+    /// Whether or not to log from the `deinit` of generated Swift classes for opaque Rust types.
+    /// The logging is only compiled into `DEBUG` Swift builds.
+    /// This helps with matching up allocations and frees when debugging memory leaks.
+    pub log_deinit: bool,
+    /// Convert `snake_case` Rust function and method names into `camelCase` Swift names.
+    /// `None` means that the Swift functions keep their Rust names.
+    pub camel_case_swift_names: Option<CamelCaseSwiftNames>,
+    /// Generate Swift computed properties for methods that use `#[swift_bridge(get(...))]`.
+    /// `None` means that getters are generated as Swift methods.
+    pub getter_properties: Option<GetterProperties>,
+    /// Which Swift functions to generate for Rust functions that return a `Result`.
+    pub swift_result_functions: SwiftResultFunctions,
+    /// Warn about bridged functions that take more than this many arguments.
+    /// `None` means that the number of arguments is not checked.
+    pub max_function_arguments: Option<usize>,
+    /// Warn about bridged types and functions whose Swift names do not follow this naming
+    /// convention.
+    /// `None` means that the casing of Swift names is not checked.
+    pub naming_convention: Option<NamingConvention>,
+    /// Swift names that generated types and functions are not allowed to have, in addition to
+    /// Swift's keywords.
+    /// See [`SwiftBridgeModule::check_reserved_swift_names`].
+    pub reserved_swift_names: Vec<String>,
+    /// Mark generated Swift functions, methods and getter properties `@inlinable` when they only
+    /// pass primitive values to and from Rust, so that calls from other Swift modules can be
+    /// optimized.
+    pub inlinable_accessors: bool,
+    /// Declare the generated Swift classes for opaque Rust types `open` instead of `public`, so
+    /// that other Swift modules can subclass them and override their methods.
+    pub open_classes: bool,
+    /// Emit a doc comment on each generated Swift type that describes the type's FFI
+    /// representation, such as the order and widths of a shared struct's C fields.
+    pub ffi_layout_docs: bool,
+    /// Emit a doc comment on each generated Swift function that links to the bridged types that
+    /// it takes and returns, such as `/// - Parameter shape: ``Shape```.
+    pub doc_comment_type_links: bool,
+    /// Generate Swift code that can be compiled in Embedded Swift mode, which has no Foundation.
+    /// Foundation conveniences such as `Data` overloads, `toBytes` and `CustomNSError`
+    /// conformances are omitted.
+    pub embedded_swift: bool,
 }
 
-#[cfg(test)]
-impl CodegenConfig {
-    pub(crate) fn no_features_enabled() -> Self {
+/// No crate features are enabled and every codegen option is turned off.
+impl Default for CodegenConfig {
+    fn default() -> Self {
         CodegenConfig {
             crate_feature_lookup: Box::new(|_| false),
+            target_swift_version: None,
+            log_deinit: false,
+            camel_case_swift_names: None,
+            getter_properties: None,
+            swift_result_functions: SwiftResultFunctions::default(),
+            max_function_arguments: None,
+            naming_convention: None,
+            reserved_swift_names: vec![],
+            inlinable_accessors: false,
+            open_classes: false,
+            ffi_layout_docs: false,
+            doc_comment_type_links: false,
+            embedded_swift: false,
         }
     }
 }
 
+#[cfg(test)]
+impl CodegenConfig {
+    pub(crate) fn no_features_enabled() -> Self {
+        CodegenConfig::default()
+    }
+}
+
 impl SwiftBridgeModule {
     /// Generate the corresponding Swift code and C header for a bridge module.
     pub fn generate_swift_code_and_c_header(&self, config: CodegenConfig) -> SwiftCodeAndCHeader {
@@ -42,6 +119,11 @@ impl SwiftBridgeModule {
         }
     }
 
+    /// Generate the corresponding Swift code for a bridge module, without its C header.
+    pub fn generate_swift_code(&self, config: CodegenConfig) -> String {
+        self.generate_swift(&config)
+    }
+
     /// Whether or not the module's conditional compilation flags willl lead it to being included
     /// in the final binary.
     /// If not, when we won't generate any C or Swift code for it.
@@ -59,3 +141,12 @@ impl SwiftBridgeModule {
         true
     }
 }
+
+impl CodegenConfig {
+    /// Whether or not the generated Swift code can use the `Sendable` protocol.
+    pub(crate) fn swift_supports_sendable(&self) -> bool {
+        self.target_swift_version
+            .map(|version| version.supports_sendable())
+            .unwrap_or(true)
+    }
+}
@@ -0,0 +1,105 @@
+/// The acronyms that [`CamelCaseSwiftNames::default`] uses.
+const DEFAULT_ACRONYMS: &[&str] = &[
+    "API", "HTML", "HTTP", "HTTPS", "ID", "JSON", "URL", "UTF8", "UUID", "XML",
+];
+
+/// Converts `snake_case` Rust function and method names into idiomatic `camelCase` Swift names.
+///
+/// For example, `fn get_user_id` becomes `func getUserID` and `fn to_json` becomes `func toJSON`.
+///
+/// Functions that use `#[swift_bridge(swift_name = "...")]` keep the name that they were given.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CamelCaseSwiftNames {
+    /// Words that are written in all caps when they are not the first word of a name.
+    ///
+    /// With the acronym `"JSON"` the function `to_json` becomes `toJSON`, while `json_value`
+    /// becomes `jsonValue`.
+    pub acronyms: Vec<String>,
+}
+
+impl Default for CamelCaseSwiftNames {
+    fn default() -> Self {
+        CamelCaseSwiftNames {
+            acronyms: DEFAULT_ACRONYMS.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+}
+
+impl CamelCaseSwiftNames {
+    /// Convert a `snake_case` name into a `camelCase` name.
+    ///
+    /// Leading underscores are preserved, so `_private_fn` becomes `_privateFn`.
+    pub fn to_camel_case(&self, name: &str) -> String {
+        let without_leading_underscores = name.trim_start_matches('_');
+        let leading_underscores = &name[..name.len() - without_leading_underscores.len()];
+
+        let mut camel_case = leading_underscores.to_string();
+
+        for (idx, word) in without_leading_underscores
+            .split('_')
+            .filter(|word| !word.is_empty())
+            .enumerate()
+        {
+            if idx == 0 {
+                camel_case += word;
+                continue;
+            }
+
+            if let Some(acronym) = self
+                .acronyms
+                .iter()
+                .find(|acronym| acronym.eq_ignore_ascii_case(word))
+            {
+                camel_case += &acronym.to_uppercase();
+                continue;
+            }
+
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                camel_case.extend(first.to_uppercase());
+                camel_case += chars.as_str();
+            }
+        }
+
+        camel_case
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we convert snake_case names to camelCase names.
+    #[test]
+    fn snake_case_to_camel_case() {
+        let names = CamelCaseSwiftNames::default();
+
+        assert_eq!(names.to_camel_case("new"), "new");
+        assert_eq!(names.to_camel_case("get_value"), "getValue");
+        assert_eq!(names.to_camel_case("set_max_len"), "setMaxLen");
+        assert_eq!(names.to_camel_case("alreadyCamelCase"), "alreadyCamelCase");
+        assert_eq!(names.to_camel_case("_private_fn"), "_privateFn");
+        assert_eq!(
+            names.to_camel_case("double__underscore"),
+            "doubleUnderscore"
+        );
+        assert_eq!(names.to_camel_case("vec_2d"), "vec2d");
+    }
+
+    /// Verify that acronyms are uppercased unless they are the first word.
+    #[test]
+    fn acronyms() {
+        let names = CamelCaseSwiftNames::default();
+
+        assert_eq!(names.to_camel_case("to_json"), "toJSON");
+        assert_eq!(names.to_camel_case("user_id"), "userID");
+        assert_eq!(names.to_camel_case("json_value"), "jsonValue");
+        assert_eq!(names.to_camel_case("parse_http_url"), "parseHTTPURL");
+
+        let names = CamelCaseSwiftNames {
+            acronyms: vec!["Sql".to_string()],
+        };
+        assert_eq!(names.to_camel_case("run_sql"), "runSQL");
+        assert_eq!(names.to_camel_case("to_json"), "toJson");
+    }
+}
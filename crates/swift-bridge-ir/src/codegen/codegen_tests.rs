@@ -15,7 +15,9 @@
 
 #![cfg(test)]
 
-use crate::codegen::CodegenConfig;
+use crate::codegen::{
+    CamelCaseSwiftNames, CodegenConfig, GetterProperties, SwiftResultFunctions, SwiftVersion,
+};
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use std::collections::HashSet;
@@ -32,22 +34,52 @@ mod argument_label_codegen_tests;
 mod async_function_codegen_tests;
 mod boxed_fnonce_codegen_tests;
 mod built_in_tuple_codegen_tests;
+mod bytes_codegen_tests;
 mod c_header_declaration_order_codegen_tests;
+mod camel_case_swift_names_codegen_tests;
+mod case_iterable_attribute_codegen_tests;
+mod char_codegen_tests;
+mod comparable_attribute_codegen_tests;
 mod conditional_compilation_codegen_tests;
+mod conforms_to_attribute_codegen_tests;
+mod custom_reflectable_attribute_codegen_tests;
+mod custom_string_convertible_attribute_codegen_tests;
 mod derive_attribute_codegen_tests;
 mod derive_struct_attribute_codegen_tests;
+mod doc_comment_type_links_codegen_tests;
+mod embedded_swift_codegen_tests;
+mod error_domain_attribute_codegen_tests;
 mod extern_rust_function_opaque_rust_type_argument_codegen_tests;
 mod extern_rust_function_opaque_rust_type_return_codegen_tests;
 mod extern_rust_method_swift_class_placement_codegen_tests;
+mod ffi_layout_docs_codegen_tests;
+mod ffi_visibility_attribute_codegen_tests;
+mod from_str_attribute_codegen_tests;
 mod function_attribute_codegen_tests;
 mod generic_opaque_rust_type_codegen_tests;
+mod getter_properties_codegen_tests;
+mod identifiable_enum_attribute_codegen_tests;
+mod inlinable_accessors_codegen_tests;
+mod int_map_codegen_tests;
+mod log_deinit_codegen_tests;
+mod objc_attribute_codegen_tests;
 mod opaque_rust_type_codegen_tests;
 mod opaque_swift_type_codegen_tests;
+mod open_classes_codegen_tests;
 mod option_codegen_tests;
+mod option_set_attribute_codegen_tests;
+mod positional_names_attribute_codegen_tests;
 mod result_codegen_tests;
 mod return_into_attribute_codegen_tests;
+mod rust_ffi_file_attribute_codegen_tests;
+mod saturating_codegen_tests;
 mod single_representation_type_elision_codegen_tests;
+mod slice_codegen_tests;
 mod string_codegen_tests;
+mod string_map_codegen_tests;
+mod swift_result_functions_codegen_tests;
+mod target_swift_version_codegen_tests;
+mod time_codegen_tests;
 mod transparent_enum_codegen_tests;
 mod transparent_struct_codegen_tests;
 mod vec_codegen_tests;
@@ -62,19 +94,40 @@ struct CodegenTest {
     expected_c_header: ExpectedCHeader,
 }
 
+#[derive(Default)]
 struct BridgeModule {
     /// The bridge module's tokens
     pub tokens: TokenStream,
     /// A mock representation of the features that are enabled for the crate that contains the
     /// bridge module.
     pub enabled_crate_features: Vec<&'static str>,
+    /// The Swift version that the generated code targets.
+    pub target_swift_version: Option<SwiftVersion>,
+    /// Whether or not to log from the `deinit` of generated Swift classes.
+    pub log_deinit: bool,
+    /// Whether or not to convert Swift function names to camelCase.
+    pub camel_case_swift_names: Option<CamelCaseSwiftNames>,
+    /// Whether or not to generate Swift computed properties for getters.
+    pub getter_properties: Option<GetterProperties>,
+    /// Which Swift functions to generate for Rust functions that return a `Result`.
+    pub swift_result_functions: SwiftResultFunctions,
+    /// Whether or not to mark trivial Swift functions `@inlinable`.
+    pub inlinable_accessors: bool,
+    /// Whether or not to declare the classes for opaque Rust types `open`.
+    pub open_classes: bool,
+    /// Whether or not to document the FFI representation of each generated Swift type.
+    pub ffi_layout_docs: bool,
+    /// Whether or not to link bridged types from generated Swift doc comments.
+    pub doc_comment_type_links: bool,
+    /// Whether or not to generate Swift code that is compatible with Embedded Swift.
+    pub embedded_swift: bool,
 }
 
 impl From<TokenStream> for BridgeModule {
     fn from(tokens: TokenStream) -> Self {
         BridgeModule {
             tokens,
-            enabled_crate_features: vec![],
+            ..Default::default()
         }
     }
 }
@@ -170,6 +223,17 @@ impl CodegenTest {
         let crate_feature_lookup = Box::new(lookup);
         let codegen_config = CodegenConfig {
             crate_feature_lookup,
+            target_swift_version: self.bridge_module.target_swift_version,
+            log_deinit: self.bridge_module.log_deinit,
+            camel_case_swift_names: self.bridge_module.camel_case_swift_names,
+            getter_properties: self.bridge_module.getter_properties,
+            swift_result_functions: self.bridge_module.swift_result_functions,
+            inlinable_accessors: self.bridge_module.inlinable_accessors,
+            open_classes: self.bridge_module.open_classes,
+            ffi_layout_docs: self.bridge_module.ffi_layout_docs,
+            doc_comment_type_links: self.bridge_module.doc_comment_type_links,
+            embedded_swift: self.bridge_module.embedded_swift,
+            ..Default::default()
         };
 
         let swift = module.generate_swift(&codegen_config);
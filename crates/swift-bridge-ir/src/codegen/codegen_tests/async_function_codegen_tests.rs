@@ -501,7 +501,7 @@ extension SomeTypeRef {
             let wrapper = CbWrapper$SomeType$some_method(cb: callback)
             let wrapperPtr = Unmanaged.passRetained(wrapper).toOpaque()
 
-            __swift_bridge__$SomeType$some_method(wrapperPtr, onComplete, ptr)
+            __swift_bridge__$SomeType$some_method(wrapperPtr, onComplete, unconsumedPtr)
         })
     }
     class CbWrapper$SomeType$some_method {
@@ -685,7 +685,7 @@ mod extern_rust_async_function_returns_result_transparent_enum {
                 let task = async move {
                 let val = match fut.await {
                     Ok(ok) => ResultOkEnumAndErrEnum::Ok(ok.into_ffi_repr()),
-                    Err(err) => ResultOkEnumAndErrEnum::Err(err.into_ffi_repr()),
+                    Err(err) => ResultOkEnumAndErrEnum::Err(match err { ErrEnum::ErrVariant1 => 1, ErrEnum::ErrVariant2 => 2 }),
                 };
                     let callback_wrapper = callback_wrapper;
                     let callback_wrapper = callback_wrapper.0;
@@ -707,7 +707,7 @@ mod extern_rust_async_function_returns_result_transparent_enum {
 public func some_function() async throws -> OkEnum {
     func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: __swift_bridge__$ResultOkEnumAndErrEnum) {
         let wrapper = Unmanaged<CbWrapper$some_function>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
-        switch rustFnRetVal.tag { case __swift_bridge__$ResultOkEnumAndErrEnum$ResultOk: wrapper.cb(.success(rustFnRetVal.payload.ok.intoSwiftRepr())) case __swift_bridge__$ResultOkEnumAndErrEnum$ResultErr: wrapper.cb(.failure(rustFnRetVal.payload.err.intoSwiftRepr())) default: fatalError() }
+        switch rustFnRetVal.tag { case __swift_bridge__$ResultOkEnumAndErrEnum$ResultOk: wrapper.cb(.success(rustFnRetVal.payload.ok.intoSwiftRepr())) case __swift_bridge__$ResultOkEnumAndErrEnum$ResultErr: wrapper.cb(.failure({ () -> ErrEnum in switch rustFnRetVal.payload.err { case 1: return ErrEnum.ErrVariant1 case 2: return ErrEnum.ErrVariant2 default: fatalError() } }())) default: fatalError() }
     }
 
     return try await withCheckedThrowingContinuation({ (continuation: CheckedContinuation<OkEnum, Error>) in
@@ -786,7 +786,7 @@ mod extern_rust_async_function_returns_result_opaque_rust_transparent_enum {
                         let val: super::SomeType = ok;
                         val
                     })) as *mut super::SomeType),
-                    Err(err) => ResultSomeTypeAndErrEnum::Err(err.into_ffi_repr()),
+                    Err(err) => ResultSomeTypeAndErrEnum::Err(match err { ErrEnum::ErrVariant1 => 1, ErrEnum::ErrVariant2 => 2 }),
                 };
                     let callback_wrapper = callback_wrapper;
                     let callback_wrapper = callback_wrapper.0;
@@ -808,7 +808,7 @@ mod extern_rust_async_function_returns_result_opaque_rust_transparent_enum {
 public func some_function() async throws -> SomeType {
     func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: __swift_bridge__$ResultSomeTypeAndErrEnum) {
         let wrapper = Unmanaged<CbWrapper$some_function>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
-        switch rustFnRetVal.tag { case __swift_bridge__$ResultSomeTypeAndErrEnum$ResultOk: wrapper.cb(.success(SomeType(ptr: rustFnRetVal.payload.ok))) case __swift_bridge__$ResultSomeTypeAndErrEnum$ResultErr: wrapper.cb(.failure(rustFnRetVal.payload.err.intoSwiftRepr())) default: fatalError() }
+        switch rustFnRetVal.tag { case __swift_bridge__$ResultSomeTypeAndErrEnum$ResultOk: wrapper.cb(.success(SomeType(ptr: rustFnRetVal.payload.ok))) case __swift_bridge__$ResultSomeTypeAndErrEnum$ResultErr: wrapper.cb(.failure({ () -> ErrEnum in switch rustFnRetVal.payload.err { case 1: return ErrEnum.ErrVariant1 case 2: return ErrEnum.ErrVariant2 default: fatalError() } }())) default: fatalError() }
     }
 
     return try await withCheckedThrowingContinuation({ (continuation: CheckedContinuation<SomeType, Error>) in
@@ -876,14 +876,14 @@ mod extern_rust_async_function_returns_result_null_transparent_enum {
         ExpectedRustTokens::Contains(quote! {
              pub extern "C" fn __swift_bridge__some_function(
                 callback_wrapper: *mut std::ffi::c_void,
-                callback: extern "C" fn(*mut std::ffi::c_void, ResultVoidAndErrEnum) -> (),
+                callback: extern "C" fn(*mut std::ffi::c_void, i32) -> (),
             ) {
                 let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
                 let fut = super::some_function();
                 let task = async move {
                 let val = match fut.await {
-                    Ok(ok) => ResultVoidAndErrEnum::Ok,
-                    Err(err) => ResultVoidAndErrEnum::Err(err.into_ffi_repr()),
+                    Ok(_) => 0,
+                    Err(err) => match err { ErrEnum::ErrVariant1 => 1, ErrEnum::ErrVariant2 => 2 },
                 };
                     let callback_wrapper = callback_wrapper;
                     let callback_wrapper = callback_wrapper.0;
@@ -903,9 +903,13 @@ mod extern_rust_async_function_returns_result_null_transparent_enum {
         ExpectedSwiftCode::ContainsAfterTrim(
             r#"
 public func some_function() async throws -> () {
-    func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: __swift_bridge__$ResultVoidAndErrEnum) {
+    func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: Int32) {
         let wrapper = Unmanaged<CbWrapper$some_function>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
-        switch rustFnRetVal.tag { case __swift_bridge__$ResultVoidAndErrEnum$ResultOk: wrapper.cb(.success(())) case __swift_bridge__$ResultVoidAndErrEnum$ResultErr: wrapper.cb(.failure(rustFnRetVal.payload.err.intoSwiftRepr())) default: fatalError() }
+        if rustFnRetVal == 0 {
+            wrapper.cb(.success(()))
+        } else {
+            wrapper.cb(.failure({ () -> ErrEnum in switch rustFnRetVal { case 1: return ErrEnum.ErrVariant1 case 2: return ErrEnum.ErrVariant2 default: fatalError() } }()))
+        }
     }
 
     return try await withCheckedThrowingContinuation({ (continuation: CheckedContinuation<(), Error>) in
@@ -933,7 +937,7 @@ class CbWrapper$some_function {
     fn expected_c_header() -> ExpectedCHeader {
         ExpectedCHeader::ContainsAfterTrim(
             r#"
-void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge__$some_function$async(void* callback_wrapper, struct __swift_bridge__$ResultVoidAndErrEnum ret));
+void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge__$some_function$async(void* callback_wrapper, int32_t ret));
     "#,
         )
     }
@@ -1049,3 +1053,257 @@ void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge_
         .test();
     }
 }
+
+/// Verify that we generate the correct code for extern "Rust" async functions that returns a Result<u32, TransparentEnum>.
+/// The `Ok` value resumes the continuation and the `Err` value is thrown.
+mod extern_rust_async_function_returns_result_primitive_transparent_enum {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum ErrEnum {
+                    ErrVariant1,
+                    ErrVariant2,
+                }
+                extern "Rust" {
+                    async fn some_function() -> Result<u32, ErrEnum>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+             pub extern "C" fn __swift_bridge__some_function(
+                callback_wrapper: *mut std::ffi::c_void,
+                callback: extern "C" fn(*mut std::ffi::c_void, ResultU32AndErrEnum) -> (),
+            ) {
+                let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
+                let fut = super::some_function();
+                let task = async move {
+                let val = match fut.await {
+                    Ok(ok) => ResultU32AndErrEnum::Ok(ok),
+                    Err(err) => ResultU32AndErrEnum::Err(match err { ErrEnum::ErrVariant1 => 1, ErrEnum::ErrVariant2 => 2 }),
+                };
+                    let callback_wrapper = callback_wrapper;
+                    let callback_wrapper = callback_wrapper.0;
+
+                    (callback)(callback_wrapper, val)
+                };
+                swift_bridge::async_support::ASYNC_RUNTIME.spawn_task(Box::pin(task))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() async throws -> UInt32 {
+    func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: __swift_bridge__$ResultU32AndErrEnum) {
+        let wrapper = Unmanaged<CbWrapper$some_function>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
+        switch rustFnRetVal.tag { case __swift_bridge__$ResultU32AndErrEnum$ResultOk: wrapper.cb(.success(rustFnRetVal.payload.ok)) case __swift_bridge__$ResultU32AndErrEnum$ResultErr: wrapper.cb(.failure({ () -> ErrEnum in switch rustFnRetVal.payload.err { case 1: return ErrEnum.ErrVariant1 case 2: return ErrEnum.ErrVariant2 default: fatalError() } }())) default: fatalError() }
+    }
+
+    return try await withCheckedThrowingContinuation({ (continuation: CheckedContinuation<UInt32, Error>) in
+        let callback = { rustFnRetVal in
+            continuation.resume(with: rustFnRetVal)
+        }
+
+        let wrapper = CbWrapper$some_function(cb: callback)
+        let wrapperPtr = Unmanaged.passRetained(wrapper).toOpaque()
+
+        __swift_bridge__$some_function(wrapperPtr, onComplete)
+    })
+}
+class CbWrapper$some_function {
+    var cb: (Result<UInt32, Error>) -> ()
+
+    public init(cb: @escaping (Result<UInt32, Error>) -> ()) {
+        self.cb = cb
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge__$some_function$async(void* callback_wrapper, struct __swift_bridge__$ResultU32AndErrEnum ret));
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_async_function_returns_result_primitive_transparent_enum() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate the correct code for extern "Rust" async functions that returns a
+/// Result<u32, Box<dyn Error + Send + Sync>>.
+/// The boxed error's `Display` message is passed to Swift as a `RustString` that gets thrown.
+mod extern_rust_async_function_returns_result_primitive_boxed_error {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    async fn some_function() -> Result<u32, Box<dyn Error + Send + Sync>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+             pub extern "C" fn __swift_bridge__some_function(
+                callback_wrapper: *mut std::ffi::c_void,
+                callback: extern "C" fn(*mut std::ffi::c_void, ResultU32AndBoxedError) -> (),
+            ) {
+                let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
+                let fut = super::some_function();
+                let task = async move {
+                let val = match fut.await {
+                    Ok(ok) => ResultU32AndBoxedError::Ok(ok),
+                    Err(err) => ResultU32AndBoxedError::Err(
+                        swift_bridge::string::RustString(err.to_string()).box_into_raw()
+                    ),
+                };
+                    let callback_wrapper = callback_wrapper;
+                    let callback_wrapper = callback_wrapper.0;
+
+                    (callback)(callback_wrapper, val)
+                };
+                swift_bridge::async_support::ASYNC_RUNTIME.spawn_task(Box::pin(task))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() async throws -> UInt32 {
+    func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: __swift_bridge__$ResultU32AndBoxedError) {
+        let wrapper = Unmanaged<CbWrapper$some_function>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
+        switch rustFnRetVal.tag { case __swift_bridge__$ResultU32AndBoxedError$ResultOk: wrapper.cb(.success(rustFnRetVal.payload.ok)) case __swift_bridge__$ResultU32AndBoxedError$ResultErr: wrapper.cb(.failure(RustString(ptr: rustFnRetVal.payload.err))) default: fatalError() }
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge__$some_function$async(void* callback_wrapper, struct __swift_bridge__$ResultU32AndBoxedError ret));
+    "#,
+            r#"
+union __swift_bridge__$ResultU32AndBoxedError$Fields {uint32_t ok; void* err;};
+    "#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_async_function_returns_result_primitive_boxed_error() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate the correct code for extern "Swift" async functions that return a
+/// Result<u32, TransparentEnum>.
+/// Rust awaits a future that Swift completes once the `async throws` function returns or throws.
+mod extern_swift_async_function_returns_result_primitive_transparent_enum {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum ErrEnum {
+                    ErrVariant1,
+                    ErrVariant2,
+                }
+                extern "Swift" {
+                    async fn some_function(arg: u8) -> Result<u32, ErrEnum>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub async fn some_function(arg: u8) -> Result<u32, ErrEnum> {
+                    extern "C" fn on_complete(callback_wrapper: *mut std::ffi::c_void, val: ResultU32AndErrEnum) {
+                        unsafe {
+                            swift_bridge::async_support::SwiftFuture::<ResultU32AndErrEnum>::complete(callback_wrapper, val)
+                        }
+                    }
+
+                    let fut = {
+                        let (fut, callback_wrapper) = swift_bridge::async_support::SwiftFuture::<ResultU32AndErrEnum>::new();
+                        unsafe { __swift_bridge__some_function(callback_wrapper, on_complete, arg) };
+                        fut
+                    };
+                    let val = fut.await;
+                    match val {
+                        ResultU32AndErrEnum::Ok(ok) => std::result::Result::Ok(ok),
+                        ResultU32AndErrEnum::Err(err) => std::result::Result::Err(match err {
+                            1 => ErrEnum::ErrVariant1,
+                            2 => ErrEnum::ErrVariant2,
+                            _ => unreachable!(),
+                        }),
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    callback_wrapper: *mut std::ffi::c_void,
+                    callback: extern "C" fn(*mut std::ffi::c_void, ResultU32AndErrEnum) -> (),
+                    arg: u8
+                );
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ callbackWrapper: UnsafeMutableRawPointer, _ callback: @convention(c) (UnsafeMutableRawPointer, __swift_bridge__$ResultU32AndErrEnum) -> Void, _ arg: UInt8) {
+    Task {
+        callback(callbackWrapper, await { do { let ok = try await some_function(arg: arg); return __swift_bridge__$ResultU32AndErrEnum(tag: __swift_bridge__$ResultU32AndErrEnum$ResultOk, payload: __swift_bridge__$ResultU32AndErrEnum$Fields(ok: ok)) } catch let err as ErrEnum { return __swift_bridge__$ResultU32AndErrEnum(tag: __swift_bridge__$ResultU32AndErrEnum$ResultErr, payload: __swift_bridge__$ResultU32AndErrEnum$Fields(err: { () -> Int32 in switch err { case ErrEnum.ErrVariant1: return 1 case ErrEnum.ErrVariant2: return 2 } }())) } catch { fatalError("Expected the thrown error to be a ErrEnum, but it was \(error)") } }())
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_async_function_returns_result_primitive_transparent_enum() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
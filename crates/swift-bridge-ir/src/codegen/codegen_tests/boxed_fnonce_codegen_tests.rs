@@ -1,4 +1,4 @@
-//! Tests for passing Box<dyn FnOnce(A, B) -> C> between languages.
+//! Tests for passing Box<dyn FnOnce(A, B) -> C> and Box<dyn Fn(A, B) -> C> between languages.
 //!
 //! `*mut dyn FnOnce(A, B) -> C` is not FFI safe, so we pass `*mut Box dyn FnOnce(A, B) -> C`
 //! over FFI instead.
@@ -994,3 +994,143 @@ void __swift_bridge__$SomeType$some_method$_free$param1(void* some_method_callba
         .test();
     }
 }
+
+/// Verify that we can pass a `Box<dyn Fn>` callback to a Swift method that returns a `Result`.
+///
+/// Swift can call an `Fn` callback any number of times, so Rust borrows the boxed function on
+/// each call and only frees it once Swift's callback class is deinitialized, which also happens
+/// when the Swift method throws.
+mod test_swift_method_takes_fn_callback_and_returns_result {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    type SomeType;
+
+                    fn some_method(
+                        &self,
+                        callback: Box<dyn Fn(u8) -> ()>
+                    ) -> Result<u16, u32>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_method (&self, callback: Box<dyn Fn(u8) -> ()>) -> Result<u16, u32>
+            },
+            quote! {
+                Box::into_raw(Box::new(callback)) as *mut Box<dyn Fn(u8) -> ()>
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$some_method$param1"]
+                pub extern "C" fn SomeType_some_method_param1(some_method_callback: *mut Box<dyn Fn(u8) -> ()>, arg0: u8) {
+                    unsafe { (*some_method_callback)(arg0) }
+                }
+
+                #[export_name = "__swift_bridge__$SomeType$some_method$_free$param1"]
+                pub extern "C" fn free_SomeType_some_method_param1(some_method_callback: *mut Box<dyn Fn(u8) -> ()>) {
+                    let _ = unsafe { Box::from_raw(some_method_callback) };
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+class __private__RustFnCallback$SomeType$some_method$param1 {
+    var ptr: UnsafeMutableRawPointer
+
+    init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    deinit {
+        __swift_bridge__$SomeType$some_method$_free$param1(ptr)
+    }
+
+    func call(_ arg0: UInt8) {
+        return __swift_bridge__$SomeType$some_method$param1(ptr, arg0)
+    }
+}
+            "#,
+            r#"{ let cb1 = __private__RustFnCallback$SomeType$some_method$param1(ptr: callback); return { do { let ok = try Unmanaged<SomeType>.fromOpaque(this).takeUnretainedValue().some_method(callback: { arg0 in cb1.call(arg0) });"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$SomeType$some_method$param1(void* some_method_callback, uint8_t arg0);
+void __swift_bridge__$SomeType$some_method$_free$param1(void* some_method_callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn test_swift_method_takes_fn_callback_and_returns_result() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a callback class for a `Box<dyn Fn()>` that has no args or return
+/// value, since the pre-defined no args class can only be called once.
+mod test_swift_takes_no_args_no_return_fn_callback {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(callback: Box<dyn Fn()>);
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    func call() {
+        return __swift_bridge__$some_function$param0(ptr)
+    }
+"#,
+            r#"
+    { let cb0 = __private__RustFnCallback$some_function$param0(ptr: callback); let _ = some_function(callback: { cb0.call() }) }()
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function$param0(void* some_function_callback);
+void __swift_bridge__$some_function$_free$param0(void* some_function_callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn test_swift_takes_no_args_no_return_fn_callback() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::Contains(quote! {
+                unsafe { (*some_function_callback)() }
+            }),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -275,6 +275,74 @@ struct __swift_bridge__$tuple$F32IntBool __swift_bridge__$some_function(struct _
     }
 }
 
+/// Verify that we can return a (primitive type, Option<transparent enum type>) from a Rust
+/// function, so that a partial result can be returned along with a non-fatal error.
+mod extern_rust_tuple_primitive_and_option_transparent_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum SomeError {
+                    Variant(u8),
+                }
+
+                extern "Rust" {
+                    fn some_function() -> (u32, Option<SomeError>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function () -> __swift_bridge__tuple_U32OptionSomeError {
+                    { let val = super::some_function(); __swift_bridge__tuple_U32OptionSomeError(val.0, __swift_bridge__Option_SomeError::from_rust_repr(val.1)) }
+                }
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__tuple_U32OptionSomeError(u32, __swift_bridge__Option_SomeError);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() -> (UInt32, Optional<SomeError>) {
+    { let val = __swift_bridge__$some_function(); return (val._0, val._1.intoSwiftRepr()); }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct __swift_bridge__$tuple$U32OptionSomeError { uint32_t _0; struct __swift_bridge__$Option$SomeError _1; } __swift_bridge__$tuple$U32OptionSomeError;
+"#,
+            r#"
+struct __swift_bridge__$tuple$U32OptionSomeError __swift_bridge__$some_function(void);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_tuple_primitive_and_option_transparent_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we can use a (primitive type, primitive type) as Swift function arg and return type.
 mod extern_swift_tuple_primitives {
     use super::*;
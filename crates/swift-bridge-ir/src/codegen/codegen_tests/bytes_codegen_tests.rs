@@ -0,0 +1,117 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for a Rust function that takes a `bytes::Bytes`.
+mod extern_rust_fn_bytes_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Bytes);
+                    fn another_function(arg: bytes::Bytes);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: swift_bridge::bytes_support::FfiSwiftBytes
+                ) {
+                    super::some_function(arg.into_bytes())
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$another_function"]
+                pub extern "C" fn __swift_bridge__another_function(
+                    arg: swift_bridge::bytes_support::FfiSwiftBytes
+                ) {
+                    super::another_function(arg.into_bytes())
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "import Foundation",
+            r#"
+public func some_function(_ arg: Data) {
+    __swift_bridge__$some_function({ let data = arg as NSData; return __private__SwiftBytes(owner: Unmanaged.passRetained(data).toOpaque(), start: data.bytes.assumingMemoryBound(to: UInt8.self), len: UInt(data.length), release: { owner in Unmanaged<NSData>.fromOpaque(owner!).release() }) }())
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void __swift_bridge__$some_function(struct __private__SwiftBytes arg);",
+            "void __swift_bridge__$another_function(struct __private__SwiftBytes arg);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_bytes_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an opaque type named `Bytes` is not treated as `bytes::Bytes`.
+mod extern_rust_fn_opaque_type_named_bytes {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Bytes;
+                    fn some_function(arg: Bytes);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: *mut super::Bytes) {
+                super::some_function(unsafe { *Box::from_raw(arg) })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Bytes) {
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim("void __swift_bridge__$some_function(void* arg);")
+    }
+
+    #[test]
+    fn extern_rust_fn_opaque_type_named_bytes() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
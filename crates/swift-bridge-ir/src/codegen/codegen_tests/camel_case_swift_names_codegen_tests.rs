@@ -0,0 +1,110 @@
+use super::{BridgeModule, CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use crate::codegen::CamelCaseSwiftNames;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+fn bridge_module_tokens() -> TokenStream {
+    quote! {
+        #[swift_bridge::bridge]
+        mod ffi {
+            extern "Rust" {
+                type SomeType;
+
+                fn to_json(&self) -> u8;
+                fn get_user_id(&self) -> u32;
+                #[swift_bridge(swift_name = "keepThisName")]
+                fn some_renamed_method(&self);
+            }
+
+            extern "Rust" {
+                fn make_http_request();
+            }
+
+            extern "Swift" {
+                fn swift_parse_json();
+            }
+        }
+    }
+}
+
+fn bridge_module(camel_case_swift_names: Option<CamelCaseSwiftNames>) -> BridgeModule {
+    BridgeModule {
+        tokens: bridge_module_tokens(),
+        camel_case_swift_names,
+        ..Default::default()
+    }
+}
+
+/// Verify that we convert snake_case function and method names to camelCase Swift names when the
+/// option is enabled, while still calling the snake_case FFI functions.
+mod camel_case_swift_names_enabled {
+    use super::*;
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func makeHTTPRequest() {
+    __swift_bridge__$make_http_request()
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$swift_parse_json")
+func __swift_bridge__swift_parse_json () {
+    swiftParseJSON()
+}
+"#,
+            r#"
+    public func toJSON() -> UInt8 {
+        __swift_bridge__$SomeType$to_json(unconsumedPtr)
+    }
+"#,
+            r#"
+    public func getUserID() -> UInt32 {
+        __swift_bridge__$SomeType$get_user_id(unconsumedPtr)
+    }
+"#,
+            r#"
+    public func keepThisName() {
+        __swift_bridge__$SomeType$some_renamed_method(unconsumedPtr)
+    }
+"#,
+        ])
+    }
+
+    #[test]
+    fn camel_case_swift_names_enabled() {
+        CodegenTest {
+            bridge_module: bridge_module(Some(CamelCaseSwiftNames::default())),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we keep the Rust function names by default.
+mod camel_case_swift_names_disabled {
+    use super::*;
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "public func make_http_request() {",
+            "swift_parse_json()",
+            "public func to_json() -> UInt8 {",
+            "public func get_user_id() -> UInt32 {",
+            "public func keepThisName() {",
+        ])
+    }
+
+    #[test]
+    fn camel_case_swift_names_disabled() {
+        CodegenTest {
+            bridge_module: bridge_module(None),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,76 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a `CaseIterable` conformance whose `allCases` lists the variants in
+/// the order that they were declared in Rust, for an enum annotated with
+/// `#[swift_bridge(case_iterable)]`.
+mod case_iterable_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(case_iterable)]
+                enum Weekday {
+                    Wednesday,
+                    Monday,
+                    Tuesday,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Weekday: CaseIterable {
+    public static var allCases: [Weekday] {
+        [.Wednesday, .Monday, .Tuesday]
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn case_iterable_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate a `CaseIterable` conformance for enums without the
+/// `case_iterable` attribute.
+mod enum_without_case_iterable_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Weekday {
+                    Monday,
+                    Tuesday,
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn enum_without_case_iterable_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("CaseIterable"),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,170 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for a Rust function that takes and returns a `char`.
+mod extern_rust_fn_char {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: char) -> char;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: u32) -> u32 {
+                (super::some_function(
+                    char::from_u32(arg).unwrap_or(char::REPLACEMENT_CHARACTER)
+                ) as u32)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Character) -> Character {
+    Character(Unicode.Scalar(__swift_bridge__$some_function(arg.unicodeScalars.first!.value))!)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "#include <stdint.h>",
+            "uint32_t __swift_bridge__$some_function(uint32_t arg);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_char() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Rust function that takes and returns an `Option<char>`.
+/// `None` is represented by the `is_some` flag, not by a sentinel scalar value.
+mod extern_rust_fn_option_char {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<char>) -> Option<char>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::option::OptionU32
+            ) -> swift_bridge::option::OptionU32 {
+                if let Some(val) = super::some_function(
+                    {
+                        let val = arg;
+                        if val.is_some {
+                            Some(char::from_u32(val.val).unwrap_or(char::REPLACEMENT_CHARACTER))
+                        } else {
+                            None
+                        }
+                    }
+                ) {
+                    swift_bridge::option::OptionU32 { val: val as u32, is_some: true }
+                } else {
+                    swift_bridge::option::OptionU32 { val: 123, is_some: false }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<Character>) -> Optional<Character> {
+    __swift_bridge__$some_function(arg.map { $0.unicodeScalars.first!.value }.intoFfiRepr()).intoSwiftRepr().map { Character(Unicode.Scalar($0)!) }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__OptionU32 __swift_bridge__$some_function(struct __private__OptionU32 arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_char() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Swift function that takes a `char` and returns an `Option<char>`.
+mod extern_swift_fn_char {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: char) -> Option<char>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[link_name = "__swift_bridge__$some_function"]
+            fn __swift_bridge__some_function(arg: u32) -> swift_bridge::option::OptionU32;
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UInt32) -> __private__OptionU32 {
+    some_function(arg: Character(Unicode.Scalar(arg)!)).map { $0.unicodeScalars.first!.value }.intoFfiRepr()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_char() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,208 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate an `Int` backed Swift enum that conforms to `Comparable` for an enum
+/// annotated with `#[swift_bridge(comparable)]`.
+mod comparable_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(comparable)]
+                enum Priority {
+                    Low = -1,
+                    Medium = 5,
+                    High = 10,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[derive(Copy, Clone)]
+            pub enum Priority {
+                Low = -1,
+                Medium = 5,
+                High = 10
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public enum Priority: Int {
+    case Low = -1
+    case Medium = 5
+    case High = 10
+}
+"#,
+            r#"
+extension Priority: Comparable {
+    public static func < (lhs: Priority, rhs: Priority) -> Bool {
+        lhs.rawValue < rhs.rawValue
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn comparable_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate a `Comparable` conformance for enums without the `comparable`
+/// attribute, even if their variants have discriminants.
+mod enum_without_comparable_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Priority {
+                    Low = 1,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public enum Priority {
+    case Low
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn enum_without_comparable_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a `Comparable` conformance for a struct annotated with
+/// `#[swift_bridge(comparable)]` that compares its fields lexicographically, in declaration order.
+mod comparable_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(comparable)]
+                enum Channel {
+                    Beta = 0,
+                    Stable = 1,
+                }
+
+                #[swift_bridge(swift_repr = "struct", comparable)]
+                struct Version {
+                    major: u32,
+                    minor: u32,
+                    channel: Channel,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Version: Comparable {
+    public static func == (lhs: Version, rhs: Version) -> Bool {
+        lhs.major == rhs.major && lhs.minor == rhs.minor && lhs.channel == rhs.channel
+    }
+
+    public static func < (lhs: Version, rhs: Version) -> Bool {
+        if lhs.major != rhs.major {
+            return lhs.major < rhs.major
+        }
+        if lhs.minor != rhs.minor {
+            return lhs.minor < rhs.minor
+        }
+        return lhs.channel < rhs.channel
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn comparable_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a `comparable` struct that derives `Hash` uses the `==` from its `Hashable`
+/// conformance instead of declaring another one.
+mod comparable_hashable_tuple_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", comparable)]
+                #[derive(Hash)]
+                struct Point(i32, i32);
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Point: Comparable {
+    public static func < (lhs: Point, rhs: Point) -> Bool {
+        if lhs._0 != rhs._0 {
+            return lhs._0 < rhs._0
+        }
+        return lhs._1 < rhs._1
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn comparable_hashable_tuple_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -20,6 +20,7 @@ mod cfg_feature_bridge_module_feature_enabled {
         BridgeModule {
             tokens,
             enabled_crate_features: vec!["some-feature"],
+            ..Default::default()
         }
     }
 
@@ -79,7 +80,7 @@ mod cfg_feature_bridge_module_feature_disabled {
         };
         BridgeModule {
             tokens,
-            enabled_crate_features: vec![],
+            ..Default::default()
         }
     }
 
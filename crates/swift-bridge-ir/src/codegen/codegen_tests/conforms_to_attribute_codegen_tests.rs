@@ -0,0 +1,75 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we emit an extension with the protocols listed in an opaque Rust type's
+/// `#[swift_bridge(conforms_to = "...")]` attribute.
+mod conforms_to_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(conforms_to = "MyProtocol, AnotherProtocol")]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn conforms_to_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::ContainsAfterTrim(
+                "extension SomeType: MyProtocol, AnotherProtocol {}",
+            ),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we emit an extension with the protocols listed in a shared struct's and a shared
+/// enum's `#[swift_bridge(conforms_to = "...")]` attribute, using their Swift names.
+mod conforms_to_shared_types {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", swift_name = "FfiStruct", conforms_to = "MyProtocol")]
+                struct SomeStruct {
+                    field: u8,
+                }
+
+                #[swift_bridge(conforms_to = "MyProtocol, AnotherProtocol")]
+                enum SomeEnum {
+                    Variant,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "extension FfiStruct: MyProtocol {}",
+            "extension SomeEnum: MyProtocol, AnotherProtocol {}",
+        ])
+    }
+
+    #[test]
+    fn conforms_to_shared_types() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,86 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a `CustomStringConvertible` conformance that describes each variant's
+/// fields for an enum annotated with `#[swift_bridge(custom_string_convertible)]`.
+mod custom_string_convertible_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(custom_string_convertible)]
+                enum Shape {
+                    Circle { radius: f64 },
+                    Rectangle(u32, u32),
+                    Labeled(String),
+                    Empty,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Shape: CustomStringConvertible {
+    public var description: String {
+        switch self {
+        case let .Circle(value0):
+            return "Circle(radius: \(value0))"
+        case let .Rectangle(value0, value1):
+            return "Rectangle(\(value0), \(value1))"
+        case let .Labeled(value0):
+            return "Labeled(\(String(reflecting: value0.toString())))"
+        case .Empty:
+            return "Empty"
+        }
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn custom_string_convertible_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate a `CustomStringConvertible` conformance for enums without the
+/// `custom_string_convertible` attribute.
+mod without_custom_string_convertible_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Shape {
+                    Circle { radius: f64 },
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn without_custom_string_convertible_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim(
+                "CustomStringConvertible",
+            ),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -66,3 +66,118 @@ void* __swift_bridge__$SomeEnum$Debug(__swift_bridge__$SomeEnum this);
         .test();
     }
 }
+
+/// Verify that we generate an Equatable conformance in Swift and derive PartialEq in Rust when
+/// using #\[derive(PartialEq)] on an enum with data.
+mod derive_partial_eq_enum_with_data {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(PartialEq)]
+                enum SomeEnum {
+                    Variant1(u8, String),
+                    Variant2 { field: i32 },
+                    Variant3
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[derive(PartialEq)]
+            pub enum SomeEnum {
+                Variant1(u8, String),
+                Variant2 { field: i32 },
+                Variant3
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeEnum: Equatable {
+    public static func == (lhs: SomeEnum, rhs: SomeEnum) -> Bool {
+        switch (lhs, rhs) {
+        case let (.Variant1(lhs0, lhs1), .Variant1(rhs0, rhs1)):
+            return lhs0 == rhs0 && lhs1.as_str() == rhs1.as_str()
+        case let (.Variant2(lhs0), .Variant2(rhs0)):
+            return lhs0 == rhs0
+        case (.Variant3, .Variant3):
+            return true
+        default:
+            return false
+        }
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn derive_partial_eq_enum_with_data() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we don't generate an Equatable conformance in Swift for an enum without data, since
+/// Swift synthesizes it.
+mod derive_partial_eq_enum_without_data {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(PartialEq)]
+                enum SomeEnum {
+                    Variant1,
+                    Variant2
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[derive(Copy, Clone, PartialEq)]
+            pub enum SomeEnum {
+                Variant1,
+                Variant2
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("Equatable")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn derive_partial_eq_enum_without_data() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
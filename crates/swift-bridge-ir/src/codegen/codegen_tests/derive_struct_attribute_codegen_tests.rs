@@ -93,3 +93,109 @@ mod derive_clone_struct {
         .test();
     }
 }
+
+/// Verify that deriving `Hash` on a transparent struct generates a Swift `Hashable`
+/// conformance that compares and hashes every field in declaration order.
+mod derive_hash_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(Hash)]
+                struct SomeStruct {
+                    field: u8,
+                    other_field: Option<i32>,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![quote! {
+            #[derive(Hash)]
+            pub struct SomeStruct {
+                pub field: u8,
+                pub other_field: Option<i32>
+            }
+        }])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct: Hashable {
+    public static func == (lhs: SomeStruct, rhs: SomeStruct) -> Bool {
+        lhs.field == rhs.field && lhs.other_field == rhs.other_field
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(self.field)
+        hasher.combine(self.other_field)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn generates_hashable_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that tuple struct fields are hashed using their Swift names.
+mod derive_hash_tuple_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(Hash)]
+                struct SomeStruct(u8, bool);
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct: Hashable {
+    public static func == (lhs: SomeStruct, rhs: SomeStruct) -> Bool {
+        lhs._0 == rhs._0 && lhs._1 == rhs._1
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(self._0)
+        hasher.combine(self._1)
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn generates_hashable_tuple_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
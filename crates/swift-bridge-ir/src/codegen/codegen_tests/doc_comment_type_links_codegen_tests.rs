@@ -0,0 +1,106 @@
+use super::{BridgeModule, CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a doc comment that links to the bridged types that a function takes
+/// and returns.
+mod function_doc_comment_links_bridged_types {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Point {
+                    x: f64,
+                    y: f64,
+                }
+
+                extern "Rust" {
+                    type Shape;
+
+                    fn make_shape(origin: Point, sides: u8) -> Option<Shape>;
+                    fn translate(self: &mut Shape, offset: Point);
+                    fn nearest(shapes: Vec<Shape>, fallback: Point) -> Point;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+/// - Parameter origin: ``Point``
+/// - Returns: ``Shape``
+public func make_shape(_ origin: Point, _ sides: UInt8) -> Optional<Shape> {
+"#,
+            r#"
+    /// - Parameter offset: ``Point``
+    public func translate(_ offset: Point) {
+"#,
+            r#"
+/// - Parameter shapes: ``Shape``
+/// - Parameter fallback: ``Point``
+/// - Returns: ``Point``
+public func nearest(_ shapes: RustVec<Shape>, _ fallback: Point) -> Point {
+"#,
+        ])
+    }
+
+    #[test]
+    fn function_doc_comment_links_bridged_types() {
+        CodegenTest {
+            bridge_module: BridgeModule {
+                tokens: bridge_module_tokens(),
+                doc_comment_type_links: true,
+                ..Default::default()
+            },
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+
+    /// Verify that the doc comments are only generated when the option is enabled.
+    #[test]
+    fn function_doc_comment_links_disabled_by_default() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("///"),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate a doc comment for a function that only uses built-in types.
+mod function_without_bridged_types_has_no_doc_comment {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn add(a: u8, b: u8) -> u8;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn function_without_bridged_types_has_no_doc_comment() {
+        CodegenTest {
+            bridge_module: BridgeModule {
+                tokens: bridge_module_tokens(),
+                doc_comment_type_links: true,
+                ..Default::default()
+            },
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("///"),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
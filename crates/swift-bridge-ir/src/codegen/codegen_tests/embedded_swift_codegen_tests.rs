@@ -0,0 +1,103 @@
+use super::{BridgeModule, CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+fn bridge_module_tokens() -> TokenStream {
+    quote! {
+        #[swift_bridge::bridge]
+        mod ffi {
+            #[swift_bridge(swift_repr = "struct", bytes)]
+            struct SomePoint {
+                x: f64,
+                y: f64,
+            }
+
+            #[swift_bridge(swift_repr = "struct", error_domain = "com.example.io")]
+            struct SomeError {
+                #[swift_bridge(error_description)]
+                message: String,
+            }
+
+            #[swift_bridge(error_domain = "com.example.query")]
+            enum SomeErrorEnum {
+                NotFound,
+                Timeout,
+            }
+
+            extern "Rust" {
+                fn checksum(bytes: &[u8]) -> u32;
+            }
+        }
+    }
+}
+
+fn bridge_module(embedded_swift: bool) -> BridgeModule {
+    BridgeModule {
+        tokens: bridge_module_tokens(),
+        embedded_swift,
+        ..Default::default()
+    }
+}
+
+/// Verify that when generating code for Embedded Swift we do not import Foundation or use any
+/// Foundation types or protocols.
+mod embedded_swift_enabled {
+    use super::*;
+
+    #[test]
+    fn embedded_swift_enabled() {
+        for foundation_only in [
+            "import Foundation",
+            "Data",
+            "LocalizedError",
+            "CustomNSError",
+        ] {
+            CodegenTest {
+                bridge_module: bridge_module(true),
+                expected_rust_tokens: ExpectedRustTokens::SkipTest,
+                expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim(foundation_only),
+                expected_c_header: ExpectedCHeader::SkipTest,
+            }
+            .test();
+        }
+    }
+
+    /// Verify that the Foundation-free parts of the bridge are still generated.
+    #[test]
+    fn embedded_swift_keeps_non_foundation_code() {
+        CodegenTest {
+            bridge_module: bridge_module(true),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+                "public struct SomePoint {",
+                "public enum SomeErrorEnum {",
+                "public func checksum(_ bytes: UnsafeBufferPointer<UInt8>) -> UInt32 {",
+            ]),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we import Foundation and generate Foundation conveniences by default.
+mod embedded_swift_disabled {
+    use super::*;
+
+    #[test]
+    fn embedded_swift_disabled() {
+        CodegenTest {
+            bridge_module: bridge_module(false),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+                "import Foundation",
+                "public func checksum(_ bytes: Data) -> UInt32 {",
+                "extension SomePoint {\n    public func toBytes() -> Data {",
+                "extension SomeError: LocalizedError {",
+                "extension SomeError: CustomNSError {",
+                "extension SomeErrorEnum: CustomNSError {",
+            ]),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
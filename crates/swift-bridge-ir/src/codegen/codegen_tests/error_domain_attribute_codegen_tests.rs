@@ -0,0 +1,177 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a `CustomNSError` conformance for an enum annotated with
+/// `#[swift_bridge(error_domain = "...")]`, using the variants' discriminants as the error codes.
+mod error_domain_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(error_domain = "com.example.query")]
+                enum QueryError {
+                    NotFound = 404,
+                    Timeout,
+                    Unknown = 1,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "import Foundation",
+            r#"
+extension QueryError: CustomNSError {
+    public static var errorDomain: String {
+        "com.example.query"
+    }
+
+    public var errorCode: Int {
+        switch self {
+        case .NotFound:
+            return 404
+        case .Timeout:
+            return 405
+        case .Unknown:
+            return 1
+        }
+    }
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn error_domain_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a data carrying enum's error codes are the variants' positions.
+mod error_domain_enum_with_data {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(error_domain = "com.example")]
+                enum SomeError {
+                    Variant1(u8),
+                    Variant2,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public var errorCode: Int {
+        switch self {
+        case .Variant1:
+            return 0
+        case .Variant2:
+            return 1
+        }
+    }
+"#,
+        )
+    }
+
+    #[test]
+    fn error_domain_enum_with_data() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a `CustomNSError` conformance for a struct annotated with
+/// `#[swift_bridge(error_domain = "...")]`.
+mod error_domain_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", error_domain = "com.example.io")]
+                struct IoError {
+                    code: u8,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "import Foundation",
+            r#"
+extension IoError: CustomNSError {
+    public static var errorDomain: String {
+        "com.example.io"
+    }
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn error_domain_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate a `CustomNSError` conformance for types without the
+/// `error_domain` attribute.
+mod without_error_domain_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum SomeError {
+                    Variant,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct AnotherError {
+                    code: u8,
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn without_error_domain_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("CustomNSError"),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -48,14 +48,19 @@ public class SomeType: SomeTypeRefMut {
             __swift_bridge__$SomeType$_free(ptr)
         }
     }
+
+    override var unconsumedPtr: UnsafeMutableRawPointer {
+        precondition(isOwned, "Attempted to use a value that was already consumed.")
+        return ptr
+    }
 }
 extension SomeType {
     public func a() {
-        __swift_bridge__$SomeType$a({isOwned = false; return ptr;}())
+        __swift_bridge__$SomeType$a({precondition(isOwned, "Attempted to use a value that was already consumed."); isOwned = false; return ptr;}())
     }
 
     public func b() {
-        __swift_bridge__$SomeType$b({isOwned = false; return ptr;}())
+        __swift_bridge__$SomeType$b({precondition(isOwned, "Attempted to use a value that was already consumed."); isOwned = false; return ptr;}())
     }
 }
 public class SomeTypeRefMut: SomeTypeRef {
@@ -65,11 +70,11 @@ public class SomeTypeRefMut: SomeTypeRef {
 }
 extension SomeTypeRefMut {
     public func e() {
-        __swift_bridge__$SomeType$e(ptr)
+        __swift_bridge__$SomeType$e(unconsumedPtr)
     }
 
     public func f() {
-        __swift_bridge__$SomeType$f(ptr)
+        __swift_bridge__$SomeType$f(unconsumedPtr)
     }
 }
 public class SomeTypeRef {
@@ -78,14 +83,18 @@ public class SomeTypeRef {
     public init(ptr: UnsafeMutableRawPointer) {
         self.ptr = ptr
     }
+
+    var unconsumedPtr: UnsafeMutableRawPointer {
+        ptr
+    }
 }
 extension SomeTypeRef {
     public func c() {
-        __swift_bridge__$SomeType$c(ptr)
+        __swift_bridge__$SomeType$c(unconsumedPtr)
     }
 
     public func d() {
-        __swift_bridge__$SomeType$d(ptr)
+        __swift_bridge__$SomeType$d(unconsumedPtr)
     }
 }
 "#,
@@ -107,3 +116,66 @@ extension SomeTypeRef {
         .test();
     }
 }
+
+/// Verify that methods that were declared in different extern "Rust" blocks get generated into
+/// separate Swift extensions.
+mod extern_rust_methods_grouped_by_extern_block {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn a(&self);
+                    fn b(&self);
+                }
+
+                extern "Rust" {
+                    fn c(self: &SomeType);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRef {
+    public func a() {
+        __swift_bridge__$SomeType$a(unconsumedPtr)
+    }
+
+    public func b() {
+        __swift_bridge__$SomeType$b(unconsumedPtr)
+    }
+}
+extension SomeTypeRef {
+    public func c() {
+        __swift_bridge__$SomeType$c(unconsumedPtr)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_rust_methods_grouped_by_extern_block() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,91 @@
+use super::{BridgeModule, CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+fn bridge_module_tokens() -> TokenStream {
+    quote! {
+        #[swift_bridge::bridge]
+        mod ffi {
+            #[swift_bridge(swift_repr = "struct")]
+            struct SomeStruct {
+                count: u32,
+                ratio: f64,
+                name: String,
+                maybe: Option<u8>,
+            }
+
+            enum SomeEnum {
+                Circle(f32),
+                Square { side: u32 },
+            }
+
+            extern "Rust" {
+                type SomeType;
+            }
+        }
+    }
+}
+
+fn bridge_module(ffi_layout_docs: bool) -> BridgeModule {
+    BridgeModule {
+        tokens: bridge_module_tokens(),
+        ffi_layout_docs,
+        ..Default::default()
+    }
+}
+
+/// Verify that we document the FFI representation of shared structs, shared enums and opaque Rust
+/// types, listing a shared struct's C fields in order along with their widths.
+mod ffi_layout_docs_enabled {
+    use super::*;
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+/// FFI representation: `__swift_bridge__$SomeStruct`, with its fields in this order:
+/// - `count`: `uint32_t` (4 bytes)
+/// - `ratio`: `double` (8 bytes)
+/// - `name`: `void*` (pointer-sized)
+/// - `maybe`: `struct __private__OptionU8`
+public struct SomeStruct {
+"#,
+            r#"
+/// FFI representation: `__swift_bridge__$SomeEnum`, with its fields in this order:
+/// - `tag`: `__swift_bridge__$SomeEnumTag` (a C enum with one case per variant)
+/// - `payload`: `union __swift_bridge__$SomeEnumFields` (the fields of the variant that `tag` names)
+public enum SomeEnum {
+"#,
+            r#"
+/// FFI representation: `void*` (pointer-sized), a pointer to the Rust `SomeType`.
+public class SomeType: SomeTypeRefMut {
+"#,
+        ])
+    }
+
+    #[test]
+    fn ffi_layout_docs_enabled() {
+        CodegenTest {
+            bridge_module: bridge_module(true),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not document the FFI representation of types by default.
+mod ffi_layout_docs_disabled {
+    use super::*;
+
+    #[test]
+    fn ffi_layout_docs_disabled() {
+        CodegenTest {
+            bridge_module: bridge_module(false),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("FFI representation"),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
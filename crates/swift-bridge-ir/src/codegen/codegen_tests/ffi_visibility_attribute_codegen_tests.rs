@@ -0,0 +1,117 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that the generated `extern "C"` functions are `pub(crate)` when the bridge module is
+/// annotated with `#[swift_bridge(ffi_visibility = "crate")]`, and that they keep their
+/// `#[export_name = "..."]` so that Swift can still link against them.
+mod ffi_visibility_crate {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            #[swift_bridge(ffi_visibility = "crate")]
+            mod ffi {
+                enum SomeEnum {
+                    Variant,
+                }
+
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function(arg: u8) -> u8;
+                }
+
+                extern "Swift" {
+                    fn swift_function(callback: Box<dyn Fn(u8) -> u8>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsManyAndDoesNotContainMany {
+            contains: vec![
+                quote! {
+                    #[export_name = "__swift_bridge__$some_function"]
+                    pub(crate) extern "C" fn __swift_bridge__some_function(arg: u8) -> u8 {
+                        super::some_function(arg)
+                    }
+                },
+                quote! {
+                    #[export_name = "__swift_bridge__$SomeType$_free"]
+                    pub(crate) extern "C" fn __swift_bridge__SomeType__free (
+                        this: *mut super::SomeType
+                    ) {
+                        swift_bridge::debug_log_free("SomeType", this);
+                        let this = unsafe { Box::from_raw(this) };
+                        drop(this);
+                    }
+                },
+                // The `Vectorizable` implementation's functions.
+                quote! {
+                    #[export_name = "__swift_bridge__$Vec_SomeType$new"]
+                    pub(crate) extern "C" fn _new() -> *mut Vec<super::SomeType> {
+                        Box::into_raw(Box::new(Vec::new()))
+                    }
+                },
+                // The functions that Swift uses to call a boxed Rust closure.
+                quote! {
+                    #[export_name = "__swift_bridge__$swift_function$param0"]
+                    pub(crate) extern "C" fn swift_function_param0(
+                        swift_function_callback: *mut Box<dyn Fn(u8) -> u8>,
+                        arg0: u8
+                    ) -> u8 {
+                        unsafe { (*swift_function_callback)(arg0) }
+                    }
+                },
+            ],
+            does_not_contain: vec![quote! { pub extern "C" fn }],
+        }
+    }
+
+    #[test]
+    fn ffi_visibility_crate() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the generated `extern "C"` functions are `pub` by default.
+mod ffi_visibility_pub {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            #[swift_bridge(ffi_visibility = "pub")]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ffi_visibility_pub() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::Contains(quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() {
+                    super::some_function()
+                }
+            }),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,100 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a failable Swift initializer that calls the Rust `FromStr` impl for an
+/// enum annotated with `#[swift_bridge(from_str)]`.
+mod from_str_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(from_str)]
+                enum Color {
+                    Red,
+                    Green,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$Color$from_str"]
+            pub extern "C" fn __swift_bridge__Color_from_str(string: swift_bridge::string::RustStr) -> __swift_bridge__Option_Color {
+                __swift_bridge__Option_Color::from_rust_repr(
+                    <Color as std::str::FromStr>::from_str(string.to_str()).ok()
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Color {
+    public init?(_ rawString: String) {
+        let parsed = rawString.toRustStr({ rawStringAsRustStr in
+            __swift_bridge__$Color$from_str(rawStringAsRustStr)
+        }).intoSwiftRepr()
+        guard let parsed = parsed else {
+            return nil
+        }
+        self = parsed
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+__swift_bridge__$Option$Color __swift_bridge__$Color$from_str(struct RustStr string);
+"#,
+        )
+    }
+
+    #[test]
+    fn from_str_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate a `FromStr` based initializer for enums without the
+/// `from_str` attribute.
+mod enum_without_from_str_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Color {
+                    Red,
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn enum_without_from_str_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::DoesNotContain(quote! {
+                __swift_bridge__Color_from_str
+            }),
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("rawString"),
+            expected_c_header: ExpectedCHeader::DoesNotContainAfterTrim("from_str"),
+        }
+        .test();
+    }
+}
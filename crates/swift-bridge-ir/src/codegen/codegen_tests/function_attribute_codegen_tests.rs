@@ -225,6 +225,10 @@ public class SomeTypeRef {
     public init(ptr: UnsafeMutableRawPointer) {
         self.ptr = ptr
     }
+
+    var unconsumedPtr: UnsafeMutableRawPointer {
+        ptr
+    }
 }
 extension SomeTypeRef: Identifiable {
     public var id: Int16 {
@@ -238,6 +242,10 @@ public class AnotherTypeRef {
     public init(ptr: UnsafeMutableRawPointer) {
         self.ptr = ptr
     }
+
+    var unconsumedPtr: UnsafeMutableRawPointer {
+        ptr
+    }
 }
 extension AnotherTypeRef: Identifiable {}"#,
         ])
@@ -467,3 +475,394 @@ func __swift_bridge__call_swift_from_rust () -> UnsafeMutableRawPointer {
         .test();
     }
 }
+
+/// Verify that we can use the get_cell and set_cell attributes to read and write a `Cell<T>`
+/// field, and that the Swift class is marked as not being `Sendable`.
+mod get_cell_and_set_cell {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(get_cell(count))]
+                    fn count(&self) -> i32;
+
+                    #[swift_bridge(set_cell(count))]
+                    fn set_count(&self, value: i32);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__SomeType_count(
+                    this: *mut super::SomeType
+                ) -> i32 {
+                    (unsafe { &*this }).count.get()
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__SomeType_set_count(
+                    this: *mut super::SomeType,
+                    value: i32
+                ) {
+                    (unsafe { &*this }).count.set(value)
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@available(*, unavailable)
+extension SomeTypeRef: Sendable {}
+"#,
+            r#"
+    public func count() -> Int32 {
+        __swift_bridge__$SomeType$count(unconsumedPtr)
+    }
+"#,
+            r#"
+    public func set_count(_ value: Int32) {
+        __swift_bridge__$SomeType$set_count(unconsumedPtr, value)
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn get_cell_and_set_cell() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the `available` attribute emits an `@available` attribute with one or more
+/// platforms on the generated Swift functions and methods.
+mod available {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(available = "iOS 15.0")]
+                    fn some_function();
+
+                    #[swift_bridge(available = "iOS 15.0, macOS 12.0")]
+                    fn some_method(&self) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@available(iOS 15.0, *)
+public func some_function() {
+    __swift_bridge__$some_function()
+}
+"#,
+            r#"
+    @available(iOS 15.0, macOS 12.0, *)
+    public func some_method() -> UInt8 {
+        __swift_bridge__$SomeType$some_method(unconsumedPtr)
+    }
+"#,
+        ])
+    }
+
+    #[test]
+    fn available() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `spi` attribute emits an `@_spi` attribute with the given group name on the
+/// generated Swift functions and methods.
+mod spi {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(spi = "Internal", available = "iOS 15.0")]
+                    fn some_function();
+
+                    #[swift_bridge(spi = "Testing")]
+                    fn some_method(&self) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@_spi(Internal)
+@available(iOS 15.0, *)
+public func some_function() {
+    __swift_bridge__$some_function()
+}
+"#,
+            r#"
+    @_spi(Testing)
+    public func some_method() -> UInt8 {
+        __swift_bridge__$SomeType$some_method(unconsumedPtr)
+    }
+"#,
+        ])
+    }
+
+    #[test]
+    fn spi() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `discardable_result` attribute emits a `@discardableResult` attribute on the
+/// generated Swift function, and that the attribute is not emitted by default.
+mod discardable_result {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(discardable_result)]
+                    fn some_function() -> Result<u8, u8>;
+
+                    #[swift_bridge(discardable_result)]
+                    fn some_method(&self) -> Result<u8, u8>;
+
+                    fn another_function() -> Result<u8, u8>;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@discardableResult
+public func some_function() throws -> UInt8 {
+"#,
+            r#"
+    @discardableResult
+    public func some_method() throws -> UInt8 {
+"#,
+        ])
+    }
+
+    #[test]
+    fn discardable_result() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+
+    #[test]
+    fn not_discardable_by_default() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim(
+                "@discardableResult\npublic func another_function()",
+            ),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `shared` attribute exposes a Rust singleton as a Swift `static let` that
+/// holds a non-owning `Ref` class.
+mod shared_instance {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(associated_to = SomeType, shared)]
+                    fn shared() -> &'static SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$shared"]
+            pub extern "C" fn __swift_bridge__SomeType_shared() -> *const super::SomeType {
+                super::SomeType::shared() as *const super::SomeType
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRef {
+    public static let shared: SomeTypeRef = SomeTypeRef(ptr: __swift_bridge__$SomeType$shared())
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_instance() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `return_data` attribute returns the `Vec<u8>` that a function returns, or that
+/// its `Result` holds on success, to Swift as a `Data`.
+mod return_data {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(return_data)]
+                    fn some_function() -> Result<Vec<u8>, String>;
+
+                    #[swift_bridge(return_data)]
+                    fn another_function() -> Vec<u8>;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "import Foundation",
+            r#"
+public func some_function() throws -> Data {
+    try { let vec: RustVec<UInt8> = try { let val = __swift_bridge__$some_function(); if val.is_ok { return RustVec(ptr: val.ok_or_err!) } else { throw RustString(ptr: val.ok_or_err!) } }(); return Data(bytesNoCopy: UnsafeMutableRawPointer(mutating: vec.as_ptr()), count: vec.len(), deallocator: .custom({ _, _ in withExtendedLifetime(vec) {} })) }()
+}
+"#,
+            r#"
+public func another_function() -> Data {
+    { let vec: RustVec<UInt8> = RustVec(ptr: __swift_bridge__$another_function()); return Data(bytesNoCopy: UnsafeMutableRawPointer(mutating: vec.as_ptr()), count: vec.len(), deallocator: .custom({ _, _ in withExtendedLifetime(vec) {} })) }()
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn return_data() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `return_warnings` attribute returns the `(T, Vec<W>)` that a function returns
+/// to Swift as a `ValueWithWarnings<T, W>`.
+mod return_warnings {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeWarning {
+                    code: u32,
+                }
+
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(return_warnings)]
+                    fn some_function() -> (u32, Vec<SomeWarning>);
+
+                    #[swift_bridge(return_warnings)]
+                    fn another_function() -> (SomeType, Vec<SomeWarning>);
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function() -> ValueWithWarnings<UInt32, SomeWarning> {
+    { let (value, warnings): (UInt32, RustVec<SomeWarning>) = { let val = __swift_bridge__$some_function(); return (val._0, RustVec(ptr: val._1)); }(); return ValueWithWarnings(value: value, warnings: warnings.toArray()) }()
+}
+"#,
+            r#"
+public func another_function() -> ValueWithWarnings<SomeType, SomeWarning> {
+    { let (value, warnings): (SomeType, RustVec<SomeWarning>) = { let val = __swift_bridge__$another_function(); return (SomeType(ptr: val._0), RustVec(ptr: val._1)); }(); return ValueWithWarnings(value: value, warnings: warnings.toArray()) }()
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn return_warnings() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -41,6 +41,11 @@ public class SomeType<A>: SomeTypeRefMut<A> {
             (self as! SwiftBridgeGenericFreer).rust_free()
         }
     }
+
+    override var unconsumedPtr: UnsafeMutableRawPointer {
+        precondition(isOwned, "Attempted to use a value that was already consumed.")
+        return ptr
+    }
 }
 public class SomeTypeRefMut<A>: SomeTypeRef<A> {
     public override init(ptr: UnsafeMutableRawPointer) {
@@ -53,6 +58,10 @@ public class SomeTypeRef<A> {
     public init(ptr: UnsafeMutableRawPointer) {
         self.ptr = ptr
     }
+
+    var unconsumedPtr: UnsafeMutableRawPointer {
+        ptr
+    }
 }
 "#,
         )
@@ -98,6 +107,7 @@ mod monomorphized_generic_opaque_rust_type {
             pub extern "C" fn __swift_bridge__SomeType_u32__free (
                 this: *mut super::SomeType<u32>
             ) {
+                swift_bridge::debug_log_free("SomeType", this);
                 let this = unsafe { Box::from_raw(this) };
                 drop(this);
             }
@@ -488,6 +498,7 @@ mod generic_opaque_rust_type_inner_opaque_ty {
             pub extern "C" fn __swift_bridge__SomeType_AnotherType__free (
                 this: *mut super::SomeType<super::AnotherType>
             ) {
+                swift_bridge::debug_log_free("SomeType", this);
                 let this = unsafe { Box::from_raw(this) };
                 drop(this);
             }
@@ -0,0 +1,117 @@
+use super::{BridgeModule, CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use crate::codegen::GetterProperties;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+fn bridge_module_tokens() -> TokenStream {
+    quote! {
+        #[swift_bridge::bridge]
+        mod ffi {
+            extern "Rust" {
+                type SomeType;
+
+                #[swift_bridge(get(width))]
+                fn get_width(&self) -> u32;
+                #[swift_bridge(get(height))]
+                fn height(&self) -> u32;
+                #[swift_bridge(get(&name))]
+                fn fetch_name(&self) -> &str;
+                #[swift_bridge(get(depth), swift_name = "zDepth")]
+                fn get_depth(&self) -> u32;
+                fn get_area(&self) -> u32;
+            }
+        }
+    }
+}
+
+fn bridge_module(getter_properties: Option<GetterProperties>) -> BridgeModule {
+    BridgeModule {
+        tokens: bridge_module_tokens(),
+        getter_properties,
+        ..Default::default()
+    }
+}
+
+/// Verify that we generate Swift computed properties for getters, with the `get_` prefix
+/// stripped from their names.
+/// Methods that are not getters are still generated as Swift methods.
+mod getter_properties_enabled {
+    use super::*;
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    public var width: UInt32 {
+        __swift_bridge__$SomeType$get_width(unconsumedPtr)
+    }
+"#,
+            r#"
+    public var height: UInt32 {
+        __swift_bridge__$SomeType$height(unconsumedPtr)
+    }
+"#,
+            "public var fetch_name: RustStr {",
+            "public var zDepth: UInt32 {",
+            "public func get_area() -> UInt32 {",
+        ])
+    }
+
+    #[test]
+    fn getter_properties_enabled() {
+        CodegenTest {
+            bridge_module: bridge_module(Some(GetterProperties::default())),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we strip a custom prefix from the names of getter properties.
+mod getter_properties_custom_prefix {
+    use super::*;
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "public var get_width: UInt32 {",
+            "public var name: RustStr {",
+        ])
+    }
+
+    #[test]
+    fn getter_properties_custom_prefix() {
+        CodegenTest {
+            bridge_module: bridge_module(Some(GetterProperties {
+                strip_prefix: "fetch_".to_string(),
+            })),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate getters as Swift methods by default.
+mod getter_properties_disabled {
+    use super::*;
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "public func get_width() -> UInt32 {",
+            "public func height() -> UInt32 {",
+        ])
+    }
+
+    #[test]
+    fn getter_properties_disabled() {
+        CodegenTest {
+            bridge_module: bridge_module(None),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
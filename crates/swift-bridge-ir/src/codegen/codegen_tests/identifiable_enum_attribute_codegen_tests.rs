@@ -0,0 +1,74 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate an `Identifiable` conformance that uses the case itself as the `id`,
+/// for an enum annotated with `#[swift_bridge(Identifiable)]`.
+mod identifiable_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Identifiable)]
+                enum Weekday {
+                    Monday,
+                    Tuesday,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Weekday: Identifiable {
+    public var id: Weekday {
+        self
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn identifiable_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate an `Identifiable` conformance for enums without the
+/// `Identifiable` attribute.
+mod enum_without_identifiable_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Weekday {
+                    Monday,
+                    Tuesday,
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn enum_without_identifiable_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("Identifiable"),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,114 @@
+use super::{BridgeModule, CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use crate::codegen::GetterProperties;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+fn bridge_module_tokens() -> TokenStream {
+    quote! {
+        #[swift_bridge::bridge]
+        mod ffi {
+            extern "Rust" {
+                type SomeType;
+
+                #[swift_bridge(get(width))]
+                fn get_width(&self) -> u32;
+                fn set_width(&mut self, width: u32);
+                fn name(&self) -> String;
+                fn into_id(self) -> u32;
+
+                fn add(a: u8, b: u8) -> u8;
+                fn maybe_add(a: Option<u8>, b: u8) -> u8;
+                async fn add_async(a: u8, b: u8) -> u8;
+            }
+        }
+    }
+}
+
+fn bridge_module(inlinable_accessors: bool) -> BridgeModule {
+    BridgeModule {
+        tokens: bridge_module_tokens(),
+        getter_properties: Some(GetterProperties::default()),
+        inlinable_accessors,
+        ..Default::default()
+    }
+}
+
+/// Verify that we mark functions, methods and getter properties that only pass primitives
+/// to and from Rust as `@inlinable`, and make the class's pointer `@usableFromInline` so that
+/// inlined methods can use it.
+mod inlinable_accessors_enabled {
+    use super::*;
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "@usableFromInline var ptr: UnsafeMutableRawPointer",
+            r#"
+@inlinable
+public func add(_ a: UInt8, _ b: UInt8) -> UInt8 {
+    __swift_bridge__$add(a, b)
+}
+"#,
+            r#"
+    @inlinable
+    public func set_width(_ width: UInt32) {
+        __swift_bridge__$SomeType$set_width(unconsumedPtr, width)
+    }
+"#,
+            r#"
+    @inlinable
+    public var width: UInt32 {
+        __swift_bridge__$SomeType$get_width(unconsumedPtr)
+    }
+"#,
+        ])
+    }
+
+    /// Functions that convert their arguments or return values, take an owned `self` or are
+    /// async are not inlinable since their bodies use internal declarations.
+    fn expected_swift_code_not_inlinable() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainManyAfterTrim(vec![
+            "@inlinable\n    public func name",
+            "@inlinable\n    public func into_id",
+            "@inlinable\npublic func maybe_add",
+            "@inlinable\npublic func add_async",
+        ])
+    }
+
+    #[test]
+    fn inlinable_accessors_enabled() {
+        CodegenTest {
+            bridge_module: bridge_module(true),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+
+        CodegenTest {
+            bridge_module: bridge_module(true),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code_not_inlinable(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not emit `@inlinable` by default.
+mod inlinable_accessors_disabled {
+    use super::*;
+
+    #[test]
+    fn inlinable_accessors_disabled() {
+        CodegenTest {
+            bridge_module: bridge_module(false),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainManyAfterTrim(vec![
+                "@inlinable",
+                "@usableFromInline",
+            ]),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
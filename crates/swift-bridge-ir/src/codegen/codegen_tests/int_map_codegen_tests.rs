@@ -0,0 +1,145 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for a Rust function that takes and returns a `HashMap<u32, u8>`.
+/// The map is passed over FFI as a buffer of keys and a buffer of values.
+mod extern_rust_fn_int_map {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: HashMap<u32, u8>) -> HashMap<u32, u8>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::hash_map::FfiIntMap<u32, u8>
+            ) -> swift_bridge::hash_map::FfiIntMap<u32, u8> {
+                swift_bridge::hash_map::FfiIntMap::from_hash_map(
+                    super::some_function(arg.to_hash_map())
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: [UInt32: UInt8]) -> [UInt32: UInt8] {
+    return arg.toFfiIntMap({ argAsFfiIntMap in
+        { let ffiIntMap = __swift_bridge__$some_function(argAsFfiIntMap); let dictionary = ffiIntMap.toSwiftDictionary(UInt32.self, UInt8.self); __swift_bridge__$FfiIntMap$free_keys_u32(ffiIntMap.keys); __swift_bridge__$FfiIntMap$free_values_u8(ffiIntMap.values); return dictionary }()
+    })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiIntMap __swift_bridge__$some_function(struct __private__FfiIntMap arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_int_map() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Rust function that takes and returns a `HashMap<i64, SomeType>`,
+/// where `SomeType` is an opaque Rust type.
+/// Whichever side receives the map takes ownership of its values.
+mod extern_rust_fn_int_map_of_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function(arg: std::collections::HashMap<i64, SomeType>) -> HashMap<i64, SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::hash_map::FfiIntMap<i64, *mut super::SomeType>
+            ) -> swift_bridge::hash_map::FfiIntMap<i64, *mut super::SomeType> {
+                swift_bridge::hash_map::FfiIntMap::from_hash_map_with(
+                    super::some_function(arg.to_hash_map_with(|value| unsafe { *Box::from_raw(value) })),
+                    |value| Box::into_raw(Box::new({ let val: super::SomeType = value; val })) as *mut super::SomeType
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: [Int64: SomeType]) -> [Int64: SomeType] {
+    return arg.mapValues({ val -> UnsafeMutableRawPointer in val.isOwned = false; return val.ptr }).toFfiIntMap({ argAsFfiIntMap in
+        { let ffiIntMap = __swift_bridge__$some_function(argAsFfiIntMap); let dictionary = ffiIntMap.toSwiftDictionary(Int64.self, UnsafeMutableRawPointer.self).mapValues({ SomeType(ptr: $0) }); __swift_bridge__$FfiIntMap$free_keys_i64(ffiIntMap.keys); __swift_bridge__$FfiIntMap$free_values_pointers(ffiIntMap.values); return dictionary }()
+    })
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_int_map_of_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a `HashMap<String, T>` is still bridged as a string keyed map.
+mod extern_rust_fn_string_keyed_map_is_not_int_map {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: HashMap<String, u32>);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn extern_rust_fn_string_keyed_map_is_not_int_map() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::DoesNotContain(quote! { FfiIntMap }),
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("toFfiIntMap"),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,84 @@
+use super::{BridgeModule, CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+fn bridge_module_tokens() -> TokenStream {
+    quote! {
+        #[swift_bridge::bridge]
+        mod ffi {
+            extern "Rust" {
+                type SomeType;
+            }
+        }
+    }
+}
+
+/// Verify that we log from the generated Swift class's `deinit` when the `log_deinit` option is
+/// enabled.
+mod log_deinit_enabled {
+    use super::*;
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$_free"]
+            pub extern "C" fn __swift_bridge__SomeType__free (
+                this: *mut super::SomeType
+            ) {
+                swift_bridge::debug_log_free("SomeType", this);
+                let this = unsafe { Box::from_raw(this) };
+                drop(this);
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    deinit {
+        #if DEBUG
+        print("swift-bridge: deinit SomeType \(ptr) isOwned: \(isOwned)")
+        #endif
+        if isOwned {
+            __swift_bridge__$SomeType$_free(ptr)
+        }
+    }
+
+    override var unconsumedPtr: UnsafeMutableRawPointer {
+        precondition(isOwned, "Attempted to use a value that was already consumed.")
+        return ptr
+    }
+"#,
+        )
+    }
+
+    #[test]
+    fn log_deinit_enabled() {
+        CodegenTest {
+            bridge_module: BridgeModule {
+                tokens: bridge_module_tokens(),
+                log_deinit: true,
+                ..Default::default()
+            },
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not log from the generated Swift class's `deinit` by default.
+mod log_deinit_disabled {
+    use super::*;
+
+    #[test]
+    fn log_deinit_disabled() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("swift-bridge: deinit"),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
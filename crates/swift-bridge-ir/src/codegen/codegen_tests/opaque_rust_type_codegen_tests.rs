@@ -24,6 +24,7 @@ mod extern_rust_type {
             pub extern "C" fn __swift_bridge__SomeType__free (
                 this: *mut super::SomeType
             ) {
+                swift_bridge::debug_log_free("SomeType", this);
                 let this = unsafe { Box::from_raw(this) };
                 drop(this);
             }
@@ -45,52 +46,893 @@ public class SomeType: SomeTypeRefMut {
             __swift_bridge__$SomeType$_free(ptr)
         }
     }
+
+    override var unconsumedPtr: UnsafeMutableRawPointer {
+        precondition(isOwned, "Attempted to use a value that was already consumed.")
+        return ptr
+    }
 }
 public class SomeTypeRefMut: SomeTypeRef {
     public override init(ptr: UnsafeMutableRawPointer) {
         super.init(ptr: ptr)
     }
 }
-public class SomeTypeRef {
-    var ptr: UnsafeMutableRawPointer
+public class SomeTypeRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    var unconsumedPtr: UnsafeMutableRawPointer {
+        ptr
+    }
+}
+"#,
+        )
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ContainsAfterTrim(
+        r#"
+typedef struct SomeType SomeType;
+void __swift_bridge__$SomeType$_free(void* self);
+    "#,
+    );
+
+    #[test]
+    fn extern_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Verify that calling a method that takes `self` by value from Swift marks the Swift handle as
+/// consumed, and that both consuming and borrowing methods trap if the handle was already
+/// consumed.
+mod extern_rust_type_consuming_method {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn into_parts(self) -> (u8, u16);
+                    fn len(&self) -> usize;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$into_parts"]
+            pub extern "C" fn __swift_bridge__SomeType_into_parts(
+                this: *mut super::SomeType
+            ) -> __swift_bridge__tuple_U8U16 {
+                {
+                    let val = (* unsafe { Box::from_raw(this) }).into_parts();
+                    __swift_bridge__tuple_U8U16(val.0, val.1)
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeType {
+    public func into_parts() -> (UInt8, UInt16) {
+        { let val = __swift_bridge__$SomeType$into_parts({precondition(isOwned, "Attempted to use a value that was already consumed."); isOwned = false; return ptr;}()); return (val._0, val._1); }()
+    }
+}
+"#,
+            r#"
+    override var unconsumedPtr: UnsafeMutableRawPointer {
+        precondition(isOwned, "Attempted to use a value that was already consumed.")
+        return ptr
+    }
+"#,
+            r#"
+    var unconsumedPtr: UnsafeMutableRawPointer {
+        ptr
+    }
+"#,
+            r#"
+extension SomeTypeRef {
+    public func len() -> UInt {
+        __swift_bridge__$SomeType$len(unconsumedPtr)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_rust_type_consuming_method() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that implements Hashable.
+mod extern_rust_hashable_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Hashable)]
+                    type HashableType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+        #[export_name = "__swift_bridge__$HashableType$_hash"]
+        pub extern "C" fn __swift_bridge__HashableType__hash (
+            this: *const super::HashableType,
+        ) -> u64 {
+            use std::hash::{Hash, Hasher};
+            use std::collections::hash_map::DefaultHasher;
+            let mut s = DefaultHasher::new();
+            (unsafe {&*this}).hash(&mut s);
+            s.finish()
+        }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension HashableTypeRef: Hashable{
+    public func hash(into hasher: inout Hasher){
+        hasher.combine(__swift_bridge__$HashableType$_hash(self.ptr))
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+uint64_t __swift_bridge__$HashableType$_hash(void* self);
+    "#,
+            r#"
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_hashable_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that implements Equatable.
+mod extern_rust_equatable_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Equatable)]
+                    type EquatableType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+        #[export_name = "__swift_bridge__$EquatableType$_partial_eq"]
+        pub extern "C" fn __swift_bridge__EquatableType__partial_eq (
+            lhs: *const super::EquatableType,
+            rhs: *const super::EquatableType
+        ) -> bool {
+            unsafe { &*lhs == &*rhs }
+        }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension EquatableTypeRef: Equatable {
+    public static func == (lhs: EquatableTypeRef, rhs: EquatableTypeRef) -> Bool {
+        __swift_bridge__$EquatableType$_partial_eq(rhs.ptr, lhs.ptr)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+bool __swift_bridge__$EquatableType$_partial_eq(void* lhs, void* rhs);
+    "#,
+            r#"
+#include <stdint.h>
+#include <stdbool.h>
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_equatable_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that implements Clone.
+/// Swift gets an `init(copying:)` initializer that takes ownership of the cloned Rust value.
+mod extern_rust_clone_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Clone)]
+                    type CloneType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$CloneType$_clone"]
+            pub extern "C" fn __swift_bridge__CloneType__clone (
+                this: *const super::CloneType,
+            ) -> *mut super::CloneType {
+                Box::into_raw(Box::new(Clone::clone(unsafe { &*this })))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension CloneType {
+    public convenience init(copying other: CloneTypeRef) {
+        self.init(ptr: __swift_bridge__$CloneType$_clone(other.ptr))
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$CloneType$_clone(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_clone_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that `#[swift_bridge(HashableByGetters)]` generates `==` and `hash(into:)` that use the
+/// type's getters, ignoring methods that are not getters.
+mod extern_rust_type_hashable_by_getters {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(HashableByGetters)]
+                    type Point;
+
+                    #[swift_bridge(get(x))]
+                    fn x(&self) -> i32;
+                    #[swift_bridge(get(y))]
+                    fn y(&self) -> i32;
+                    fn distance(&self) -> f64;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension PointRef: Equatable {
+    public static func == (lhs: PointRef, rhs: PointRef) -> Bool {
+        lhs.x() == rhs.x() && lhs.y() == rhs.y()
+    }
+}
+extension PointRef: Hashable {
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(self.x())
+        hasher.combine(self.y())
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_type_hashable_by_getters() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::DoesNotContain(quote! { _partial_eq }),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that `#[doc(alias = "...")]` attributes on an extern "Rust" type are emitted as keywords
+/// in the generated Swift class's doc comment.
+mod extern_rust_type_doc_alias {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[doc(alias = "FirstAlias")]
+                    #[doc(alias = "SecondAlias")]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::DoesNotContain(quote! {
+            alias
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+/// - Keyword: FirstAlias
+/// - Keyword: SecondAlias
+public class SomeType: SomeTypeRefMut {
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::DoesNotContainAfterTrim("Alias")
+    }
+
+    #[test]
+    fn extern_rust_type_doc_alias() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we check that a type annotated with `#[swift_bridge(dispatch_queue = "...")]` is
+/// being used on the expected dispatch queue before calling into Rust.
+mod extern_rust_type_dispatch_queue {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(dispatch_queue = "com.example.queue")]
+                    type SomeType;
+
+                    fn some_method(&self) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::DoesNotContain(quote! {
+            dispatch_queue
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+import Dispatch
+"#,
+            r#"
+    public func some_method() -> UInt8 {
+        precondition(String(cString: __dispatch_queue_get_label(nil)) == "com.example.queue", "SomeType must only be used on the \"com.example.queue\" dispatch queue.")
+        return __swift_bridge__$SomeType$some_method(unconsumedPtr)
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::DoesNotContainAfterTrim("dispatch")
+    }
+
+    #[test]
+    fn extern_rust_type_dispatch_queue() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a `range` method for a `#[swift_bridge(btree_map(K, V))]` type.
+mod extern_rust_type_btree_map {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(btree_map(u32, f64))]
+                    type Scores;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$Scores$_range"]
+            pub extern "C" fn __swift_bridge__Scores__range (
+                this: *const super::Scores,
+                start: u32,
+                end: u32,
+                values: *mut *mut Vec<f64>,
+            ) -> *mut Vec<u32> {
+                let map: &std::collections::BTreeMap<u32, f64> =
+                    std::convert::AsRef::as_ref(unsafe { &*this });
+                let (keys, vals): (Vec<u32>, Vec<f64>) = if start <= end {
+                    map.range(start..end).map(|(key, value)| (*key, *value)).unzip()
+                } else {
+                    (Vec::new(), Vec::new())
+                };
+                unsafe { *values = Box::into_raw(Box::new(vals)) };
+                Box::into_raw(Box::new(keys))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension ScoresRef {
+    public func range(_ start: UInt32, _ end: UInt32) -> [(key: UInt32, value: Double)] {
+        var valuesPtr: UnsafeMutableRawPointer? = nil
+        let keys = RustVec<UInt32>(ptr: __swift_bridge__$Scores$_range(ptr, start, end, &valuesPtr))
+        let values = RustVec<Double>(ptr: valuesPtr!)
+        return zip(keys, values).map { (key: $0, value: $1) }
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "#include <stdint.h>",
+            "void* __swift_bridge__$Scores$_range(void* self, uint32_t start, uint32_t end, void** values);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_type_btree_map() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate `increment()` and `decrement()` methods and a `value` property for a
+/// `#[swift_bridge(counter(T))]` type.
+mod extern_rust_type_counter {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(counter(u32))]
+                    type Visits;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$Visits$_increment"]
+            pub extern "C" fn __swift_bridge__Visits__increment (
+                this: *mut super::Visits
+            ) {
+                <super::Visits as std::ops::AddAssign<u32>>::add_assign(unsafe { &mut *this }, 1)
+            }
+
+            #[export_name = "__swift_bridge__$Visits$_decrement"]
+            pub extern "C" fn __swift_bridge__Visits__decrement (
+                this: *mut super::Visits
+            ) {
+                <super::Visits as std::ops::SubAssign<u32>>::sub_assign(unsafe { &mut *this }, 1)
+            }
+
+            #[export_name = "__swift_bridge__$Visits$_counter_value"]
+            pub extern "C" fn __swift_bridge__Visits__counter_value (
+                this: *const super::Visits
+            ) -> u32 {
+                *<super::Visits as std::convert::AsRef<u32>>::as_ref(unsafe { &*this })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension VisitsRefMut {
+    public func increment() {
+        __swift_bridge__$Visits$_increment(ptr)
+    }
+
+    public func decrement() {
+        __swift_bridge__$Visits$_decrement(ptr)
+    }
+}
+extension VisitsRef {
+    public var value: UInt32 {
+        __swift_bridge__$Visits$_counter_value(ptr)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "#include <stdint.h>",
+            "void __swift_bridge__$Visits$_increment(void* self);",
+            "void __swift_bridge__$Visits$_decrement(void* self);",
+            "uint32_t __swift_bridge__$Visits$_counter_value(void* self);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_type_counter() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a read-only subscript for a `#[swift_bridge(index(Idx, Output))]` type.
+mod extern_rust_type_index {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(index(usize, f64))]
+                    type Samples;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$Samples$_index"]
+            pub extern "C" fn __swift_bridge__Samples__index (
+                this: *const super::Samples,
+                index: usize,
+            ) -> f64 {
+                *<super::Samples as std::ops::Index<usize>>::index(unsafe { &*this }, index)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SamplesRef {
+    public subscript(index: UInt) -> Double {
+        __swift_bridge__$Samples$_index(ptr, index)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+double __swift_bridge__$Samples$_index(void* self, uintptr_t index);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_type_index() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a `description` that includes the error's `source()` chain for a
+/// `#[swift_bridge(error_chain)]` type.
+mod extern_rust_type_error_chain {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(error_chain)]
+                    type SomeError;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeError$_error_chain"]
+            pub extern "C" fn __swift_bridge__SomeError__error_chain (
+                this: *const super::SomeError,
+            ) -> *mut swift_bridge::string::RustString {
+                let error: &dyn std::error::Error = unsafe { &*this };
+                let mut chain = error.to_string();
+                let mut source = error.source();
+                while let Some(cause) = source {
+                    chain += ": ";
+                    chain += &cause.to_string();
+                    source = cause.source();
+                }
+                swift_bridge::string::RustString(chain).box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeErrorRef: CustomStringConvertible {
+    public var description: String {
+        RustString(ptr: __swift_bridge__$SomeError$_error_chain(ptr)).toString()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeError$_error_chain(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_type_error_chain() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate `+=` and `-=` operators for a type annotated with
+/// `#[swift_bridge(AddAssign, SubAssign)]`.
+mod extern_rust_type_compound_assignment {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(AddAssign, SubAssign)]
+                    type Money;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$Money$_add_assign"]
+                pub extern "C" fn __swift_bridge__Money__add_assign (
+                    this: *mut super::Money,
+                    rhs: *const super::Money
+                ) {
+                    <super::Money as std::ops::AddAssign<&super::Money>>::add_assign(unsafe { &mut *this }, unsafe { &*rhs })
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Money$_sub_assign"]
+                pub extern "C" fn __swift_bridge__Money__sub_assign (
+                    this: *mut super::Money,
+                    rhs: *const super::Money
+                ) {
+                    <super::Money as std::ops::SubAssign<&super::Money>>::sub_assign(unsafe { &mut *this }, unsafe { &*rhs })
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension MoneyRefMut {
+    public static func += <Lhs: MoneyRefMut>(lhs: inout Lhs, rhs: MoneyRef) {
+        precondition(lhs.ptr != rhs.ptr, "Cannot use `+=` with the same value on both sides.")
+        __swift_bridge__$Money$_add_assign(lhs.ptr, rhs.ptr)
+    }
+    public static func -= <Lhs: MoneyRefMut>(lhs: inout Lhs, rhs: MoneyRef) {
+        precondition(lhs.ptr != rhs.ptr, "Cannot use `-=` with the same value on both sides.")
+        __swift_bridge__$Money$_sub_assign(lhs.ptr, rhs.ptr)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void __swift_bridge__$Money$_add_assign(void* self, void* rhs);",
+            "void __swift_bridge__$Money$_sub_assign(void* self, void* rhs);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_type_compound_assignment() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
 
-    public init(ptr: UnsafeMutableRawPointer) {
-        self.ptr = ptr
+/// Verify that `+=` on a `Clone` type clones the right hand side when Swift passes the same value
+/// on both sides, instead of rejecting it.
+mod extern_rust_type_compound_assignment_clone {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(AddAssign, Clone)]
+                    type Money;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$Money$_add_assign"]
+            pub extern "C" fn __swift_bridge__Money__add_assign (
+                this: *mut super::Money,
+                rhs: *const super::Money
+            ) {
+                if this as *const super::Money == rhs {
+                    let rhs = <super::Money as Clone>::clone(unsafe { &*rhs });
+                    return <super::Money as std::ops::AddAssign<&super::Money>>::add_assign(unsafe { &mut *this }, &rhs);
+                }
+                <super::Money as std::ops::AddAssign<&super::Money>>::add_assign(unsafe { &mut *this }, unsafe { &*rhs })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension MoneyRefMut {
+    public static func += <Lhs: MoneyRefMut>(lhs: inout Lhs, rhs: MoneyRef) {
+        __swift_bridge__$Money$_add_assign(lhs.ptr, rhs.ptr)
     }
 }
 "#,
         )
     }
 
-    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ContainsAfterTrim(
-        r#"
-typedef struct SomeType SomeType;
-void __swift_bridge__$SomeType$_free(void* self);
-    "#,
-    );
-
     #[test]
-    fn extern_rust_type() {
+    fn extern_rust_type_compound_assignment_clone() {
         CodegenTest {
             bridge_module: bridge_module_tokens().into(),
             expected_rust_tokens: expected_rust_tokens(),
             expected_swift_code: expected_swift_code(),
-            expected_c_header: EXPECTED_C_HEADER,
+            expected_c_header: ExpectedCHeader::SkipTest,
         }
         .test();
     }
 }
 
-/// Test code generation for an extern "Rust" type that implements Hashable.
-mod extern_rust_hashable_type {
+/// Verify that we generate a prefix `-` operator for a type annotated with
+/// `#[swift_bridge(Neg)]`.
+mod extern_rust_type_neg {
     use super::*;
 
     fn bridge_module_tokens() -> TokenStream {
         quote! {
             mod ffi {
                 extern "Rust" {
-                    #[swift_bridge(Hashable)]
-                    type HashableType;
+                    #[swift_bridge(Neg)]
+                    type Vector;
                 }
             }
         }
@@ -98,25 +940,21 @@ mod extern_rust_hashable_type {
 
     fn expected_rust_tokens() -> ExpectedRustTokens {
         ExpectedRustTokens::Contains(quote! {
-        #[export_name = "__swift_bridge__$HashableType$_hash"]
-        pub extern "C" fn __swift_bridge__HashableType__hash (
-            this: *const super::HashableType,
-        ) -> u64 {
-            use std::hash::{Hash, Hasher};
-            use std::collections::hash_map::DefaultHasher;
-            let mut s = DefaultHasher::new();
-            (unsafe {&*this}).hash(&mut s);
-            s.finish()
-        }
+            #[export_name = "__swift_bridge__$Vector$_neg"]
+            pub extern "C" fn __swift_bridge__Vector__neg (
+                this: *const super::Vector
+            ) -> *mut super::Vector {
+                Box::into_raw(Box::new(<&super::Vector as std::ops::Neg>::neg(unsafe { &*this })))
+            }
         })
     }
 
     fn expected_swift_code() -> ExpectedSwiftCode {
         ExpectedSwiftCode::ContainsAfterTrim(
             r#"
-extension HashableTypeRef: Hashable{
-    public func hash(into hasher: inout Hasher){
-        hasher.combine(__swift_bridge__$HashableType$_hash(self.ptr))
+extension VectorRef {
+    public static prefix func - (operand: VectorRef) -> Vector {
+        Vector(ptr: __swift_bridge__$Vector$_neg(operand.ptr))
     }
 }
 "#,
@@ -124,37 +962,99 @@ extension HashableTypeRef: Hashable{
     }
 
     fn expected_c_header() -> ExpectedCHeader {
-        ExpectedCHeader::ContainsManyAfterTrim(vec![
+        ExpectedCHeader::ContainsAfterTrim("void* __swift_bridge__$Vector$_neg(void* self);")
+    }
+
+    #[test]
+    fn extern_rust_type_neg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we isolate the methods and initializers of a `#[swift_bridge(main_actor)]` type
+/// to the main actor, while leaving the class declarations themselves non-isolated.
+mod extern_rust_type_main_actor {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(main_actor)]
+                    type ViewModel;
+
+                    #[swift_bridge(init)]
+                    fn new() -> ViewModel;
+                    fn count(&self) -> u32;
+                    fn increment(&mut self);
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
             r#"
-uint64_t __swift_bridge__$HashableType$_hash(void* self);
-    "#,
+@MainActor
+extension ViewModel {
+    public convenience init() {
+        self.init(ptr: __swift_bridge__$ViewModel$new())
+    }
+}
+"#,
+            r#"
+@MainActor
+extension ViewModelRefMut {
+    public func increment() {
+        __swift_bridge__$ViewModel$increment(unconsumedPtr)
+    }
+}
+"#,
+            r#"
+@MainActor
+extension ViewModelRef {
+    public func count() -> UInt32 {
+        __swift_bridge__$ViewModel$count(unconsumedPtr)
+    }
+}
+"#,
             r#"
+public class ViewModel: ViewModelRefMut {
 "#,
         ])
     }
 
     #[test]
-    fn extern_rust_hashable_type() {
+    fn extern_rust_type_main_actor() {
         CodegenTest {
             bridge_module: bridge_module_tokens().into(),
-            expected_rust_tokens: expected_rust_tokens(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
             expected_swift_code: expected_swift_code(),
-            expected_c_header: expected_c_header(),
+            expected_c_header: ExpectedCHeader::SkipTest,
         }
         .test();
     }
 }
 
-/// Test code generation for an extern "Rust" type that implements Equatable.
-mod extern_rust_equatable_type {
+/// Verify that we generate the `call` method of a `#[swift_bridge(callable)]` type as a Swift
+/// `callAsFunction` method that calls the Rust `call` method.
+mod extern_rust_type_callable {
     use super::*;
 
     fn bridge_module_tokens() -> TokenStream {
         quote! {
             mod ffi {
                 extern "Rust" {
-                    #[swift_bridge(Equatable)]
-                    type EquatableType;
+                    #[swift_bridge(callable)]
+                    type Multiplier;
+
+                    fn call(&self, value: i64) -> i64;
                 }
             }
         }
@@ -162,22 +1062,83 @@ mod extern_rust_equatable_type {
 
     fn expected_rust_tokens() -> ExpectedRustTokens {
         ExpectedRustTokens::Contains(quote! {
-        #[export_name = "__swift_bridge__$EquatableType$_partial_eq"]
-        pub extern "C" fn __swift_bridge__EquatableType__partial_eq (
-            lhs: *const super::EquatableType,
-            rhs: *const super::EquatableType
-        ) -> bool {
-            unsafe { &*lhs == &*rhs }
+            #[export_name = "__swift_bridge__$Multiplier$call"]
+            pub extern "C" fn __swift_bridge__Multiplier_call(
+                this: *mut super::Multiplier,
+                value: i64
+            ) -> i64 {
+                (unsafe { &*this }).call(value)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension MultiplierRef {
+    public func callAsFunction(_ value: Int64) -> Int64 {
+        __swift_bridge__$Multiplier$call(unconsumedPtr, value)
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_type_callable() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
         }
+        .test();
+    }
+}
+
+/// Verify that we generate Swift methods that forward to the `&self` methods of the type that a
+/// `#[swift_bridge(deref = "...")]` type derefs to.
+mod extern_rust_type_deref {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Inner;
+
+                    fn inner_method(&self, #[swift_bridge(label = "to")] a: u8, b: u8) -> u8;
+                    fn shadowed_method(&self) -> u8;
+                    fn mutable_method(&mut self);
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(deref = "Inner")]
+                    type Wrapper;
+
+                    fn shadowed_method(&self) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$Wrapper$_deref"]
+            pub extern "C" fn __swift_bridge__Wrapper__deref (
+                this: *const super::Wrapper,
+            ) -> *const super::Inner {
+                std::ops::Deref::deref(unsafe { &*this }) as *const super::Inner
+            }
         })
     }
 
     fn expected_swift_code() -> ExpectedSwiftCode {
         ExpectedSwiftCode::ContainsAfterTrim(
             r#"
-extension EquatableTypeRef: Equatable {
-    public static func == (lhs: EquatableTypeRef, rhs: EquatableTypeRef) -> Bool {
-        __swift_bridge__$EquatableType$_partial_eq(rhs.ptr, lhs.ptr)
+extension WrapperRef {
+    public func inner_method(to a: UInt8, _ b: UInt8) -> UInt8 {
+        InnerRef(ptr: __swift_bridge__$Wrapper$_deref(ptr)).inner_method(to: a, b)
     }
 }
 "#,
@@ -185,19 +1146,106 @@ extension EquatableTypeRef: Equatable {
     }
 
     fn expected_c_header() -> ExpectedCHeader {
-        ExpectedCHeader::ContainsManyAfterTrim(vec![
-            r#"
-bool __swift_bridge__$EquatableType$_partial_eq(void* lhs, void* rhs);
-    "#,
+        ExpectedCHeader::ContainsAfterTrim(
             r#"
-#include <stdint.h>
-#include <stdbool.h>
+void* __swift_bridge__$Wrapper$_deref(void* self);
 "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_type_deref() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we share `#[swift_bridge(arc_mutex)]` types with Swift as an `Arc<Mutex<T>>`, and
+/// that method calls lock the mutex.
+mod extern_rust_type_arc_mutex {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(arc_mutex)]
+                    type Counter;
+
+                    fn new_counter() -> Counter;
+                    fn increment(&mut self, other: &Counter);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$Counter$_free"]
+                pub extern "C" fn __swift_bridge__Counter__free (this: *const std::sync::Mutex<super::Counter>) {
+                    swift_bridge::debug_log_free("Counter", this);
+                    let this = unsafe { std::sync::Arc::from_raw(this) };
+                    drop(this);
+                }
+
+                #[export_name = "__swift_bridge__$Counter$_share"]
+                pub extern "C" fn __swift_bridge__Counter__share (this: *const std::sync::Mutex<super::Counter>) -> *const std::sync::Mutex<super::Counter> {
+                    unsafe { std::sync::Arc::increment_strong_count(this) };
+                    this
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$new_counter"]
+                pub extern "C" fn __swift_bridge__new_counter() -> *const std::sync::Mutex<super::Counter> {
+                    std::sync::Arc::into_raw({
+                        let val: std::sync::Arc<std::sync::Mutex<super::Counter>> = super::new_counter();
+                        val
+                    })
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Counter$increment"]
+                pub extern "C" fn __swift_bridge__Counter_increment(
+                    this: *const std::sync::Mutex<super::Counter>,
+                    other: *const std::sync::Mutex<super::Counter>
+                ) {
+                    (&mut *swift_bridge::arc_mutex_support::lock(unsafe { &*this })).increment(
+                        &*swift_bridge::arc_mutex_support::lock(unsafe { &*other })
+                    )
+                }
+            },
         ])
     }
 
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension CounterRef {
+    public func share() -> Counter {
+        Counter(ptr: __swift_bridge__$Counter$_share(ptr))
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$Counter$_free(void* self);
+void* __swift_bridge__$Counter$_share(void* self);
+"#,
+        )
+    }
+
     #[test]
-    fn extern_rust_equatable_type() {
+    fn extern_rust_type_arc_mutex() {
         CodegenTest {
             bridge_module: bridge_module_tokens().into(),
             expected_rust_tokens: expected_rust_tokens(),
@@ -501,6 +1549,11 @@ public class Foo: FooRefMut {
             __swift_bridge__$Foo$_free(ptr)
         }
     }
+
+    override var unconsumedPtr: UnsafeMutableRawPointer {
+        precondition(isOwned, "Attempted to use a value that was already consumed.")
+        return ptr
+    }
 }
 extension Foo {
     public convenience init?() {
@@ -527,3 +1580,63 @@ void* __swift_bridge__$Foo$new(void);
         .test();
     }
 }
+
+/// Verify that methods that return a view that borrows from `self`, such as a `&str` or a
+/// `&[T]`, get an overload that passes the view to a closure and keeps `self` alive with
+/// `withExtendedLifetime` until the closure returns.
+mod extern_rust_method_returns_borrowed_view {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn title(&self) -> &str;
+                    fn bytes(&self) -> &[u8];
+                    fn count(&self) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    public func title() -> RustStr {
+        __swift_bridge__$SomeType$title(unconsumedPtr)
+    }
+    public func title<R>(_ body: (RustStr) throws -> R) rethrows -> R {
+        try withExtendedLifetime(self) { try body(self.title()) }
+    }
+"#,
+            r#"
+    public func bytes() -> UnsafeBufferPointer<UInt8> {
+        let slice = __swift_bridge__$SomeType$bytes(unconsumedPtr); return UnsafeBufferPointer(start: slice.start.assumingMemoryBound(to: UInt8.self), count: Int(slice.len));
+    }
+    public func bytes<R>(_ body: (UnsafeBufferPointer<UInt8>) throws -> R) rethrows -> R {
+        try withExtendedLifetime(self) { try body(self.bytes()) }
+    }
+"#,
+            // Methods that do not return a borrowed view do not get a closure overload.
+            r#"
+    public func count() -> UInt32 {
+        __swift_bridge__$SomeType$count(unconsumedPtr)
+    }
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_method_returns_borrowed_view() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,118 @@
+use super::{BridgeModule, CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+fn bridge_module_tokens() -> TokenStream {
+    quote! {
+        #[swift_bridge::bridge]
+        mod ffi {
+            extern "Rust" {
+                type SomeType;
+
+                #[swift_bridge(init)]
+                fn new() -> SomeType;
+                fn area(&self) -> f64;
+                fn scale(&mut self, by: f64);
+                fn consume(self);
+            }
+        }
+    }
+}
+
+fn bridge_module(open_classes: bool) -> BridgeModule {
+    BridgeModule {
+        tokens: bridge_module_tokens(),
+        open_classes,
+        ..Default::default()
+    }
+}
+
+/// Verify that we declare the classes `open`, and declare their instance methods as `open` methods
+/// in the class bodies so that they can be overridden.
+mod open_classes_enabled {
+    use super::*;
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+open class SomeType: SomeTypeRefMut {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$SomeType$_free(ptr)
+        }
+    }
+
+    override var unconsumedPtr: UnsafeMutableRawPointer {
+        precondition(isOwned, "Attempted to use a value that was already consumed.")
+        return ptr
+    }
+
+    open func consume() {
+"#,
+            r#"
+extension SomeType {
+    public convenience init() {
+        self.init(ptr: __swift_bridge__$SomeType$new())
+    }
+}
+open class SomeTypeRefMut: SomeTypeRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    open func scale(_ by: Double) {
+        __swift_bridge__$SomeType$scale(unconsumedPtr, by)
+    }
+}
+open class SomeTypeRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    var unconsumedPtr: UnsafeMutableRawPointer {
+        ptr
+    }
+
+    open func area() -> Double {
+        __swift_bridge__$SomeType$area(unconsumedPtr)
+    }
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn open_classes_enabled() {
+        CodegenTest {
+            bridge_module: bridge_module(true),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we declare the classes `public` by default.
+mod open_classes_disabled {
+    use super::*;
+
+    #[test]
+    fn open_classes_disabled() {
+        CodegenTest {
+            bridge_module: bridge_module(false),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("open "),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -204,6 +204,74 @@ void* __swift_bridge__$some_function(void* arg);
     }
 }
 
+/// Test code generation for Rust function that accepts and returns an Option<Vec<T>> where T is an
+/// opaque Rust type.
+mod extern_rust_fn_option_vector_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function (arg: Option<Vec<SomeType>>) -> Option<Vec<SomeType>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut Vec<super::SomeType>
+            ) -> *mut Vec<super::SomeType> {
+                if let Some(value) = super::some_function(
+                    if arg.is_null() {
+                        None
+                    } else {
+                        Some( unsafe { * Box::from_raw(arg) } )
+                    }
+                ) {
+                    Box::into_raw(Box::new(value))
+                } else {
+                    std::ptr::null_mut()
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<RustVec<SomeType>>) -> Optional<RustVec<SomeType>> {
+    { let val = __swift_bridge__$some_function({ if let val = arg { val.isOwned = false; return val.ptr } else { return nil } }()); if val != nil { return RustVec(ptr: val!) } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_vector_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 // TODO: Split this into two test modules, one for `Option<String>` arg
 //  and another for `Option<String>` return value.
 //  Easier to reason about when each codegen module is focused on one type in one position.
@@ -838,6 +906,135 @@ void __swift_bridge__$some_function(void* arg);
     }
 }
 
+/// Test code generation for Rust method that returns an Option<&mut OpaqueRustType>.
+/// We also generate a Swift method that passes the reference to a closure.
+mod extern_rust_fn_return_option_ref_mut_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    type Value;
+                    fn get_mut(self: &mut SomeType, key: u32) -> Option<&mut Value>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$get_mut"]
+            pub extern "C" fn __swift_bridge__SomeType_get_mut(
+                this: *mut super::SomeType,
+                key: u32
+            ) -> *mut super::Value {
+                if let Some(val) = (unsafe { &mut *this }).get_mut(key) {
+                    val as *mut super::Value
+                } else {
+                    std::ptr::null_mut()
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    public func get_mut(_ key: UInt32) -> Optional<ValueRefMut> {
+        { let val = __swift_bridge__$SomeType$get_mut(unconsumedPtr, key); if val != nil { return ValueRefMut(ptr: val!) } else { return nil } }()
+    }
+"#,
+            r#"
+    @discardableResult
+    public func get_mut<R>(_ key: UInt32, _ body: (ValueRefMut) throws -> R) rethrows -> Optional<R> {
+        if let value = get_mut(key) { return try body(value) } else { return nil }
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeType$get_mut(void* self, uint32_t key);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_option_ref_mut_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes an Option<&mut OpaqueRustType> argument.
+mod extern_rust_fn_arg_option_ref_mut_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_function (arg: Option<&mut SomeType>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: *mut super::SomeType) {
+                super::some_function(
+                    if arg.is_null() {
+                        None
+                    } else {
+                        Some( unsafe { &mut * arg })
+                    }
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: Optional<SomeTypeRefMut>) {
+    __swift_bridge__$some_function({ if let val = arg { return val.ptr } else { return nil } }())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arg_option_ref_mut_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Rust function that takes an Option<OpaqueRustType> argument.
 mod extern_rust_fn_with_option_opaque_rust_type_arg {
     use super::*;
@@ -1437,7 +1634,7 @@ mod shared_struct_with_option_field_ffi_repr {
 public struct SomeStruct {
     public var field: Optional<UInt8>
 
-    public init(field: Optional<UInt8>) {
+    public init(field: Optional<UInt8> = nil) {
         self.field = field
     }
 
@@ -1475,3 +1672,120 @@ typedef struct __swift_bridge__$SomeStruct { struct __private__OptionU8 field; }
         .test();
     }
 }
+
+/// Test code generation for a recursive struct that contains an `Option<Box<Self>>` field.
+mod shared_struct_with_option_box_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct TreeNode {
+                    value: u8,
+                    child: Option<Box<TreeNode>>
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct TreeNode {
+                    pub value: u8,
+                    pub child: Option<Box<TreeNode>>
+                }
+            },
+            quote! {
+                pub struct __swift_bridge__TreeNode {
+                    value: u8,
+                    child: *mut __swift_bridge__TreeNode
+                }
+            },
+            quote! {
+                child: if let Some(val) = val.child {
+                    Box::into_raw(Box::new((*val).into_ffi_repr()))
+                } else {
+                    std::ptr::null_mut()
+                }
+            },
+            quote! {
+                child: {
+                    let val = val.child;
+                    if val.is_null() {
+                        None
+                    } else {
+                        Some(Box::new(unsafe { *Box::from_raw(val) }.into_rust_repr()))
+                    }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$TreeNode$_box"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__TreeNode__box(
+                    val: __swift_bridge__TreeNode
+                ) -> *mut __swift_bridge__TreeNode {
+                    Box::into_raw(Box::new(val))
+                }
+
+                #[export_name = "__swift_bridge__$TreeNode$_unbox"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__TreeNode__unbox(
+                    ptr: *mut __swift_bridge__TreeNode
+                ) -> __swift_bridge__TreeNode {
+                    unsafe { *Box::from_raw(ptr) }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public struct TreeNode {
+    public var value: UInt8
+    public var child: Optional<RustBox<TreeNode>>
+
+    public init(value: UInt8,child: Optional<RustBox<TreeNode>> = nil) {
+        self.value = value
+        self.child = child
+    }
+
+    @inline(__always)
+    func intoFfiRepr() -> __swift_bridge__$TreeNode {
+        { let val = self; return __swift_bridge__$TreeNode(value: val.value, child: { if let boxed = val.child { return __swift_bridge__$TreeNode$_box(boxed.value.intoFfiRepr()) } else { return nil } }()); }()
+    }
+}
+extension __swift_bridge__$TreeNode {
+    @inline(__always)
+    func intoSwiftRepr() -> TreeNode {
+        { let val = self; return TreeNode(value: val.value, child: { if let ptr = val.child { return RustBox(__swift_bridge__$TreeNode$_unbox(ptr).intoSwiftRepr()) } else { return nil } }()); }()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$TreeNode { uint8_t value; void* child; } __swift_bridge__$TreeNode;
+typedef struct __swift_bridge__$Option$TreeNode { bool is_some; __swift_bridge__$TreeNode val; } __swift_bridge__$Option$TreeNode;
+void* __swift_bridge__$TreeNode$_box(__swift_bridge__$TreeNode val);
+__swift_bridge__$TreeNode __swift_bridge__$TreeNode$_unbox(void* ptr);
+    "#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_with_option_box_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,106 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a Swift `OptionSet` for an enum annotated with
+/// `#[swift_bridge(option_set)]`, and that the Rust enum keeps its discriminants.
+mod option_set_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(option_set)]
+                enum Permissions {
+                    Read = 1,
+                    Write = 2,
+                    Execute = 4,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[derive(Copy, Clone)]
+            pub enum Permissions {
+                Read = 1,
+                Write = 2,
+                Execute = 4
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public struct PermissionsOptions: OptionSet {
+    public let rawValue: UInt32
+
+    public init(rawValue: UInt32) {
+        self.rawValue = rawValue
+    }
+
+    public init(_ flag: Permissions) {
+        switch flag {
+        case Permissions.Read:
+            self.init(rawValue: 1)
+        case Permissions.Write:
+            self.init(rawValue: 2)
+        case Permissions.Execute:
+            self.init(rawValue: 4)
+        }
+    }
+
+    public static let Read = PermissionsOptions(rawValue: 1)
+    public static let Write = PermissionsOptions(rawValue: 2)
+    public static let Execute = PermissionsOptions(rawValue: 4)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn option_set_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate a Swift `OptionSet` for enums without the `option_set`
+/// attribute.
+mod enum_without_option_set_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Permissions {
+                    Read,
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn enum_without_option_set_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("OptionSet"),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
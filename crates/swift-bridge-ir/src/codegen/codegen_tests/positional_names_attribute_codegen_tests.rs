@@ -0,0 +1,70 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a tuple struct that uses `#[swift_bridge(positional_names = [...])]` gets Swift
+/// properties with those names, while its FFI representation keeps using `_0`, `_1`, etc.
+mod positional_names_tuple_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", positional_names = ["x", "y"])]
+                struct Point(f64, f64);
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub struct Point(pub f64, pub f64);
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct Point {
+    public var x: Double
+    public var y: Double
+
+    public init(x: Double,y: Double) {
+        self.x = x
+        self.y = y
+    }
+
+    @inline(__always)
+    func intoFfiRepr() -> __swift_bridge__$Point {
+        { let val = self; return __swift_bridge__$Point(_0: val.x, _1: val.y); }()
+    }
+}
+"#,
+            r#"
+    func intoSwiftRepr() -> Point {
+        { let val = self; return Point(x: val._0, y: val._1); }()
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$Point { double _0; double _1; } __swift_bridge__$Point;
+"#,
+        )
+    }
+
+    #[test]
+    fn positional_names_tuple_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
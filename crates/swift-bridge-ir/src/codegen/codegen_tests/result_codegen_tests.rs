@@ -199,6 +199,82 @@ struct __private__ResultPtrAndPtr __swift_bridge__$some_function(void);
     }
 }
 
+/// Test code generation for Rust function that returns a Result<T, E> where T and E are
+/// different opaque Rust types.
+/// The `Ok` value is returned as a `T` and the `Err` value is thrown as an `E`, each of which
+/// takes ownership of the pointer that Rust handed over.
+mod extern_rust_fn_return_result_different_opaque_rust_types {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Session;
+                    type SessionError;
+
+                    fn connect() -> Result<Session, SessionError>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$connect"]
+            pub extern "C" fn __swift_bridge__connect() -> swift_bridge::result::ResultPtrAndPtr {
+                match super::connect() {
+                    Ok(ok) => {
+                        swift_bridge::result::ResultPtrAndPtr {
+                            is_ok: true,
+                            ok_or_err: Box::into_raw(Box::new({
+                                let val: super::Session = ok;
+                                val
+                            })) as *mut super::Session as *mut std::ffi::c_void
+                        }
+                    }
+                    Err(err) => {
+                        swift_bridge::result::ResultPtrAndPtr {
+                            is_ok: false,
+                            ok_or_err: Box::into_raw(Box::new({
+                                let val: super::SessionError = err;
+                                val
+                            })) as *mut super::SessionError as *mut std::ffi::c_void
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func connect() throws -> Session {
+    try { let val = __swift_bridge__$connect(); if val.is_ok { return Session(ptr: val.ok_or_err!) } else { throw SessionError(ptr: val.ok_or_err!) } }()
+}
+"#,
+        )
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ContainsAfterTrim(
+        r#"
+struct __private__ResultPtrAndPtr __swift_bridge__$connect(void);
+    "#,
+    );
+
+    #[test]
+    fn extern_rust_fn_return_result_different_opaque_rust_types() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Rust function that accepts and returns a Result<T, E>
 /// where T and E are opaque Swift types.
 mod extern_rust_fn_result_opaque_swift {
@@ -467,6 +543,83 @@ typedef struct __swift_bridge__$ResultSomeOkTypeAndSomeErrEnum{__swift_bridge__$
     }
 }
 
+/// Test code generation for Rust function that returns a Result<T, E> where T is a Vec of opaque
+/// Rust types and E is a transparent enum type.
+/// The `Ok` vector is handed to Swift as a pointer, so its elements are never copied.
+mod extern_rust_fn_return_result_vec_and_transparent_enum_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Record;
+                }
+                enum QueryError {
+                    NotFound,
+                    InvalidLimit(u32),
+                }
+                extern "Rust" {
+                    fn query(limit: u32) -> Result<Vec<Record>, QueryError>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[repr(C)]
+            pub enum ResultVec_RecordAndQueryError{
+                #[allow(unused)]
+                Ok(*mut Vec<super::Record>),
+                #[allow(unused)]
+                Err(__swift_bridge__QueryError),
+            }
+
+
+            #[export_name = "__swift_bridge__$query"]
+            pub extern "C" fn __swift_bridge__query(limit: u32) -> ResultVec_RecordAndQueryError{
+                match super::query(limit) {
+                    Ok(ok) => ResultVec_RecordAndQueryError::Ok(Box::into_raw(Box::new(ok))),
+                    Err(err) => ResultVec_RecordAndQueryError::Err(err.into_ffi_repr()),
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func query(_ limit: UInt32) throws -> RustVec<Record> {
+    try { let val = __swift_bridge__$query(limit); switch val.tag { case __swift_bridge__$ResultVec_RecordAndQueryError$ResultOk: return RustVec(ptr: val.payload.ok) case __swift_bridge__$ResultVec_RecordAndQueryError$ResultErr: throw val.payload.err.intoSwiftRepr() default: fatalError() } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef enum __swift_bridge__$ResultVec_RecordAndQueryError$Tag {__swift_bridge__$ResultVec_RecordAndQueryError$ResultOk, __swift_bridge__$ResultVec_RecordAndQueryError$ResultErr} __swift_bridge__$ResultVec_RecordAndQueryError$Tag;
+union __swift_bridge__$ResultVec_RecordAndQueryError$Fields {void* ok; struct __swift_bridge__$QueryError err;};
+typedef struct __swift_bridge__$ResultVec_RecordAndQueryError{__swift_bridge__$ResultVec_RecordAndQueryError$Tag tag; union __swift_bridge__$ResultVec_RecordAndQueryError$Fields payload;} __swift_bridge__$ResultVec_RecordAndQueryError;
+"#,
+            r#"struct __swift_bridge__$ResultVec_RecordAndQueryError __swift_bridge__$query(uint32_t limit)"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_vec_and_transparent_enum_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Rust function that returns a Result<T, E> where T is a transparent enum type and
 /// E is a opaque Rust type.
 mod extern_rust_fn_return_result_transparent_enum_type_and_opaque_rust_type {
@@ -624,6 +777,84 @@ typedef struct __swift_bridge__$ResultVoidAndSomeErrEnum{__swift_bridge__$Result
     }
 }
 
+/// Test code generation for Rust function that returns a Result<(), E> where E is an enum
+/// whose variants have no data.
+/// The result is passed over FFI as an integer error code, where `0` means `Ok`, so no tagged
+/// union is generated.
+mod extern_rust_fn_return_result_unit_type_and_unit_only_enum_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                enum SomeErrEnum {
+                    Variant1,
+                    Variant2,
+                }
+                extern "Rust" {
+                    fn some_function() -> Result<(), SomeErrEnum>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function() -> i32 {
+                match super::some_function() {
+                    Ok(_) => 0,
+                    Err(err) => match err {
+                        SomeErrEnum::Variant1 => 1,
+                        SomeErrEnum::Variant2 => 2
+                    },
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() throws -> () {
+    try { let val = __swift_bridge__$some_function(); if val == 0 { return } else { throw { () -> SomeErrEnum in switch val { case 1: return SomeErrEnum.Variant1 case 2: return SomeErrEnum.Variant2 default: fatalError() } }() } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(r#"int32_t __swift_bridge__$some_function(void);"#)
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_unit_type_and_unit_only_enum_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+
+    /// Verify that no tagged union is generated for the result.
+    #[test]
+    fn does_not_generate_result_union() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::DoesNotContain(quote! {
+                pub enum ResultVoidAndSomeErrEnum
+            }),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::DoesNotContainAfterTrim(
+                "__swift_bridge__$ResultVoidAndSomeErrEnum",
+            ),
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Rust function that returns a Result<T, E> where T is a tuple type and
 /// E is a transparent enum type.
 mod extern_rust_fn_return_result_tuple_type_and_transparent_enum_type {
@@ -654,7 +885,7 @@ mod extern_rust_fn_return_result_tuple_type_and_transparent_enum_type {
                     #[allow(unused)]
                     Ok(__swift_bridge__tuple_I32U32),
                     #[allow(unused)]
-                    Err(__swift_bridge__SomeErrEnum),
+                    Err(i32),
                 }
             },
             quote! {
@@ -662,7 +893,7 @@ mod extern_rust_fn_return_result_tuple_type_and_transparent_enum_type {
                 pub extern "C" fn __swift_bridge__some_function() -> ResultTupleI32U32AndSomeErrEnum{
                     match super::some_function() {
                         Ok(ok) => ResultTupleI32U32AndSomeErrEnum::Ok({let val = ok; __swift_bridge__tuple_I32U32(val.0, val.1)}),
-                        Err(err) => ResultTupleI32U32AndSomeErrEnum::Err(err.into_ffi_repr()),
+                        Err(err) => ResultTupleI32U32AndSomeErrEnum::Err(match err { SomeErrEnum::Variant1 => 1, SomeErrEnum::Variant2 => 2 }),
                     }
                 }
             },
@@ -678,7 +909,7 @@ mod extern_rust_fn_return_result_tuple_type_and_transparent_enum_type {
         ExpectedSwiftCode::ContainsAfterTrim(
             r#"
 public func some_function() throws -> (Int32, UInt32) {
-    try { let val = __swift_bridge__$some_function(); switch val.tag { case __swift_bridge__$ResultTupleI32U32AndSomeErrEnum$ResultOk: return { let val = val.payload.ok; return (val._0, val._1); }() case __swift_bridge__$ResultTupleI32U32AndSomeErrEnum$ResultErr: throw val.payload.err.intoSwiftRepr() default: fatalError() } }()
+    try { let val = __swift_bridge__$some_function(); switch val.tag { case __swift_bridge__$ResultTupleI32U32AndSomeErrEnum$ResultOk: return { let val = val.payload.ok; return (val._0, val._1); }() case __swift_bridge__$ResultTupleI32U32AndSomeErrEnum$ResultErr: throw { () -> SomeErrEnum in switch val.payload.err { case 1: return SomeErrEnum.Variant1 case 2: return SomeErrEnum.Variant2 default: fatalError() } }() default: fatalError() } }()
 }
 "#,
         )
@@ -688,7 +919,7 @@ public func some_function() throws -> (Int32, UInt32) {
         ExpectedCHeader::ContainsManyAfterTrim(vec![
             r#"
 typedef enum __swift_bridge__$ResultTupleI32U32AndSomeErrEnum$Tag {__swift_bridge__$ResultTupleI32U32AndSomeErrEnum$ResultOk, __swift_bridge__$ResultTupleI32U32AndSomeErrEnum$ResultErr} __swift_bridge__$ResultTupleI32U32AndSomeErrEnum$Tag;
-union __swift_bridge__$ResultTupleI32U32AndSomeErrEnum$Fields {struct __swift_bridge__$tuple$I32U32 ok; struct __swift_bridge__$SomeErrEnum err;};
+union __swift_bridge__$ResultTupleI32U32AndSomeErrEnum$Fields {struct __swift_bridge__$tuple$I32U32 ok; int32_t err;};
 typedef struct __swift_bridge__$ResultTupleI32U32AndSomeErrEnum{__swift_bridge__$ResultTupleI32U32AndSomeErrEnum$Tag tag; union __swift_bridge__$ResultTupleI32U32AndSomeErrEnum$Fields payload;} __swift_bridge__$ResultTupleI32U32AndSomeErrEnum;        
 "#,
             r#"struct __swift_bridge__$ResultTupleI32U32AndSomeErrEnum __swift_bridge__$some_function(void)"#,
@@ -781,3 +1012,590 @@ typedef struct __swift_bridge__$ResultVoidAndSomeErrStruct{__swift_bridge__$Resu
         .test();
     }
 }
+
+/// Test code generation for Rust function that returns a Result<(), ()>.
+/// The result is passed over FFI as a `bool` and `Err(())` is thrown as a `RustResultUnitErr`.
+mod extern_rust_fn_return_result_unit_and_unit {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> Result<(), ()>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function() -> bool {
+                super::some_function().is_ok()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() throws -> () {
+    try { let val = __swift_bridge__$some_function(); if val { return } else { throw RustResultUnitErr() } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"#include <stdbool.h>"#,
+            r#"bool __swift_bridge__$some_function(void);"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_unit_and_unit() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that accepts a Result<(), ()>.
+mod extern_rust_fn_arg_result_unit_and_unit {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Result<(), ()>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: bool) {
+                super::some_function(
+                    if arg {
+                        std::result::Result::Ok(())
+                    } else {
+                        std::result::Result::Err(())
+                    }
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: RustResult<(), ()>) {
+    __swift_bridge__$some_function({ switch arg { case .Ok(_): return true case .Err(_): return false } }())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(r#"void __swift_bridge__$some_function(bool arg);"#)
+    }
+
+    #[test]
+    fn extern_rust_fn_arg_result_unit_and_unit() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Swift function that returns a Result<u32, SomeEnum>.
+/// The Swift function throws the error, and Rust receives it as an `Err`.
+mod extern_swift_fn_return_result_primitive_and_transparent_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                enum SomeErrEnum {
+                    Variant1,
+                }
+
+                extern "Swift" {
+                    fn some_function(arg: u32) -> Result<u32, SomeErrEnum>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: u32) -> Result<u32, SomeErrEnum> {
+                    let val = unsafe { __swift_bridge__some_function(arg) };
+                    match val {
+                        ResultU32AndSomeErrEnum::Ok(ok) => std::result::Result::Ok(ok),
+                        ResultU32AndSomeErrEnum::Err(err) => std::result::Result::Err(match err { 1 => SomeErrEnum::Variant1, _ => unreachable!(), }),
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: u32) -> ResultU32AndSomeErrEnum;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UInt32) -> __swift_bridge__$ResultU32AndSomeErrEnum {
+    { do { let ok = try some_function(arg: arg); return __swift_bridge__$ResultU32AndSomeErrEnum(tag: __swift_bridge__$ResultU32AndSomeErrEnum$ResultOk, payload: __swift_bridge__$ResultU32AndSomeErrEnum$Fields(ok: ok)) } catch let err as SomeErrEnum { return __swift_bridge__$ResultU32AndSomeErrEnum(tag: __swift_bridge__$ResultU32AndSomeErrEnum$ResultErr, payload: __swift_bridge__$ResultU32AndSomeErrEnum$Fields(err: { () -> Int32 in switch err { case SomeErrEnum.Variant1: return 1 } }())) } catch { fatalError("Expected the thrown error to be a SomeErrEnum, but it was \(error)") } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$ResultU32AndSomeErrEnum{__swift_bridge__$ResultU32AndSomeErrEnum$Tag tag; union __swift_bridge__$ResultU32AndSomeErrEnum$Fields payload;} __swift_bridge__$ResultU32AndSomeErrEnum;
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_return_result_primitive_and_transparent_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Swift function that returns a Result<(), SomeType>, where the
+/// error is an opaque Rust type.
+mod extern_swift_fn_return_result_unit_and_opaque_rust {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                }
+
+                extern "Swift" {
+                    fn some_function() -> Result<(), SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function() -> Result<(), super::SomeType> {
+                let val = unsafe { __swift_bridge__some_function() };
+                {
+                    let err = val;
+                    if err.is_null() {
+                        std::result::Result::Ok(())
+                    } else {
+                        std::result::Result::Err(unsafe { *Box::from_raw(err) })
+                    }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function () -> UnsafeMutableRawPointer? {
+    { do { try some_function(); return nil } catch let err as SomeType { return {err.isOwned = false; return err.ptr;}() } catch { fatalError("Expected the thrown error to be a SomeType, but it was \(error)") } }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_return_result_unit_and_opaque_rust() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a Result<T, E> where T is a primitive
+/// and E is an opaque Rust type.
+/// The `Err` value is only boxed in the `Err` arm, so the success path never allocates.
+mod extern_rust_fn_return_result_primitive_and_opaque_rust {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeError;
+
+                    fn some_function () -> Result<u32, SomeError>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function() -> ResultU32AndSomeError {
+                match super::some_function() {
+                    Ok(ok) => ResultU32AndSomeError::Ok(ok),
+                    Err(err) => ResultU32AndSomeError::Err(Box::into_raw(Box::new({
+                        let val: super::SomeError = err;
+                        val
+                    })) as *mut super::SomeError),
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() throws -> UInt32 {
+    try { let val = __swift_bridge__$some_function(); switch val.tag { case __swift_bridge__$ResultU32AndSomeError$ResultOk: return val.payload.ok case __swift_bridge__$ResultU32AndSomeError$ResultErr: throw SomeError(ptr: val.payload.err) default: fatalError() } }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_primitive_and_opaque_rust() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a Result<(), Vec<E>> where E is a
+/// transparent enum type.
+/// A `RustVec` is not a Swift `Error`, so the errors are thrown as a `RustVecErr` that holds
+/// all of them.
+mod extern_rust_fn_return_result_unit_and_vec_of_transparent_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                enum ValidationError {
+                    Empty,
+                    TooLong(u32),
+                }
+
+                extern "Rust" {
+                    fn validate() -> Result<(), Vec<ValidationError>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$validate"]
+            pub extern "C" fn __swift_bridge__validate() -> *mut Vec<ValidationError> {
+                match super::validate() {
+                    Ok(ok) => std::ptr::null_mut(),
+                    Err(err) => Box::into_raw(Box::new(err))
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func validate() throws -> () {
+    try { let val = __swift_bridge__$validate(); if val != nil { throw RustVecErr(RustVec<ValidationError>(ptr: val!)) } else { return } }()
+}
+"#,
+        )
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ContainsAfterTrim(
+        r#"
+void* __swift_bridge__$validate(void);
+    "#,
+    );
+
+    #[test]
+    fn extern_rust_fn_return_result_unit_and_vec_of_transparent_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a Result<Option<T>, E> where T is an
+/// opaque Rust type and E is a transparent enum type.
+/// `Ok(None)` is passed over FFI as a null pointer, which Swift returns as `nil`.
+mod extern_rust_fn_return_result_option_opaque_rust_type_and_transparent_enum_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                enum SomeErrEnum {
+                    Variant1,
+                }
+                extern "Rust" {
+                    type SomeOkType;
+                    fn some_function() -> Result<Option<SomeOkType>, SomeErrEnum>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[repr(C)]
+            pub enum ResultOptionSomeOkTypeAndSomeErrEnum {
+                #[allow(unused)]
+                Ok(*mut super::SomeOkType),
+                #[allow(unused)]
+                Err(i32),
+            }
+
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function() -> ResultOptionSomeOkTypeAndSomeErrEnum {
+                match super::some_function() {
+                    Ok(ok) => ResultOptionSomeOkTypeAndSomeErrEnum::Ok(
+                        if let Some(val) = ok {
+                            Box::into_raw(Box::new(val))
+                        } else {
+                            std::ptr::null_mut()
+                        }
+                    ),
+                    Err(err) => ResultOptionSomeOkTypeAndSomeErrEnum::Err(match err { SomeErrEnum::Variant1 => 1 }),
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() throws -> Optional<SomeOkType> {
+    try { let val = __swift_bridge__$some_function(); switch val.tag { case __swift_bridge__$ResultOptionSomeOkTypeAndSomeErrEnum$ResultOk: return { let val = val.payload.ok; if val != nil { return SomeOkType(ptr: val!) } else { return nil } }() case __swift_bridge__$ResultOptionSomeOkTypeAndSomeErrEnum$ResultErr: throw { () -> SomeErrEnum in switch val.payload.err { case 1: return SomeErrEnum.Variant1 default: fatalError() } }() default: fatalError() } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"union __swift_bridge__$ResultOptionSomeOkTypeAndSomeErrEnum$Fields {void* ok; int32_t err;};"#,
+            r#"struct __swift_bridge__$ResultOptionSomeOkTypeAndSomeErrEnum __swift_bridge__$some_function(void)"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_option_opaque_rust_type_and_transparent_enum_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a Result<T, E> where E has a lifetime
+/// parameter, such as `SomeError<'a>`.
+/// The error is converted into the bridged `SomeError` using its `into_owned` method before it
+/// crosses the FFI boundary, so the Swift error does not borrow any Rust data.
+mod extern_rust_fn_return_result_primitive_and_borrowed_opaque_rust {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeError;
+
+                    fn some_function (text: &str) -> Result<u32, SomeError<'_>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                text: swift_bridge::string::RustStr
+            ) -> ResultU32AndSomeError {
+                match super::some_function(text.to_str()) {
+                    Ok(ok) => ResultU32AndSomeError::Ok(ok),
+                    Err(err) => ResultU32AndSomeError::Err(Box::into_raw(Box::new({
+                        let val: super::SomeError = err.into_owned();
+                        val
+                    })) as *mut super::SomeError),
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function<GenericToRustStr: ToRustStr>(_ text: GenericToRustStr) throws -> UInt32 {
+    return text.toRustStr({ textAsRustStr in
+        try { let val = __swift_bridge__$some_function(textAsRustStr); switch val.tag { case __swift_bridge__$ResultU32AndSomeError$ResultOk: return val.payload.ok case __swift_bridge__$ResultU32AndSomeError$ResultErr: throw SomeError(ptr: val.payload.err) default: fatalError() } }()
+    })
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_primitive_and_borrowed_opaque_rust() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a Result<(), E> where E is a shared
+/// struct and the returned error has lifetime parameters, such as `SomeError<'a, 'b>`.
+mod extern_rust_fn_return_result_unit_and_borrowed_transparent_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                struct SomeError {
+                    message: String,
+                }
+
+                extern "Rust" {
+                    fn some_function () -> Result<(), SomeError<'static, 'static>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            Err(err) => ResultVoidAndSomeError::Err(err.into_owned().into_ffi_repr()),
+        })
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_unit_and_borrowed_transparent_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a `&mut u32` from Swift to Rust as an `inout` value, and that this
+/// can be combined with a `Result` return type.
+mod extern_rust_method_inout_primitive_arg_returns_result {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Client;
+                    fn call(&self, attempts: &mut u32) -> Result<u32, String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$Client$call"]
+            pub extern "C" fn __swift_bridge__Client_call(
+                this: *mut super::Client,
+                attempts: *mut u32
+            ) -> ResultU32AndString {
+                match (unsafe { &*this }).call(unsafe { &mut *attempts }) {
+                    Ok(ok) => ResultU32AndString::Ok(ok),
+                    Err(err) => ResultU32AndString::Err(
+                        swift_bridge::string::RustString(err).box_into_raw()
+                    ),
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public func call(_ attempts: inout UInt32) throws -> UInt32 {
+        try { let val = __swift_bridge__$Client$call(unconsumedPtr, &attempts); switch val.tag { case __swift_bridge__$ResultU32AndString$ResultOk: return val.payload.ok case __swift_bridge__$ResultU32AndString$ResultErr: throw RustString(ptr: val.payload.err) default: fatalError() } }()
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __swift_bridge__$ResultU32AndString __swift_bridge__$Client$call(void* self, uint32_t* attempts);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_method_inout_primitive_arg_returns_result() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
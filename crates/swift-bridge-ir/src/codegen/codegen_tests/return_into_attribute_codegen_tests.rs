@@ -47,3 +47,47 @@ mod return_into_already_declared_enum {
         .test();
     }
 }
+
+/// Verify that `return_into` converts the error of a returned `Result` into the bridged error
+/// type, so that the function can return a different error type that implements `Into<E>`.
+mod return_into_result_error {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                enum SomeError {
+                    Invalid,
+                    Message(String),
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(return_into)]
+                    fn some_function() -> Result<u32, SomeError>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function() -> ResultU32AndSomeError {
+                match super::some_function().map_err(|err| -> SomeError { err.into() }) {
+                    Ok(ok) => ResultU32AndSomeError::Ok(ok),
+                    Err(err) => ResultU32AndSomeError::Err(err.into_ffi_repr()),
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn return_into_result_error() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,55 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a bridge module annotated with `#[swift_bridge(rust_ffi_file = "...")]` includes
+/// its `extern "C"` functions from `OUT_DIR` instead of expanding them inline, while still
+/// generating the functions that Rust uses to call Swift.
+mod rust_ffi_file {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            #[swift_bridge(rust_ffi_file = "some_ffi.rs")]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function(arg: u8) -> u8;
+                }
+
+                extern "Swift" {
+                    fn some_swift_function();
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsManyAndDoesNotContainMany {
+            contains: vec![
+                quote! {
+                    include!(concat!(env!("OUT_DIR"), "/", "some_ffi.rs"));
+                },
+                quote! {
+                    pub fn some_swift_function() {
+                        unsafe { __swift_bridge__some_swift_function() }
+                    }
+                },
+            ],
+            does_not_contain: vec![quote! { extern "C" fn }],
+        }
+    }
+
+    #[test]
+    fn rust_ffi_file() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,354 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can pass a `&[&[T]]` jagged slice from Swift to Rust.
+mod extern_rust_fn_jagged_slice_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: &[&[i32]]);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::FfiSlice<swift_bridge::FfiSlice<i32>>
+            ) {
+                super::some_function(&arg.as_jagged_slices())
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: [[Int32]]) {
+    arg.toFfiJaggedSlice({ argAsFfiSlice in
+        __swift_bridge__$some_function(argAsFfiSlice)
+    })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+#include <stdint.h>
+void __swift_bridge__$some_function(struct __private__FfiSlice arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_jagged_slice_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a `&[SharedStruct]` from Swift to Rust as an array, and that Rust
+/// reconstructs each struct from its FFI representation.
+mod extern_rust_fn_shared_struct_slice_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Point {
+                    x: f64,
+                    y: f64,
+                }
+
+                extern "Rust" {
+                    fn sum_points(points: &[Point]) -> f64;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$sum_points"]
+            pub extern "C" fn __swift_bridge__sum_points(
+                points: swift_bridge::FfiSlice<__swift_bridge__Point>
+            ) -> f64 {
+                super::sum_points(
+                    &points
+                        .as_slice()
+                        .iter()
+                        .map(|val| unsafe { std::ptr::read(val) }.into_rust_repr())
+                        .collect::<Vec<_>>()
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func sum_points(_ points: [Point]) -> Double {
+    return points.map({ $0.intoFfiRepr() }).toFfiSlice({ pointsAsFfiSlice in
+        __swift_bridge__$sum_points(pointsAsFfiSlice)
+    })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+double __swift_bridge__$sum_points(struct __private__FfiSlice points);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_shared_struct_slice_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate an overload that borrows a Swift `Data`'s bytes for each `&[u8]`
+/// argument, and that we import Foundation for it.
+mod extern_rust_fn_byte_slice_arg_data_overload {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn checksum(bytes: &[u8]) -> u64;
+                    fn write(&self, offset: u32, #[swift_bridge(label = "from")] bytes: &[u8]) -> Result<(), String>;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "import Foundation",
+            r#"
+public func checksum(_ bytes: UnsafeBufferPointer<UInt8>) -> UInt64 {
+    __swift_bridge__$checksum(bytes.toFfiSlice())
+}
+public func checksum(_ bytes: Data) -> UInt64 {
+    return bytes.withUnsafeBytes({ bytesAsRawBuffer in checksum(bytesAsRawBuffer.bindMemory(to: UInt8.self)) })
+}
+"#,
+            r#"
+    public func write(_ offset: UInt32, from bytes: Data) throws -> () {
+        return try bytes.withUnsafeBytes({ bytesAsRawBuffer in try write(offset, from: bytesAsRawBuffer.bindMemory(to: UInt8.self)) })
+    }
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_byte_slice_arg_data_overload() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate a `Data` overload for functions that only take other kinds of
+/// slices.
+mod extern_rust_fn_non_byte_slice_arg_no_data_overload {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn sum(values: &[u16]) -> u64;
+                    fn fill(buf: &mut [u8]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn extern_rust_fn_non_byte_slice_arg_no_data_overload() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainManyAfterTrim(vec![
+                "Data",
+                "import Foundation",
+            ]),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a `&mut [T]` from Swift to Rust as an `inout` array, and that this
+/// can be combined with a `Result` return type.
+mod extern_rust_method_mutable_slice_arg_returns_result {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum IoError {
+                    Eof,
+                }
+
+                extern "Rust" {
+                    type Reader;
+                    fn read(&self, buf: &mut [u8]) -> Result<usize, IoError>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$Reader$read"]
+            pub extern "C" fn __swift_bridge__Reader_read(
+                this: *mut super::Reader,
+                buf: swift_bridge::FfiSlice<u8>
+            ) -> ResultUIntAndIoError {
+                match (unsafe { &*this }).read(buf.as_mut_slice()) {
+                    Ok(ok) => ResultUIntAndIoError::Ok(ok),
+                    Err(err) => ResultUIntAndIoError::Err(match err { IoError::Eof => 1 }),
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public func read(_ buf: inout [UInt8]) throws -> UInt {
+        return try buf.toFfiMutableSlice({ bufAsFfiSlice in
+            try { let val = __swift_bridge__$Reader$read(unconsumedPtr, bufAsFfiSlice); switch val.tag { case __swift_bridge__$ResultUIntAndIoError$ResultOk: return val.payload.ok case __swift_bridge__$ResultUIntAndIoError$ResultErr: throw { () -> IoError in switch val.payload.err { case 1: return IoError.Eof default: fatalError() } }() default: fatalError() } }()
+        })
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __swift_bridge__$ResultUIntAndIoError __swift_bridge__$Reader$read(void* self, struct __private__FfiSlice buf);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_method_mutable_slice_arg_returns_result() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Rust method that returns a `Cow<[T]>`.
+/// Borrowed and owned `Cow`s are both copied into a Swift `Array`.
+mod extern_rust_method_returns_cow_slice {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn bytes(&self) -> Cow<[u8]>;
+                    fn floats(&self) -> std::borrow::Cow<'_, [f32]>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$bytes"]
+                pub extern "C" fn __swift_bridge__SomeType_bytes(
+                    this: *mut super::SomeType
+                ) -> *mut Vec<u8> {
+                    Box::into_raw(Box::new((unsafe { &*this }).bytes().into_owned()))
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$floats"]
+                pub extern "C" fn __swift_bridge__SomeType_floats(
+                    this: *mut super::SomeType
+                ) -> *mut Vec<f32> {
+                    Box::into_raw(Box::new((unsafe { &*this }).floats().into_owned()))
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    public func bytes() -> Array<UInt8> {
+        Array(RustVec<UInt8>(ptr: __swift_bridge__$SomeType$bytes(unconsumedPtr)))
+    }
+"#,
+            r#"
+    public func floats() -> Array<Float> {
+        Array(RustVec<Float>(ptr: __swift_bridge__$SomeType$floats(unconsumedPtr)))
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void* __swift_bridge__$SomeType$bytes(void* self);",
+            "void* __swift_bridge__$SomeType$floats(void* self);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_method_returns_cow_slice() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
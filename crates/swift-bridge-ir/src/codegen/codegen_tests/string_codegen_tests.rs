@@ -216,6 +216,294 @@ struct RustStr __swift_bridge__$some_function(void);
     }
 }
 
+/// Test code generation for Rust function that returns a &'static str.
+/// The static string is copied into a Swift `String`.
+mod extern_rust_fn_return_static_str {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (code: u8) -> &'static str;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(code: u8) -> swift_bridge::string::RustStr {
+                swift_bridge::string::RustStr::from_str(super::some_function(code))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ code: UInt8) -> String {
+    __swift_bridge__$some_function(code).toString()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+#include <stdint.h>
+struct RustStr __swift_bridge__$some_function(uint8_t code);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_static_str() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust functions that return a &str that borrows from their arguments.
+/// Swift's strings are only lent to Rust for the duration of the call, so the returned string is
+/// copied into a Swift `String` before the arguments go away.
+mod extern_rust_fn_return_str_borrowed_from_args {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    type Picker;
+
+                    fn longest<'a>(&self, a: &'a str, b: &'a str) -> &'a str;
+                    fn trimmed(text: &str) -> &str;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$Picker$longest"]
+                pub extern "C" fn __swift_bridge__Picker_longest(
+                    this: *mut super::Picker,
+                    a: swift_bridge::string::RustStr,
+                    b: swift_bridge::string::RustStr
+                ) -> swift_bridge::string::RustStr {
+                    swift_bridge::string::RustStr::from_str((unsafe { &*this }).longest(a.to_str(), b.to_str()))
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$trimmed"]
+                pub extern "C" fn __swift_bridge__trimmed(
+                    text: swift_bridge::string::RustStr
+                ) -> swift_bridge::string::RustStr {
+                    swift_bridge::string::RustStr::from_str(super::trimmed(text.to_str()))
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    public func longest<GenericToRustStr: ToRustStr>(_ a: GenericToRustStr, _ b: GenericToRustStr) -> String {
+        return b.toRustStr({ bAsRustStr in
+            return a.toRustStr({ aAsRustStr in
+            __swift_bridge__$Picker$longest(unconsumedPtr, aAsRustStr, bAsRustStr).toString()
+        })
+        })
+    }
+"#,
+            r#"
+public func trimmed<GenericToRustStr: ToRustStr>(_ text: GenericToRustStr) -> String {
+    return text.toRustStr({ textAsRustStr in
+        __swift_bridge__$trimmed(textAsRustStr).toString()
+    })
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_str_borrowed_from_args() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Rust method that takes a &str argument but returns a &str that
+/// borrows from `self`.
+/// The returned string is not copied, since `self` is kept alive until Rust returns it.
+mod extern_rust_method_return_str_borrowed_from_self {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    type Picker;
+
+                    fn name<'a>(&'a self, prefix: &str) -> &'a str;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public func name<GenericToRustStr: ToRustStr>(_ prefix: GenericToRustStr) -> RustStr {
+        return prefix.toRustStr({ prefixAsRustStr in
+            __swift_bridge__$Picker$name(unconsumedPtr, prefixAsRustStr)
+        })
+    }
+    public func name<GenericToRustStr: ToRustStr, R>(_ prefix: GenericToRustStr, _ body: (RustStr) throws -> R) rethrows -> R {
+        try withExtendedLifetime(self) { try body(self.name(prefix)) }
+    }
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_method_return_str_borrowed_from_self() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes and returns a `Box<str>`.
+/// The returned string is copied into a Swift `String`.
+mod extern_rust_fn_boxed_str {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: Box<str>) -> Box<str>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut swift_bridge::string::RustString
+            ) -> *mut swift_bridge::string::RustString {
+                swift_bridge::string::RustString(
+                    super::some_function(unsafe { Box::from_raw(arg).0 }.into_boxed_str()).into_string()
+                ).box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function<GenericIntoRustString: IntoRustString>(_ arg: GenericIntoRustString) -> String {
+    RustString(ptr: __swift_bridge__$some_function({ let rustString = arg.intoRustString(); rustString.isOwned = false; return rustString.ptr }())).toString()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_boxed_str() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift function that takes and returns a `Box<str>`.
+mod extern_swift_fn_boxed_str {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    fn some_function (arg: Box<str>) -> Box<str>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function (arg: Box<str>) -> Box<str> {
+                unsafe {
+                    Box::from_raw(unsafe {
+                        __swift_bridge__some_function(
+                            swift_bridge::string::RustString(arg.into_string()).box_into_raw()
+                        )
+                    }).0
+                }.into_boxed_str()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {
+    { let rustString = some_function(arg: RustString(ptr: arg).toString()).intoRustString(); rustString.isOwned = false; return rustString.ptr }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim("")
+    }
+
+    #[test]
+    fn extern_swift_fn_boxed_str() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Swift function that returns an owned String argument.
 mod extern_swift_func_returns_string {
     use super::*;
@@ -311,3 +599,60 @@ func __swift_bridge__some_function (_ value: UnsafeMutableRawPointer) {
         .test();
     }
 }
+
+/// Test code generation for Rust method that has a `&mut String` argument.
+/// Rust borrows the Swift `RustString`, so Swift does not give up ownership of it.
+mod extern_rust_fn_arg_mutable_string_reference {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn append_summary(&self, out: &mut String);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__SomeType_append_summary(
+                this: *mut super::SomeType,
+                out: *mut swift_bridge::string::RustString
+            ) {
+                (unsafe { &*this }).append_summary(unsafe { &mut (* out).0 })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public func append_summary(_ out: RustStringRefMut) {
+        __swift_bridge__$SomeType$append_summary(unconsumedPtr, out.ptr)
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$SomeType$append_summary(void* self, void* out);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arg_mutable_string_reference() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
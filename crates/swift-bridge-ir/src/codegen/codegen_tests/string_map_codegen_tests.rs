@@ -0,0 +1,229 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for a Rust function that takes and returns a `HashMap<String, u32>`.
+/// The map is passed over FFI as a batch of buffers instead of entry by entry.
+mod extern_rust_fn_string_map {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: HashMap<String, u32>) -> HashMap<String, u32>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::hash_map::FfiStringMap<u32>
+            ) -> swift_bridge::hash_map::FfiStringMap<u32> {
+                swift_bridge::hash_map::FfiStringMap::from_hash_map(
+                    super::some_function(arg.to_hash_map())
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: [String: UInt32]) -> [String: UInt32] {
+    return arg.toFfiStringMap({ argAsFfiStringMap in
+        { let ffiStringMap = __swift_bridge__$some_function(argAsFfiStringMap); let dictionary = ffiStringMap.toSwiftDictionary(UInt32.self); __swift_bridge__$FfiStringMap$free_u32(ffiStringMap); return dictionary }()
+    })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiStringMap __swift_bridge__$some_function(struct __private__FfiStringMap arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_string_map() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we support the fully qualified `std::collections::HashMap` path.
+mod extern_rust_fn_std_collections_string_map {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: std::collections::HashMap<String, f64>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::hash_map::FfiStringMap<f64>
+            ) {
+                super::some_function(arg.to_hash_map())
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: [String: Double]) {
+    arg.toFfiStringMap({ argAsFfiStringMap in
+        __swift_bridge__$some_function(argAsFfiStringMap)
+    })
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_std_collections_string_map() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Swift function that takes a `HashMap<String, bool>`.
+/// Rust leaks the map's buffers and Swift frees them after copying them into a `Dictionary`.
+mod extern_swift_fn_string_map_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: HashMap<String, bool>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(arg: std::collections::HashMap<String, bool>) {
+                unsafe {
+                    __swift_bridge__some_function(
+                        swift_bridge::hash_map::FfiStringMap::from_hash_map(arg)
+                    )
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__FfiStringMap) {
+    some_function(arg: { let ffiStringMap = arg; let dictionary = ffiStringMap.toSwiftDictionary(Bool.self); __swift_bridge__$FfiStringMap$free_bool(ffiStringMap); return dictionary }())
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_string_map_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Rust function that takes and returns a map of opaque Rust types.
+/// Each value is passed as a boxed pointer, and the receiving side takes ownership of it.
+mod extern_rust_fn_string_map_of_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_function(arg: HashMap<String, SomeType>) -> HashMap<String, SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::hash_map::FfiStringMap<*mut super::SomeType>
+            ) -> swift_bridge::hash_map::FfiStringMap<*mut super::SomeType> {
+                swift_bridge::hash_map::FfiStringMap::from_hash_map_with(
+                    super::some_function(
+                        arg.to_hash_map_with(|value| unsafe { *Box::from_raw(value) })
+                    ),
+                    |value| Box::into_raw(Box::new({
+                        let val: super::SomeType = value;
+                        val
+                    })) as *mut super::SomeType
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: [String: SomeType]) -> [String: SomeType] {
+    return arg.mapValues({ val -> UnsafeMutableRawPointer in val.isOwned = false; return val.ptr }).toFfiStringMap({ argAsFfiStringMap in
+        { let ffiStringMap = __swift_bridge__$some_function(argAsFfiStringMap); let dictionary = ffiStringMap.toSwiftDictionary(UnsafeMutableRawPointer.self).mapValues({ SomeType(ptr: $0) }); __swift_bridge__$FfiStringMap$free_pointers(ffiStringMap); return dictionary }()
+    })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiStringMap __swift_bridge__$some_function(struct __private__FfiStringMap arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_string_map_of_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,125 @@
+use super::{BridgeModule, CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use crate::codegen::SwiftResultFunctions;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+fn bridge_module_tokens() -> TokenStream {
+    quote! {
+        #[swift_bridge::bridge]
+        mod ffi {
+            extern "Rust" {
+                type SomeType;
+
+                fn parse(#[swift_bridge(label = "input")] text: u8, n: u8) -> Result<u32, u8>;
+                fn validate(&self) -> Result<(), u8>;
+                async fn fetch() -> Result<u32, u8>;
+            }
+        }
+    }
+}
+
+fn bridge_module(swift_result_functions: SwiftResultFunctions) -> BridgeModule {
+    BridgeModule {
+        tokens: bridge_module_tokens(),
+        swift_result_functions,
+        ..Default::default()
+    }
+}
+
+/// Verify that by default we only generate throwing Swift functions.
+mod throwing_only {
+    use super::*;
+
+    #[test]
+    fn throwing_only() {
+        CodegenTest {
+            bridge_module: bridge_module(SwiftResultFunctions::Throwing),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainManyAfterTrim(vec![
+                "parseResult",
+                "validateResult",
+                "fetchResult",
+            ]),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate both a throwing Swift function and a `Result` returning Swift
+/// function for every function that returns a `Result`.
+mod throwing_and_result_returning {
+    use super::*;
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "public func parse(input text: UInt8, _ n: UInt8) throws -> UInt32 {",
+            r#"
+public func parseResult(input text: UInt8, _ n: UInt8) -> Result<UInt32, Error> {
+    do {
+        return .success(try parse(input: text, n))
+    } catch {
+        return .failure(error)
+    }
+}
+"#,
+            "public func validate() throws -> () {",
+            r#"
+    public func validateResult() -> Result<(), Error> {
+        do {
+            return .success(try validate())
+        } catch {
+            return .failure(error)
+        }
+    }
+"#,
+            "public func fetch() async throws -> UInt32 {",
+            r#"
+public func fetchResult() async -> Result<UInt32, Error> {
+    do {
+        return .success(try await fetch())
+    } catch {
+        return .failure(error)
+    }
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn throwing_and_result_returning() {
+        CodegenTest {
+            bridge_module: bridge_module(SwiftResultFunctions::Both),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that when only `Result` returning Swift functions are requested the throwing Swift
+/// functions are not public.
+mod result_returning_only {
+    use super::*;
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "fileprivate func parse(input text: UInt8, _ n: UInt8) throws -> UInt32 {",
+            "public func parseResult(input text: UInt8, _ n: UInt8) -> Result<UInt32, Error> {",
+            "fileprivate func validate() throws -> () {",
+            "public func validateResult() -> Result<(), Error> {",
+        ])
+    }
+
+    #[test]
+    fn result_returning_only() {
+        CodegenTest {
+            bridge_module: bridge_module(SwiftResultFunctions::ResultReturning),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,63 @@
+use super::{BridgeModule, CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use crate::codegen::SwiftVersion;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+fn bridge_module_tokens() -> TokenStream {
+    quote! {
+        #[swift_bridge::bridge]
+        mod ffi {
+            extern "Rust" {
+                type SomeType;
+
+                #[swift_bridge(get_cell(count))]
+                fn count(&self) -> i32;
+            }
+        }
+    }
+}
+
+/// Verify that we do not use `Sendable` when targeting a Swift version that predates it.
+mod target_swift_version_before_sendable {
+    use super::*;
+
+    #[test]
+    fn target_swift_version_before_sendable() {
+        CodegenTest {
+            bridge_module: BridgeModule {
+                tokens: bridge_module_tokens(),
+                target_swift_version: Some(SwiftVersion::new(5, 4)),
+                ..Default::default()
+            },
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("Sendable"),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we use `Sendable` when targeting a Swift version that supports it.
+mod target_swift_version_with_sendable {
+    use super::*;
+
+    #[test]
+    fn target_swift_version_with_sendable() {
+        CodegenTest {
+            bridge_module: BridgeModule {
+                tokens: bridge_module_tokens(),
+                target_swift_version: Some(SwiftVersion::new(5, 5)),
+                ..Default::default()
+            },
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::ContainsAfterTrim(
+                r#"
+@available(*, unavailable)
+extension SomeTypeRef: Sendable {}
+"#,
+            ),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
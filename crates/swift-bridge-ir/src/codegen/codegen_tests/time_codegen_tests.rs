@@ -0,0 +1,392 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for a Rust function that takes and returns a `Duration`.
+mod extern_rust_fn_duration {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Duration) -> std::time::Duration;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: f64) -> f64 {
+                super::some_function(std::time::Duration::from_secs_f64(arg)).as_secs_f64()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "import Foundation",
+            r#"
+public func some_function(_ arg: TimeInterval) -> TimeInterval {
+    __swift_bridge__$some_function(arg)
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+double __swift_bridge__$some_function(double arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_duration() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Rust function that takes and returns an `Option<Duration>`.
+mod extern_rust_fn_option_duration {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<Duration>) -> Option<Duration>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::option::OptionF64
+            ) -> swift_bridge::option::OptionF64 {
+                if let Some(val) = super::some_function(
+                    {
+                        let val = arg;
+                        if val.is_some {
+                            Some(std::time::Duration::from_secs_f64(val.val))
+                        } else {
+                            None
+                        }
+                    }
+                ) {
+                    swift_bridge::option::OptionF64 { val: val.as_secs_f64(), is_some: true }
+                } else {
+                    swift_bridge::option::OptionF64 { val: 123.4, is_some: false }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<TimeInterval>) -> Optional<TimeInterval> {
+    __swift_bridge__$some_function(arg.intoFfiRepr()).intoSwiftRepr()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__OptionF64 __swift_bridge__$some_function(struct __private__OptionF64 arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_duration() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Rust function that takes and returns an `Option<SystemTime>`.
+mod extern_rust_fn_option_system_time {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<SystemTime>) -> Option<std::time::SystemTime>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::option::OptionF64
+            ) -> swift_bridge::option::OptionF64 {
+                if let Some(val) = super::some_function(
+                    {
+                        let val = arg;
+                        if val.is_some {
+                            Some({
+                                let secs: f64 = val.val;
+                                if secs >= 0. {
+                                    std::time::UNIX_EPOCH + std::time::Duration::from_secs_f64(secs)
+                                } else {
+                                    std::time::UNIX_EPOCH - std::time::Duration::from_secs_f64(-secs)
+                                }
+                            })
+                        } else {
+                            None
+                        }
+                    }
+                ) {
+                    swift_bridge::option::OptionF64 {
+                        val: match val.duration_since(std::time::UNIX_EPOCH) {
+                            Ok(after_epoch) => after_epoch.as_secs_f64(),
+                            Err(before_epoch) => -before_epoch.duration().as_secs_f64(),
+                        },
+                        is_some: true
+                    }
+                } else {
+                    swift_bridge::option::OptionF64 { val: 123.4, is_some: false }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "import Foundation",
+            r#"
+public func some_function(_ arg: Optional<Date>) -> Optional<Date> {
+    __swift_bridge__$some_function(arg.map { $0.timeIntervalSince1970 }.intoFfiRepr()).intoSwiftRepr().map { Date(timeIntervalSince1970: $0) }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__OptionF64 __swift_bridge__$some_function(struct __private__OptionF64 arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_system_time() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Swift function that takes and returns an `Option<SystemTime>`.
+mod extern_swift_fn_option_system_time {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: Option<SystemTime>) -> Option<SystemTime>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[link_name = "__swift_bridge__$some_function"]
+            fn __swift_bridge__some_function(
+                arg: swift_bridge::option::OptionF64
+            ) -> swift_bridge::option::OptionF64;
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__OptionF64) -> __private__OptionF64 {
+    some_function(arg: arg.intoSwiftRepr().map { Date(timeIntervalSince1970: $0) }).map { $0.timeIntervalSince1970 }.intoFfiRepr()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_option_system_time() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Swift function that takes and returns a `SystemTime`.
+/// The `@_cdecl` function receives and returns the FFI representation of the `Date`.
+mod extern_swift_fn_system_time {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: SystemTime) -> SystemTime;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: Double) -> Double {
+    some_function(arg: Date(timeIntervalSince1970: arg)).timeIntervalSince1970
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_system_time() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Rust function that takes and returns a `Vec<Duration>`.
+/// The durations are passed over FFI as a `Vec<f64>` of seconds.
+mod extern_rust_fn_vec_duration {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Vec<Duration>) -> Vec<Duration>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: *mut Vec<f64>) -> *mut Vec<f64> {
+                Box::into_raw(Box::new(
+                    super::some_function(
+                        unsafe { *Box::from_raw(arg) }
+                            .into_iter()
+                            .map(|secs| std::time::Duration::from_secs_f64(secs))
+                            .collect::<Vec<_>>()
+                    )
+                    .into_iter()
+                    .map(|val| val.as_secs_f64())
+                    .collect::<Vec<f64>>()
+                ))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: [TimeInterval]) -> [TimeInterval] {
+    RustVec<Double>(ptr: __swift_bridge__$some_function({ let vec = RustVec<Double>(); for val in arg { vec.push(value: val) }; vec.isOwned = false; return vec.ptr }())).toArray()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_vec_duration() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Rust function that takes and returns a `Vec<SystemTime>`.
+/// Each `Date` is passed over FFI as its number of seconds since the Unix epoch.
+mod extern_rust_fn_vec_system_time {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Vec<SystemTime>) -> Vec<SystemTime>;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: [Date]) -> [Date] {
+    RustVec<Double>(ptr: __swift_bridge__$some_function({ let vec = RustVec<Double>(); for val in arg { vec.push(value: val.timeIntervalSince1970) }; vec.isOwned = false; return vec.ptr }())).toArray().map { Date(timeIntervalSince1970: $0) }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_vec_system_time() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
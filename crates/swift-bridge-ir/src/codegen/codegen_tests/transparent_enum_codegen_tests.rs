@@ -847,3 +847,212 @@ typedef struct __swift_bridge__$Option$SomeEnum { bool is_some; __swift_bridge__
         .test();
     }
 }
+
+/// Verify that we generate `indirect` Swift cases for the variants of a recursive enum, along with
+/// the functions that Swift uses to box and unbox the enum's FFI representation.
+mod generates_recursive_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                enum Expr {
+                    Number(i64),
+                    Negate(Box<Expr>),
+                    Add { left: Box<Expr>, right: Box<Expr> },
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub enum Expr {
+                    Number(i64),
+                    Negate(Box<Expr>),
+                    Add { left: Box<Expr>, right: Box<Expr> }
+                }
+            },
+            quote! {
+                pub enum __swift_bridge__Expr {
+                    Number(i64),
+                    Negate(*mut __swift_bridge__Expr),
+                    Add { left: *mut __swift_bridge__Expr, right: *mut __swift_bridge__Expr }
+                }
+            },
+            quote! {
+                Expr::Negate(_0) => __swift_bridge__Expr::Negate(
+                    Box::into_raw(Box::new((*_0).into_ffi_repr()))
+                )
+            },
+            quote! {
+                __swift_bridge__Expr::Negate(_0) => Expr::Negate(
+                    Box::new(unsafe { *Box::from_raw(_0) }.into_rust_repr())
+                )
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Expr$_box"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__Expr__box(val: __swift_bridge__Expr) -> *mut __swift_bridge__Expr {
+                    Box::into_raw(Box::new(val))
+                }
+
+                #[export_name = "__swift_bridge__$Expr$_unbox"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__Expr__unbox(ptr: *mut __swift_bridge__Expr) -> __swift_bridge__Expr {
+                    unsafe { *Box::from_raw(ptr) }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public enum Expr {
+    case Number(Int64)
+    indirect case Negate(Expr)
+    indirect case Add(left: Expr, right: Expr)
+}
+"#,
+            r#"
+            case Expr.Negate(let _0):
+                return __swift_bridge__$Expr(tag: __swift_bridge__$Expr$Negate, payload: __swift_bridge__$ExprFields(Negate: __swift_bridge__$Expr$FieldOfNegate(_0: __swift_bridge__$Expr$_box(_0.intoFfiRepr()))))
+"#,
+            r#"
+            case __swift_bridge__$Expr$Negate:
+                return Expr.Negate(__swift_bridge__$Expr$_unbox(self.payload.Negate._0).intoSwiftRepr())
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct __swift_bridge__$Expr$FieldOfAdd {void* left; void* right;} __swift_bridge__$Expr$FieldOfAdd;
+"#,
+            r#"
+void* __swift_bridge__$Expr$_box(__swift_bridge__$Expr val);
+__swift_bridge__$Expr __swift_bridge__$Expr$_unbox(void* ptr);
+"#,
+        ])
+    }
+
+    #[test]
+    fn generates_recursive_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an enum that mixes unit variants that have discriminants with variants that hold
+/// data gets a `rawValue` for its unit cases, and that the discriminants are left out of the Rust
+/// enum since Rust would require a `#[repr(inttype)]` for them.
+mod generate_enum_with_hybrid_raw_values {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Status {
+                    Idle = 1,
+                    Running(u32),
+                    Done,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub enum Status {
+                Idle,
+                Running(u32),
+                Done
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public enum Status {
+    case Idle
+    case Running(UInt32)
+    case Done
+}
+"#,
+            r#"
+extension Status {
+    public var rawValue: Int? {
+        switch self {
+        case .Idle:
+            return 1
+        case .Done:
+            return 3
+        default:
+            return nil
+        }
+    }
+
+    public init?(rawValue: Int) {
+        switch rawValue {
+        case 1:
+            self = .Idle
+        case 3:
+            self = .Done
+        default:
+            return nil
+        }
+    }
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn generate_enum_with_hybrid_raw_values() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that enums whose unit variants have no discriminants do not get a `rawValue`.
+mod enum_with_data_and_no_discriminants_has_no_raw_value {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Status {
+                    Idle,
+                    Running(u32),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn enum_with_data_and_no_discriminants_has_no_raw_value() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("rawValue"),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -277,6 +277,134 @@ typedef struct __swift_bridge__$AnotherStruct { uint8_t _0; } __swift_bridge__$A
     }
 }
 
+/// Verify that we generate both the Swift -> Rust and Rust -> Swift conversions for a struct
+/// with an `Option<String>` field.
+mod struct_with_option_string_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: Option<String>
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__SomeStruct {
+                    field: *mut swift_bridge::string::RustString
+                }
+            },
+            quote! {
+                impl __swift_bridge__SomeStruct {
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    pub fn into_rust_repr(self) -> SomeStruct {
+                        { let val = self; SomeStruct { field: { let val = val.field; if val.is_null() { None } else { Some(unsafe { Box::from_raw(val).0 }) } } } }
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    @inline(__always)
+    func intoFfiRepr() -> __swift_bridge__$SomeStruct {
+        { let val = self; return __swift_bridge__$SomeStruct(field: { if let rustString = optionalStringIntoRustString(val.field) { rustString.isOwned = false; return rustString.ptr } else { return nil } }()); }()
+    }
+"#,
+            r#"
+    @inline(__always)
+    func intoSwiftRepr() -> SomeStruct {
+        { let val = self; return SomeStruct(field: { let val = val.field; if val != nil { return RustString(ptr: val!) } else { return nil } }()); }()
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeStruct { void* field; } __swift_bridge__$SomeStruct;
+    "#,
+        )
+    }
+
+    #[test]
+    fn struct_with_option_string_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the Swift initializer defaults optional fields to `nil`, while leaving the other
+/// fields required.
+mod struct_initializer_optional_fields_default_to_nil {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    required: u8,
+                    optional: Option<u8>,
+                    another_required: u16,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeTupleStruct(Option<u8>, u8);
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    public init(required: UInt8,optional: Optional<UInt8> = nil,another_required: UInt16) {
+"#,
+            r#"
+    public init(_0: Optional<UInt8> = nil,_1: UInt8) {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn struct_initializer_optional_fields_default_to_nil() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Test code generation for passing a `swift_repr = "struct"` as an argument to a
 /// extern "Rust" fn.
 mod extern_rust_fn_arg_swift_repr_struct {
@@ -702,3 +830,579 @@ struct __swift_bridge__$Option$SomeStruct __swift_bridge__$some_function(struct
         .test();
     }
 }
+
+/// Verify that we generate a `LocalizedError` conformance for a struct whose fields use the
+/// `error_description`, `failure_reason` and `recovery_suggestion` attributes.
+/// Related: crates/swift-integration-tests/src/struct_attributes/localized_error.rs
+mod shared_struct_localized_error_field_attributes {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeError {
+                    code: u32,
+                    #[swift_bridge(error_description)]
+                    message: String,
+                    #[swift_bridge(failure_reason)]
+                    reason: String,
+                    #[swift_bridge(recovery_suggestion)]
+                    hint: String,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::DoesNotContain(quote! {
+            #[swift_bridge(error_description)]
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "import Foundation",
+            r#"
+extension SomeError: LocalizedError {
+    public var errorDescription: String? {
+        self.message
+    }
+
+    public var failureReason: String? {
+        self.reason
+    }
+
+    public var recoverySuggestion: String? {
+        self.hint
+    }
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn shared_struct_localized_error_field_attributes() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate a `LocalizedError` conformance for a struct that does not use
+/// any `LocalizedError` field attributes.
+mod shared_struct_without_localized_error_field_attributes {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeError {
+                    message: String,
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn shared_struct_without_localized_error_field_attributes() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainManyAfterTrim(vec![
+                "LocalizedError",
+                "import Foundation",
+            ]),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate `toBytes()` and `init?(bytes:)` helpers for a struct that uses the
+/// `bytes` attribute.
+/// Related: crates/swift-integration-tests/src/struct_attributes/bytes.rs
+mod shared_struct_bytes_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", bytes)]
+                struct SomeStruct {
+                    id: u64,
+                    enabled: bool,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "import Foundation",
+            r#"
+extension SomeStruct {
+    public func toBytes() -> Data {
+        var bytes = Data()
+        withUnsafeBytes(of: self.id) { bytes.append(contentsOf: $0) }
+        bytes.append(self.enabled ? 1 : 0)
+        return bytes
+    }
+
+    public init?(bytes: Data) {
+        guard bytes.count == MemoryLayout<UInt64>.size + 1 else {
+            return nil
+        }
+
+        var offset = bytes.startIndex
+
+        var field0: UInt64 = 0
+        _ = withUnsafeMutableBytes(of: &field0) {
+            bytes[offset..<offset + MemoryLayout<UInt64>.size].copyBytes(to: $0)
+        }
+        offset += MemoryLayout<UInt64>.size
+
+        let field1Byte = bytes[offset]
+        guard field1Byte <= 1 else {
+            return nil
+        }
+        let field1 = field1Byte == 1
+        offset += 1
+
+        self.id = field0
+        self.enabled = field1
+    }
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn shared_struct_bytes_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a `self: &mut SomeStruct` method on a `swift_repr = "struct"` struct becomes a
+/// Swift `mutating func` that passes the struct to Rust by pointer and reads the mutated struct
+/// back.
+/// Related: crates/swift-integration-tests/src/shared_types/shared_struct.rs
+mod shared_struct_mutating_method {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    count: u32,
+                }
+
+                extern "Rust" {
+                    fn increment(self: &mut SomeStruct, by: u32) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeStruct$increment"]
+            pub extern "C" fn __swift_bridge__SomeStruct_increment(
+                this: *mut __swift_bridge__SomeStruct,
+                by: u32
+            ) -> u32 {
+                {
+                    let mut this_val = unsafe { std::ptr::read(this) }.into_rust_repr();
+                    let val = this_val.increment(by);
+                    unsafe { std::ptr::write(this, this_val.into_ffi_repr()) };
+                    val
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct {
+    public mutating func increment(_ by: UInt32) -> UInt32 {
+        var ffiSelf = self.intoFfiRepr()
+        defer { self = ffiSelf.intoSwiftRepr() }
+        return __swift_bridge__$SomeStruct$increment(&ffiSelf, by)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uint32_t __swift_bridge__$SomeStruct$increment(struct __swift_bridge__$SomeStruct* this, uint32_t by);
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_mutating_method() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that fallible `self: SomeStruct` and `self: &SomeStruct` methods on a
+/// `swift_repr = "struct"` struct pass a copy of the struct to Rust by value and become
+/// non-mutating Swift functions that throw, so that the Swift struct is never written to.
+/// Related: crates/swift-integration-tests/src/shared_types/shared_struct.rs
+mod shared_struct_fallible_value_methods {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    count: u32,
+                }
+
+                extern "Rust" {
+                    fn incremented(self: SomeStruct, by: u32) -> Result<SomeStruct, String>;
+                    fn checked_half(self: &SomeStruct) -> Result<u32, String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeStruct$incremented"]
+                pub extern "C" fn __swift_bridge__SomeStruct_incremented(
+                    this: __swift_bridge__SomeStruct,
+                    by: u32
+                ) -> ResultSomeStructAndString {
+                    match this.into_rust_repr().incremented(by) {
+                        Ok(ok) => ResultSomeStructAndString::Ok(ok.into_ffi_repr()),
+                        Err(err) => ResultSomeStructAndString::Err(
+                            swift_bridge::string::RustString(err).box_into_raw()
+                        ),
+                    }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeStruct$checked_half"]
+                pub extern "C" fn __swift_bridge__SomeStruct_checked_half(
+                    this: __swift_bridge__SomeStruct
+                ) -> ResultU32AndString {
+                    match this.into_rust_repr().checked_half() {
+                        Ok(ok) => ResultU32AndString::Ok(ok),
+                        Err(err) => ResultU32AndString::Err(
+                            swift_bridge::string::RustString(err).box_into_raw()
+                        ),
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct {
+    public func incremented(_ by: UInt32) throws -> SomeStruct {
+        try { let val = __swift_bridge__$SomeStruct$incremented(self.intoFfiRepr(), by); switch val.tag { case __swift_bridge__$ResultSomeStructAndString$ResultOk: return val.payload.ok.intoSwiftRepr() case __swift_bridge__$ResultSomeStructAndString$ResultErr: throw RustString(ptr: val.payload.err) default: fatalError() } }()
+    }
+
+    public func checked_half() throws -> UInt32 {
+        try { let val = __swift_bridge__$SomeStruct$checked_half(self.intoFfiRepr()); switch val.tag { case __swift_bridge__$ResultU32AndString$ResultOk: return val.payload.ok case __swift_bridge__$ResultU32AndString$ResultErr: throw RustString(ptr: val.payload.err) default: fatalError() } }()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "struct __swift_bridge__$ResultSomeStructAndString __swift_bridge__$SomeStruct$incremented(struct __swift_bridge__$SomeStruct this, uint32_t by);",
+            "struct __swift_bridge__$ResultU32AndString __swift_bridge__$SomeStruct$checked_half(struct __swift_bridge__$SomeStruct this);",
+        ])
+    }
+
+    #[test]
+    fn shared_struct_fallible_value_methods() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a struct's `PhantomData` fields are declared on the Rust struct but are left out
+/// of the FFI representation, the Swift struct and the C header.
+/// Related: crates/swift-integration-tests/src/shared_types/shared_struct.rs
+mod shared_struct_with_phantom_data_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Distance {
+                    value: f64,
+                    unit: std::marker::PhantomData<Meters>,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct Distance {
+                    pub value: f64,
+                    pub unit: std::marker::PhantomData<Meters>
+                }
+            },
+            quote! {
+                pub struct __swift_bridge__Distance {
+                    value: f64
+                }
+            },
+            quote! {
+                pub fn into_rust_repr(self) -> Distance {
+                    {
+                        let val = self;
+                        Distance {
+                            value: val.value,
+                            unit: std::marker::PhantomData
+                        }
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct Distance {
+    public var value: Double
+
+    public init(value: Double) {
+        self.value = value
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$Distance { double value; } __swift_bridge__$Distance;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_with_phantom_data_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a failable initializer that accepts the raw value of a shared enum
+/// field, for enums that have Swift raw values.
+mod shared_struct_with_raw_value_enum_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(comparable)]
+                enum Priority {
+                    Low = 1,
+                    High = 5,
+                }
+
+                enum Plain {
+                    A,
+                    B,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct Task {
+                    id: u32,
+                    priority: Priority,
+                    plain: Plain,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Task {
+    public init?(id: UInt32, priority: Int, plain: Plain) {
+        guard let priority = Priority(rawValue: priority) else {
+            return nil
+        }
+        self.init(id: id, priority: priority, plain: plain)
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_with_raw_value_enum_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate a raw value initializer for structs whose enum fields do not
+/// have Swift raw values.
+mod shared_struct_without_raw_value_enum_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                enum Plain {
+                    A = 1,
+                    B = 2,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    plain: Plain,
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn shared_struct_without_raw_value_enum_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("public init?("),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a struct whose fields are other shared structs holds their FFI representations,
+/// and that the nested struct is declared first in the C header even when it is declared later
+/// in the bridge module.
+/// Related: crates/swift-integration-tests/src/shared_types/shared_struct.rs
+mod shared_struct_with_nested_shared_struct_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Line {
+                    start: Point,
+                    end: Option<Point>,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct Point {
+                    x: f64,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__Line {
+                    start: __swift_bridge__Point,
+                    end: __swift_bridge__Option_Point
+                }
+            },
+            quote! {
+                __swift_bridge__Line {
+                    start: val.start.into_ffi_repr(),
+                    end: __swift_bridge__Option_Point::from_rust_repr(val.end)
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct Line {
+    public var start: Point
+    public var end: Optional<Point>
+"#,
+            r#"
+return __swift_bridge__$Line(start: val.start.intoFfiRepr(), end: __swift_bridge__$Option$Point.fromSwiftRepr(val.end));
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$Point { double x; } __swift_bridge__$Point;
+typedef struct __swift_bridge__$Option$Point { bool is_some; __swift_bridge__$Point val; } __swift_bridge__$Option$Point;
+typedef struct __swift_bridge__$Line { struct __swift_bridge__$Point start; struct __swift_bridge__$Option$Point end; } __swift_bridge__$Line;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_with_nested_shared_struct_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -41,6 +41,12 @@ mod extern_rust_type_vec_support {
                     unsafe { &*vec }.len()
                 }
 
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_MyRustType$capacity"]
+                pub extern "C" fn _capacity(vec: *const Vec<super::MyRustType>) -> usize {
+                    unsafe { &*vec }.capacity()
+                }
+
                 #[doc(hidden)]
                 #[export_name = "__swift_bridge__$Vec_MyRustType$get"]
                 pub extern "C" fn _get(vec: *const Vec<super::MyRustType>, index: usize) -> *const super::MyRustType {
@@ -139,6 +145,10 @@ extension MyRustType: Vectorizable {
     public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {
         __swift_bridge__$Vec_MyRustType$len(vecPtr)
     }
+
+    public static func vecOfSelfCapacity(vecPtr: UnsafeMutableRawPointer) -> UInt {
+        __swift_bridge__$Vec_MyRustType$capacity(vecPtr)
+    }
 }
 "#,
         )
@@ -157,6 +167,7 @@ void* __swift_bridge__$Vec_MyRustType$pop(void* vec_ptr);
 void* __swift_bridge__$Vec_MyRustType$get(void* vec_ptr, uintptr_t index);
 void* __swift_bridge__$Vec_MyRustType$get_mut(void* vec_ptr, uintptr_t index);
 uintptr_t __swift_bridge__$Vec_MyRustType$len(void* vec_ptr);
+uintptr_t __swift_bridge__$Vec_MyRustType$capacity(void* vec_ptr);
 void* __swift_bridge__$Vec_MyRustType$as_ptr(void* vec_ptr);
 "#,
         )
@@ -283,6 +294,198 @@ void __swift_bridge__$some_function(void* arg);
     }
 }
 
+/// Test code generation for Rust method that has a `&mut Vec<T>` argument.
+/// Rust borrows the Swift `RustVec<T>`, so Swift does not give up ownership of it.
+mod extern_rust_fn_arg_mutable_vec_reference {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type MyRustType;
+                    fn append_results(&self, out: &mut Vec<u32>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__MyRustType_append_results(
+                this: *mut super::MyRustType,
+                out: *mut Vec<u32>
+            ) {
+                (unsafe { &*this }).append_results(unsafe { &mut * out })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public func append_results(_ out: RustVec<UInt32>) {
+        __swift_bridge__$MyRustType$append_results(unconsumedPtr, out.ptr)
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$MyRustType$append_results(void* self, void* out);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arg_mutable_vec_reference() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a Vec<Option<T>> where T is an opaque
+/// Rust type.
+mod extern_rust_fn_return_vec_of_optional_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type MyRustType;
+                    fn some_function() -> Vec<Option<MyRustType>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function() -> swift_bridge::FfiSlice<*mut super::MyRustType> {
+                swift_bridge::FfiSlice::from_vec(
+                    super::some_function()
+                        .into_iter()
+                        .map(|val| {
+                            if let Some(val) = val {
+                                Box::into_raw(Box::new(val))
+                            } else {
+                                std::ptr::null_mut()
+                            }
+                        })
+                        .collect::<Vec<*mut super::MyRustType>>()
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function() -> [Optional<MyRustType>] {
+    { let slice = __swift_bridge__$some_function(); let vec = UnsafeBufferPointer(start: slice.start.assumingMemoryBound(to: Optional<UnsafeMutableRawPointer>.self), count: Int(slice.len)).map { ptr -> Optional<MyRustType> in if let ptr = ptr { return MyRustType(ptr: ptr) } else { return nil } }; __swift_bridge__$free_ffi_slice_of_pointers(slice); return vec }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiSlice __swift_bridge__$some_function(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_vec_of_optional_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that has an argument
+/// Vec<Option<T>> where T is an opaque Rust type.
+mod extern_rust_fn_arg_vec_of_optional_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type MyRustType;
+                    fn some_function(arg: Vec<Option<MyRustType>>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::FfiSlice<*mut super::MyRustType>
+            ) {
+                super::some_function(
+                    arg
+                        .as_slice()
+                        .iter()
+                        .map(|ptr| {
+                            if ptr.is_null() {
+                                None
+                            } else {
+                                Some(unsafe { *Box::from_raw(*ptr) })
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: [Optional<MyRustType>]) {
+    arg.map({ val -> Optional<UnsafeMutableRawPointer> in if let val = val { val.isOwned = false; return val.ptr } else { return nil } }).toFfiPointerSlice({ argAsFfiSlice in
+        __swift_bridge__$some_function(argAsFfiSlice)
+    })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(struct __private__FfiSlice arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arg_vec_of_optional_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we emit Rust, Swift and C header code that allows a transparent enum be used
 /// within a Vec<T>.
 mod transparent_enum_vec_support {
@@ -321,6 +524,12 @@ mod transparent_enum_vec_support {
                     unsafe { &*vec }.len()
                 }
 
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeEnum$capacity"]
+                pub extern "C" fn _capacity(vec: *const Vec<SomeEnum>) -> usize {
+                    unsafe { &*vec }.capacity()
+                }
+
                 #[doc(hidden)]
                 #[export_name = "__swift_bridge__$Vec_SomeEnum$get"]
                 pub extern "C" fn _get(vec: *const Vec<SomeEnum>, index: usize) -> __swift_bridge__Option_SomeEnum {
@@ -398,6 +607,10 @@ extension SomeEnum: Vectorizable {
     public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {
         __swift_bridge__$Vec_SomeEnum$len(vecPtr)
     }
+
+    public static func vecOfSelfCapacity(vecPtr: UnsafeMutableRawPointer) -> UInt {
+        __swift_bridge__$Vec_SomeEnum$capacity(vecPtr)
+    }
 }
 "#,
         )
@@ -413,6 +626,7 @@ __swift_bridge__$Option$SomeEnum __swift_bridge__$Vec_SomeEnum$pop(void* vec_ptr
 __swift_bridge__$Option$SomeEnum __swift_bridge__$Vec_SomeEnum$get(void* vec_ptr, uintptr_t index);
 __swift_bridge__$Option$SomeEnum __swift_bridge__$Vec_SomeEnum$get_mut(void* vec_ptr, uintptr_t index);
 uintptr_t __swift_bridge__$Vec_SomeEnum$len(void* vec_ptr);
+uintptr_t __swift_bridge__$Vec_SomeEnum$capacity(void* vec_ptr);
 void* __swift_bridge__$Vec_SomeEnum$as_ptr(void* vec_ptr);
 "#,
         )
@@ -430,6 +644,314 @@ void* __swift_bridge__$Vec_SomeEnum$as_ptr(void* vec_ptr);
     }
 }
 
+/// Verify that we emit Rust, Swift and C header code that allows a transparent enum with
+/// variants that contain data be used within a Vec<T> when it derives `Clone`.
+mod transparent_enum_with_data_vec_support {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[derive(Clone)]
+                enum Shape {
+                    Circle(f64),
+                    Rectangle { width: f64, height: f64 },
+                    Empty
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[derive(Clone)]
+                pub enum Shape
+            },
+            quote! {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_Shape$get"]
+                pub extern "C" fn _get(vec: *const Vec<Shape>, index: usize) -> __swift_bridge__Option_Shape {
+                    let vec = unsafe { &*vec };
+                    let val = vec.get(index).map(|v| v.clone());
+                    __swift_bridge__Option_Shape::from_rust_repr(val)
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_Shape$get_mut"]
+                pub extern "C" fn _get_mut(vec: *mut Vec<Shape>, index: usize) -> __swift_bridge__Option_Shape {
+                    let vec = unsafe { &mut *vec };
+                    let val = vec.get_mut(index).map(|v| v.clone());
+                    __swift_bridge__Option_Shape::from_rust_repr(val)
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_Shape$push"]
+                pub extern "C" fn _push(vec: *mut Vec<Shape>, val: __swift_bridge__Shape) {
+                    unsafe { &mut *vec }.push(val.into_rust_repr())
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Shape: Vectorizable {
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+        __swift_bridge__$Vec_Shape$new()
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void __swift_bridge__$Vec_Shape$push(void* vec_ptr, __swift_bridge__$Shape item);",
+            "__swift_bridge__$Option$Shape __swift_bridge__$Vec_Shape$get(void* vec_ptr, uintptr_t index);",
+        ])
+    }
+
+    #[test]
+    fn transparent_enum_with_data_vec_support() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not emit Vec<T> support for a transparent enum with variants that contain
+/// data if it does not derive `Clone`, since getting an element from a `RustVec` clones it.
+mod transparent_enum_with_data_without_clone_no_vec_support {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                enum Shape {
+                    Circle(f64),
+                    Empty
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn transparent_enum_with_data_without_clone_no_vec_support() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::DoesNotContain(quote! {
+                #[export_name = "__swift_bridge__$Vec_Shape$new"]
+            }),
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim(
+                "extension Shape: Vectorizable",
+            ),
+            expected_c_header: ExpectedCHeader::DoesNotContainAfterTrim(
+                "__swift_bridge__$Vec_Shape$new",
+            ),
+        }
+        .test();
+    }
+}
+
+/// Verify that we emit Rust, Swift and C header code that allows a transparent struct that
+/// derives `Clone` be used within a Vec<T>.
+mod transparent_struct_vec_support {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(Clone)]
+                struct Record {
+                    id: u32,
+                    score: f64,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            const _: () = {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_Record$new"]
+                pub extern "C" fn _new() -> *mut Vec<Record> {
+                    Box::into_raw(Box::new(Vec::new()))
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_Record$drop"]
+                pub extern "C" fn _drop(vec: *mut Vec<Record>) {
+                    let vec = unsafe { Box::from_raw(vec) };
+                    drop(vec)
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_Record$len"]
+                pub extern "C" fn _len(vec: *const Vec<Record>) -> usize {
+                    unsafe { &*vec }.len()
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_Record$capacity"]
+                pub extern "C" fn _capacity(vec: *const Vec<Record>) -> usize {
+                    unsafe { &*vec }.capacity()
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_Record$get"]
+                pub extern "C" fn _get(vec: *const Vec<Record>, index: usize) -> __swift_bridge__Option_Record {
+                    let vec = unsafe { &*vec };
+                    let val = vec.get(index).map(|v| v.clone());
+                    __swift_bridge__Option_Record::from_rust_repr(val)
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_Record$get_mut"]
+                pub extern "C" fn _get_mut(vec: *mut Vec<Record>, index: usize) -> __swift_bridge__Option_Record {
+                    let vec = unsafe { &mut *vec };
+                    let val = vec.get_mut(index).map(|v| v.clone());
+                    __swift_bridge__Option_Record::from_rust_repr(val)
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_Record$push"]
+                pub extern "C" fn _push(vec: *mut Vec<Record>, val: __swift_bridge__Record) {
+                    unsafe { &mut *vec }.push(val.into_rust_repr())
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_Record$pop"]
+                pub extern "C" fn _pop(vec: *mut Vec<Record>) -> __swift_bridge__Option_Record {
+                    let vec = unsafe { &mut *vec };
+                    let val = vec.pop();
+                    __swift_bridge__Option_Record::from_rust_repr(val)
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_Record$as_ptr"]
+                pub extern "C" fn _as_ptr(vec: *const Vec<Record>) -> *const Record {
+                    unsafe { & *vec }.as_ptr()
+                }
+            };
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Record: Vectorizable {
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+        __swift_bridge__$Vec_Record$new()
+    }
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {
+        __swift_bridge__$Vec_Record$drop(vecPtr)
+    }
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: Self) {
+        __swift_bridge__$Vec_Record$push(vecPtr, value.intoFfiRepr())
+    }
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {
+        let maybeStruct = __swift_bridge__$Vec_Record$pop(vecPtr)
+        return maybeStruct.intoSwiftRepr()
+    }
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {
+        let maybeStruct = __swift_bridge__$Vec_Record$get(vecPtr, index)
+        return maybeStruct.intoSwiftRepr()
+    }
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {
+        let maybeStruct = __swift_bridge__$Vec_Record$get_mut(vecPtr, index)
+        return maybeStruct.intoSwiftRepr()
+    }
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<Self> {
+        UnsafePointer<Self>(OpaquePointer(__swift_bridge__$Vec_Record$as_ptr(vecPtr)))
+    }
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {
+        __swift_bridge__$Vec_Record$len(vecPtr)
+    }
+
+    public static func vecOfSelfCapacity(vecPtr: UnsafeMutableRawPointer) -> UInt {
+        __swift_bridge__$Vec_Record$capacity(vecPtr)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$Vec_Record$new(void);
+void __swift_bridge__$Vec_Record$drop(void* vec_ptr);
+void __swift_bridge__$Vec_Record$push(void* vec_ptr, __swift_bridge__$Record item);
+__swift_bridge__$Option$Record __swift_bridge__$Vec_Record$pop(void* vec_ptr);
+__swift_bridge__$Option$Record __swift_bridge__$Vec_Record$get(void* vec_ptr, uintptr_t index);
+__swift_bridge__$Option$Record __swift_bridge__$Vec_Record$get_mut(void* vec_ptr, uintptr_t index);
+uintptr_t __swift_bridge__$Vec_Record$len(void* vec_ptr);
+uintptr_t __swift_bridge__$Vec_Record$capacity(void* vec_ptr);
+void* __swift_bridge__$Vec_Record$as_ptr(void* vec_ptr);
+"#,
+        )
+    }
+
+    #[test]
+    fn transparent_struct_vec_support() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not emit Vec<T> support for a transparent struct that does not derive
+/// `Clone`, since getting an element from a `RustVec` clones it.
+mod transparent_struct_without_clone_no_vec_support {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Record {
+                    id: u32,
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn transparent_struct_without_clone_no_vec_support() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::DoesNotContain(quote! {
+                #[export_name = "__swift_bridge__$Vec_Record$new"]
+            }),
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim(
+                "extension Record: Vectorizable",
+            ),
+            expected_c_header: ExpectedCHeader::DoesNotContainAfterTrim(
+                "__swift_bridge__$Vec_Record$new",
+            ),
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Rust function that returns a Vec<T> where T is a transparent enum.
 mod extern_rust_fn_return_vec_of_transparent_enum {
     use super::*;
@@ -661,3 +1183,67 @@ func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) {
         .test();
     }
 }
+
+/// Verify that we copy each of the strings in a returned `Vec<&str>` into a `String` before
+/// passing the vector to Swift, where it is converted into a `[String]`.
+mod extern_rust_method_return_vec_of_str {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn words(&self) -> Vec<&str>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$words"]
+            pub extern "C" fn __swift_bridge__SomeType_words(
+                this: *mut super::SomeType
+            ) -> *mut Vec<String> {
+                Box::into_raw(Box::new(
+                    (unsafe { &*this })
+                        .words()
+                        .into_iter()
+                        .map(|val| val.to_string())
+                        .collect::<Vec<String>>()
+                ))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public func words() -> [String] {
+        RustVec<RustString>(ptr: __swift_bridge__$SomeType$words(unconsumedPtr)).map { $0.as_str().toString() }
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeType$words(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_method_return_vec_of_str() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
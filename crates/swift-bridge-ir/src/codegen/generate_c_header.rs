@@ -1,11 +1,15 @@
 //! Tests can be found in src/codegen/codegen_tests.rs and its submodules.
 
+use crate::bridged_type::bridgeable_boxed_enum::shared_enum_is_boxed;
+use crate::bridged_type::bridgeable_boxed_struct::shared_struct_is_boxed;
 use crate::bridged_type::shared_struct::StructField;
 use crate::bridged_type::{BridgeableType, BridgedType, CFfiStruct, StdLibType, StructFields};
 use crate::codegen::CodegenConfig;
 use crate::parse::{SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
 use crate::parsed_extern_fn::ParsedExternFn;
 use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
 use std::collections::{BTreeSet, HashSet};
 use syn::{FnArg, ReturnType};
 
@@ -53,7 +57,7 @@ impl SwiftBridgeModule {
             slice_types: HashSet::new(),
         };
 
-        for ty in self.types.types() {
+        for ty in types_in_c_declaration_order(&self.types) {
             match ty {
                 TypeDeclaration::Shared(ty) => match ty {
                     SharedTypeDeclaration::Struct(ty_struct) => {
@@ -140,6 +144,19 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
 
                         header += &ty_decl;
                         header += "\n";
+
+                        if shared_struct_is_boxed(ty_struct, &self.types) {
+                            header += &format!(
+                                r#"void* {ffi_name}$_box({ffi_name} val);
+{ffi_name} {ffi_name}$_unbox(void* ptr);
+"#,
+                                ffi_name = ffi_name
+                            );
+                        }
+                        if ty_struct.supports_vec() {
+                            bookkeeping.includes.insert("stdint.h");
+                            header += &vec_transparent_struct_c_support(&name);
+                        }
                     }
                     SharedTypeDeclaration::Enum(ty_enum) => {
                         if ty_enum.already_declared {
@@ -172,10 +189,25 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                             "".to_string()
                         };
 
-                        let maybe_vec_support = if ty_enum.has_one_or_more_variants_with_data() {
-                            "".to_string()
+                        let from_str_impl = if ty_enum.from_str {
+                            format!("{option_ffi_name} {ffi_name}$from_str(struct RustStr string);")
                         } else {
+                            "".to_string()
+                        };
+
+                        let maybe_vec_support = if ty_enum.supports_vec() {
                             vec_transparent_enum_c_support(&ty_enum.swift_name_string())
+                        } else {
+                            "".to_string()
+                        };
+                        let maybe_box_support = if shared_enum_is_boxed(ty_enum, &self.types) {
+                            format!(
+                                r#"void* {ffi_name}$_box({ffi_name} val);
+{ffi_name} {ffi_name}$_unbox(void* ptr);
+"#
+                            )
+                        } else {
+                            "".to_string()
                         };
                         let mut variant_fields = "".to_string();
                         if all_variants_empty {
@@ -183,7 +215,7 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                                 r#"typedef enum {ffi_tag_name} {{ {variants}}} {ffi_tag_name};
 typedef struct {ffi_name} {{ {ffi_tag_name} tag; }} {ffi_name};
 typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi_name};
-{derive_debug_impl}{maybe_vec_support}"#,
+{derive_debug_impl}{from_str_impl}{maybe_vec_support}"#,
                                 ffi_name = ffi_name,
                                 ffi_tag_name = ffi_tag_name,
                                 option_ffi_name = option_ffi_name,
@@ -245,7 +277,7 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
 typedef enum {ffi_tag_name} {{ {variants}}} {ffi_tag_name};
 typedef struct {ffi_name} {{ {ffi_tag_name} tag; union {ffi_union_name} payload;}} {ffi_name};
 typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi_name};
-{derive_debug_impl}{maybe_vec_support}"#,
+{derive_debug_impl}{from_str_impl}{maybe_vec_support}{maybe_box_support}"#,
                                 union_fields = ffi_union_field_names,
                                 variant_fields = variant_fields,
                                 ffi_name = ffi_name,
@@ -289,6 +321,99 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                         header += &equal_ty;
                         header += "\n";
                     }
+                    if ty.attributes.clone {
+                        let ty_name = ty.ty_name_ident();
+                        header +=
+                            &format!("void* __swift_bridge__${}$_clone(void* self);\n", ty_name);
+                    }
+                    if ty.attributes.deref.is_some() {
+                        let ty_name = ty.ty_name_ident();
+                        let deref_ty =
+                            format!("void* __swift_bridge__${}$_deref(void* self);", ty_name);
+                        header += &deref_ty;
+                        header += "\n";
+                    }
+                    if ty.attributes.error_chain {
+                        let ty_name = ty.ty_name_ident();
+                        let error_chain_ty = format!(
+                            "void* __swift_bridge__${}$_error_chain(void* self);",
+                            ty_name
+                        );
+                        header += &error_chain_ty;
+                        header += "\n";
+                    }
+                    if let Some(index) = ty.attributes.index.as_ref() {
+                        let ty_name = ty.ty_name_ident();
+                        let idx = BridgedType::new_with_type(&index.index, &self.types).unwrap();
+                        let output =
+                            BridgedType::new_with_type(&index.output, &self.types).unwrap();
+                        for bridged in [&idx, &output] {
+                            if let Some(includes) = bridged.to_c_include(&self.types) {
+                                for include in includes {
+                                    bookkeeping.includes.insert(include);
+                                }
+                            }
+                        }
+                        let index_ty = format!(
+                            "{output} __swift_bridge__${ty_name}$_index(void* self, {idx} index);",
+                            output = output.to_c(&self.types),
+                            ty_name = ty_name,
+                            idx = idx.to_c(&self.types)
+                        );
+                        header += &index_ty;
+                        header += "\n";
+                    }
+                    for (enabled, method) in [
+                        (ty.attributes.add_assign, "add_assign"),
+                        (ty.attributes.sub_assign, "sub_assign"),
+                    ] {
+                        if enabled {
+                            header += &format!(
+                                "void __swift_bridge__${}$_{}(void* self, void* rhs);\n",
+                                ty.ty_name_ident(),
+                                method
+                            );
+                        }
+                    }
+                    if ty.attributes.neg {
+                        header += &format!(
+                            "void* __swift_bridge__${}$_neg(void* self);\n",
+                            ty.ty_name_ident()
+                        );
+                    }
+                    if let Some(btree_map) = ty.attributes.btree_map.as_ref() {
+                        let ty_name = ty.ty_name_ident();
+                        let key = BridgedType::new_with_type(&btree_map.key, &self.types).unwrap();
+                        if let Some(includes) = key.to_c_include(&self.types) {
+                            for include in includes {
+                                bookkeeping.includes.insert(include);
+                            }
+                        }
+                        let range_ty = format!(
+                            "void* __swift_bridge__${ty_name}$_range(void* self, {key} start, {key} end, void** values);",
+                            ty_name = ty_name,
+                            key = key.to_c(&self.types)
+                        );
+                        header += &range_ty;
+                        header += "\n";
+                    }
+                    if let Some(counter) = ty.attributes.counter.as_ref() {
+                        let ty_name = ty.ty_name_ident();
+                        let counter = BridgedType::new_with_type(counter, &self.types).unwrap();
+                        if let Some(includes) = counter.to_c_include(&self.types) {
+                            for include in includes {
+                                bookkeeping.includes.insert(include);
+                            }
+                        }
+                        header +=
+                            &format!("void __swift_bridge__${ty_name}$_increment(void* self);\n");
+                        header +=
+                            &format!("void __swift_bridge__${ty_name}$_decrement(void* self);\n");
+                        header += &format!(
+                            "{counter} __swift_bridge__${ty_name}$_counter_value(void* self);\n",
+                            counter = counter.to_c(&self.types)
+                        );
+                    }
                     let ty_name = ty.to_string();
 
                     if let Some(copy) = ty.attributes.copy {
@@ -327,12 +452,24 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
 
                         header += &drop_ty;
                         header += "\n";
+
+                        if ty.attributes.arc_mutex {
+                            let share_ty = format!(
+                                "void* {}(void* self);",
+                                ty.share_rust_opaque_type_ffi_name()
+                            );
+                            header += &share_ty;
+                            header += "\n";
+                        }
                     }
 
                     // TODO: Support Vec<OpaqueCopyType>. Add codegen tests and then
                     //  make them pass.
                     // TODO: Support Vec<GenericOpaqueRustType
-                    if ty.attributes.copy.is_none() && ty.generics.len() == 0 {
+                    if ty.attributes.copy.is_none()
+                        && !ty.attributes.arc_mutex
+                        && ty.generics.len() == 0
+                    {
                         let vec_functions = vec_opaque_rust_type_c_support(&ty_name);
 
                         header += &vec_functions;
@@ -350,7 +487,7 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
             declare_custom_c_ffi_types(func, &self.types, &mut c_ffi_struct_bookkeeping);
             if func.host_lang.is_swift() {
                 for (idx, boxed_fn) in func.args_filtered_to_boxed_fns(&self.types) {
-                    if boxed_fn.params.is_empty() && boxed_fn.ret.is_null() {
+                    if boxed_fn.uses_no_args_no_ret_fn_once_class() {
                         continue;
                     }
 
@@ -400,6 +537,7 @@ void* __swift_bridge__$Vec_{ty_name}$pop(void* vec_ptr);
 void* __swift_bridge__$Vec_{ty_name}$get(void* vec_ptr, uintptr_t index);
 void* __swift_bridge__$Vec_{ty_name}$get_mut(void* vec_ptr, uintptr_t index);
 uintptr_t __swift_bridge__$Vec_{ty_name}$len(void* vec_ptr);
+uintptr_t __swift_bridge__$Vec_{ty_name}$capacity(void* vec_ptr);
 void* __swift_bridge__$Vec_{ty_name}$as_ptr(void* vec_ptr);
 "#,
         ty_name = ty_name
@@ -416,12 +554,104 @@ __swift_bridge__$Option${enum_name} __swift_bridge__$Vec_{enum_name}$pop(void* v
 __swift_bridge__$Option${enum_name} __swift_bridge__$Vec_{enum_name}$get(void* vec_ptr, uintptr_t index);
 __swift_bridge__$Option${enum_name} __swift_bridge__$Vec_{enum_name}$get_mut(void* vec_ptr, uintptr_t index);
 uintptr_t __swift_bridge__$Vec_{enum_name}$len(void* vec_ptr);
+uintptr_t __swift_bridge__$Vec_{enum_name}$capacity(void* vec_ptr);
 void* __swift_bridge__$Vec_{enum_name}$as_ptr(void* vec_ptr);
 "#,
         enum_name = enum_name
     )
 }
 
+fn vec_transparent_struct_c_support(struct_name: &str) -> String {
+    format!(
+        r#"
+void* __swift_bridge__$Vec_{struct_name}$new(void);
+void __swift_bridge__$Vec_{struct_name}$drop(void* vec_ptr);
+void __swift_bridge__$Vec_{struct_name}$push(void* vec_ptr, __swift_bridge__${struct_name} item);
+__swift_bridge__$Option${struct_name} __swift_bridge__$Vec_{struct_name}$pop(void* vec_ptr);
+__swift_bridge__$Option${struct_name} __swift_bridge__$Vec_{struct_name}$get(void* vec_ptr, uintptr_t index);
+__swift_bridge__$Option${struct_name} __swift_bridge__$Vec_{struct_name}$get_mut(void* vec_ptr, uintptr_t index);
+uintptr_t __swift_bridge__$Vec_{struct_name}$len(void* vec_ptr);
+uintptr_t __swift_bridge__$Vec_{struct_name}$capacity(void* vec_ptr);
+void* __swift_bridge__$Vec_{struct_name}$as_ptr(void* vec_ptr);
+"#,
+        struct_name = struct_name
+    )
+}
+
+/// The module's type declarations, ordered so that every shared struct and enum comes after the
+/// shared structs and enums that its fields hold, since a C struct's fields must be declared before
+/// the struct itself.
+///
+/// For example, `struct Line { start: Point, end: Point }` needs `Point` to be declared first, even
+/// if `Point` comes after `Line` in the bridge module.
+fn types_in_c_declaration_order(types: &TypeDeclarations) -> Vec<&TypeDeclaration> {
+    let mut encountered = HashSet::new();
+    let mut ordered = vec![];
+
+    for ty in types.types() {
+        push_type_declaration(ty, types, &mut encountered, &mut ordered);
+    }
+
+    ordered
+}
+
+fn push_type_declaration<'a>(
+    ty: &'a TypeDeclaration,
+    types: &'a TypeDeclarations,
+    encountered: &mut HashSet<*const TypeDeclaration>,
+    ordered: &mut Vec<&'a TypeDeclaration>,
+) {
+    // Generic opaque types such as `SomeType<u32>` and `SomeType<u64>` share a Swift name, so we
+    // keep track of the declarations themselves.
+    if !encountered.insert(ty as *const TypeDeclaration) {
+        return;
+    }
+
+    let fields = match ty {
+        TypeDeclaration::Shared(SharedTypeDeclaration::Struct(ty_struct)) => {
+            ty_struct.fields.normalized_fields()
+        }
+        TypeDeclaration::Shared(SharedTypeDeclaration::Enum(ty_enum)) => ty_enum
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.normalized_fields())
+            .collect(),
+        TypeDeclaration::Opaque(_) => vec![],
+    };
+    for field in fields {
+        for field_ty in shared_types_within(&field.ty.to_token_stream(), types) {
+            push_type_declaration(field_ty, types, encountered, ordered);
+        }
+    }
+
+    ordered.push(ty);
+}
+
+/// The shared structs and enums that are named anywhere within a type, such as the `Point` in
+/// `Option<Point>`.
+fn shared_types_within<'a>(
+    tokens: &TokenStream,
+    types: &'a TypeDeclarations,
+) -> Vec<&'a TypeDeclaration> {
+    let mut shared_types = vec![];
+
+    for token in tokens.clone() {
+        match token {
+            TokenTree::Ident(ident) => {
+                if let Some(ty @ TypeDeclaration::Shared(_)) = types.get(&ident.to_string()) {
+                    shared_types.push(ty);
+                }
+            }
+            TokenTree::Group(group) => {
+                shared_types.extend(shared_types_within(&group.stream(), types));
+            }
+            _ => {}
+        }
+    }
+
+    shared_types
+}
+
 fn push_custom_type_declarations(
     custom_type_declaration: &CFfiStruct,
     c_ffi_struct_bookkeeping: &mut CFfiStructDeclarationBookkeeping,
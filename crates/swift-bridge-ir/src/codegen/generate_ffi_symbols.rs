@@ -0,0 +1,134 @@
+use crate::codegen::CodegenConfig;
+use crate::SwiftBridgeModule;
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use syn::LitStr;
+
+impl SwiftBridgeModule {
+    /// The names of the `extern "C"` functions that the generated Rust code exports, in the order
+    /// that they get generated.
+    ///
+    /// These are the symbols that Swift links against, so a linker exports list that contains
+    /// them can hide every other symbol in the Rust library.
+    pub fn generate_ffi_symbols(&self, config: &CodegenConfig) -> Vec<String> {
+        if !self.module_will_be_compiled(config) {
+            return vec![];
+        }
+
+        let (module, rust_ffi) = self.generate_module_and_rust_ffi_tokens();
+
+        let mut symbols = vec![];
+        push_export_names(module, &mut symbols);
+        // The module only `include!`s the `extern "C"` functions when they live in their own file.
+        if self.rust_ffi_file.is_some() {
+            push_export_names(rust_ffi, &mut symbols);
+        }
+
+        symbols
+    }
+}
+
+/// Push the name of every `#[export_name = "..."]` attribute in the tokens.
+fn push_export_names(tokens: TokenStream, symbols: &mut Vec<String>) {
+    for token in tokens {
+        let group = match token {
+            TokenTree::Group(group) => group,
+            _ => continue,
+        };
+
+        if group.delimiter() == Delimiter::Bracket {
+            let attribute: Vec<TokenTree> = group.stream().into_iter().collect();
+            match attribute.as_slice() {
+                [TokenTree::Ident(name), TokenTree::Punct(eq), TokenTree::Literal(value)]
+                    if name == "export_name" && eq.as_char() == '=' =>
+                {
+                    if let Ok(value) = syn::parse_str::<LitStr>(&value.to_string()) {
+                        symbols.push(value.value());
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+        }
+
+        push_export_names(group.stream(), symbols);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse_ok;
+    use quote::quote;
+
+    /// Verify that we list the symbol of every `extern "C"` function that Swift calls, including
+    /// the ones that support opaque types, and none of the functions that Swift implements.
+    #[test]
+    fn lists_exported_ffi_symbols() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Counter;
+
+                    #[swift_bridge(init)]
+                    fn new() -> Counter;
+                    fn increment(&mut self, amount: u32);
+                    fn make_greeting(name: &str) -> String;
+                }
+
+                extern "Swift" {
+                    fn swift_function();
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let symbols = module.generate_ffi_symbols(&CodegenConfig::no_features_enabled());
+
+        assert_eq!(
+            symbols,
+            vec![
+                "__swift_bridge__$Counter$new",
+                "__swift_bridge__$Counter$increment",
+                "__swift_bridge__$make_greeting",
+                "__swift_bridge__$Counter$_free",
+                "__swift_bridge__$Vec_Counter$new",
+                "__swift_bridge__$Vec_Counter$drop",
+                "__swift_bridge__$Vec_Counter$len",
+                "__swift_bridge__$Vec_Counter$capacity",
+                "__swift_bridge__$Vec_Counter$get",
+                "__swift_bridge__$Vec_Counter$get_mut",
+                "__swift_bridge__$Vec_Counter$push",
+                "__swift_bridge__$Vec_Counter$pop",
+                "__swift_bridge__$Vec_Counter$as_ptr",
+            ]
+        );
+
+        // Each exported symbol is one that the generated C header declares for Swift to call.
+        let c_header = module
+            .generate_swift_code_and_c_header(CodegenConfig::no_features_enabled())
+            .c_header;
+        for symbol in symbols {
+            assert!(c_header.contains(&format!("{}(", symbol)), "{}", symbol);
+        }
+    }
+
+    /// Verify that a module that will not be compiled does not export any symbols.
+    #[test]
+    fn no_ffi_symbols_for_module_that_is_not_compiled() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            #[cfg(feature = "some-feature")]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function();
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        assert!(module
+            .generate_ffi_symbols(&CodegenConfig::no_features_enabled())
+            .is_empty());
+    }
+}
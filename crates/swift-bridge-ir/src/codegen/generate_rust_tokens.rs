@@ -17,9 +17,34 @@ mod vec;
 
 impl ToTokens for SwiftBridgeModule {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (module, _rust_ffi) = self.generate_module_and_rust_ffi_tokens();
+        module.to_tokens(tokens);
+    }
+}
+
+impl SwiftBridgeModule {
+    /// The name and contents of the file that holds the `extern "C"` functions of a module that
+    /// uses `#[swift_bridge(rust_ffi_file = "...")]`.
+    ///
+    /// The generated module `include!`s the file from `OUT_DIR`, so the build script needs to
+    /// write it there. Returns `None` if the module does not use the attribute.
+    pub fn generate_rust_ffi_file(&self) -> Option<(String, String)> {
+        let file_name = self.rust_ffi_file.as_ref()?.value();
+        let (_module, rust_ffi) = self.generate_module_and_rust_ffi_tokens();
+
+        Some((file_name, rust_ffi.to_string()))
+    }
+
+    /// Generate the module, along with the `extern "C"` functions that Swift uses to call into
+    /// Rust.
+    ///
+    /// The functions are part of the module unless it uses the `rust_ffi_file` attribute, in which
+    /// case the module `include!`s them instead.
+    pub(super) fn generate_module_and_rust_ffi_tokens(&self) -> (TokenStream, TokenStream) {
         let mod_name = &self.name;
         let vis = &self.vis;
         let swift_bridge_path = &self.swift_bridge_path;
+        let ffi_visibility = self.ffi_visibility;
 
         let mut extern_rust_fn_tokens = vec![];
 
@@ -40,13 +65,17 @@ impl ToTokens for SwiftBridgeModule {
                         &self.swift_bridge_path,
                         &self.types,
                         &mut custom_type_definitions,
+                        self.ffi_visibility,
                     ));
                 }
                 HostLang::Swift => {
                     let tokens = func
                         .to_rust_fn_that_calls_a_swift_extern(&self.swift_bridge_path, &self.types);
-                    callbacks_support
-                        .push(func.callbacks_support(&self.swift_bridge_path, &self.types));
+                    callbacks_support.push(func.callbacks_support(
+                        &self.swift_bridge_path,
+                        &self.types,
+                        self.ffi_visibility,
+                    ));
 
                     if let Some(ty) = func.associated_type.as_ref() {
                         match ty {
@@ -70,6 +99,7 @@ impl ToTokens for SwiftBridgeModule {
                         &self.swift_bridge_path,
                         &self.types,
                         &mut custom_type_definitions,
+                        self.ffi_visibility,
                     ));
                 }
             };
@@ -109,7 +139,7 @@ impl ToTokens for SwiftBridgeModule {
                                 );
                                 let tokens = quote! {
                                 #[export_name = #export_name]
-                                pub extern "C" fn #function_name (
+                                #ffi_visibility extern "C" fn #function_name (
                                     this: *const super::#ty_name,
                                 ) -> u64 {
                                     use std::hash::{Hash, Hasher};
@@ -130,7 +160,7 @@ impl ToTokens for SwiftBridgeModule {
                                 );
                                 let tokens = quote! {
                                     #[export_name = #export_name]
-                                    pub extern "C" fn #function_name (
+                                    #ffi_visibility extern "C" fn #function_name (
                                         lhs: *const super::#ty_name,
                                         rhs: *const super::#ty_name
                                     ) -> bool {
@@ -139,6 +169,212 @@ impl ToTokens for SwiftBridgeModule {
                                 };
                                 extern_rust_fn_tokens.push(tokens);
                             }
+                            if ty.attributes.clone {
+                                let export_name = format!("__swift_bridge__${}$_clone", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__clone", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    #ffi_visibility extern "C" fn #function_name (
+                                        this: *const super::#ty_name,
+                                    ) -> *mut super::#ty_name {
+                                        Box::into_raw(Box::new(Clone::clone(unsafe { &*this })))
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            if let Some(target) = ty.attributes.deref.as_ref() {
+                                let export_name = format!("__swift_bridge__${}$_deref", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__deref", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    #ffi_visibility extern "C" fn #function_name (
+                                        this: *const super::#ty_name,
+                                    ) -> *const super::#target {
+                                        std::ops::Deref::deref(unsafe { &*this }) as *const super::#target
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            if ty.attributes.error_chain {
+                                let export_name =
+                                    format!("__swift_bridge__${}$_error_chain", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__error_chain", ty_name),
+                                    ty.ty.span(),
+                                );
+                                // "outer error: inner error: root cause"
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    #ffi_visibility extern "C" fn #function_name (
+                                        this: *const super::#ty_name,
+                                    ) -> *mut #swift_bridge_path::string::RustString {
+                                        let error: &dyn std::error::Error = unsafe { &*this };
+                                        let mut chain = error.to_string();
+                                        let mut source = error.source();
+                                        while let Some(cause) = source {
+                                            chain += ": ";
+                                            chain += &cause.to_string();
+                                            source = cause.source();
+                                        }
+                                        #swift_bridge_path::string::RustString(chain).box_into_raw()
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            if let Some(index) = ty.attributes.index.as_ref() {
+                                let idx = &index.index;
+                                let output = &index.output;
+                                let export_name = format!("__swift_bridge__${}$_index", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__index", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    #ffi_visibility extern "C" fn #function_name (
+                                        this: *const super::#ty_name,
+                                        index: #idx,
+                                    ) -> #output {
+                                        *<super::#ty_name as std::ops::Index<#idx>>::index(unsafe { &*this }, index)
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            for (enabled, trait_name, method) in [
+                                (ty.attributes.add_assign, "AddAssign", "add_assign"),
+                                (ty.attributes.sub_assign, "SubAssign", "sub_assign"),
+                            ] {
+                                if !enabled {
+                                    continue;
+                                }
+                                let trait_name = syn::Ident::new(trait_name, ty.ty.span());
+                                let method = syn::Ident::new(method, ty.ty.span());
+                                let export_name =
+                                    format!("__swift_bridge__${}$_{}", ty_name, method);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__{}", ty_name, method),
+                                    ty.ty.span(),
+                                );
+                                // `x += x` passes the same pointer as both arguments, and we can't
+                                // hold a `&mut` and a `&` to the same value at once. `Clone` types
+                                // apply a clone of the right hand side. For other types the
+                                // generated Swift operator rejects this before calling into Rust.
+                                let aliased = if ty.attributes.clone {
+                                    quote! {
+                                        if this as *const super::#ty_name == rhs {
+                                            let rhs = <super::#ty_name as Clone>::clone(unsafe { &*rhs });
+                                            return <super::#ty_name as std::ops::#trait_name<&super::#ty_name>>::#method(unsafe { &mut *this }, &rhs);
+                                        }
+                                    }
+                                } else {
+                                    quote! {}
+                                };
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    #ffi_visibility extern "C" fn #function_name (
+                                        this: *mut super::#ty_name,
+                                        rhs: *const super::#ty_name
+                                    ) {
+                                        #aliased
+                                        <super::#ty_name as std::ops::#trait_name<&super::#ty_name>>::#method(unsafe { &mut *this }, unsafe { &*rhs })
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            if ty.attributes.neg {
+                                let export_name = format!("__swift_bridge__${}$_neg", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__neg", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    #ffi_visibility extern "C" fn #function_name (
+                                        this: *const super::#ty_name
+                                    ) -> *mut super::#ty_name {
+                                        Box::into_raw(Box::new(<&super::#ty_name as std::ops::Neg>::neg(unsafe { &*this })))
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            if let Some(btree_map) = ty.attributes.btree_map.as_ref() {
+                                let key = &btree_map.key;
+                                let value = &btree_map.value;
+                                let export_name = format!("__swift_bridge__${}$_range", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__range", ty_name),
+                                    ty.ty.span(),
+                                );
+                                // `BTreeMap::range` panics if the start of the range is greater
+                                // than its end, so we return an empty range instead.
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    #ffi_visibility extern "C" fn #function_name (
+                                        this: *const super::#ty_name,
+                                        start: #key,
+                                        end: #key,
+                                        values: *mut *mut Vec<#value>,
+                                    ) -> *mut Vec<#key> {
+                                        let map: &std::collections::BTreeMap<#key, #value> =
+                                            std::convert::AsRef::as_ref(unsafe { &*this });
+                                        let (keys, vals): (Vec<#key>, Vec<#value>) = if start <= end {
+                                            map.range(start..end).map(|(key, value)| (*key, *value)).unzip()
+                                        } else {
+                                            (Vec::new(), Vec::new())
+                                        };
+                                        unsafe { *values = Box::into_raw(Box::new(vals)) };
+                                        Box::into_raw(Box::new(keys))
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            if let Some(counter) = ty.attributes.counter.as_ref() {
+                                let function_name = |method: &str| {
+                                    syn::Ident::new(
+                                        &format!("__swift_bridge__{}__{}", ty_name, method),
+                                        ty.ty.span(),
+                                    )
+                                };
+                                let increment_export_name =
+                                    format!("__swift_bridge__${}$_increment", ty_name);
+                                let increment_function_name = function_name("increment");
+                                let decrement_export_name =
+                                    format!("__swift_bridge__${}$_decrement", ty_name);
+                                let decrement_function_name = function_name("decrement");
+                                let value_export_name =
+                                    format!("__swift_bridge__${}$_counter_value", ty_name);
+                                let value_function_name = function_name("counter_value");
+
+                                let tokens = quote! {
+                                    #[export_name = #increment_export_name]
+                                    #ffi_visibility extern "C" fn #increment_function_name (
+                                        this: *mut super::#ty_name
+                                    ) {
+                                        <super::#ty_name as std::ops::AddAssign<#counter>>::add_assign(unsafe { &mut *this }, 1)
+                                    }
+
+                                    #[export_name = #decrement_export_name]
+                                    #ffi_visibility extern "C" fn #decrement_function_name (
+                                        this: *mut super::#ty_name
+                                    ) {
+                                        <super::#ty_name as std::ops::SubAssign<#counter>>::sub_assign(unsafe { &mut *this }, 1)
+                                    }
+
+                                    #[export_name = #value_export_name]
+                                    #ffi_visibility extern "C" fn #value_function_name (
+                                        this: *const super::#ty_name
+                                    ) -> #counter {
+                                        *<super::#ty_name as std::convert::AsRef<#counter>>::as_ref(unsafe { &*this })
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
                             if let Some(copy) = ty.attributes.copy {
                                 let size = copy.size_bytes;
 
@@ -191,15 +427,41 @@ impl ToTokens for SwiftBridgeModule {
                                 extern_rust_fn_tokens.push(copy_ty);
                             }
 
-                            if !ty.attributes.already_declared {
+                            if !ty.attributes.already_declared && ty.attributes.arc_mutex {
+                                let ty_name_string = ty_name.to_string();
+                                let share_link_name = ty.share_rust_opaque_type_ffi_name();
+                                let share_func_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__share", ty_name),
+                                    ty.ty.span(),
+                                );
+
+                                let arc_mutex_fns = quote! {
+                                    #[export_name = #link_name]
+                                    #ffi_visibility extern "C" fn #free_mem_func_name (this: *const std::sync::Mutex<super::#this>) {
+                                        #swift_bridge_path::debug_log_free(#ty_name_string, this);
+                                        let this = unsafe { std::sync::Arc::from_raw(this) };
+                                        drop(this);
+                                    }
+
+                                    #[export_name = #share_link_name]
+                                    #ffi_visibility extern "C" fn #share_func_name (this: *const std::sync::Mutex<super::#this>) -> *const std::sync::Mutex<super::#this> {
+                                        unsafe { std::sync::Arc::increment_strong_count(this) };
+                                        this
+                                    }
+                                };
+
+                                extern_rust_fn_tokens.push(arc_mutex_fns);
+                            } else if !ty.attributes.already_declared {
                                 if ty.attributes.copy.is_none() {
                                     let generics = ty
                                         .generics
                                         .angle_bracketed_concrete_generics_tokens(&self.types);
 
+                                    let ty_name_string = ty_name.to_string();
                                     let free = quote! {
                                         #[export_name = #link_name]
-                                        pub extern "C" fn #free_mem_func_name (this: *mut super::#this #generics) {
+                                        #ffi_visibility extern "C" fn #free_mem_func_name (this: *mut super::#this #generics) {
+                                            #swift_bridge_path::debug_log_free(#ty_name_string, this);
                                             let this = unsafe { Box::from_raw(this) };
                                             drop(this);
                                         }
@@ -212,7 +474,10 @@ impl ToTokens for SwiftBridgeModule {
                                     // TODO: Support Vec<GenericOpaqueRustType
                                     if ty.generics.len() == 0 {
                                         let vec_functions =
-                                            generate_vec_of_opaque_rust_type_functions(ty_name);
+                                            generate_vec_of_opaque_rust_type_functions(
+                                                ty_name,
+                                                ffi_visibility,
+                                            );
                                         extern_rust_fn_tokens.push(vec_functions);
                                     }
                                 }
@@ -278,6 +543,17 @@ impl ToTokens for SwiftBridgeModule {
             };
         }
         let custom_type_definitions = custom_type_definitions.into_values();
+
+        let rust_ffi = quote! {
+            #(#extern_rust_fn_tokens)*
+        };
+        let extern_rust_fn_tokens = match self.rust_ffi_file.as_ref() {
+            Some(file) => quote! {
+                include!(concat!(env!("OUT_DIR"), "/", #file));
+            },
+            None => rust_ffi.clone(),
+        };
+
         let module_inner = quote! {
             #(#shared_struct_definitions)*
 
@@ -285,7 +561,7 @@ impl ToTokens for SwiftBridgeModule {
 
             #(#custom_type_definitions)*
 
-            #(#extern_rust_fn_tokens)*
+            #extern_rust_fn_tokens
 
             #(#freestanding_rust_call_swift_fn_tokens)*
 
@@ -296,14 +572,15 @@ impl ToTokens for SwiftBridgeModule {
             #(#callbacks_support)*
         };
 
-        let t = quote! {
+        let module = quote! {
             #[allow(non_snake_case)]
             #(#module_attributes)*
             #vis mod #mod_name {
                 #module_inner
             }
         };
-        t.to_tokens(tokens);
+
+        (module, rust_ffi)
     }
 }
 
@@ -1028,6 +1305,46 @@ mod tests {
         assert_to_extern_c_function_tokens(start, &expected);
     }
 
+    /// Verify that a module that uses `#[swift_bridge(rust_ffi_file = "...")]` generates a
+    /// separate file that holds its `extern "C"` functions.
+    #[test]
+    fn generates_rust_ffi_file() {
+        let start = quote! {
+            #[swift_bridge(rust_ffi_file = "some_ffi.rs")]
+            mod foo {
+                extern "Rust" {
+                    fn some_function (bar: u8);
+                }
+            }
+        };
+        let expected = quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function (bar: u8) {
+                super::some_function(bar)
+            }
+        };
+
+        let (file_name, contents) = parse_ok(start).generate_rust_ffi_file().unwrap();
+
+        assert_eq!(file_name, "some_ffi.rs");
+        assert_tokens_eq(&contents.parse().unwrap(), &expected);
+    }
+
+    /// Verify that we do not generate a separate file for the `extern "C"` functions unless the
+    /// module uses the `rust_ffi_file` attribute.
+    #[test]
+    fn no_rust_ffi_file_by_default() {
+        let start = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function ();
+                }
+            }
+        };
+
+        assert!(parse_ok(start).generate_rust_ffi_file().is_none());
+    }
+
     fn parse_ok(tokens: TokenStream) -> SwiftBridgeModule {
         let module_and_errors: SwiftBridgeModuleAndErrors = syn::parse2(tokens).unwrap();
         module_and_errors.module
@@ -1042,6 +1359,7 @@ mod tests {
                 &module.swift_bridge_path,
                 &module.types,
                 &mut HashMap::new(),
+                module.ffi_visibility,
             ),
             &expected_fn,
         );
@@ -1,6 +1,7 @@
 //! More tests can be found in
 //! crates/swift-bridge-ir/src/codegen/codegen_tests/shared_enum_codegen_tests.rs
 
+use crate::bridged_type::bridgeable_boxed_enum::shared_enum_is_boxed;
 use crate::bridged_type::{BridgedType, SharedEnum, StructFields};
 use crate::codegen::generate_rust_tokens::vec::vec_of_transparent_enum::generate_vec_of_transparent_enum_functions;
 use crate::parse::TypeDeclarations;
@@ -22,6 +23,7 @@ impl SwiftBridgeModule {
 
         let enum_name = &shared_enum.name;
         let swift_bridge_path = &self.swift_bridge_path;
+        let ffi_visibility = self.ffi_visibility;
 
         let enum_ffi_name = format!("{}{}", SWIFT_BRIDGE_PREFIX, enum_name);
         let enum_ffi_name = Ident::new(&enum_ffi_name, enum_name.span());
@@ -59,11 +61,20 @@ impl SwiftBridgeModule {
                         #variant_name (#(#names),*)
                     }
                 }
-                StructFields::Unit => {
-                    quote! {
-                        #variant_name
+                // Rust only allows discriminants on enums with data when they have a
+                // `#[repr(inttype)]`, so a hybrid enum's discriminants are only used in Swift.
+                StructFields::Unit => match &variant.discriminant {
+                    Some(discriminant) if !shared_enum.has_hybrid_raw_values() => {
+                        quote! {
+                            #variant_name = #discriminant
+                        }
                     }
-                }
+                    _ => {
+                        quote! {
+                            #variant_name
+                        }
+                    }
+                },
             };
             enum_variants.push(enum_variant);
         }
@@ -132,7 +143,11 @@ impl SwiftBridgeModule {
 
         // Auto derives
         let mut derives = if shared_enum.has_one_or_more_variants_with_data() {
-            vec![]
+            if shared_enum.derive.clone {
+                vec![quote! {Clone}]
+            } else {
+                vec![]
+            }
         } else {
             vec![quote! {Copy}, quote! {Clone}]
         };
@@ -140,6 +155,10 @@ impl SwiftBridgeModule {
         // User derives
         let mut derive_impl_ffi_bridges = vec![];
 
+        if shared_enum.derive.partial_eq {
+            derives.push(quote! {PartialEq});
+        }
+
         if shared_enum.derive.debug {
             // We don't want to confuse the developer if one of our variants has data and Debug isn't derived,
             // so we still want to derive(Debug) on the Rust side.
@@ -156,18 +175,61 @@ impl SwiftBridgeModule {
                 let fn_name = format_ident!("{}_Debug", enum_ffi_name);
                 derive_impl_ffi_bridges.push(quote! {
                     #[export_name = #export_name]
-                    pub extern "C" fn #fn_name(this: #enum_ffi_name) -> *mut swift_bridge::string::RustString {
+                    #ffi_visibility extern "C" fn #fn_name(this: #enum_ffi_name) -> *mut swift_bridge::string::RustString {
                         swift_bridge::string::RustString(format!("{:?}", this.into_rust_repr())).box_into_raw()
                     }
                 });
             }
         }
 
-        let vec_support = if shared_enum.has_one_or_more_variants_with_data() {
-            // Enums with variants that contain data are not yet supported.
+        let from_str_impl = if shared_enum.from_str {
+            // __swift_bridge__$SomeEnum$from_str
+            let export_name = format!("{}$from_str", shared_enum.ffi_name_string());
+            // __swift_bridge__SomeEnum_from_str
+            let fn_name = format_ident!("{}_from_str", enum_ffi_name);
+            quote! {
+                #[export_name = #export_name]
+                #ffi_visibility extern "C" fn #fn_name(string: #swift_bridge_path::string::RustStr) -> #option_enum {
+                    #option_enum::from_rust_repr(
+                        <#enum_name as std::str::FromStr>::from_str(string.to_str()).ok()
+                    )
+                }
+            }
+        } else {
             quote! {}
+        };
+
+        let vec_support = if shared_enum.supports_vec() {
+            generate_vec_of_transparent_enum_functions(&shared_enum, ffi_visibility)
         } else {
-            generate_vec_of_transparent_enum_functions(&shared_enum)
+            quote! {}
+        };
+
+        // Used by Swift to move the FFI repr onto and off of the Rust heap when bridging
+        //  `Box<SomeEnum>`.
+        let box_support = if shared_enum_is_boxed(shared_enum, &self.types) {
+            // __swift_bridge__$SomeEnum$_box
+            let export_name_box = format!("{}$_box", shared_enum.ffi_name_string());
+            let export_name_unbox = format!("{}$_unbox", shared_enum.ffi_name_string());
+            // __swift_bridge__SomeEnum__box
+            let fn_name_box = format_ident!("{}__box", enum_ffi_name);
+            let fn_name_unbox = format_ident!("{}__unbox", enum_ffi_name);
+
+            quote! {
+                #[export_name = #export_name_box]
+                #[doc(hidden)]
+                #ffi_visibility extern "C" fn #fn_name_box(val: #enum_ffi_name) -> *mut #enum_ffi_name {
+                    Box::into_raw(Box::new(val))
+                }
+
+                #[export_name = #export_name_unbox]
+                #[doc(hidden)]
+                #ffi_visibility extern "C" fn #fn_name_unbox(ptr: *mut #enum_ffi_name) -> #enum_ffi_name {
+                    unsafe { *Box::from_raw(ptr) }
+                }
+            }
+        } else {
+            quote! {}
         };
 
         let definition = quote! {
@@ -243,7 +305,11 @@ impl SwiftBridgeModule {
 
             #vec_support
 
+            #box_support
+
             #(#derive_impl_ffi_bridges),*
+
+            #from_str_impl
         };
 
         Some(definition)
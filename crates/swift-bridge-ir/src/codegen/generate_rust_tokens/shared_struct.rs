@@ -1,10 +1,12 @@
 //! More tests can be found in
 //! crates/swift-bridge-ir/src/codegen/codegen_tests/shared_struct_codegen_tests.rs
 
+use crate::bridged_type::bridgeable_boxed_struct::shared_struct_is_boxed;
 use crate::bridged_type::{BridgedType, SharedStruct};
+use crate::codegen::generate_rust_tokens::vec::vec_of_transparent_struct::generate_vec_of_transparent_struct_functions;
 use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::Ident;
 
 impl SwiftBridgeModule {
@@ -19,6 +21,7 @@ impl SwiftBridgeModule {
 
         let struct_name = &shared_struct.name;
         let swift_bridge_path = &self.swift_bridge_path;
+        let ffi_visibility = self.ffi_visibility;
 
         let struct_ffi_name = format!("{}{}", SWIFT_BRIDGE_PREFIX, struct_name);
         let struct_ffi_name = Ident::new(&struct_ffi_name, struct_name.span());
@@ -37,6 +40,14 @@ impl SwiftBridgeModule {
                     pub #maybe_name_and_colon #ty
                 }
             })
+            .chain(shared_struct.phantom_data_fields.iter().map(|field| {
+                let name = &field.name;
+                let ty = &field.ty;
+
+                quote! {
+                    pub #name: #ty
+                }
+            }))
             .collect();
         let struct_fields = shared_struct.fields.wrap_declaration_fields(&struct_fields);
 
@@ -98,6 +109,42 @@ impl SwiftBridgeModule {
         if shared_struct.derives.clone {
             derives.push(quote! {Clone});
         }
+        if shared_struct.derives.hash {
+            derives.push(quote! {Hash});
+        }
+
+        // Used by Swift to move the FFI repr onto and off of the Rust heap when bridging
+        //  `Option<Box<SomeStruct>>`.
+        let box_support = if shared_struct_is_boxed(shared_struct, &self.types) {
+            // __swift_bridge__$SomeStruct$_box
+            let export_name_box = format!("{}$_box", shared_struct.ffi_name_string());
+            let export_name_unbox = format!("{}$_unbox", shared_struct.ffi_name_string());
+            // __swift_bridge__SomeStruct__box
+            let fn_name_box = format_ident!("{}__box", struct_ffi_name);
+            let fn_name_unbox = format_ident!("{}__unbox", struct_ffi_name);
+
+            quote! {
+                #[export_name = #export_name_box]
+                #[doc(hidden)]
+                #ffi_visibility extern "C" fn #fn_name_box(val: #struct_ffi_name) -> *mut #struct_ffi_name {
+                    Box::into_raw(Box::new(val))
+                }
+
+                #[export_name = #export_name_unbox]
+                #[doc(hidden)]
+                #ffi_visibility extern "C" fn #fn_name_unbox(ptr: *mut #struct_ffi_name) -> #struct_ffi_name {
+                    unsafe { *Box::from_raw(ptr) }
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        let vec_support = if shared_struct.supports_vec() {
+            generate_vec_of_transparent_struct_functions(shared_struct, ffi_visibility)
+        } else {
+            quote! {}
+        };
 
         let definition = quote! {
             #[derive(#(#derives),*)]
@@ -153,6 +200,10 @@ impl SwiftBridgeModule {
                     }
                 }
             }
+
+            #vec_support
+
+            #box_support
         };
 
         Some(definition)
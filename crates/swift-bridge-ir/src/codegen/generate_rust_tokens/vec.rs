@@ -1,2 +1,3 @@
 pub(super) mod vec_of_opaque_rust_type;
 pub(super) mod vec_of_transparent_enum;
+pub(super) mod vec_of_transparent_struct;
@@ -1,3 +1,4 @@
+use crate::bridge_module_attributes::FfiVisibility;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
@@ -5,7 +6,10 @@ use quote::quote;
 /// Rust type's Vectorizable implementation.
 ///
 /// So inside of `extension MyRustType: Vectorizable {}` on the Swift side.
-pub(in super::super) fn generate_vec_of_opaque_rust_type_functions(ty: &Ident) -> TokenStream {
+pub(in super::super) fn generate_vec_of_opaque_rust_type_functions(
+    ty: &Ident,
+    ffi_visibility: FfiVisibility,
+) -> TokenStream {
     // examples:
     // "__swift_bridge__$Vec_MyRustType$new"
     // "__swift_bridge__$Vec_MyRustType$drop"
@@ -13,6 +17,7 @@ pub(in super::super) fn generate_vec_of_opaque_rust_type_functions(ty: &Ident) -
     let export_name_new = make_export_name("new");
     let export_name_drop = make_export_name("drop");
     let export_name_len = make_export_name("len");
+    let export_name_capacity = make_export_name("capacity");
     let export_name_get = make_export_name("get");
     let export_name_get_mut = make_export_name("get_mut");
     let export_name_push = make_export_name("push");
@@ -23,26 +28,32 @@ pub(in super::super) fn generate_vec_of_opaque_rust_type_functions(ty: &Ident) -
         const _: () = {
             #[doc(hidden)]
             #[export_name = #export_name_new]
-            pub extern "C" fn _new() -> *mut Vec<super::#ty> {
+            #ffi_visibility extern "C" fn _new() -> *mut Vec<super::#ty> {
                 Box::into_raw(Box::new(Vec::new()))
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_drop]
-            pub extern "C" fn _drop(vec: *mut Vec<super::#ty>) {
+            #ffi_visibility extern "C" fn _drop(vec: *mut Vec<super::#ty>) {
                 let vec = unsafe { Box::from_raw(vec) };
                 drop(vec)
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_len]
-            pub extern "C" fn _len(vec: *const Vec<super::#ty>) -> usize {
+            #ffi_visibility extern "C" fn _len(vec: *const Vec<super::#ty>) -> usize {
                 unsafe { &*vec }.len()
             }
 
+            #[doc(hidden)]
+            #[export_name = #export_name_capacity]
+            #ffi_visibility extern "C" fn _capacity(vec: *const Vec<super::#ty>) -> usize {
+                unsafe { &*vec }.capacity()
+            }
+
             #[doc(hidden)]
             #[export_name = #export_name_get]
-            pub extern "C" fn _get(vec: *const Vec<super::#ty>, index: usize) -> *const super::#ty {
+            #ffi_visibility extern "C" fn _get(vec: *const Vec<super::#ty>, index: usize) -> *const super::#ty {
                 let vec = unsafe { & *vec };
                 if let Some(val) = vec.get(index) {
                     val as *const super::#ty
@@ -53,7 +64,7 @@ pub(in super::super) fn generate_vec_of_opaque_rust_type_functions(ty: &Ident) -
 
             #[doc(hidden)]
             #[export_name = #export_name_get_mut]
-            pub extern "C" fn _get_mut(vec: *mut Vec<super::#ty>, index: usize) -> *mut super::#ty {
+            #ffi_visibility extern "C" fn _get_mut(vec: *mut Vec<super::#ty>, index: usize) -> *mut super::#ty {
                 let vec = unsafe { &mut *vec };
                 if let Some(val) = vec.get_mut(index) {
                     val as *mut super::#ty
@@ -64,13 +75,13 @@ pub(in super::super) fn generate_vec_of_opaque_rust_type_functions(ty: &Ident) -
 
             #[doc(hidden)]
             #[export_name = #export_name_push]
-            pub extern "C" fn _push(vec: *mut Vec<super::#ty>, val: *mut super::#ty) {
+            #ffi_visibility extern "C" fn _push(vec: *mut Vec<super::#ty>, val: *mut super::#ty) {
                 unsafe { &mut *vec }.push( unsafe { *Box::from_raw(val) } )
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_pop]
-            pub extern "C" fn _pop(vec: *mut Vec<super::#ty>) -> *mut super::#ty {
+            #ffi_visibility extern "C" fn _pop(vec: *mut Vec<super::#ty>) -> *mut super::#ty {
                 let vec = unsafe { &mut *vec };
                 if let Some(val) = vec.pop() {
                     Box::into_raw(Box::new(val))
@@ -81,7 +92,7 @@ pub(in super::super) fn generate_vec_of_opaque_rust_type_functions(ty: &Ident) -
 
             #[doc(hidden)]
             #[export_name = #export_name_as_ptr]
-            pub extern "C" fn _as_ptr(vec: *const Vec<super::#ty>) -> *const super::#ty {
+            #ffi_visibility extern "C" fn _as_ptr(vec: *const Vec<super::#ty>) -> *const super::#ty {
                 unsafe { & *vec }.as_ptr()
             }
         };
@@ -120,6 +131,12 @@ mod tests {
                     unsafe { &*vec }.len()
                 }
 
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_ARustType$capacity"]
+                pub extern "C" fn _capacity(vec: *const Vec<super::ARustType>) -> usize {
+                    unsafe { &*vec }.capacity()
+                }
+
                 #[doc(hidden)]
                 #[export_name = "__swift_bridge__$Vec_ARustType$get"]
                 pub extern "C" fn _get(vec: *const Vec<super::ARustType>, index: usize) -> *const super::ARustType {
@@ -168,10 +185,10 @@ mod tests {
         };
 
         assert_tokens_eq(
-            &generate_vec_of_opaque_rust_type_functions(&Ident::new(
-                "ARustType",
-                Span::call_site(),
-            )),
+            &generate_vec_of_opaque_rust_type_functions(
+                &Ident::new("ARustType", Span::call_site()),
+                FfiVisibility::Pub,
+            ),
             &expected,
         );
     }
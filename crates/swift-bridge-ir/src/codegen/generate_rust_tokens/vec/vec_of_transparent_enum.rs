@@ -1,3 +1,4 @@
+use crate::bridge_module_attributes::FfiVisibility;
 use crate::bridged_type::SharedEnum;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -8,6 +9,7 @@ use quote::quote;
 /// So inside of `extension SomeTransparentEnum: Vectorizable {}` on the Swift side.
 pub(in super::super) fn generate_vec_of_transparent_enum_functions(
     shared_enum: &SharedEnum,
+    ffi_visibility: FfiVisibility,
 ) -> TokenStream {
     let enum_name = &shared_enum.name;
 
@@ -24,12 +26,20 @@ pub(in super::super) fn generate_vec_of_transparent_enum_functions(
     let export_name_new = make_export_name("new");
     let export_name_drop = make_export_name("drop");
     let export_name_len = make_export_name("len");
+    let export_name_capacity = make_export_name("capacity");
     let export_name_get = make_export_name("get");
     let export_name_get_mut = make_export_name("get_mut");
     let export_name_push = make_export_name("push");
     let export_name_pop = make_export_name("pop");
     let export_name_as_ptr = make_export_name("as_ptr");
 
+    // Enums without data are `Copy`, while enums with data must be cloned.
+    let copy_element = if shared_enum.has_one_or_more_variants_with_data() {
+        quote! { v.clone() }
+    } else {
+        quote! { *v }
+    };
+
     let ffi_enum_repr = &shared_enum.ffi_name_tokens();
     let ffi_option_enum_repr = shared_enum.ffi_option_name_tokens();
 
@@ -37,48 +47,54 @@ pub(in super::super) fn generate_vec_of_transparent_enum_functions(
         const _: () = {
             #[doc(hidden)]
             #[export_name = #export_name_new]
-            pub extern "C" fn _new() -> *mut Vec<#enum_name> {
+            #ffi_visibility extern "C" fn _new() -> *mut Vec<#enum_name> {
                 Box::into_raw(Box::new(Vec::new()))
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_drop]
-            pub extern "C" fn _drop(vec: *mut Vec<#enum_name>) {
+            #ffi_visibility extern "C" fn _drop(vec: *mut Vec<#enum_name>) {
                 let vec = unsafe { Box::from_raw(vec) };
                 drop(vec)
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_len]
-            pub extern "C" fn _len(vec: *const Vec<#enum_name>) -> usize {
+            #ffi_visibility extern "C" fn _len(vec: *const Vec<#enum_name>) -> usize {
                 unsafe { &*vec }.len()
             }
 
+            #[doc(hidden)]
+            #[export_name = #export_name_capacity]
+            #ffi_visibility extern "C" fn _capacity(vec: *const Vec<#enum_name>) -> usize {
+                unsafe { &*vec }.capacity()
+            }
+
             #[doc(hidden)]
             #[export_name = #export_name_get]
-            pub extern "C" fn _get(vec: *const Vec<#enum_name>, index: usize) -> #ffi_option_enum_repr {
+            #ffi_visibility extern "C" fn _get(vec: *const Vec<#enum_name>, index: usize) -> #ffi_option_enum_repr {
                 let vec = unsafe { &*vec };
-                let val = vec.get(index).map(|v| *v);
+                let val = vec.get(index).map(|v| #copy_element);
                 #ffi_option_enum_repr::from_rust_repr(val)
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_get_mut]
-            pub extern "C" fn _get_mut(vec: *mut Vec<#enum_name>, index: usize) -> #ffi_option_enum_repr {
+            #ffi_visibility extern "C" fn _get_mut(vec: *mut Vec<#enum_name>, index: usize) -> #ffi_option_enum_repr {
                 let vec = unsafe { &mut *vec };
-                let val = vec.get_mut(index).map(|v| *v);
+                let val = vec.get_mut(index).map(|v| #copy_element);
                 #ffi_option_enum_repr::from_rust_repr(val)
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_push]
-            pub extern "C" fn _push(vec: *mut Vec<#enum_name>, val: #ffi_enum_repr) {
+            #ffi_visibility extern "C" fn _push(vec: *mut Vec<#enum_name>, val: #ffi_enum_repr) {
                 unsafe { &mut *vec }.push( val.into_rust_repr() )
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_pop]
-            pub extern "C" fn _pop(vec: *mut Vec<#enum_name>) -> #ffi_option_enum_repr {
+            #ffi_visibility extern "C" fn _pop(vec: *mut Vec<#enum_name>) -> #ffi_option_enum_repr {
                 let vec = unsafe { &mut *vec };
                 let val = vec.pop();
                 #ffi_option_enum_repr::from_rust_repr(val)
@@ -86,7 +102,7 @@ pub(in super::super) fn generate_vec_of_transparent_enum_functions(
 
             #[doc(hidden)]
             #[export_name = #export_name_as_ptr]
-            pub extern "C" fn _as_ptr(vec: *const Vec<#enum_name>) -> *const #enum_name {
+            #ffi_visibility extern "C" fn _as_ptr(vec: *const Vec<#enum_name>) -> *const #enum_name {
                 unsafe { & *vec }.as_ptr()
             }
         };
@@ -125,6 +141,12 @@ mod tests {
                     unsafe { &*vec }.len()
                 }
 
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_AnEnum$capacity"]
+                pub extern "C" fn _capacity(vec: *const Vec<AnEnum>) -> usize {
+                    unsafe { &*vec }.capacity()
+                }
+
                 #[doc(hidden)]
                 #[export_name = "__swift_bridge__$Vec_AnEnum$get"]
                 pub extern "C" fn _get(vec: *const Vec<AnEnum>, index: usize) -> __swift_bridge__Option_AnEnum {
@@ -169,9 +191,18 @@ mod tests {
             already_declared: false,
             swift_name: None,
             derive: DeriveAttrs::default(),
+            case_iterable: false,
+            identifiable: false,
+            comparable: false,
+            conforms_to: vec![],
+            custom_string_convertible: false,
+            error_domain: None,
+            from_str: false,
+            objc: false,
+            option_set: false,
         };
         assert_tokens_eq(
-            &generate_vec_of_transparent_enum_functions(&shared_enum),
+            &generate_vec_of_transparent_enum_functions(&shared_enum, FfiVisibility::Pub),
             &expected,
         );
     }
@@ -1,9 +1,15 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use syn::Path;
 
+use crate::bridged_type::boxed_fn::BoxedFnTrait;
 use crate::bridged_type::{BridgeableType, BridgedType, TypePosition};
-use crate::codegen::generate_swift::generate_function_swift_calls_rust::gen_func_swift_calls_rust;
+use crate::codegen::generate_swift::ffi_layout_docs::{
+    shared_enum_ffi_layout_docs, shared_struct_ffi_layout_docs,
+};
+use crate::codegen::generate_swift::generate_function_swift_calls_rust::{
+    gen_func_swift_calls_rust, takes_swift_data_overload,
+};
 use crate::codegen::generate_swift::opaque_copy_type::generate_opaque_copy_struct;
 use crate::codegen::generate_swift::swift_class::generate_swift_class;
 use crate::codegen::generate_swift::vec::generate_vectorizable_extension;
@@ -17,6 +23,7 @@ use crate::SwiftBridgeModule;
 
 mod vec;
 
+mod ffi_layout_docs;
 mod generate_function_swift_calls_rust;
 mod opaque_copy_type;
 mod shared_enum;
@@ -32,15 +39,42 @@ impl SwiftBridgeModule {
             return swift;
         }
 
+        // The `#[swift_bridge(dispatch_queue = "...")]` precondition uses Dispatch's APIs.
+        let uses_dispatch_queue = self.types.types().iter().any(|ty| match ty {
+            TypeDeclaration::Opaque(ty) => ty.attributes.dispatch_queue.is_some(),
+            _ => false,
+        });
+        if uses_dispatch_queue {
+            swift += "import Dispatch\n";
+        }
+
+        // Functions that take a `&[u8]` get an overload that takes a Foundation `Data`,
+        // `return_data` functions return a `Data`, some types such as `SystemTime` are represented
+        // using Foundation types such as `Date`, `LocalizedError` and `CustomNSError` are declared
+        // by Foundation and `bytes` structs are serialized to `Data`.
+        let uses_foundation = self.functions.iter().any(|function| {
+            takes_swift_data_overload(function, &self.types) || function.return_data
+        }) || self.uses_swift_foundation_types();
+        if uses_foundation && !config.embedded_swift {
+            swift += "import Foundation\n";
+        }
+
         let mut associated_funcs_and_methods: HashMap<String, Vec<&ParsedExternFn>> =
             HashMap::new();
         let mut class_protocols: HashMap<String, ClassProtocols> = HashMap::new();
+        let mut shared_struct_methods: HashMap<String, Vec<&ParsedExternFn>> = HashMap::new();
 
         for function in &self.functions {
             if function.host_lang.is_rust() {
                 if let Some(ty) = function.associated_type.as_ref() {
                     match ty {
-                        TypeDeclaration::Shared(_) => {
+                        TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                            shared_struct_methods
+                                .entry(shared_struct.name.to_string())
+                                .or_default()
+                                .push(function);
+                        }
+                        TypeDeclaration::Shared(SharedTypeDeclaration::Enum(_)) => {
                             //
                             todo!("Think about what to do here..")
                         }
@@ -52,7 +86,7 @@ impl SwiftBridgeModule {
 
                             if function.is_swift_identifiable {
                                 let identifiable_protocol = IdentifiableProtocol {
-                                    func_name: function.func.sig.ident.to_string(),
+                                    func_name: function.swift_fn_name(config),
                                     return_ty: BridgedType::new_with_return_type(
                                         &function.func.sig.output,
                                         &self.types,
@@ -69,19 +103,30 @@ impl SwiftBridgeModule {
                                     .or_default()
                                     .identifiable = Some(identifiable_protocol);
                             }
+
+                            if function.uses_cell_field() && config.swift_supports_sendable() {
+                                class_protocols
+                                    .entry(opaque_ty.to_string())
+                                    .or_default()
+                                    .non_sendable = true;
+                            }
                         }
                     };
                     continue;
                 }
             }
             let func_definition = match function.host_lang {
-                HostLang::Rust => {
-                    gen_func_swift_calls_rust(function, &self.types, &self.swift_bridge_path)
-                }
+                HostLang::Rust => gen_func_swift_calls_rust(
+                    function,
+                    &self.types,
+                    &self.swift_bridge_path,
+                    config,
+                ),
                 HostLang::Swift => gen_function_exposes_swift_to_rust(
                     function,
                     &self.types,
                     &self.swift_bridge_path,
+                    config,
                 ),
             };
             swift += &func_definition;
@@ -91,13 +136,45 @@ impl SwiftBridgeModule {
         for ty in self.types.types() {
             match ty {
                 TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
-                    if let Some(swift_struct) = self.generate_shared_struct_string(shared_struct) {
+                    if let Some(swift_struct) =
+                        self.generate_shared_struct_string(shared_struct, config.embedded_swift)
+                    {
+                        if config.ffi_layout_docs {
+                            swift += &shared_struct_ffi_layout_docs(shared_struct, &self.types);
+                        }
                         swift += &swift_struct;
                         swift += "\n";
                     }
+
+                    if let Some(methods) =
+                        shared_struct_methods.get(&shared_struct.name.to_string())
+                    {
+                        let methods: Vec<String> = methods
+                            .iter()
+                            .map(|method| {
+                                gen_func_swift_calls_rust(
+                                    method,
+                                    &self.types,
+                                    &self.swift_bridge_path,
+                                    config,
+                                )
+                            })
+                            .collect();
+
+                        swift += &format!(
+                            "extension {} {{\n{}\n}}\n",
+                            shared_struct.swift_name_string(),
+                            methods.join("\n\n")
+                        );
+                    }
                 }
                 TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
-                    if let Some(swift_enum) = self.generate_shared_enum_string(shared_enum) {
+                    if let Some(swift_enum) =
+                        self.generate_shared_enum_string(shared_enum, config.embedded_swift)
+                    {
+                        if config.ffi_layout_docs {
+                            swift += &shared_enum_ffi_layout_docs(shared_enum);
+                        }
                         swift += &swift_enum;
                         swift += "\n";
                     }
@@ -110,6 +187,7 @@ impl SwiftBridgeModule {
                                 &associated_funcs_and_methods,
                                 &self.types,
                                 &self.swift_bridge_path,
+                                config,
                             );
                         } else {
                             let class_protocols = class_protocols.get(&ty.ty.to_string());
@@ -122,6 +200,7 @@ impl SwiftBridgeModule {
                                 class_protocols,
                                 &self.types,
                                 &self.swift_bridge_path,
+                                config,
                             );
                         }
 
@@ -131,7 +210,10 @@ impl SwiftBridgeModule {
                             // TODO: Support Vec<OpaqueCopyType>. Add codegen tests and then
                             //  make them pass.
                             // TODO: Support Vec<GenericOpaqueRustType
-                            if ty.attributes.copy.is_none() && ty.generics.len() == 0 {
+                            if ty.attributes.copy.is_none()
+                                && !ty.attributes.arc_mutex
+                                && ty.generics.len() == 0
+                            {
                                 swift += &generate_vectorizable_extension(&ty);
                                 swift += "\n";
                             }
@@ -143,16 +225,83 @@ impl SwiftBridgeModule {
                     }
                 },
             };
+
+            // The user implements the protocols listed in `#[swift_bridge(conforms_to = "...")]`.
+            let conforms_to = match ty {
+                TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                    &shared_struct.conforms_to
+                }
+                TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
+                    &shared_enum.conforms_to
+                }
+                TypeDeclaration::Opaque(opaque) => &opaque.attributes.conforms_to,
+            };
+            if !conforms_to.is_empty() {
+                swift += &format!(
+                    "extension {}: {} {{}}\n",
+                    ty.swift_name_string(),
+                    conforms_to.join(", ")
+                );
+            }
         }
 
         swift
     }
+
+    /// Whether or not any function or shared struct uses a type whose Swift representation comes
+    /// from Foundation, such as `SystemTime`, which is represented as a `Date`.
+    fn uses_swift_foundation_types(&self) -> bool {
+        let function_uses_foundation = |function: &ParsedExternFn| {
+            let args_use_foundation = function.func.sig.inputs.iter().any(|arg| {
+                BridgedType::new_with_fn_arg(arg, &self.types)
+                    .map(|ty| ty.uses_swift_foundation())
+                    .unwrap_or(false)
+            });
+            let return_uses_foundation =
+                BridgedType::new_with_return_type(&function.func.sig.output, &self.types)
+                    .map(|ty| ty.uses_swift_foundation())
+                    .unwrap_or(false);
+
+            args_use_foundation || return_uses_foundation
+        };
+
+        let shared_type_uses_foundation = |ty: &TypeDeclaration| match ty {
+            TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                shared_struct.bytes
+                    || shared_struct.localized_error.is_localized_error()
+                    || shared_struct.error_domain.is_some()
+                    || shared_struct
+                        .fields
+                        .normalized_fields()
+                        .iter()
+                        .any(|field| {
+                            BridgedType::new_with_type(&field.ty, &self.types)
+                                .map(|ty| ty.uses_swift_foundation())
+                                .unwrap_or(false)
+                        })
+            }
+            TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
+                shared_enum.error_domain.is_some()
+            }
+            _ => false,
+        };
+
+        self.functions.iter().any(function_uses_foundation)
+            || self
+                .types
+                .types()
+                .into_iter()
+                .any(shared_type_uses_foundation)
+    }
 }
 
 #[derive(Default)]
 struct ClassProtocols {
     // The name of the function to use for the Identifiable protocol implementation.
     identifiable: Option<IdentifiableProtocol>,
+    // Whether or not to explicitly mark the class as not being `Sendable`, such as when it
+    // exposes a `Cell<T>` field, which can only be used from one thread at a time.
+    non_sendable: bool,
 }
 struct IdentifiableProtocol {
     func_name: String,
@@ -190,14 +339,15 @@ fn gen_function_exposes_swift_to_rust(
     func: &ParsedExternFn,
     types: &TypeDeclarations,
     swift_bridge_path: &Path,
+    config: &CodegenConfig,
 ) -> String {
+    if func.sig.asyncness.is_some() {
+        return gen_async_function_exposes_swift_to_rust(func, types, swift_bridge_path, config);
+    }
+
     let link_name = func.link_name();
     let prefixed_fn_name = func.prefixed_fn_name();
-    let fn_name = if let Some(swift_name) = func.swift_name_override.as_ref() {
-        swift_name.value()
-    } else {
-        func.sig.ident.to_string()
-    };
+    let fn_name = func.swift_fn_name(config);
 
     let params = func.to_swift_param_names_and_types(true, types, swift_bridge_path);
     let ret = func.to_swift_return_type(types, swift_bridge_path);
@@ -220,10 +370,12 @@ fn gen_function_exposes_swift_to_rust(
                     ty_name = ty_name,
                     call_fn = call_fn
                 );
-                call_fn = built_in.convert_swift_expression_to_ffi_type(
+                call_fn = convert_swift_call_to_ffi_type(
+                    &built_in,
                     &call_fn,
+                    func,
                     types,
-                    TypePosition::FnReturn(func.host_lang),
+                    swift_bridge_path,
                 );
             } else if func.is_swift_initializer {
                 call_fn = format!("Unmanaged.passRetained({}({})).toOpaque()", ty_name, args);
@@ -231,11 +383,8 @@ fn gen_function_exposes_swift_to_rust(
                 call_fn = format!("{}::{}", ty_name, call_fn);
             }
         } else {
-            call_fn = built_in.convert_swift_expression_to_ffi_type(
-                &call_fn,
-                types,
-                TypePosition::FnReturn(func.host_lang),
-            );
+            call_fn =
+                convert_swift_call_to_ffi_type(&built_in, &call_fn, func, types, swift_bridge_path);
         }
     } else {
         todo!("Push to ParsedErrors")
@@ -250,7 +399,7 @@ fn gen_function_exposes_swift_to_rust(
     };
 
     for (idx, boxed_fn) in func.args_filtered_to_boxed_fns(types) {
-        if boxed_fn.does_not_have_params_or_return() {
+        if boxed_fn.uses_no_args_no_ret_fn_once_class() {
             continue;
         }
 
@@ -279,10 +428,34 @@ fn gen_function_exposes_swift_to_rust(
         );
 
         let maybe_generics = boxed_fn.maybe_swift_generics(types);
+        let callback_class =
+            boxed_fn.swift_callback_class_name(&maybe_associated_ty, &fn_name, idx);
+
+        if boxed_fn.fn_trait == BoxedFnTrait::Fn {
+            rust_fn_once_callback_classes += &format!(
+                r#"
+class {callback_class} {{
+    var ptr: UnsafeMutableRawPointer
+
+    init(ptr: UnsafeMutableRawPointer) {{
+        self.ptr = ptr
+    }}
+
+    deinit {{
+        __swift_bridge__{maybe_associated_ty}${fn_name}$_free$param{idx}(ptr)
+    }}
+
+    func call{maybe_generics}({params_as_swift}){maybe_ret} {{
+        return {ret_value}
+    }}
+}}"#
+            );
+            continue;
+        }
 
         rust_fn_once_callback_classes += &format!(
             r#"
-class __private__RustFnOnceCallback{maybe_associated_ty}${fn_name}$param{idx} {{
+class {callback_class} {{
     var ptr: UnsafeMutableRawPointer
     var called = false
 
@@ -335,6 +508,105 @@ func {prefixed_fn_name} ({params}){ret} {{
     generated_func
 }
 
+/// Expose a Swift `async` function to Rust.
+///
+/// Rust passes in a pointer to the future that it is awaiting along with a callback. We call the
+/// Swift function in a `Task` and then pass its FFI representation to the callback.
+fn gen_async_function_exposes_swift_to_rust(
+    func: &ParsedExternFn,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+    config: &CodegenConfig,
+) -> String {
+    let link_name = func.link_name();
+    let prefixed_fn_name = func.prefixed_fn_name();
+    let fn_name = func.swift_fn_name(config);
+
+    let params = func.to_swift_param_names_and_types(true, types, swift_bridge_path);
+    let maybe_params = if params.is_empty() {
+        "".to_string()
+    } else {
+        format!(", {params}")
+    };
+    let ret = func.to_swift_return_type(types, swift_bridge_path);
+
+    let args = func.to_swift_call_args(false, true, types, swift_bridge_path);
+    let mut call_fn = format!("{fn_name}({args})");
+    if let Some(TypeDeclaration::Opaque(associated_type)) = func.associated_type.as_ref() {
+        let ty_name = associated_type.to_string();
+        if func.is_method() {
+            call_fn =
+                format!("Unmanaged<{ty_name}>.fromOpaque(this).takeUnretainedValue().{call_fn}");
+        } else {
+            call_fn = format!("{ty_name}.{call_fn}");
+        }
+    }
+
+    let built_in = BridgedType::new_with_return_type(&func.sig.output, types).unwrap();
+    let (maybe_ret, call_and_complete) = if let Some(result) = built_in.as_result() {
+        let call_fn = result.convert_throwing_swift_expression_to_ffi_compatible(
+            &format!("await {call_fn}"),
+            types,
+            swift_bridge_path,
+        );
+        (
+            ret.replacen(" -> ", ", ", 1),
+            format!("callback(callbackWrapper, await {call_fn})"),
+        )
+    } else if ret.is_empty() {
+        (
+            "".to_string(),
+            format!("let _ = await {call_fn}\n        callback(callbackWrapper)"),
+        )
+    } else {
+        let val = built_in.convert_swift_expression_to_ffi_type(
+            "val",
+            types,
+            TypePosition::FnReturn(func.host_lang),
+        );
+        (
+            ret.replacen(" -> ", ", ", 1),
+            format!("let val = await {call_fn}\n        callback(callbackWrapper, {val})"),
+        )
+    };
+
+    format!(
+        r#"@_cdecl("{link_name}")
+func {prefixed_fn_name} (_ callbackWrapper: UnsafeMutableRawPointer, _ callback: @convention(c) (UnsafeMutableRawPointer{maybe_ret}) -> Void{maybe_params}) {{
+    Task {{
+        {call_and_complete}
+    }}
+}}
+"#
+    )
+}
+
+/// Convert the value returned by a Swift function into its FFI representation.
+///
+/// Swift functions that return a `Result` throw the `Err` value, so we catch it and pass it
+/// to Rust.
+fn convert_swift_call_to_ffi_type(
+    built_in: &BridgedType,
+    call_fn: &str,
+    func: &ParsedExternFn,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    match built_in.as_result() {
+        Some(result) => result.convert_throwing_swift_expression_to_ffi_compatible(
+            call_fn,
+            types,
+            swift_bridge_path,
+        ),
+        None => built_in.convert_swift_expression_to_ffi_type(
+            call_fn,
+            types,
+            TypePosition::FnReturn(func.host_lang),
+        ),
+    }
+}
+
+#[derive(Default)]
 struct ClassMethods {
     initializers: Vec<String>,
     owned_self_methods: Vec<String>,
@@ -347,42 +619,42 @@ fn generate_swift_class_methods(
     associated_funcs_and_methods: &HashMap<String, Vec<&ParsedExternFn>>,
     types: &TypeDeclarations,
     swift_bridge_path: &Path,
-) -> ClassMethods {
-    let mut initializers = vec![];
-    let mut owned_self_methods = vec![];
-    let mut ref_self_methods = vec![];
-    let mut ref_mut_self_methods = vec![];
+    config: &CodegenConfig,
+) -> Vec<ClassMethods> {
+    // Methods are grouped by the `extern "Rust"` block that they were declared in so that the
+    // generated Swift extensions mirror how they were grouped in Rust.
+    let mut extern_blocks: BTreeMap<usize, ClassMethods> = BTreeMap::new();
 
     if let Some(methods) = associated_funcs_and_methods.get(type_name) {
         for type_method in methods {
-            let func_definition = gen_func_swift_calls_rust(type_method, types, swift_bridge_path);
+            let func_definition =
+                gen_func_swift_calls_rust(type_method, types, swift_bridge_path, config);
 
             let is_class_func = type_method.func.sig.inputs.is_empty();
 
+            let class_methods = extern_blocks
+                .entry(type_method.extern_block_idx)
+                .or_default();
+
             if type_method.is_swift_initializer {
-                initializers.push(func_definition);
+                class_methods.initializers.push(func_definition);
             } else if is_class_func {
-                ref_self_methods.push(func_definition);
+                class_methods.ref_self_methods.push(func_definition);
             } else {
                 if type_method.self_reference().is_some() {
                     if type_method.self_mutability().is_some() {
-                        ref_mut_self_methods.push(func_definition);
+                        class_methods.ref_mut_self_methods.push(func_definition);
                     } else {
-                        ref_self_methods.push(func_definition);
+                        class_methods.ref_self_methods.push(func_definition);
                     }
                 } else {
-                    owned_self_methods.push(func_definition);
+                    class_methods.owned_self_methods.push(func_definition);
                 }
             }
         }
     }
 
-    ClassMethods {
-        initializers,
-        owned_self_methods,
-        ref_self_methods,
-        ref_mut_self_methods,
-    }
+    extern_blocks.into_values().collect()
 }
 
 #[cfg(test)]
@@ -635,6 +907,11 @@ public class Foo: FooRefMut {
             __swift_bridge__$Foo$_free(ptr)
         }
     }
+
+    override var unconsumedPtr: UnsafeMutableRawPointer {
+        precondition(isOwned, "Attempted to use a value that was already consumed.")
+        return ptr
+    }
 }
 extension Foo {
     public convenience init() {
@@ -675,6 +952,11 @@ public class Foo: FooRefMut {
             __swift_bridge__$Foo$_free(ptr)
         }
     }
+
+    override var unconsumedPtr: UnsafeMutableRawPointer {
+        precondition(isOwned, "Attempted to use a value that was already consumed.")
+        return ptr
+    }
 }
 extension Foo {
     public convenience init(_ val: UInt8) {
@@ -740,10 +1022,14 @@ public class FooRef {
     public init(ptr: UnsafeMutableRawPointer) {
         self.ptr = ptr
     }
+
+    var unconsumedPtr: UnsafeMutableRawPointer {
+        ptr
+    }
 }
 extension FooRef {
     public func bar() -> UInt8 {
-        __swift_bridge__$Foo$bar(ptr)
+        __swift_bridge__$Foo$bar(unconsumedPtr)
     }
 }
 "#;
@@ -773,10 +1059,14 @@ public class FooRef {
     public init(ptr: UnsafeMutableRawPointer) {
         self.ptr = ptr
     }
+
+    var unconsumedPtr: UnsafeMutableRawPointer {
+        ptr
+    }
 }
 extension FooRef {
     public func bar(_ other: FooRef) {
-        __swift_bridge__$Foo$bar(ptr, other.ptr)
+        __swift_bridge__$Foo$bar(unconsumedPtr, other.ptr)
     }
 }
 "#;
@@ -807,6 +1097,10 @@ public class FooRef {
     public init(ptr: UnsafeMutableRawPointer) {
         self.ptr = ptr
     }
+
+    var unconsumedPtr: UnsafeMutableRawPointer {
+        ptr
+    }
 }
 extension FooRef {
     class public func bar() {
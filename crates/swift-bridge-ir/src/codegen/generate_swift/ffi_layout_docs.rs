@@ -0,0 +1,104 @@
+use crate::bridged_type::shared_struct::StructField;
+use crate::bridged_type::{BridgedType, SharedEnum, SharedStruct, StructFields};
+use crate::parse::{OpaqueForeignTypeDeclaration, TypeDeclarations};
+use crate::SWIFT_BRIDGE_PREFIX;
+
+/// Doc comment lines that describe the C struct that a shared struct is passed over FFI as.
+///
+/// ```swift
+/// /// FFI representation: `__swift_bridge__$Point`, with its fields in this order:
+/// /// - `x`: `float` (4 bytes)
+/// /// - `label`: `void*` (pointer-sized)
+/// ```
+pub(super) fn shared_struct_ffi_layout_docs(
+    shared_struct: &SharedStruct,
+    types: &TypeDeclarations,
+) -> String {
+    let ffi_name = shared_struct.ffi_name_string();
+
+    let fields: Vec<(String, String)> = match &shared_struct.fields {
+        StructFields::Named(named) => named
+            .iter()
+            .map(|field| (field.swift_name_string(), c_type_of(&field.ty, types)))
+            .collect(),
+        StructFields::Unnamed(unnamed) => unnamed
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| (format!("_{}", idx), c_type_of(&field.ty, types)))
+            .collect(),
+        StructFields::Unit => vec![],
+    };
+
+    // Empty structs get a placeholder field, since C structs can't be empty.
+    if fields.is_empty() {
+        return format!(
+            "/// FFI representation: `{ffi_name}`, with a single `uint8_t _private` field (1 byte).\n"
+        );
+    }
+
+    let mut docs =
+        format!("/// FFI representation: `{ffi_name}`, with its fields in this order:\n");
+    for (name, c_type) in fields {
+        docs += &format!(
+            "/// - `{name}`: `{c_type}`{}\n",
+            c_type_width_suffix(&c_type)
+        );
+    }
+
+    docs
+}
+
+/// Doc comment lines that describe the C struct that a shared enum is passed over FFI as.
+pub(super) fn shared_enum_ffi_layout_docs(shared_enum: &SharedEnum) -> String {
+    let ffi_name = shared_enum.ffi_name_string();
+    let tag_name = shared_enum.ffi_tag_name_string();
+
+    if shared_enum.has_one_or_more_variants_with_data() {
+        format!(
+            r#"/// FFI representation: `{ffi_name}`, with its fields in this order:
+/// - `tag`: `{tag_name}` (a C enum with one case per variant)
+/// - `payload`: `union {union_name}` (the fields of the variant that `tag` names)
+"#,
+            union_name = shared_enum.ffi_union_name_string()
+        )
+    } else {
+        format!(
+            r#"/// FFI representation: `{ffi_name}`, with a single field:
+/// - `tag`: `{tag_name}` (a C enum with one case per variant)
+"#
+        )
+    }
+}
+
+/// Doc comment lines that describe how an opaque Rust type is passed over FFI.
+pub(super) fn opaque_rust_type_ffi_layout_docs(ty: &OpaqueForeignTypeDeclaration) -> String {
+    let type_name = ty.ty.to_string();
+
+    match &ty.attributes.copy {
+        Some(copy) => format!(
+            "/// FFI representation: `{prefix}${type_name}`, a {size}-byte copy of the Rust value.\n",
+            prefix = SWIFT_BRIDGE_PREFIX,
+            size = copy.size_bytes
+        ),
+        None => format!(
+            "/// FFI representation: `void*` (pointer-sized), a pointer to the Rust `{type_name}`.\n"
+        ),
+    }
+}
+
+fn c_type_of(ty: &syn::Type, types: &TypeDeclarations) -> String {
+    BridgedType::new_with_type(ty, types).unwrap().to_c(types)
+}
+
+/// The width of C types whose size doesn't depend on the bridged type, such as ` (4 bytes)`.
+fn c_type_width_suffix(c_type: &str) -> &'static str {
+    match c_type {
+        "uint8_t" | "int8_t" | "bool" => " (1 byte)",
+        "uint16_t" | "int16_t" => " (2 bytes)",
+        "uint32_t" | "int32_t" | "float" => " (4 bytes)",
+        "uint64_t" | "int64_t" | "double" => " (8 bytes)",
+        "uintptr_t" | "intptr_t" => " (pointer-sized)",
+        _ if c_type.ends_with('*') => " (pointer-sized)",
+        _ => "",
+    }
+}
@@ -1,14 +1,129 @@
-use crate::bridged_type::{fn_arg_name, BridgeableType, BridgedType, StdLibType, TypePosition};
-use crate::parse::{HostLang, TypeDeclaration};
+use crate::bridged_type::{
+    fn_arg_name, pat_type_pat_is_self, BridgeableType, BridgedType, StdLibType, TypePosition,
+};
+use crate::codegen::{CodegenConfig, SwiftResultFunctions};
+use crate::parse::{HostLang, SharedTypeDeclaration, TypeDeclaration};
 use crate::{ParsedExternFn, TypeDeclarations, SWIFT_BRIDGE_PREFIX};
 use quote::ToTokens;
 use std::ops::Deref;
-use syn::{Path, ReturnType, Type};
+use syn::{FnArg, GenericArgument, Path, PathArguments, ReturnType, Type};
 
 pub(super) fn gen_func_swift_calls_rust(
     function: &ParsedExternFn,
     types: &TypeDeclarations,
     swift_bridge_path: &Path,
+    config: &CodegenConfig,
+) -> String {
+    format!(
+        "{}{}",
+        bridged_types_doc_comment(function, types, config),
+        gen_func_definition(function, types, swift_bridge_path, config)
+    )
+}
+
+/// Generate a doc comment that links to the bridged types that the function takes and returns,
+/// so that Xcode's Quick Help can navigate between related types.
+///
+/// ```swift
+/// /// - Parameter shape: ``Shape``
+/// /// - Returns: ``Area``
+/// public func area(_ shape: Shape) -> Area {
+/// ```
+fn bridged_types_doc_comment(
+    function: &ParsedExternFn,
+    types: &TypeDeclarations,
+    config: &CodegenConfig,
+) -> String {
+    if !config.doc_comment_type_links
+        || function.swift_getter_property_name(config).is_some()
+        || function.is_swift_shared_instance
+    {
+        return "".to_string();
+    }
+
+    let indentation = if function.associated_type.is_some() {
+        "    "
+    } else {
+        ""
+    };
+
+    let mut doc_comment = "".to_string();
+
+    for arg in function.func.sig.inputs.iter() {
+        let pat_ty = match arg {
+            FnArg::Typed(pat_ty) if !pat_type_pat_is_self(pat_ty) => pat_ty,
+            _ => continue,
+        };
+
+        let links = bridged_type_links(&pat_ty.ty, types);
+        if !links.is_empty() {
+            doc_comment += &format!(
+                "{indentation}/// - Parameter {}: {}\n",
+                pat_ty.pat.to_token_stream(),
+                links
+            );
+        }
+    }
+
+    if let ReturnType::Type(_, ty) = &function.func.sig.output {
+        let links = bridged_type_links(ty, types);
+        if !function.is_swift_initializer && !links.is_empty() {
+            doc_comment += &format!("{indentation}/// - Returns: {}\n", links);
+        }
+    }
+
+    doc_comment
+}
+
+/// DocC symbol links to each of the bridged types within a type, such as "``Point``" for an
+/// `Option<Point>` or "``Point``, ``ParseError``" for a `Result<Point, ParseError>`.
+fn bridged_type_links(ty: &Type, types: &TypeDeclarations) -> String {
+    fn push_bridged_type_names(ty: &Type, types: &TypeDeclarations, names: &mut Vec<String>) {
+        match ty {
+            Type::Path(path) => {
+                for segment in path.path.segments.iter() {
+                    if let Some(declaration) = types.get(&segment.ident.to_string()) {
+                        let name = declaration.swift_name_string();
+                        if !names.contains(&name) {
+                            names.push(name);
+                        }
+                    }
+
+                    if let PathArguments::AngleBracketed(generics) = &segment.arguments {
+                        for generic in generics.args.iter() {
+                            if let GenericArgument::Type(generic) = generic {
+                                push_bridged_type_names(generic, types, names);
+                            }
+                        }
+                    }
+                }
+            }
+            Type::Reference(reference) => push_bridged_type_names(&reference.elem, types, names),
+            Type::Slice(slice) => push_bridged_type_names(&slice.elem, types, names),
+            Type::Tuple(tuple) => {
+                for elem in tuple.elems.iter() {
+                    push_bridged_type_names(elem, types, names);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut names = vec![];
+    push_bridged_type_names(ty, types, &mut names);
+
+    names
+        .iter()
+        .map(|name| format!("``{}``", name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn gen_func_definition(
+    function: &ParsedExternFn,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+    config: &CodegenConfig,
 ) -> String {
     let fn_name = function.sig.ident.to_string();
     let params = function.to_swift_param_names_and_types(false, types, swift_bridge_path);
@@ -27,7 +142,10 @@ pub(super) fn gen_func_swift_calls_rust(
 
     let maybe_type_name_segment = if let Some(ty) = function.associated_type.as_ref() {
         match ty {
-            TypeDeclaration::Shared(_) => {
+            TypeDeclaration::Shared(SharedTypeDeclaration::Struct(ty)) => {
+                format!("${}", ty.name)
+            }
+            TypeDeclaration::Shared(SharedTypeDeclaration::Enum(_)) => {
                 //
                 todo!()
             }
@@ -51,6 +169,18 @@ pub(super) fn gen_func_swift_calls_rust(
         ""
     };
 
+    // `self: &mut SomeStruct` methods on shared structs mutate the Swift struct in place.
+    let maybe_mutating = if function.mutates_shared_struct() {
+        "mutating "
+    } else {
+        ""
+    };
+
+    let returns_result = function
+        .return_ty_built_in(types)
+        .map(|ty| ty.is_result())
+        .unwrap_or(false);
+
     let public_func_fn_name = if function.is_swift_initializer {
         if function.is_copy_method_on_opaque_type() {
             "public init".to_string()
@@ -61,12 +191,19 @@ pub(super) fn gen_func_swift_calls_rust(
                 "public convenience init".to_string()
             }
         }
+    } else if returns_result && !config.swift_result_functions.generates_throwing() {
+        // The `Result` returning function calls the throwing function, so we still need it.
+        format!(
+            "fileprivate {}func {}",
+            maybe_mutating,
+            function.swift_fn_name(config)
+        )
     } else {
-        if let Some(swift_name) = &function.swift_name_override {
-            format!("public func {}", swift_name.value())
-        } else {
-            format!("public func {}", fn_name.as_str())
-        }
+        format!(
+            "public {}func {}",
+            maybe_mutating,
+            function.swift_fn_name(config)
+        )
     };
 
     let indentation = if function.associated_type.is_some() {
@@ -75,6 +212,27 @@ pub(super) fn gen_func_swift_calls_rust(
         ""
     };
 
+    let maybe_available = function
+        .swift_available_attribute()
+        .map(|available| format!("{}\n{}", available, indentation))
+        .unwrap_or_default();
+    let maybe_spi = function
+        .swift_spi_attribute()
+        .map(|spi| format!("{}\n{}", spi, indentation))
+        .unwrap_or_default();
+    // `@_spi` can only be applied to public declarations, so the `fileprivate` throwing function
+    // that a `Result` returning function calls goes without it.
+    let maybe_attributes = if public_func_fn_name.starts_with("public") {
+        format!("{}{}", maybe_spi, maybe_available)
+    } else {
+        maybe_available.clone()
+    };
+    let maybe_discardable_result = if function.discardable_result {
+        format!("@discardableResult\n{}", indentation)
+    } else {
+        "".to_string()
+    };
+
     let call_rust = format!(
         "{prefix}{type_name_segment}${call_fn}",
         prefix = SWIFT_BRIDGE_PREFIX,
@@ -132,6 +290,27 @@ pub(super) fn gen_func_swift_calls_rust(
             }
         }
     };
+    if function.copies_returned_str() {
+        call_rust = format!("{}.toString()", call_rust);
+    }
+    if function.return_data {
+        // The `Data` holds on to the `RustVec` until it no longer needs the bytes, so that Rust's
+        // buffer is handed to Swift without being copied.
+        let maybe_try = if returns_result { "try " } else { "" };
+        call_rust = format!(
+            "{maybe_try}{{ let vec: RustVec<UInt8> = {call_rust}; return Data(bytesNoCopy: UnsafeMutableRawPointer(mutating: vec.as_ptr()), count: vec.len(), deallocator: .custom({{ _, _ in withExtendedLifetime(vec) {{}} }})) }}()"
+        );
+    }
+
+    if let Some((value, warning)) = function.swift_value_and_warning_types(types, swift_bridge_path)
+    {
+        // The warnings are copied into an array so that the `ValueWithWarnings` does not depend
+        // on the `RustVec`, which is freed once this expression is evaluated.
+        call_rust = format!(
+            "{{ let (value, warnings): ({value}, RustVec<{warning}>) = {call_rust}; return ValueWithWarnings(value: value, warnings: warnings.toArray()) }}()"
+        );
+    }
+
     let returns_null = BridgedType::new_with_return_type(&function.func.sig.output, types)
         .map(|b| b.is_null())
         .unwrap_or(false);
@@ -175,6 +354,98 @@ pub(super) fn gen_func_swift_calls_rust(
                     call_rust = call_rust
                 );
             }
+            BridgedType::StdLib(StdLibType::RefSlice(slice)) if slice.mutable => {
+                let maybe_try = if returns_result { "try " } else { "" };
+                call_rust = format!(
+                    r#"{maybe_return}{maybe_try}{arg}.toFfiMutableSlice({{ {arg}AsFfiSlice in
+{indentation}        {call_rust}
+{indentation}    }})"#,
+                    maybe_return = maybe_return,
+                    maybe_try = maybe_try,
+                    indentation = indentation,
+                    arg = arg_name,
+                    call_rust = call_rust
+                );
+            }
+            BridgedType::StdLib(StdLibType::Vec(vec)) if vec.optional_opaque_rust_type => {
+                // Rust takes ownership of each of the opaque types in the array.
+                let maybe_try = if returns_result { "try " } else { "" };
+                call_rust = format!(
+                    r#"{maybe_return}{maybe_try}{arg}.map({{ val -> Optional<UnsafeMutableRawPointer> in if let val = val {{ val.isOwned = false; return val.ptr }} else {{ return nil }} }}).toFfiPointerSlice({{ {arg}AsFfiSlice in
+{indentation}        {call_rust}
+{indentation}    }})"#,
+                    maybe_return = maybe_return,
+                    maybe_try = maybe_try,
+                    indentation = indentation,
+                    arg = arg_name,
+                    call_rust = call_rust
+                );
+            }
+            BridgedType::StdLib(StdLibType::RefSlice(slice)) if slice.is_shared_struct_slice() => {
+                // Rust takes ownership of each of the structs' FFI representations.
+                let maybe_try = if returns_result { "try " } else { "" };
+                call_rust = format!(
+                    r#"{maybe_return}{maybe_try}{arg}.map({{ $0.intoFfiRepr() }}).toFfiSlice({{ {arg}AsFfiSlice in
+{indentation}        {call_rust}
+{indentation}    }})"#,
+                    maybe_return = maybe_return,
+                    maybe_try = maybe_try,
+                    indentation = indentation,
+                    arg = arg_name,
+                    call_rust = call_rust
+                );
+            }
+            BridgedType::StdLib(StdLibType::RefSlice(slice)) if slice.is_jagged() => {
+                call_rust = format!(
+                    r#"{maybe_return}{arg}.toFfiJaggedSlice({{ {arg}AsFfiSlice in
+{indentation}        {call_rust}
+{indentation}    }})"#,
+                    maybe_return = maybe_return,
+                    indentation = indentation,
+                    arg = arg_name,
+                    call_rust = call_rust
+                );
+            }
+            BridgedType::Bridgeable(b) if b.as_string_map().is_some() => {
+                let maybe_try = if returns_result { "try " } else { "" };
+                // Rust takes ownership of each of the opaque values in the dictionary.
+                let maybe_map_values = if b.as_string_map().unwrap().has_opaque_rust_values() {
+                    ".mapValues({ val -> UnsafeMutableRawPointer in val.isOwned = false; return val.ptr })"
+                } else {
+                    ""
+                };
+                call_rust = format!(
+                    r#"{maybe_return}{maybe_try}{arg}{maybe_map_values}.toFfiStringMap({{ {arg}AsFfiStringMap in
+{indentation}        {call_rust}
+{indentation}    }})"#,
+                    maybe_return = maybe_return,
+                    maybe_try = maybe_try,
+                    maybe_map_values = maybe_map_values,
+                    indentation = indentation,
+                    arg = arg_name,
+                    call_rust = call_rust
+                );
+            }
+            BridgedType::Bridgeable(b) if b.as_int_map().is_some() => {
+                let maybe_try = if returns_result { "try " } else { "" };
+                // Rust takes ownership of each of the opaque values in the dictionary.
+                let maybe_map_values = if b.as_int_map().unwrap().has_opaque_rust_values() {
+                    ".mapValues({ val -> UnsafeMutableRawPointer in val.isOwned = false; return val.ptr })"
+                } else {
+                    ""
+                };
+                call_rust = format!(
+                    r#"{maybe_return}{maybe_try}{arg}{maybe_map_values}.toFfiIntMap({{ {arg}AsFfiIntMap in
+{indentation}        {call_rust}
+{indentation}    }})"#,
+                    maybe_return = maybe_return,
+                    maybe_try = maybe_try,
+                    maybe_map_values = maybe_map_values,
+                    indentation = indentation,
+                    arg = arg_name,
+                    call_rust = call_rust
+                );
+            }
             _ => {}
         }
     }
@@ -200,6 +471,19 @@ pub(super) fn gen_func_swift_calls_rust(
         function.to_swift_return_type(types, swift_bridge_path)
     };
 
+    // Types annotated with `#[swift_bridge(dispatch_queue = "...")]` must only be used on the
+    // dispatch queue with the given label.
+    let dispatch_precondition = match function.associated_type.as_ref() {
+        Some(TypeDeclaration::Opaque(ty)) => ty.attributes.dispatch_queue.as_ref().map(|label| {
+            format!(
+                r#"precondition(String(cString: __dispatch_queue_get_label(nil)) == "{label}", "{ty} must only be used on the \"{label}\" dispatch queue.")"#,
+                label = label,
+                ty = ty.ty,
+            )
+        }),
+        _ => None,
+    };
+
     let maybe_generics = function.maybe_swift_generics(types);
 
     let func_definition = if function.sig.asyncness.is_some() {
@@ -266,13 +550,17 @@ pub(super) fn gen_func_swift_calls_rust(
             cb_wrapper_ty = callback_wrapper_ty
         );
 
+        let maybe_dispatch_precondition = dispatch_precondition
+            .map(|precondition| format!("{}\n\n", precondition))
+            .unwrap_or_default();
+
         let fn_body = format!(
             r#"func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?{maybe_on_complete_sig_ret_val}) {{
     let wrapper = Unmanaged<{cb_wrapper_ty}>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
     {run_wrapper_cb}
 }}
 
-return{maybe_try}await {with_checked_continuation_function_name}({{ (continuation: CheckedContinuation<{rust_fn_ret_ty}, {error}>) in
+{maybe_dispatch_precondition}return{maybe_try}await {with_checked_continuation_function_name}({{ (continuation: CheckedContinuation<{rust_fn_ret_ty}, {error}>) in
     let callback = {{ rustFnRetVal in
         continuation.resume(with: rustFnRetVal)
     }}
@@ -300,11 +588,13 @@ return{maybe_try}await {with_checked_continuation_function_name}({{ (continuatio
         let fn_body_indented = fn_body_indented.trim_end();
 
         format!(
-            r#"{indentation}{maybe_static_class_func}{swift_class_func_name}{maybe_generics}({params}) async{maybe_ret} {{
+            r#"{indentation}{maybe_attributes}{maybe_discardable_result}{maybe_static_class_func}{swift_class_func_name}{maybe_generics}({params}) async{maybe_ret} {{
 {fn_body_indented}
 {indentation}}}
 {callback_wrapper}"#,
             indentation = indentation,
+            maybe_attributes = maybe_attributes,
+            maybe_discardable_result = maybe_discardable_result,
             maybe_static_class_func = maybe_static_class_func,
             swift_class_func_name = public_func_fn_name,
             maybe_generics = maybe_generics,
@@ -314,11 +604,66 @@ return{maybe_try}await {with_checked_continuation_function_name}({{ (continuatio
             callback_wrapper = callback_wrapper
         )
     } else {
+        let dispatch_precondition_is_some = dispatch_precondition.is_some();
+        let maybe_dispatch_precondition = dispatch_precondition
+            .map(|precondition| format!("{}\n{}    ", precondition, indentation))
+            .unwrap_or_default();
+        // Rust mutates the struct's FFI representation, which we then read back into `self`.
+        let maybe_ffi_self = if function.mutates_shared_struct() {
+            format!(
+                "var ffiSelf = self.intoFfiRepr()\n{indentation}    defer {{ self = ffiSelf.intoSwiftRepr() }}\n{indentation}    "
+            )
+        } else {
+            "".to_string()
+        };
+        // The function body is no longer a single expression, so we need an explicit return.
+        let call_rust = if (dispatch_precondition_is_some || !maybe_ffi_self.is_empty())
+            && !returns_null
+            && !function.is_swift_initializer
+            && !call_rust.starts_with("return ")
+        {
+            format!("return {}", call_rust)
+        } else {
+            call_rust
+        };
+
+        let maybe_inlinable = if is_inlinable(function, types, config) {
+            format!("@inlinable\n{}", indentation)
+        } else {
+            "".to_string()
+        };
+
+        // Shared instances are fetched from Rust once. The returned `Ref` class never frees the
+        // Rust singleton.
+        if function.is_swift_shared_instance {
+            return format!(
+                r#"{indentation}{maybe_attributes}public static let {name}{maybe_ret} = {call_rust}"#,
+                name = function.swift_fn_name(config),
+                maybe_ret = maybe_return.replacen(" -> ", ": ", 1),
+            );
+        }
+
+        // Getters that return a value without throwing can become computed properties.
+        let getter_property = function
+            .swift_getter_property_name(config)
+            .and_then(|name| Some((name, maybe_return.strip_prefix(" -> ")?)));
+        if let Some((property_name, property_ty)) = getter_property {
+            return format!(
+                r#"{indentation}{maybe_attributes}{maybe_inlinable}public var {property_name}: {property_ty} {{
+{indentation}    {maybe_dispatch_precondition}{call_rust}
+{indentation}}}"#
+            );
+        }
+
         format!(
-            r#"{indentation}{maybe_static_class_func}{swift_class_func_name}{maybe_generics}({params}){maybe_ret} {{
-{indentation}    {call_rust}
+            r#"{indentation}{maybe_attributes}{maybe_discardable_result}{maybe_inlinable}{maybe_static_class_func}{swift_class_func_name}{maybe_generics}({params}){maybe_ret} {{
+{indentation}    {maybe_dispatch_precondition}{maybe_ffi_self}{call_rust}
 {indentation}}}"#,
             indentation = indentation,
+            maybe_attributes = maybe_attributes,
+            maybe_discardable_result = maybe_discardable_result,
+            maybe_inlinable = maybe_inlinable,
+            maybe_dispatch_precondition = maybe_dispatch_precondition,
             maybe_static_class_func = maybe_static_class_func,
             swift_class_func_name = public_func_fn_name,
             maybe_generics = maybe_generics,
@@ -328,5 +673,396 @@ return{maybe_try}await {with_checked_continuation_function_name}({{ (continuatio
         )
     };
 
-    func_definition
+    let func_definition = if takes_swift_data_overload(function, types) && !config.embedded_swift {
+        let data_func = gen_data_overload_func(
+            function,
+            &format!(
+                "{}{}{}{}",
+                maybe_attributes,
+                maybe_discardable_result,
+                maybe_static_class_func,
+                public_func_fn_name
+            ),
+            &maybe_return,
+            &maybe_generics,
+            &params,
+            types,
+            config,
+        );
+        format!("{}\n{}", func_definition, data_func)
+    } else {
+        func_definition
+    };
+
+    let func_definition = if returns_optional_opaque_ref_mut(function, types) {
+        let closure_func = gen_optional_ref_mut_closure_overload_func(
+            function,
+            &format!(
+                "{}{}{}",
+                maybe_attributes, maybe_static_class_func, public_func_fn_name
+            ),
+            &maybe_generics,
+            &params,
+            types,
+            swift_bridge_path,
+            config,
+        );
+        format!("{}\n{}", func_definition, closure_func)
+    } else {
+        func_definition
+    };
+
+    let func_definition = if function.returns_borrowed_view_of_self() {
+        let closure_func = gen_borrowed_view_closure_overload_func(
+            function,
+            &format!("{}{}", maybe_attributes, public_func_fn_name),
+            &maybe_generics,
+            &params,
+            &maybe_return,
+            config,
+        );
+        format!("{}\n{}", func_definition, closure_func)
+    } else {
+        func_definition
+    };
+
+    if returns_result
+        && !function.is_swift_initializer
+        && config.swift_result_functions.generates_result_returning()
+    {
+        let result_func = gen_result_returning_func(
+            function,
+            &maybe_return,
+            &format!(
+                "{}{}{}{}{}",
+                maybe_spi,
+                maybe_available,
+                maybe_discardable_result,
+                maybe_static_class_func,
+                maybe_mutating
+            ),
+            &maybe_generics,
+            &params,
+            indentation,
+            config,
+        );
+        format!("{}\n{}", func_definition, result_func)
+    } else {
+        func_definition
+    }
+}
+
+/// Whether or not to mark the Swift function `@inlinable`.
+///
+/// Only functions that pass primitive values straight to and from the Rust function are
+/// inlinable, since an `@inlinable` body can only use public or `@usableFromInline` declarations.
+fn is_inlinable(
+    function: &ParsedExternFn,
+    types: &TypeDeclarations,
+    config: &CodegenConfig,
+) -> bool {
+    if !config.inlinable_accessors
+        || !function.host_lang.is_rust()
+        || function.sig.asyncness.is_some()
+        || function.is_swift_initializer
+    {
+        return false;
+    }
+
+    // Methods that take an owned `self` update the class's internal `isOwned` property.
+    if function.is_method() && function.self_reference().is_none() {
+        return false;
+    }
+
+    match function.associated_type.as_ref() {
+        None => {}
+        Some(TypeDeclaration::Opaque(ty))
+            if ty.generics.is_empty()
+                && ty.attributes.copy.is_none()
+                && ty.attributes.dispatch_queue.is_none() => {}
+        Some(_) => return false,
+    }
+
+    let is_primitive = |ty: Option<BridgedType>| {
+        matches!(
+            ty,
+            Some(BridgedType::StdLib(
+                StdLibType::Null
+                    | StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+                    | StdLibType::F32
+                    | StdLibType::F64
+                    | StdLibType::Bool
+            ))
+        )
+    };
+
+    let args_are_primitive = function
+        .func
+        .sig
+        .inputs
+        .iter()
+        .filter(|arg| match arg {
+            FnArg::Receiver(_) => false,
+            FnArg::Typed(pat_ty) => !pat_type_pat_is_self(pat_ty),
+        })
+        .all(|arg| is_primitive(BridgedType::new_with_fn_arg(arg, types)));
+
+    args_are_primitive
+        && is_primitive(BridgedType::new_with_return_type(
+            &function.func.sig.output,
+            types,
+        ))
+}
+
+/// Whether or not we generate an overload of a Rust function that takes a Swift `Data` in place
+/// of each of the function's `&[u8]` arguments.
+pub(super) fn takes_swift_data_overload(
+    function: &ParsedExternFn,
+    types: &TypeDeclarations,
+) -> bool {
+    if !function.host_lang.is_rust()
+        || function.sig.asyncness.is_some()
+        || function.is_swift_initializer
+    {
+        return false;
+    }
+
+    function.func.sig.inputs.iter().any(|arg| {
+        matches!(
+            BridgedType::new_with_fn_arg(arg, types),
+            Some(BridgedType::StdLib(StdLibType::RefSlice(slice))) if slice.is_byte_slice()
+        )
+    })
+}
+
+/// Generate a Swift function that takes a `Data` in place of each `&[u8]` argument and calls the
+/// `UnsafeBufferPointer<UInt8>` taking Swift function.
+///
+/// The `Data`'s bytes are borrowed using `withUnsafeBytes` instead of being copied, so Rust must
+/// not hold on to the slice after the function returns.
+fn gen_data_overload_func(
+    function: &ParsedExternFn,
+    func_name_with_keywords: &str,
+    return_ty: &str,
+    maybe_generics: &str,
+    params: &str,
+    types: &TypeDeclarations,
+    config: &CodegenConfig,
+) -> String {
+    let indentation = if function.associated_type.is_some() {
+        "    "
+    } else {
+        ""
+    };
+
+    let byte_slice_args: Vec<String> = function
+        .func
+        .sig
+        .inputs
+        .iter()
+        .filter(|arg| {
+            matches!(
+                BridgedType::new_with_fn_arg(arg, types),
+                Some(BridgedType::StdLib(StdLibType::RefSlice(slice))) if slice.is_byte_slice()
+            )
+        })
+        .map(|arg| fn_arg_name(arg).unwrap().to_string())
+        .collect();
+
+    let maybe_try = if return_ty.starts_with(" throws") {
+        "try "
+    } else {
+        ""
+    };
+
+    let call_args = function.to_swift_forwarding_call_args_mapped(|arg_name| {
+        if byte_slice_args.iter().any(|arg| arg == arg_name) {
+            format!("{}AsRawBuffer.bindMemory(to: UInt8.self)", arg_name)
+        } else {
+            arg_name.to_string()
+        }
+    });
+    let mut body = format!(
+        "{maybe_try}{fn_name}({call_args})",
+        fn_name = function.swift_fn_name(config),
+    );
+    for arg in byte_slice_args.iter().rev() {
+        body = format!(
+            "{maybe_try}{arg}.withUnsafeBytes({{ {arg}AsRawBuffer in {body} }})",
+            maybe_try = maybe_try,
+            arg = arg,
+            body = body
+        );
+    }
+
+    let params = params.replace(": UnsafeBufferPointer<UInt8>", ": Data");
+
+    format!(
+        r#"{indentation}{func_name_with_keywords}{maybe_generics}({params}){return_ty} {{
+{indentation}    return {body}
+{indentation}}}"#,
+    )
+}
+
+/// Whether or not we generate an overload of a Rust function that returns an `Option<&mut T>`,
+/// where `T` is an opaque Rust type, that passes the reference to a closure.
+fn returns_optional_opaque_ref_mut(function: &ParsedExternFn, types: &TypeDeclarations) -> bool {
+    if !function.host_lang.is_rust()
+        || function.sig.asyncness.is_some()
+        || function.is_swift_initializer
+        || function.is_swift_shared_instance
+    {
+        return false;
+    }
+
+    function
+        .return_ty_built_in(types)
+        .map(|ty| {
+            ty.as_option()
+                .map(|option| option.ty.is_opaque_rust_type_ref_mut())
+                .unwrap_or(false)
+        })
+        .unwrap_or(false)
+}
+
+/// Generate a Swift function that takes a closure in place of returning an
+/// `Optional<SomeTypeRefMut>`.
+///
+/// The closure is only called when Rust returns a reference, so the reference can't outlive the
+/// scope that mutates through it.
+///
+/// ```swift
+/// @discardableResult
+/// public func get_mut<R>(_ key: UInt32, _ body: (ValueRefMut) throws -> R) rethrows -> Optional<R> {
+///     if let value = get_mut(key) { return try body(value) } else { return nil }
+/// }
+/// ```
+fn gen_optional_ref_mut_closure_overload_func(
+    function: &ParsedExternFn,
+    func_name_with_keywords: &str,
+    maybe_generics: &str,
+    params: &str,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+    config: &CodegenConfig,
+) -> String {
+    let indentation = if function.associated_type.is_some() {
+        "    "
+    } else {
+        ""
+    };
+
+    let return_ty = function.return_ty_built_in(types).unwrap();
+    let ref_mut_ty = return_ty.as_option().unwrap().ty.to_swift_type(
+        TypePosition::FnReturn(HostLang::Rust),
+        types,
+        swift_bridge_path,
+    );
+
+    let generics = match maybe_generics.strip_suffix('>') {
+        Some(generics) => format!("{}, R>", generics),
+        None => "<R>".to_string(),
+    };
+    let params = if params.is_empty() {
+        format!("_ body: ({}) throws -> R", ref_mut_ty)
+    } else {
+        format!("{}, _ body: ({}) throws -> R", params, ref_mut_ty)
+    };
+
+    format!(
+        r#"{indentation}@discardableResult
+{indentation}{func_name_with_keywords}{generics}({params}) rethrows -> Optional<R> {{
+{indentation}    if let value = {fn_name}({call_args}) {{ return try body(value) }} else {{ return nil }}
+{indentation}}}"#,
+        fn_name = function.swift_fn_name(config),
+        call_args = function.to_swift_forwarding_call_args(),
+    )
+}
+
+/// Generate an overload of a method that returns a view that borrows from `self`, which passes the
+/// view to a closure and keeps `self` alive until the closure returns.
+///
+/// For example, `func title() -> RustStr` gets a
+/// `func title<R>(_ body: (RustStr) throws -> R) rethrows -> R` overload.
+fn gen_borrowed_view_closure_overload_func(
+    function: &ParsedExternFn,
+    func_name_with_keywords: &str,
+    maybe_generics: &str,
+    params: &str,
+    maybe_return: &str,
+    config: &CodegenConfig,
+) -> String {
+    let indentation = "    ";
+
+    let view_ty = maybe_return.trim_start_matches(" -> ");
+    let generics = match maybe_generics.strip_suffix('>') {
+        Some(generics) => format!("{}, R>", generics),
+        None => "<R>".to_string(),
+    };
+    let params = if params.is_empty() {
+        format!("_ body: ({}) throws -> R", view_ty)
+    } else {
+        format!("{}, _ body: ({}) throws -> R", params, view_ty)
+    };
+
+    let get_view = match function.swift_getter_property_name(config) {
+        Some(property_name) => format!("self.{}", property_name),
+        None => format!(
+            "self.{}({})",
+            function.swift_fn_name(config),
+            function.to_swift_forwarding_call_args()
+        ),
+    };
+
+    format!(
+        r#"{indentation}{func_name_with_keywords}{generics}({params}) rethrows -> R {{
+{indentation}    try withExtendedLifetime(self) {{ try body({get_view}) }}
+{indentation}}}"#
+    )
+}
+
+/// Generate a Swift function that calls a throwing Swift function and returns its outcome as a
+/// `Result<T, Error>`.
+fn gen_result_returning_func(
+    function: &ParsedExternFn,
+    throwing_return_ty: &str,
+    maybe_static_class_func: &str,
+    maybe_generics: &str,
+    params: &str,
+    indentation: &str,
+    config: &CodegenConfig,
+) -> String {
+    let fn_name = function.swift_fn_name(config);
+    let result_fn_name = SwiftResultFunctions::result_returning_fn_name(&fn_name);
+
+    let ok_ty = throwing_return_ty
+        .trim_start_matches(" throws")
+        .trim_start_matches(" -> ");
+    let ok_ty = if ok_ty.is_empty() { "()" } else { ok_ty };
+
+    let (maybe_async, maybe_await) = if function.sig.asyncness.is_some() {
+        (" async", "await ")
+    } else {
+        ("", "")
+    };
+
+    format!(
+        r#"{indentation}{maybe_static_class_func}public func {result_fn_name}{maybe_generics}({params}){maybe_async} -> Result<{ok_ty}, Error> {{
+{indentation}    do {{
+{indentation}        return .success(try {maybe_await}{fn_name}({call_args}))
+{indentation}    }} catch {{
+{indentation}        return .failure(error)
+{indentation}    }}
+{indentation}}}"#,
+        call_args = function.to_swift_forwarding_call_args(),
+    )
 }
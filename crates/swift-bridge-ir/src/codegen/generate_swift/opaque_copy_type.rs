@@ -1,4 +1,6 @@
+use crate::codegen::generate_swift::ffi_layout_docs::opaque_rust_type_ffi_layout_docs;
 use crate::codegen::generate_swift::generate_swift_class_methods;
+use crate::codegen::CodegenConfig;
 use crate::parse::OpaqueForeignTypeDeclaration;
 use crate::{ParsedExternFn, TypeDeclarations, SWIFT_BRIDGE_PREFIX};
 use std::collections::HashMap;
@@ -9,6 +11,7 @@ pub(super) fn generate_opaque_copy_struct(
     associated_funcs_and_methods: &HashMap<String, Vec<&ParsedExternFn>>,
     types: &TypeDeclarations,
     swift_bridge_path: &Path,
+    config: &CodegenConfig,
 ) -> String {
     let type_name = &ty.ty.to_string();
 
@@ -17,22 +20,29 @@ pub(super) fn generate_opaque_copy_struct(
         associated_funcs_and_methods,
         types,
         swift_bridge_path,
+        config,
     );
 
     let mut extensions = "".to_string();
 
-    append_methods_extension(&mut extensions, type_name, &class_methods.initializers);
-    append_methods_extension(
-        &mut extensions,
-        type_name,
-        &class_methods.owned_self_methods,
-    );
-    append_methods_extension(&mut extensions, type_name, &class_methods.ref_self_methods);
-
-    if class_methods.owned_self_methods.len() > 0 {};
+    for class_methods in class_methods.iter() {
+        append_methods_extension(&mut extensions, type_name, &class_methods.initializers);
+        append_methods_extension(
+            &mut extensions,
+            type_name,
+            &class_methods.owned_self_methods,
+        );
+        append_methods_extension(&mut extensions, type_name, &class_methods.ref_self_methods);
+    }
 
     let struct_definition = if !ty.attributes.already_declared {
-        generate_struct_definition(ty, types, swift_bridge_path)
+        let maybe_ffi_layout_docs = if config.ffi_layout_docs {
+            opaque_rust_type_ffi_layout_docs(ty)
+        } else {
+            "".to_string()
+        };
+
+        maybe_ffi_layout_docs + &generate_struct_definition(ty, types, swift_bridge_path)
     } else {
         "".to_string()
     };
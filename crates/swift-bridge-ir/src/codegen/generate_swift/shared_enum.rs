@@ -1,9 +1,17 @@
-use crate::bridged_type::{BridgedType, SharedEnum, StructFields, TypePosition};
+use crate::bridged_type::shared_struct::NormalizedStructFieldAccessor;
+use crate::bridged_type::{
+    swift_field_equality, BridgedType, SharedEnum, StructFields, TypePosition,
+};
 use crate::SwiftBridgeModule;
+use quote::ToTokens;
 
 impl SwiftBridgeModule {
     /// Generate the tokens for a shared enum.
-    pub(super) fn generate_shared_enum_string(&self, shared_enum: &SharedEnum) -> Option<String> {
+    pub(super) fn generate_shared_enum_string(
+        &self,
+        shared_enum: &SharedEnum,
+        embedded_swift: bool,
+    ) -> Option<String> {
         if shared_enum.already_declared {
             return None;
         }
@@ -17,6 +25,11 @@ impl SwiftBridgeModule {
         let mut convert_ffi_repr_to_swift = "\n".to_string();
         let all_variants_empty = shared_enum.all_variants_empty();
         for variant in shared_enum.variants.iter() {
+            let maybe_indirect = if variant.is_indirect(&self.types) {
+                "indirect "
+            } else {
+                ""
+            };
             let v = match &variant.fields {
                 StructFields::Named(named_fields) => {
                     let mut params = vec![];
@@ -33,7 +46,7 @@ impl SwiftBridgeModule {
                     let params = params.join(", ");
                     format!(
                         r#"
-    case {name}({params})"#,
+    {maybe_indirect}case {name}({params})"#,
                         name = variant.name,
                         params = params,
                     )
@@ -53,18 +66,27 @@ impl SwiftBridgeModule {
                     let params = params.join(", ");
                     format!(
                         r#"
-    case {name}({params})"#,
+    {maybe_indirect}case {name}({params})"#,
                         name = variant.name,
                         params = params,
                     )
                 }
-                StructFields::Unit => {
-                    format!(
-                        r#"
+                StructFields::Unit => match variant.integer_discriminant() {
+                    Some(raw_value) if shared_enum.has_swift_raw_values() => {
+                        format!(
+                            r#"
+    case {name} = {raw_value}"#,
+                            name = variant.name
+                        )
+                    }
+                    _ => {
+                        format!(
+                            r#"
     case {name}"#,
-                        name = variant.name
-                    )
-                }
+                            name = variant.name
+                        )
+                    }
+                },
             };
             variants += &v;
         }
@@ -101,7 +123,7 @@ impl SwiftBridgeModule {
             );
         }
 
-        let vectorizable_impl = if shared_enum.has_one_or_more_variants_with_data() {
+        let vectorizable_impl = if !shared_enum.supports_vec() {
             "".to_string()
         } else {
             format!(
@@ -141,6 +163,10 @@ extension {enum_name}: Vectorizable {{
     public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {{
         __swift_bridge__$Vec_{enum_name}$len(vecPtr)
     }}
+
+    public static func vecOfSelfCapacity(vecPtr: UnsafeMutableRawPointer) -> UInt {{
+        __swift_bridge__$Vec_{enum_name}$capacity(vecPtr)
+    }}
 }}"#
             )
         };
@@ -159,8 +185,114 @@ extension {enum_name}: CustomDebugStringConvertible {{
                 "".to_string()
             };
 
+        // Swift only synthesizes `Equatable` for enums without data.
+        let equatable_impl =
+            if shared_enum.derive.partial_eq && shared_enum.has_one_or_more_variants_with_data() {
+                self.generate_shared_enum_equatable_string(shared_enum)
+            } else {
+                "".to_string()
+            };
+
+        let raw_type = if shared_enum.has_swift_raw_values() {
+            ": Int"
+        } else {
+            ""
+        };
+        let maybe_objc = if shared_enum.objc { "@objc " } else { "" };
+        // `allCases` lists the variants in the order that they were declared in Rust.
+        let case_iterable_impl = if shared_enum.case_iterable {
+            let all_cases = shared_enum
+                .variants
+                .iter()
+                .map(|variant| format!(".{}", variant.name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                r#"
+extension {enum_name}: CaseIterable {{
+    public static var allCases: [{enum_name}] {{
+        [{all_cases}]
+    }}
+}}"#
+            )
+        } else {
+            "".to_string()
+        };
+
+        // Each case of a unit-only enum is unique and Swift synthesizes its `Hashable`
+        // conformance, so the case itself can be the `id`.
+        let identifiable_impl = if shared_enum.identifiable {
+            format!(
+                r#"
+extension {enum_name}: Identifiable {{
+    public var id: {enum_name} {{
+        self
+    }}
+}}"#
+            )
+        } else {
+            "".to_string()
+        };
+        let comparable_impl = if shared_enum.comparable {
+            format!(
+                r#"
+extension {enum_name}: Comparable {{
+    public static func < (lhs: {enum_name}, rhs: {enum_name}) -> Bool {{
+        lhs.rawValue < rhs.rawValue
+    }}
+}}"#
+            )
+        } else {
+            "".to_string()
+        };
+
+        let from_str_impl = if shared_enum.from_str {
+            format!(
+                r#"
+extension {enum_name} {{
+    public init?(_ rawString: String) {{
+        let parsed = rawString.toRustStr({{ rawStringAsRustStr in
+            {ffi_repr_name}$from_str(rawStringAsRustStr)
+        }}).intoSwiftRepr()
+        guard let parsed = parsed else {{
+            return nil
+        }}
+        self = parsed
+    }}
+}}"#,
+                ffi_repr_name = shared_enum.ffi_name_string(),
+            )
+        } else {
+            "".to_string()
+        };
+
+        let option_set_impl = if shared_enum.option_set {
+            self.generate_shared_enum_option_set_string(shared_enum)
+        } else {
+            "".to_string()
+        };
+
+        let custom_string_convertible_impl = if shared_enum.custom_string_convertible {
+            self.generate_shared_enum_custom_string_convertible_string(shared_enum)
+        } else {
+            "".to_string()
+        };
+
+        let hybrid_raw_value_impl = if shared_enum.has_hybrid_raw_values() {
+            self.generate_shared_enum_hybrid_raw_value_string(shared_enum)
+        } else {
+            "".to_string()
+        };
+
+        let custom_ns_error_impl = match shared_enum.error_domain.as_ref() {
+            Some(error_domain) if !embedded_swift => {
+                self.generate_shared_enum_custom_ns_error_string(shared_enum, &error_domain.value())
+            }
+            _ => "".to_string(),
+        };
+
         let swift_enum = format!(
-            r#"public enum {enum_name} {{{variants}}}
+            r#"{maybe_objc}public enum {enum_name}{raw_type} {{{variants}}}
 extension {enum_name} {{
     func intoFfiRepr() -> {ffi_repr_name} {{
         switch self {{{convert_swift_to_ffi_repr}}}
@@ -188,7 +320,7 @@ extension {option_ffi_name} {{
             return {option_ffi_name}(is_some: false, val: {ffi_repr_name}())
         }}
     }}
-}}{vectorizable_impl}{derive_debug_impl}"#,
+}}{vectorizable_impl}{derive_debug_impl}{equatable_impl}{case_iterable_impl}{identifiable_impl}{comparable_impl}{from_str_impl}{option_set_impl}{custom_string_convertible_impl}{hybrid_raw_value_impl}{custom_ns_error_impl}"#,
             enum_name = enum_name,
             enum_ffi_name = enum_ffi_name,
             option_ffi_name = option_ffi_name,
@@ -200,4 +332,278 @@ extension {option_ffi_name} {{
 
         Some(swift_enum)
     }
+
+    /// Generate an `Equatable` conformance that compares the variants and then each of their
+    /// fields.
+    fn generate_shared_enum_equatable_string(&self, shared_enum: &SharedEnum) -> String {
+        let enum_name = shared_enum.swift_name_string();
+
+        let mut cases = "".to_string();
+        for variant in shared_enum.variants.iter() {
+            let fields = variant.fields.normalized_fields();
+            if fields.is_empty() {
+                cases += &format!(
+                    r#"
+        case (.{name}, .{name}):
+            return true"#,
+                    name = variant.name
+                );
+                continue;
+            }
+
+            let lhs_fields: Vec<String> = (0..fields.len()).map(|i| format!("lhs{i}")).collect();
+            let rhs_fields: Vec<String> = (0..fields.len()).map(|i| format!("rhs{i}")).collect();
+            let comparisons: Vec<String> = fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    swift_field_equality(&field.ty, &lhs_fields[i], &rhs_fields[i], &self.types)
+                        .unwrap()
+                })
+                .collect();
+
+            cases += &format!(
+                r#"
+        case let (.{name}({lhs_fields}), .{name}({rhs_fields})):
+            return {comparisons}"#,
+                name = variant.name,
+                lhs_fields = lhs_fields.join(", "),
+                rhs_fields = rhs_fields.join(", "),
+                comparisons = comparisons.join(" && "),
+            );
+        }
+        if shared_enum.variants.len() > 1 {
+            cases += r#"
+        default:
+            return false"#;
+        }
+
+        format!(
+            r#"
+extension {enum_name}: Equatable {{
+    public static func == (lhs: {enum_name}, rhs: {enum_name}) -> Bool {{
+        switch (lhs, rhs) {{{cases}
+        }}
+    }}
+}}"#
+        )
+    }
+
+    /// Generate a `CustomStringConvertible` conformance whose `description` is the variant's name
+    /// followed by its fields, such as `Circle(radius: 2.0)`.
+    fn generate_shared_enum_custom_string_convertible_string(
+        &self,
+        shared_enum: &SharedEnum,
+    ) -> String {
+        let enum_name = shared_enum.swift_name_string();
+
+        let mut cases = "".to_string();
+        for variant in shared_enum.variants.iter() {
+            let fields = variant.fields.normalized_fields();
+            if fields.is_empty() {
+                cases += &format!(
+                    r#"
+        case .{name}:
+            return "{name}""#,
+                    name = variant.name
+                );
+                continue;
+            }
+
+            let bindings: Vec<String> = (0..fields.len()).map(|i| format!("value{i}")).collect();
+            let values: Vec<String> = fields
+                .iter()
+                .zip(bindings.iter())
+                .map(|(field, binding)| {
+                    let swift_ty = BridgedType::new_with_type(&field.ty, &self.types)
+                        .unwrap()
+                        .to_swift_type(
+                            TypePosition::SharedStructField,
+                            &self.types,
+                            &self.swift_bridge_path,
+                        );
+
+                    // Strings are quoted, as they are in Swift's default description of an enum.
+                    let value = if swift_ty == "RustString" {
+                        format!(r#"\(String(reflecting: {binding}.toString()))"#)
+                    } else {
+                        format!(r#"\({binding})"#)
+                    };
+
+                    match &field.accessor {
+                        NormalizedStructFieldAccessor::Named(name) => format!("{name}: {value}"),
+                        NormalizedStructFieldAccessor::Unnamed(_) => value,
+                    }
+                })
+                .collect();
+
+            cases += &format!(
+                r#"
+        case let .{name}({bindings}):
+            return "{name}({values})""#,
+                name = variant.name,
+                bindings = bindings.join(", "),
+                values = values.join(", "),
+            );
+        }
+
+        format!(
+            r#"
+extension {enum_name}: CustomStringConvertible {{
+    public var description: String {{
+        switch self {{{cases}
+        }}
+    }}
+}}"#
+        )
+    }
+
+    /// Generate a Swift `OptionSet` whose flags are the variants of an `option_set` enum.
+    ///
+    /// The generated `init(rawValue:)` keeps every bit that it is given, including bits that do
+    /// not correspond to a variant, as per Swift's `OptionSet` semantics.
+    fn generate_shared_enum_option_set_string(&self, shared_enum: &SharedEnum) -> String {
+        let enum_name = shared_enum.swift_name_string();
+        let option_set_name = shared_enum.swift_option_set_name_string();
+
+        let mut flag_cases = "".to_string();
+        let mut static_flags = "".to_string();
+        for variant in shared_enum.variants.iter() {
+            let raw_value = variant
+                .discriminant
+                .as_ref()
+                .map(|d| d.to_token_stream().to_string())
+                .unwrap_or_default();
+
+            flag_cases += &format!(
+                r#"
+        case {enum_name}.{variant_name}:
+            self.init(rawValue: {raw_value})"#,
+                variant_name = variant.name,
+            );
+            static_flags += &format!(
+                r#"
+    public static let {variant_name} = {option_set_name}(rawValue: {raw_value})"#,
+                variant_name = variant.name,
+            );
+        }
+
+        format!(
+            r#"
+public struct {option_set_name}: OptionSet {{
+    public let rawValue: UInt32
+
+    public init(rawValue: UInt32) {{
+        self.rawValue = rawValue
+    }}
+
+    public init(_ flag: {enum_name}) {{
+        switch flag {{{flag_cases}
+        }}
+    }}
+{static_flags}
+}}"#
+        )
+    }
+
+    /// Generate a `rawValue` property and an `init?(rawValue:)` for an enum that mixes unit
+    /// variants that have discriminants with variants that hold data.
+    ///
+    /// Unit variants without a discriminant use the previous variant's discriminant plus one,
+    /// following Rust's rules. Variants that hold data have no raw value.
+    fn generate_shared_enum_hybrid_raw_value_string(&self, shared_enum: &SharedEnum) -> String {
+        let enum_name = shared_enum.swift_name_string();
+
+        let mut raw_value_cases = "".to_string();
+        let mut init_cases = "".to_string();
+        let mut next = Some(0);
+        for variant in shared_enum.variants.iter() {
+            let raw_value = match &variant.discriminant {
+                Some(_) => variant.integer_discriminant(),
+                None => next,
+            };
+            next = raw_value.and_then(|raw_value| raw_value.checked_add(1));
+
+            if !variant.fields.is_empty() {
+                continue;
+            }
+            // We can't evaluate discriminants that aren't integer literals, such as constants.
+            let raw_value = match raw_value {
+                Some(raw_value) => raw_value,
+                None => continue,
+            };
+
+            raw_value_cases += &format!(
+                r#"
+        case .{name}:
+            return {raw_value}"#,
+                name = variant.name
+            );
+            init_cases += &format!(
+                r#"
+        case {raw_value}:
+            self = .{name}"#,
+                name = variant.name
+            );
+        }
+
+        format!(
+            r#"
+extension {enum_name} {{
+    public var rawValue: Int? {{
+        switch self {{{raw_value_cases}
+        default:
+            return nil
+        }}
+    }}
+
+    public init?(rawValue: Int) {{
+        switch rawValue {{{init_cases}
+        default:
+            return nil
+        }}
+    }}
+}}"#
+        )
+    }
+
+    /// Generate a `CustomNSError` conformance for an `error_domain` enum.
+    ///
+    /// Each variant's error code is its integer discriminant, or the previous variant's code plus
+    /// one, following Rust's rules for implicit discriminants.
+    fn generate_shared_enum_custom_ns_error_string(
+        &self,
+        shared_enum: &SharedEnum,
+        error_domain: &str,
+    ) -> String {
+        let enum_name = shared_enum.swift_name_string();
+
+        let mut cases = "".to_string();
+        let mut next_code = 0;
+        for variant in shared_enum.variants.iter() {
+            let code = variant.integer_discriminant().unwrap_or(next_code);
+            next_code = code + 1;
+
+            cases += &format!(
+                r#"
+        case .{name}:
+            return {code}"#,
+                name = variant.name
+            );
+        }
+
+        format!(
+            r#"
+extension {enum_name}: CustomNSError {{
+    public static var errorDomain: String {{
+        "{error_domain}"
+    }}
+
+    public var errorCode: Int {{
+        switch self {{{cases}
+        }}
+    }}
+}}"#
+        )
+    }
 }
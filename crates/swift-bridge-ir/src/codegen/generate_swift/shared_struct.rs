@@ -1,12 +1,107 @@
 use crate::bridged_type::shared_struct::StructField;
-use crate::bridged_type::{BridgedType, SharedStruct, StructFields, StructSwiftRepr, TypePosition};
+use crate::bridged_type::{
+    BridgeableType, BridgedType, CustomBridgedType, SharedStruct, SharedType, StdLibType,
+    StructFields, StructSwiftRepr, TypePosition,
+};
 use crate::SwiftBridgeModule;
 
 impl SwiftBridgeModule {
+    /// Generate the `toBytes()` and `init?(bytes:)` helpers for a `#[swift_bridge(bytes)]` struct.
+    ///
+    /// The fields are written one after another in declaration order and native byte order, so
+    /// the bytes never contain padding. A `Bool` is written as a single `0` or `1` byte, and
+    /// `init?(bytes:)` returns `nil` for any other value since untrusted bytes could otherwise
+    /// produce an invalid `Bool`.
+    fn generate_bytes_helpers(&self, shared_struct: &SharedStruct) -> String {
+        let struct_name = shared_struct.swift_name_string();
+
+        let mut sizes = vec![];
+        let mut write_fields = "".to_string();
+        let mut read_fields = "".to_string();
+        let mut assign_fields = "".to_string();
+
+        for (idx, field) in shared_struct.fields.normalized_fields().iter().enumerate() {
+            let name = &field.swift_name;
+            let local = format!("field{}", idx);
+            let bridged_type = BridgedType::new_with_type(&field.ty, &self.types).unwrap();
+
+            if matches!(bridged_type, BridgedType::StdLib(StdLibType::Bool)) {
+                sizes.push("1".to_string());
+                write_fields += &format!(
+                    r#"
+        bytes.append(self.{name} ? 1 : 0)"#
+                );
+                read_fields += &format!(
+                    r#"
+        let {local}Byte = bytes[offset]
+        guard {local}Byte <= 1 else {{
+            return nil
+        }}
+        let {local} = {local}Byte == 1
+        offset += 1
+"#
+                );
+            } else {
+                let swift_ty = bridged_type.to_swift_type(
+                    TypePosition::SharedStructField,
+                    &self.types,
+                    &self.swift_bridge_path,
+                );
+                sizes.push(format!("MemoryLayout<{swift_ty}>.size"));
+                write_fields += &format!(
+                    r#"
+        withUnsafeBytes(of: self.{name}) {{ bytes.append(contentsOf: $0) }}"#
+                );
+                read_fields += &format!(
+                    r#"
+        var {local}: {swift_ty} = 0
+        _ = withUnsafeMutableBytes(of: &{local}) {{
+            bytes[offset..<offset + MemoryLayout<{swift_ty}>.size].copyBytes(to: $0)
+        }}
+        offset += MemoryLayout<{swift_ty}>.size
+"#
+                );
+            }
+
+            assign_fields += &format!(
+                r#"
+        self.{name} = {local}"#
+            );
+        }
+
+        let size = if sizes.is_empty() {
+            "0".to_string()
+        } else {
+            sizes.join(" + ")
+        };
+        let maybe_offset = if read_fields.is_empty() {
+            ""
+        } else {
+            "\n        var offset = bytes.startIndex\n"
+        };
+
+        format!(
+            r#"extension {struct_name} {{
+    public func toBytes() -> Data {{
+        var bytes = Data(){write_fields}
+        return bytes
+    }}
+
+    public init?(bytes: Data) {{
+        guard bytes.count == {size} else {{
+            return nil
+        }}
+{maybe_offset}{read_fields}{assign_fields}
+    }}
+}}"#
+        )
+    }
+
     /// Generate the tokens for a shared struct.
     pub(super) fn generate_shared_struct_string(
         &self,
         shared_struct: &SharedStruct,
+        embedded_swift: bool,
     ) -> Option<String> {
         if shared_struct.already_declared {
             return None;
@@ -96,6 +191,214 @@ extension {option_ffi_name} {{
                     convert_ffi_repr_to_swift = convert_ffi_repr_to_swift
                 );
 
+                let raw_value_initializer = match &shared_struct.fields {
+                    StructFields::Named(named) => self.generate_raw_value_initializer(named),
+                    StructFields::Unnamed(unnamed) => self.generate_raw_value_initializer(unnamed),
+                    StructFields::Unit => None,
+                };
+                let swift_struct = if let Some(raw_value_initializer) = raw_value_initializer {
+                    format!(
+                        r#"{swift_struct}
+extension {struct_name} {{{raw_value_initializer}
+}}"#
+                    )
+                } else {
+                    swift_struct
+                };
+
+                let swift_struct = if shared_struct.custom_reflectable {
+                    let mirror_children = match &shared_struct.fields {
+                        StructFields::Named(named) => self.convert_fields_to_mirror_children(named),
+                        StructFields::Unnamed(unnamed) => {
+                            self.convert_fields_to_mirror_children(unnamed)
+                        }
+                        StructFields::Unit => ":".to_string(),
+                    };
+
+                    format!(
+                        r#"{swift_struct}
+extension {struct_name}: CustomReflectable {{
+    public var customMirror: Mirror {{
+        Mirror(self, children: [{mirror_children}])
+    }}
+}}"#
+                    )
+                } else {
+                    swift_struct
+                };
+
+                let swift_struct = if shared_struct.derives.hash {
+                    let (equal_fields, hash_fields) = match &shared_struct.fields {
+                        StructFields::Named(named) => self.convert_fields_to_hashable(named),
+                        StructFields::Unnamed(unnamed) => self.convert_fields_to_hashable(unnamed),
+                        StructFields::Unit => ("true".to_string(), "".to_string()),
+                    };
+
+                    format!(
+                        r#"{swift_struct}
+extension {struct_name}: Hashable {{
+    public static func == (lhs: {struct_name}, rhs: {struct_name}) -> Bool {{
+        {equal_fields}
+    }}
+
+    public func hash(into hasher: inout Hasher) {{{hash_fields}}}
+}}"#
+                    )
+                } else {
+                    swift_struct
+                };
+
+                let swift_struct = if shared_struct.comparable {
+                    let names: Vec<String> = shared_struct
+                        .fields
+                        .normalized_fields()
+                        .iter()
+                        .map(|field| field.swift_name.clone())
+                        .collect();
+                    let comparable_body = generate_lexicographic_less_than(&names);
+
+                    // `Hashable` already implements the `==` that `Comparable` requires.
+                    let equal_impl = if shared_struct.derives.hash {
+                        "".to_string()
+                    } else {
+                        let equal_fields = if names.is_empty() {
+                            "true".to_string()
+                        } else {
+                            names
+                                .iter()
+                                .map(|name| format!("lhs.{name} == rhs.{name}"))
+                                .collect::<Vec<_>>()
+                                .join(" && ")
+                        };
+
+                        format!(
+                            r#"
+    public static func == (lhs: {struct_name}, rhs: {struct_name}) -> Bool {{
+        {equal_fields}
+    }}
+"#
+                        )
+                    };
+
+                    format!(
+                        r#"{swift_struct}
+extension {struct_name}: Comparable {{{equal_impl}
+    public static func < (lhs: {struct_name}, rhs: {struct_name}) -> Bool {{
+{comparable_body}
+    }}
+}}"#
+                    )
+                } else {
+                    swift_struct
+                };
+
+                let swift_struct = if shared_struct.bytes && !embedded_swift {
+                    format!(
+                        "{swift_struct}\n{}",
+                        self.generate_bytes_helpers(shared_struct)
+                    )
+                } else {
+                    swift_struct
+                };
+
+                let swift_struct =
+                    if shared_struct.localized_error.is_localized_error() && !embedded_swift {
+                        let fields = shared_struct.fields.normalized_fields();
+                        let localized_error = &shared_struct.localized_error;
+
+                        let mut properties = vec![];
+                        for (property, field_idx) in [
+                            ("errorDescription", localized_error.error_description),
+                            ("failureReason", localized_error.failure_reason),
+                            ("recoverySuggestion", localized_error.recovery_suggestion),
+                        ] {
+                            if let Some(field_idx) = field_idx {
+                                properties.push(format!(
+                                    r#"
+    public var {property}: String? {{
+        self.{field}
+    }}"#,
+                                    field = fields[field_idx].swift_name
+                                ));
+                            }
+                        }
+                        let properties = properties.join("\n");
+
+                        format!(
+                            r#"{swift_struct}
+extension {struct_name}: LocalizedError {{{properties}
+}}"#
+                        )
+                    } else {
+                        swift_struct
+                    };
+
+                let error_domain = shared_struct
+                    .error_domain
+                    .as_ref()
+                    .filter(|_| !embedded_swift);
+                let swift_struct = if let Some(error_domain) = error_domain {
+                    format!(
+                        r#"{swift_struct}
+extension {struct_name}: CustomNSError {{
+    public static var errorDomain: String {{
+        "{error_domain}"
+    }}
+}}"#,
+                        error_domain = error_domain.value()
+                    )
+                } else {
+                    swift_struct
+                };
+
+                let swift_struct = if shared_struct.supports_vec() {
+                    format!(
+                        r#"{swift_struct}
+extension {struct_name}: Vectorizable {{
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {{
+        __swift_bridge__$Vec_{struct_name}$new()
+    }}
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {{
+        __swift_bridge__$Vec_{struct_name}$drop(vecPtr)
+    }}
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: Self) {{
+        __swift_bridge__$Vec_{struct_name}$push(vecPtr, value.intoFfiRepr())
+    }}
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {{
+        let maybeStruct = __swift_bridge__$Vec_{struct_name}$pop(vecPtr)
+        return maybeStruct.intoSwiftRepr()
+    }}
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {{
+        let maybeStruct = __swift_bridge__$Vec_{struct_name}$get(vecPtr, index)
+        return maybeStruct.intoSwiftRepr()
+    }}
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {{
+        let maybeStruct = __swift_bridge__$Vec_{struct_name}$get_mut(vecPtr, index)
+        return maybeStruct.intoSwiftRepr()
+    }}
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<Self> {{
+        UnsafePointer<Self>(OpaquePointer(__swift_bridge__$Vec_{struct_name}$as_ptr(vecPtr)))
+    }}
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {{
+        __swift_bridge__$Vec_{struct_name}$len(vecPtr)
+    }}
+
+    public static func vecOfSelfCapacity(vecPtr: UnsafeMutableRawPointer) -> UInt {{
+        __swift_bridge__$Vec_{struct_name}$capacity(vecPtr)
+    }}
+}}"#
+                    )
+                } else {
+                    swift_struct
+                };
+
                 Some(swift_struct)
             }
         }
@@ -113,14 +416,22 @@ extension {option_ffi_name} {{
         for field in struct_fields.into_iter() {
             let bridged_ty = BridgedType::new_with_type(field.field_type(), &self.types).unwrap();
 
+            // Optional fields default to `nil` so that callers can omit them.
+            let default_value = if bridged_ty.as_option().is_some() {
+                " = nil"
+            } else {
+                ""
+            };
+
             params += &format!(
-                "{}: {},",
+                "{}: {}{},",
                 field.swift_name_string(),
                 bridged_ty.to_swift_type(
                     TypePosition::SharedStructField,
                     &self.types,
                     &self.swift_bridge_path
-                )
+                ),
+                default_value
             );
         }
 
@@ -155,6 +466,112 @@ extension {option_ffi_name} {{
         body
     }
 
+    /// Generate a failable initializer that accepts the raw `Int` value of every field whose type
+    /// is a shared enum with Swift raw values, for interop with integer based APIs.
+    ///
+    /// Returns `None` if the struct does not have any such fields.
+    fn generate_raw_value_initializer<'a, T>(
+        &self,
+        struct_fields: impl IntoIterator<Item = &'a T>,
+    ) -> Option<String>
+    where
+        T: StructField + 'a,
+    {
+        let mut params = vec![];
+        let mut guards = "".to_string();
+        let mut args = vec![];
+
+        for field in struct_fields.into_iter() {
+            let name = field.swift_name_string();
+            let bridged_ty = BridgedType::new_with_type(field.field_type(), &self.types).unwrap();
+
+            match &bridged_ty {
+                BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum)))
+                    if shared_enum.has_swift_raw_values() =>
+                {
+                    params.push(format!("{name}: Int"));
+                    guards += &format!(
+                        r#"
+        guard let {name} = {enum_name}(rawValue: {name}) else {{
+            return nil
+        }}"#,
+                        enum_name = shared_enum.swift_name_string()
+                    );
+                }
+                _ => {
+                    params.push(format!(
+                        "{name}: {}",
+                        bridged_ty.to_swift_type(
+                            TypePosition::SharedStructField,
+                            &self.types,
+                            &self.swift_bridge_path
+                        )
+                    ));
+                }
+            };
+            args.push(format!("{name}: {name}"));
+        }
+
+        if guards.is_empty() {
+            return None;
+        }
+
+        Some(format!(
+            r#"
+    public init?({params}) {{{guards}
+        self.init({args})
+    }}"#,
+            params = params.join(", "),
+            args = args.join(", ")
+        ))
+    }
+
+    fn convert_fields_to_mirror_children<'a, T>(
+        &self,
+        struct_fields: impl IntoIterator<Item = &'a T>,
+    ) -> String
+    where
+        T: StructField + 'a,
+    {
+        let children: Vec<String> = struct_fields
+            .into_iter()
+            .map(|field| {
+                let name = field.swift_name_string();
+                format!("\"{name}\": self.{name} as Any")
+            })
+            .collect();
+
+        children.join(", ")
+    }
+
+    /// Compare and hash every field in declaration order, so that equal structs always have equal
+    /// hashes.
+    ///
+    /// Returns the body of `==` and the body of `hash(into:)`.
+    fn convert_fields_to_hashable<'a, T>(
+        &self,
+        struct_fields: impl IntoIterator<Item = &'a T>,
+    ) -> (String, String)
+    where
+        T: StructField + 'a,
+    {
+        let mut equal_fields = vec![];
+        let mut hash_fields = "".to_string();
+
+        for field in struct_fields.into_iter() {
+            let name = field.swift_name_string();
+
+            equal_fields.push(format!("lhs.{name} == rhs.{name}"));
+            hash_fields += &format!("        hasher.combine(self.{name})\n");
+        }
+
+        if !hash_fields.is_empty() {
+            hash_fields = format!("\n{}    ", hash_fields);
+        }
+
+        (equal_fields.join(" && "), hash_fields)
+    }
+
     fn declare_fields<'a, T>(&self, struct_fields: impl IntoIterator<Item = &'a T>) -> String
     where
         T: StructField + 'a,
@@ -182,3 +599,32 @@ extension {option_ffi_name} {{
         fields
     }
 }
+
+/// The body of a `<` that compares fields in declaration order, moving on to the next field only
+/// when the current fields are equal. This makes the comparison a total order over the fields.
+///
+/// ```swift
+/// if lhs.major != rhs.major {
+///     return lhs.major < rhs.major
+/// }
+/// return lhs.minor < rhs.minor
+/// ```
+fn generate_lexicographic_less_than(field_names: &[String]) -> String {
+    let (last, rest) = match field_names.split_last() {
+        Some(split) => split,
+        None => return "        false".to_string(),
+    };
+
+    let mut body = "".to_string();
+    for name in rest {
+        body += &format!(
+            r#"        if lhs.{name} != rhs.{name} {{
+            return lhs.{name} < rhs.{name}
+        }}
+"#
+        );
+    }
+    body += &format!("        return lhs.{last} < rhs.{last}");
+
+    body
+}
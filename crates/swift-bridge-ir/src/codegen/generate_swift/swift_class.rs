@@ -1,8 +1,12 @@
-use crate::codegen::generate_swift::{generate_swift_class_methods, ClassProtocols};
-use crate::parse::OpaqueForeignTypeDeclaration;
+use crate::bridged_type::{pat_type_pat_is_self, BridgedType, TypePosition};
+use crate::codegen::generate_swift::ffi_layout_docs::opaque_rust_type_ffi_layout_docs;
+use crate::codegen::generate_swift::{generate_swift_class_methods, ClassMethods, ClassProtocols};
+use crate::codegen::CodegenConfig;
+use crate::parse::{OpaqueForeignTypeDeclaration, TypeDeclaration};
 use crate::{ParsedExternFn, TypeDeclarations, SWIFT_BRIDGE_PREFIX};
+use quote::{format_ident, ToTokens};
 use std::collections::HashMap;
-use syn::Path;
+use syn::{FnArg, Path};
 
 pub(super) fn generate_swift_class(
     ty: &OpaqueForeignTypeDeclaration,
@@ -10,6 +14,7 @@ pub(super) fn generate_swift_class(
     class_protocols: &ClassProtocols,
     types: &TypeDeclarations,
     swift_bridge_path: &Path,
+    config: &CodegenConfig,
 ) -> String {
     let type_name = ty.to_string();
 
@@ -18,33 +23,480 @@ pub(super) fn generate_swift_class(
         associated_funcs_and_methods,
         types,
         swift_bridge_path,
+        config,
     );
 
-    create_class_declaration(
+    let mut class = create_class_declaration(
         ty,
         class_protocols,
-        &class_methods.initializers,
-        &class_methods.owned_self_methods,
-        &class_methods.ref_self_methods,
-        &class_methods.ref_mut_self_methods,
+        config,
+        &class_methods,
         types,
         swift_bridge_path,
+    );
+
+    if let Some(target) = ty.attributes.deref.as_ref() {
+        class += &deref_forwarding_methods(
+            ty,
+            &target.to_string(),
+            associated_funcs_and_methods,
+            types,
+            swift_bridge_path,
+            config,
+        );
+    }
+
+    if ty.attributes.arc_mutex && !ty.attributes.already_declared {
+        class += &arc_mutex_share_method(ty);
+    }
+
+    if ty.attributes.error_chain {
+        class += &error_chain_description(ty);
+    }
+
+    if ty.attributes.index.is_some() {
+        class += &index_subscript(ty, types, swift_bridge_path);
+    }
+
+    if ty.attributes.add_assign || ty.attributes.sub_assign {
+        class += &compound_assignment_operators(ty);
+    }
+
+    if ty.attributes.neg {
+        class += &negation_operator(ty);
+    }
+
+    if ty.attributes.btree_map.is_some() {
+        class += &btree_map_range_method(ty, types, swift_bridge_path);
+    }
+
+    if ty.attributes.counter.is_some() {
+        class += &counter_methods(ty, types, swift_bridge_path);
+    }
+
+    if ty.attributes.hashable_by_getters {
+        class += &hashable_by_getters_conformances(ty, associated_funcs_and_methods, config);
+    }
+
+    class
+}
+
+/// For a type annotated with `#[swift_bridge(arc_mutex)]`, generate a method that creates
+/// another owned handle to the same `Arc<Mutex<T>>`.
+fn arc_mutex_share_method(ty: &OpaqueForeignTypeDeclaration) -> String {
+    format!(
+        r#"
+extension {type_name}Ref {{
+    public func share() -> {type_name} {{
+        {type_name}(ptr: {share_func_name}(ptr))
+    }}
+}}"#,
+        type_name = ty.ty_name_ident(),
+        share_func_name = ty.share_rust_opaque_type_ffi_name()
+    )
+}
+
+/// For a type annotated with `#[swift_bridge(error_chain)]`, generate a `description` that
+/// includes the Rust error's entire `source()` chain, so that printing a thrown error shows
+/// what caused it.
+fn error_chain_description(ty: &OpaqueForeignTypeDeclaration) -> String {
+    format!(
+        r#"
+extension {type_name}Ref: CustomStringConvertible {{
+    public var description: String {{
+        RustString(ptr: {prefix}${type_name}$_error_chain(ptr)).toString()
+    }}
+}}"#,
+        type_name = ty.ty_name_ident(),
+        prefix = SWIFT_BRIDGE_PREFIX,
+    )
+}
+
+/// For a type annotated with `#[swift_bridge(index(Idx, Output))]`, generate a read-only subscript
+/// that calls the type's `std::ops::Index` implementation.
+fn index_subscript(
+    ty: &OpaqueForeignTypeDeclaration,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    let index = ty.attributes.index.as_ref().unwrap();
+    let to_swift_type = |rust_ty| {
+        BridgedType::new_with_type(rust_ty, types)
+            .unwrap()
+            .to_swift_type(TypePosition::SharedStructField, types, swift_bridge_path)
+    };
+
+    format!(
+        r#"
+extension {type_name}Ref {{
+    public subscript(index: {idx}) -> {output} {{
+        {prefix}${type_name}$_index(ptr, index)
+    }}
+}}"#,
+        type_name = ty.ty_name_ident(),
+        idx = to_swift_type(&index.index),
+        output = to_swift_type(&index.output),
+        prefix = SWIFT_BRIDGE_PREFIX,
+    )
+}
+
+/// For a type annotated with `#[swift_bridge(AddAssign)]` or `#[swift_bridge(SubAssign)]`,
+/// generate `+=` and `-=` operators that mutate the left hand side in place.
+///
+/// Rust can't mutably borrow the left hand side while the right hand side borrows the same value,
+/// so `x += x` is only allowed for types that Rust can clone the right hand side of.
+fn compound_assignment_operators(ty: &OpaqueForeignTypeDeclaration) -> String {
+    let type_name = ty.ty_name_ident();
+
+    let mut operators = String::new();
+    for (enabled, operator, method) in [
+        (ty.attributes.add_assign, "+=", "add_assign"),
+        (ty.attributes.sub_assign, "-=", "sub_assign"),
+    ] {
+        if enabled {
+            let maybe_aliasing_check = if ty.attributes.clone {
+                "".to_string()
+            } else {
+                format!(
+                    r#"
+        precondition(lhs.ptr != rhs.ptr, "Cannot use `{operator}` with the same value on both sides.")"#
+                )
+            };
+            operators += &format!(
+                r#"
+    public static func {operator} <Lhs: {type_name}RefMut>(lhs: inout Lhs, rhs: {type_name}Ref) {{{maybe_aliasing_check}
+        {prefix}${type_name}$_{method}(lhs.ptr, rhs.ptr)
+    }}"#,
+                prefix = SWIFT_BRIDGE_PREFIX,
+            );
+        }
+    }
+
+    format!(
+        r#"
+extension {type_name}RefMut {{{operators}
+}}"#
+    )
+}
+
+/// For a type annotated with `#[swift_bridge(Neg)]`, generate a prefix `-` operator that returns
+/// a new negated instance.
+fn negation_operator(ty: &OpaqueForeignTypeDeclaration) -> String {
+    format!(
+        r#"
+extension {type_name}Ref {{
+    public static prefix func - (operand: {type_name}Ref) -> {type_name} {{
+        {type_name}(ptr: {prefix}${type_name}$_neg(operand.ptr))
+    }}
+}}"#,
+        type_name = ty.ty_name_ident(),
+        prefix = SWIFT_BRIDGE_PREFIX,
+    )
+}
+
+/// For a type annotated with `#[swift_bridge(counter(T))]`, generate `increment()` and
+/// `decrement()` methods that step the counter by one, along with a `value` property that reads it.
+///
+/// Swift has no `++` or `--` operators, so these stand in for them.
+fn counter_methods(
+    ty: &OpaqueForeignTypeDeclaration,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    let counter = ty.attributes.counter.as_ref().unwrap();
+    let value = BridgedType::new_with_type(counter, types)
+        .unwrap()
+        .to_swift_type(TypePosition::SharedStructField, types, swift_bridge_path);
+
+    format!(
+        r#"
+extension {type_name}RefMut {{
+    public func increment() {{
+        {prefix}${type_name}$_increment(ptr)
+    }}
+
+    public func decrement() {{
+        {prefix}${type_name}$_decrement(ptr)
+    }}
+}}
+extension {type_name}Ref {{
+    public var value: {value} {{
+        {prefix}${type_name}$_counter_value(ptr)
+    }}
+}}"#,
+        type_name = ty.ty_name_ident(),
+        prefix = SWIFT_BRIDGE_PREFIX,
+    )
+}
+
+/// For a type annotated with `#[swift_bridge(btree_map(K, V))]`, generate a method that returns
+/// the map's entries whose keys are in `start..<end`, in ascending key order.
+fn btree_map_range_method(
+    ty: &OpaqueForeignTypeDeclaration,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    let btree_map = ty.attributes.btree_map.as_ref().unwrap();
+    let to_swift_type = |rust_ty| {
+        BridgedType::new_with_type(rust_ty, types)
+            .unwrap()
+            .to_swift_type(TypePosition::SharedStructField, types, swift_bridge_path)
+    };
+
+    format!(
+        r#"
+extension {type_name}Ref {{
+    public func range(_ start: {key}, _ end: {key}) -> [(key: {key}, value: {value})] {{
+        var valuesPtr: UnsafeMutableRawPointer? = nil
+        let keys = RustVec<{key}>(ptr: {prefix}${type_name}$_range(ptr, start, end, &valuesPtr))
+        let values = RustVec<{value}>(ptr: valuesPtr!)
+        return zip(keys, values).map {{ (key: $0, value: $1) }}
+    }}
+}}"#,
+        type_name = ty.ty_name_ident(),
+        key = to_swift_type(&btree_map.key),
+        value = to_swift_type(&btree_map.value),
+        prefix = SWIFT_BRIDGE_PREFIX,
+    )
+}
+
+/// For a type annotated with `#[swift_bridge(HashableByGetters)]`, generate `Equatable` and
+/// `Hashable` conformances that compare and hash the values returned by the type's
+/// `#[swift_bridge(get(...))]` getters, so that two instances with equal fields are equal.
+fn hashable_by_getters_conformances(
+    ty: &OpaqueForeignTypeDeclaration,
+    associated_funcs_and_methods: &HashMap<String, Vec<&ParsedExternFn>>,
+    config: &CodegenConfig,
+) -> String {
+    let type_name = ty.ty_name_ident();
+
+    let mut getters = vec![];
+    for method in associated_funcs_and_methods
+        .get(&type_name.to_string())
+        .into_iter()
+        .flatten()
+    {
+        let is_ref_self_method =
+            method.self_reference().is_some() && method.self_mutability().is_none();
+        if method.get_field.is_none()
+            || !is_ref_self_method
+            || method.func.sig.inputs.len() != 1
+            || method.sig.asyncness.is_some()
+        {
+            continue;
+        }
+
+        getters.push(match method.swift_getter_property_name(config) {
+            Some(property_name) => property_name,
+            None => format!("{}()", method.swift_fn_name(config)),
+        });
+    }
+
+    let comparisons = if getters.is_empty() {
+        "true".to_string()
+    } else {
+        getters
+            .iter()
+            .map(|getter| format!("lhs.{getter} == rhs.{getter}"))
+            .collect::<Vec<_>>()
+            .join(" && ")
+    };
+    let combines = getters
+        .iter()
+        .map(|getter| format!("\n        hasher.combine(self.{getter})"))
+        .collect::<String>();
+
+    format!(
+        r#"
+extension {type_name}Ref: Equatable {{
+    public static func == (lhs: {type_name}Ref, rhs: {type_name}Ref) -> Bool {{
+        {comparisons}
+    }}
+}}
+extension {type_name}Ref: Hashable {{
+    public func hash(into hasher: inout Hasher) {{{combines}
+    }}
+}}"#
+    )
+}
+
+/// For a type annotated with `#[swift_bridge(deref = "Inner")]`, generate methods on the type's
+/// Swift class that forward to each of `Inner`'s `&self` methods and getter properties.
+///
+/// Methods that the type declares itself take precedence over `Inner`'s methods, similar to
+/// how Rust resolves method calls through `Deref`.
+fn deref_forwarding_methods(
+    ty: &OpaqueForeignTypeDeclaration,
+    target: &str,
+    associated_funcs_and_methods: &HashMap<String, Vec<&ParsedExternFn>>,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+    config: &CodegenConfig,
+) -> String {
+    let type_name = ty.ty_name_ident().to_string();
+
+    let own_methods: Vec<String> = associated_funcs_and_methods
+        .get(&type_name)
+        .map(|methods| {
+            methods
+                .iter()
+                .map(|method| {
+                    method
+                        .swift_getter_property_name(config)
+                        .unwrap_or_else(|| method.swift_fn_name(config))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let target_methods = match associated_funcs_and_methods.get(target) {
+        Some(methods) => methods,
+        None => return "".to_string(),
+    };
+
+    let mut forwarding_methods = vec![];
+
+    for method in target_methods {
+        // TODO: Support forwarding async methods.
+        let is_ref_self_method =
+            method.self_reference().is_some() && method.self_mutability().is_none();
+        if !is_ref_self_method || method.sig.asyncness.is_some() {
+            continue;
+        }
+
+        let ret = method.to_swift_return_type(types, swift_bridge_path);
+
+        let property = method
+            .swift_getter_property_name(config)
+            .and_then(|name| Some((name, ret.strip_prefix(" -> ")?)));
+        if let Some((property_name, property_ty)) = property {
+            if !own_methods.contains(&property_name) {
+                forwarding_methods.push(format!(
+                    r#"    public var {property_name}: {property_ty} {{
+        {target}Ref(ptr: {prefix}${type_name}$_deref(ptr)).{property_name}
+    }}"#,
+                    prefix = SWIFT_BRIDGE_PREFIX,
+                ));
+            }
+            continue;
+        }
+
+        let fn_name = method.swift_fn_name(config);
+        if own_methods.contains(&fn_name) {
+            continue;
+        }
+
+        let params = method.to_swift_param_names_and_types(false, types, swift_bridge_path);
+        let maybe_try = if ret.contains("throws ") { "try " } else { "" };
+
+        let mut args = vec![];
+        for arg in method.func.sig.inputs.iter() {
+            let arg_name = match arg {
+                FnArg::Typed(pat_ty) if !pat_type_pat_is_self(pat_ty) => {
+                    pat_ty.pat.to_token_stream().to_string()
+                }
+                _ => continue,
+            };
+
+            match method.argument_labels.get(&format_ident!("{}", arg_name)) {
+                Some(label) => args.push(format!("{}: {}", label.value(), arg_name)),
+                None => args.push(arg_name),
+            }
+        }
+
+        forwarding_methods.push(format!(
+            r#"    public func {fn_name}({params}){ret} {{
+        {maybe_try}{target}Ref(ptr: {prefix}${type_name}$_deref(ptr)).{fn_name}({args})
+    }}"#,
+            fn_name = fn_name,
+            params = params,
+            ret = ret,
+            maybe_try = maybe_try,
+            target = target,
+            prefix = SWIFT_BRIDGE_PREFIX,
+            type_name = type_name,
+            args = args.join(", ")
+        ));
+    }
+
+    // The forwarding methods call the target's methods, so they must be isolated to the main
+    // actor if either type is.
+    let target_is_main_actor = match types.get(target) {
+        Some(TypeDeclaration::Opaque(target)) => target.attributes.main_actor,
+        _ => false,
+    };
+    let maybe_main_actor = if ty.attributes.main_actor || target_is_main_actor {
+        "@MainActor\n"
+    } else {
+        ""
+    };
+
+    methods_extensions(
+        &format!("{}Ref", type_name),
+        maybe_main_actor,
+        std::iter::once(&forwarding_methods),
     )
 }
 
 fn create_class_declaration(
     ty: &OpaqueForeignTypeDeclaration,
     class_protocols: &ClassProtocols,
-    initializers: &[String],
-    owned_self_methods: &[String],
-    ref_self_methods: &[String],
-    ref_mut_self_methods: &[String],
+    config: &CodegenConfig,
+    class_methods: &[ClassMethods],
     types: &TypeDeclarations,
     swift_bridge_path: &Path,
 ) -> String {
     let type_name = &ty.ty_name_ident().to_string();
     let generics = ty.generics.angle_bracketed_generic_placeholders_string();
 
+    let is_concrete_generic = ty.generics.len() > 0 && !ty.attributes.declare_generic;
+    let declares_class = !ty.attributes.already_declared && !is_concrete_generic;
+
+    // We isolate the extensions that hold the type's methods instead of the classes themselves,
+    // so that the generated glue code can still create and free instances on any thread.
+    let maybe_main_actor = if ty.attributes.main_actor {
+        "@MainActor\n"
+    } else {
+        ""
+    };
+
+    // Methods that are declared in an extension can't be overridden, so open classes declare
+    // their instance methods in the class body instead.
+    let open_class = config.open_classes && declares_class;
+    let class_access = if open_class { "open" } else { "public" };
+    let (owned_class_body_methods, ref_mut_class_body_methods, ref_class_body_methods) =
+        if open_class {
+            (
+                open_class_body_methods(
+                    maybe_main_actor,
+                    class_methods
+                        .iter()
+                        .map(|methods| &methods.owned_self_methods),
+                ),
+                open_class_body_methods(
+                    maybe_main_actor,
+                    class_methods
+                        .iter()
+                        .map(|methods| &methods.ref_mut_self_methods),
+                ),
+                open_class_body_methods(
+                    maybe_main_actor,
+                    class_methods
+                        .iter()
+                        .map(|methods| &methods.ref_self_methods),
+                ),
+            )
+        } else {
+            ("".to_string(), "".to_string(), "".to_string())
+        };
+
+    // `@inlinable` methods can only use `@usableFromInline` internal declarations.
+    let maybe_usable_from_inline = if config.inlinable_accessors {
+        "@usableFromInline "
+    } else {
+        ""
+    };
+
     let mut class_decl = {
         let free_func_call = if ty.generics.len() == 0 {
             format!("{}${}$_free(ptr)", SWIFT_BRIDGE_PREFIX, type_name)
@@ -52,20 +504,51 @@ fn create_class_declaration(
             "(self as! SwiftBridgeGenericFreer).rust_free()".to_string()
         };
 
+        let doc_aliases: String = ty
+            .attributes
+            .doc_aliases
+            .iter()
+            .map(|alias| format!("/// - Keyword: {}\n", alias))
+            .collect();
+
+        let ffi_layout_docs = if config.ffi_layout_docs {
+            opaque_rust_type_ffi_layout_docs(ty)
+        } else {
+            "".to_string()
+        };
+
+        let log_deinit = if config.log_deinit {
+            format!(
+                r#"
+        #if DEBUG
+        print("swift-bridge: deinit {type_name} \(ptr) isOwned: \(isOwned)")
+        #endif"#
+            )
+        } else {
+            "".to_string()
+        };
+
         format!(
-            r#"public class {type_name}{generics}: {type_name}RefMut{generics} {{
+            r#"{doc_aliases}{ffi_layout_docs}{class_access} class {type_name}{generics}: {type_name}RefMut{generics} {{
     var isOwned: Bool = true
 
     public override init(ptr: UnsafeMutableRawPointer) {{
         super.init(ptr: ptr)
     }}
 
-    deinit {{
+    deinit {{{log_deinit}
         if isOwned {{
             {free_func_call}
         }}
     }}
+
+    {maybe_usable_from_inline}override var unconsumedPtr: UnsafeMutableRawPointer {{
+        precondition(isOwned, "Attempted to use a value that was already consumed.")
+        return ptr
+    }}{owned_class_body_methods}
 }}"#,
+            doc_aliases = doc_aliases,
+            log_deinit = log_deinit,
             type_name = type_name,
             generics = generics,
             free_func_call = free_func_call
@@ -75,10 +558,10 @@ fn create_class_declaration(
     let mut class_ref_mut_decl = {
         format!(
             r#"
-public class {type_name}RefMut{generics}: {type_name}Ref{generics} {{
+{class_access} class {type_name}RefMut{generics}: {type_name}Ref{generics} {{
     public override init(ptr: UnsafeMutableRawPointer) {{
         super.init(ptr: ptr)
-    }}
+    }}{ref_mut_class_body_methods}
 }}"#,
             type_name = type_name,
             generics = generics
@@ -87,12 +570,16 @@ public class {type_name}RefMut{generics}: {type_name}Ref{generics} {{
     let mut class_ref_decl = {
         format!(
             r#"
-public class {type_name}Ref{generics} {{
-    var ptr: UnsafeMutableRawPointer
+{class_access} class {type_name}Ref{generics} {{
+    {maybe_usable_from_inline}var ptr: UnsafeMutableRawPointer
 
     public init(ptr: UnsafeMutableRawPointer) {{
         self.ptr = ptr
     }}
+
+    {maybe_usable_from_inline}var unconsumedPtr: UnsafeMutableRawPointer {{
+        ptr
+    }}{ref_class_body_methods}
 }}"#,
             type_name = type_name,
             generics = generics
@@ -121,65 +608,49 @@ extension {type_name}Ref: Identifiable {{{identifiable_var}}}"#,
         );
     }
 
-    let initializers = if initializers.len() == 0 {
-        "".to_string()
-    } else {
-        let initializers: String = initializers.join("\n\n");
-        format!(
-            r#"
-extension {type_name} {{
-{initializers}
-}}"#,
-            type_name = type_name,
-            initializers = initializers
-        )
-    };
-
-    let owned_instance_methods = if owned_self_methods.len() == 0 {
-        "".to_string()
-    } else {
-        let owned_instance_methods: String = owned_self_methods.join("\n\n");
-        format!(
-            r#"
-extension {type_name} {{
-{owned_instance_methods}
-}}"#,
-            type_name = type_name,
-            owned_instance_methods = owned_instance_methods
-        )
-    };
-
-    let ref_instance_methods = if ref_self_methods.len() == 0 {
-        "".to_string()
-    } else {
-        let ref_instance_methods: String = ref_self_methods.join("\n\n");
-        format!(
+    if class_protocols.non_sendable {
+        class_ref_decl += &format!(
             r#"
-extension {type_name}Ref {{
-{ref_instance_methods}
-}}"#,
+@available(*, unavailable)
+extension {type_name}Ref: Sendable {{}}"#,
             type_name = type_name,
-            ref_instance_methods = ref_instance_methods
-        )
-    };
+        );
+    }
 
-    let ref_mut_instance_methods = if ref_mut_self_methods.len() == 0 {
-        "".to_string()
+    let initializers = methods_extensions(
+        type_name,
+        maybe_main_actor,
+        class_methods.iter().map(|methods| &methods.initializers),
+    );
+    let (owned_instance_methods, ref_instance_methods, ref_mut_instance_methods) = if open_class {
+        ("".to_string(), "".to_string(), "".to_string())
     } else {
-        let ref_mut_instance_methods: String = ref_mut_self_methods.join("\n\n");
-        format!(
-            r#"
-extension {type_name}RefMut {{
-{ref_mut_instance_methods}
-}}"#,
-            type_name = type_name,
-            ref_mut_instance_methods = ref_mut_instance_methods
+        (
+            methods_extensions(
+                type_name,
+                maybe_main_actor,
+                class_methods
+                    .iter()
+                    .map(|methods| &methods.owned_self_methods),
+            ),
+            methods_extensions(
+                &format!("{}Ref", type_name),
+                maybe_main_actor,
+                class_methods
+                    .iter()
+                    .map(|methods| &methods.ref_self_methods),
+            ),
+            methods_extensions(
+                &format!("{}RefMut", type_name),
+                maybe_main_actor,
+                class_methods
+                    .iter()
+                    .map(|methods| &methods.ref_mut_self_methods),
+            ),
         )
     };
 
-    let is_concrete_generic = ty.generics.len() > 0 && !ty.attributes.declare_generic;
-
-    if ty.attributes.already_declared || is_concrete_generic {
+    if !declares_class {
         class_decl = "".to_string();
         class_ref_decl = "".to_string();
         class_ref_mut_decl = "".to_string();
@@ -211,6 +682,21 @@ extension {ty_name}Ref: Equatable {{
     public static func == (lhs: {ty_name}Ref, rhs: {ty_name}Ref) -> Bool {{
         __swift_bridge__${ty_name}$_partial_eq(rhs.ptr, lhs.ptr)
     }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    let clone_initializer: String = {
+        if ty.attributes.clone {
+            let ty_name = ty.ty_name_ident();
+            format!(
+                r#"
+extension {ty_name} {{
+    public convenience init(copying other: {ty_name}Ref) {{
+        self.init(ptr: __swift_bridge__${ty_name}$_clone(other.ptr))
+    }}
 }}"#,
             )
         } else {
@@ -236,7 +722,7 @@ extension {ty_name}Ref: Hashable{{
 
     let class = format!(
         r#"
-{class_decl}{initializers}{owned_instance_methods}{class_ref_decl}{ref_mut_instance_methods}{class_ref_mut_decl}{ref_instance_methods}{generic_freer}{equatable_method}{hashable_method}"#,
+{class_decl}{initializers}{owned_instance_methods}{class_ref_decl}{ref_mut_instance_methods}{class_ref_mut_decl}{ref_instance_methods}{generic_freer}{equatable_method}{hashable_method}{clone_initializer}"#,
         class_decl = class_decl,
         class_ref_decl = class_ref_mut_decl,
         class_ref_mut_decl = class_ref_decl,
@@ -246,7 +732,61 @@ extension {ty_name}Ref: Hashable{{
         ref_instance_methods = ref_instance_methods,
         equatable_method = equatable_method,
         hashable_method = hashable_method,
+        clone_initializer = clone_initializer,
     );
 
     return class;
 }
+
+/// Generate the instance methods that an open class declares in its body, as `open` methods that
+/// other Swift modules can override.
+fn open_class_body_methods<'a>(
+    maybe_main_actor: &str,
+    method_groups: impl Iterator<Item = &'a Vec<String>>,
+) -> String {
+    let mut methods = "".to_string();
+
+    for method in method_groups.flatten() {
+        let method = method.replacen("public func ", "open func ", 1).replacen(
+            "public var ",
+            "open var ",
+            1,
+        );
+
+        methods += "\n\n";
+        if !maybe_main_actor.is_empty() {
+            methods += &format!("    {}", maybe_main_actor);
+        }
+        methods += &method;
+    }
+
+    methods
+}
+
+/// Generate one extension per group of methods, where each group holds the methods that were
+/// declared in the same `extern "Rust"` block.
+fn methods_extensions<'a>(
+    extended_type: &str,
+    maybe_main_actor: &str,
+    method_groups: impl Iterator<Item = &'a Vec<String>>,
+) -> String {
+    let mut extensions = "".to_string();
+
+    for methods in method_groups {
+        if methods.len() == 0 {
+            continue;
+        }
+
+        extensions += &format!(
+            r#"
+{maybe_main_actor}extension {extended_type} {{
+{methods}
+}}"#,
+            maybe_main_actor = maybe_main_actor,
+            extended_type = extended_type,
+            methods = methods.join("\n\n")
+        );
+    }
+
+    extensions
+}
@@ -50,6 +50,10 @@ pub(super) fn generate_vectorizable_extension(ty: &Ident) -> String {
     public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {{
         __swift_bridge__$Vec_{ty}$len(vecPtr)
     }}
+
+    public static func vecOfSelfCapacity(vecPtr: UnsafeMutableRawPointer) -> UInt {{
+        __swift_bridge__$Vec_{ty}$capacity(vecPtr)
+    }}
 }}
 "#,
         ty = ty.to_string()
@@ -114,6 +118,10 @@ extension ARustType: Vectorizable {
     public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {
         __swift_bridge__$Vec_ARustType$len(vecPtr)
     }
+
+    public static func vecOfSelfCapacity(vecPtr: UnsafeMutableRawPointer) -> UInt {
+        __swift_bridge__$Vec_ARustType$capacity(vecPtr)
+    }
 }
 "#;
 
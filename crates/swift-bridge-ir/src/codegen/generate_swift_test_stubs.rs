@@ -0,0 +1,232 @@
+use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType, TypePosition};
+use crate::codegen::{CodegenConfig, NameCasing, SwiftResultFunctions};
+use crate::{ParsedExternFn, SwiftBridgeModule};
+use quote::{format_ident, ToTokens};
+use syn::FnArg;
+
+impl SwiftBridgeModule {
+    /// Generate an empty XCTest test method for each of the module's extern "Rust" functions.
+    ///
+    /// Each test method calls its function with Xcode placeholder arguments, such as
+    /// `<#count: UInt32#>`, that are meant to be filled in by hand. The methods belong inside of
+    /// an `XCTestCase` subclass.
+    pub fn generate_swift_test_stubs(&self, config: &CodegenConfig) -> String {
+        if !self.module_will_be_compiled(config) {
+            return "".to_string();
+        }
+
+        self.functions
+            .iter()
+            .filter(|function| function.host_lang.is_rust())
+            .map(|function| self.swift_test_stub(function, config))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// ```swift
+    /// func testCounterIncrement() {
+    ///     let counter: Counter = <#Counter#>
+    ///     _ = counter.increment(<#amount: UInt32#>)
+    /// }
+    /// ```
+    fn swift_test_stub(&self, function: &ParsedExternFn, config: &CodegenConfig) -> String {
+        let types = &self.types;
+
+        let returns_result = function
+            .return_ty_built_in(types)
+            .is_some_and(|ty| ty.is_result());
+        let is_async = function.sig.asyncness.is_some();
+        let throws =
+            returns_result && (is_async || config.swift_result_functions.generates_throwing());
+
+        let mut fn_name = function.swift_fn_name(config);
+        let getter_property_name = if returns_result {
+            None
+        } else {
+            function.swift_getter_property_name(config)
+        };
+        if returns_result && !throws {
+            fn_name = SwiftResultFunctions::result_returning_fn_name(&fn_name);
+        }
+
+        let args = self.swift_test_stub_args(function);
+        let owner = function
+            .associated_type
+            .as_ref()
+            .map(|ty| ty.swift_name_string());
+
+        let mut body = "".to_string();
+        let (test_name, call) = match owner {
+            Some(owner) if function.is_swift_initializer => {
+                (format!("test{}Init", owner), format!("{}({})", owner, args))
+            }
+            Some(owner) if function.is_swift_shared_instance => (
+                format!("test{}{}", owner, NameCasing::PascalCase.convert(&fn_name)),
+                format!("{}.{}", owner, fn_name),
+            ),
+            Some(owner) if function.is_method() => {
+                let instance = NameCasing::CamelCase.convert(&owner);
+                body += &format!("        let {instance}: {owner} = <#{owner}#>\n");
+
+                match getter_property_name {
+                    Some(property_name) => (
+                        format!(
+                            "test{}{}",
+                            owner,
+                            NameCasing::PascalCase.convert(&property_name)
+                        ),
+                        format!("{}.{}", instance, property_name),
+                    ),
+                    None => (
+                        format!("test{}{}", owner, NameCasing::PascalCase.convert(&fn_name)),
+                        format!("{}.{}({})", instance, fn_name, args),
+                    ),
+                }
+            }
+            Some(owner) => (
+                format!("test{}{}", owner, NameCasing::PascalCase.convert(&fn_name)),
+                format!("{}.{}({})", owner, fn_name, args),
+            ),
+            None => (
+                format!("test{}", NameCasing::PascalCase.convert(&fn_name)),
+                format!("{}({})", fn_name, args),
+            ),
+        };
+
+        let (maybe_async, maybe_await) = if is_async {
+            (" async", "await ")
+        } else {
+            ("", "")
+        };
+        let (maybe_throws, maybe_try) = if throws {
+            (" throws", "try ")
+        } else {
+            ("", "")
+        };
+        body += &format!("        _ = {maybe_try}{maybe_await}{call}");
+
+        format!(
+            r#"    func {test_name}(){maybe_async}{maybe_throws} {{
+{body}
+    }}"#
+        )
+    }
+
+    /// Xcode placeholders for each of the function's arguments, along with their argument labels.
+    ///
+    /// `fn add(a: u8, #[swift_bridge(label = "to")] b: u8)` -> `<#a: UInt8#>, to: <#b: UInt8#>`
+    fn swift_test_stub_args(&self, function: &ParsedExternFn) -> String {
+        let mut args = vec![];
+
+        for (arg_idx, arg) in function.func.sig.inputs.iter().enumerate() {
+            let pat_ty = match arg {
+                FnArg::Typed(pat_ty) if !pat_type_pat_is_self(pat_ty) => pat_ty,
+                _ => continue,
+            };
+
+            let arg_name = pat_ty.pat.to_token_stream().to_string();
+            let swift_ty = match BridgedType::new_with_type(&pat_ty.ty, &self.types) {
+                Some(ty) => ty.to_swift_type(
+                    TypePosition::FnArg(function.host_lang, arg_idx),
+                    &self.types,
+                    &self.swift_bridge_path,
+                ),
+                None => continue,
+            };
+            // Swift strings are passed to the generic string parameters of generated functions.
+            let swift_ty = swift_ty
+                .replace("GenericIntoRustString", "String")
+                .replace("GenericToRustStr", "String");
+
+            let placeholder = format!("<#{}: {}#>", arg_name, swift_ty);
+            args.push(
+                match function.argument_labels.get(&format_ident!("{}", arg_name)) {
+                    Some(label) => format!("{}: {}", label.value(), placeholder),
+                    None => placeholder,
+                },
+            );
+        }
+
+        args.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse_ok;
+    use quote::quote;
+
+    /// Verify that we generate a test stub for each extern "Rust" function, calling it with a
+    /// placeholder for each argument.
+    #[test]
+    fn generates_stub_per_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Counter;
+
+                    #[swift_bridge(init)]
+                    fn new(start: u32) -> Counter;
+                    fn increment(&mut self, amount: u32) -> u32;
+                    fn reset_all(#[swift_bridge(label = "to")] value: u32, name: String);
+                    fn parse(text: &str) -> Result<Counter, String>;
+                    async fn fetch() -> u32;
+                }
+
+                extern "Swift" {
+                    fn swift_function();
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let stubs = module.generate_swift_test_stubs(&CodegenConfig::no_features_enabled());
+
+        assert_eq!(
+            stubs,
+            r#"    func testCounterInit() {
+        _ = Counter(<#start: UInt32#>)
+    }
+
+    func testCounterIncrement() {
+        let counter: Counter = <#Counter#>
+        _ = counter.increment(<#amount: UInt32#>)
+    }
+
+    func testResetAll() {
+        _ = reset_all(to: <#value: UInt32#>, <#name: String#>)
+    }
+
+    func testParse() throws {
+        _ = try parse(<#text: String#>)
+    }
+
+    func testFetch() async {
+        _ = await fetch()
+    }"#
+        );
+    }
+
+    /// Verify that we call the `Result` returning variant of a function when the throwing
+    /// variant is not generated.
+    #[test]
+    fn calls_result_returning_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn parse(text: &str) -> Result<u32, String>;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let mut config = CodegenConfig::no_features_enabled();
+        config.swift_result_functions = SwiftResultFunctions::ResultReturning;
+
+        let stubs = module.generate_swift_test_stubs(&config);
+        assert!(stubs.contains("_ = parseResult(<#text: String#>)"));
+    }
+}
@@ -0,0 +1,56 @@
+/// Generates Swift computed properties, instead of methods, for getters that use
+/// `#[swift_bridge(get(...))]`, `#[swift_bridge(get_with(...))]` or
+/// `#[swift_bridge(get_cell(...))]`.
+///
+/// For example, `fn get_width(&self) -> u32` becomes `var width: UInt32`.
+///
+/// Getters that use `#[swift_bridge(swift_name = "...")]` use the name that they were given.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GetterProperties {
+    /// The prefix that is removed from a getter's name to get its property name.
+    ///
+    /// With the default `"get_"` prefix `get_width` becomes `width`, while `height` stays
+    /// `height`.
+    /// An empty prefix keeps the getter's full name.
+    pub strip_prefix: String,
+}
+
+impl Default for GetterProperties {
+    fn default() -> Self {
+        GetterProperties {
+            strip_prefix: "get_".to_string(),
+        }
+    }
+}
+
+impl GetterProperties {
+    /// Get the name of the property for a getter.
+    ///
+    /// A getter whose entire name is the prefix keeps its name.
+    pub fn property_name<'a>(&self, getter_name: &'a str) -> &'a str {
+        match getter_name.strip_prefix(self.strip_prefix.as_str()) {
+            Some(property_name) if !property_name.is_empty() => property_name,
+            _ => getter_name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we strip the configured prefix from getter names.
+    #[test]
+    fn strips_prefix() {
+        let getters = GetterProperties::default();
+        assert_eq!(getters.property_name("get_width"), "width");
+        assert_eq!(getters.property_name("height"), "height");
+        assert_eq!(getters.property_name("get_"), "get_");
+
+        let getters = GetterProperties {
+            strip_prefix: "fetch_".to_string(),
+        };
+        assert_eq!(getters.property_name("fetch_width"), "width");
+        assert_eq!(getters.property_name("get_width"), "get_width");
+    }
+}
@@ -0,0 +1,177 @@
+use std::fmt::{Display, Formatter};
+
+/// The casing that bridged Swift names are expected to follow.
+///
+/// See [`SwiftBridgeModule::parse_warnings`](crate::SwiftBridgeModule::parse_warnings).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NamingConvention {
+    /// The casing of the Swift names of bridged types.
+    pub types: NameCasing,
+    /// The casing of the Swift names of bridged functions and methods.
+    pub functions: NameCasing,
+}
+
+impl Default for NamingConvention {
+    /// `PascalCase` types and `camelCase` functions, following the Swift API design guidelines.
+    fn default() -> Self {
+        NamingConvention {
+            types: NameCasing::PascalCase,
+            functions: NameCasing::CamelCase,
+        }
+    }
+}
+
+/// A way of writing names that are made up of multiple words.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum NameCasing {
+    /// `SomeName`
+    PascalCase,
+    /// `someName`
+    CamelCase,
+    /// `some_name`
+    SnakeCase,
+}
+
+impl NameCasing {
+    /// Whether or not the name is written in this casing.
+    ///
+    /// Leading underscores are ignored, so `_privateFn` is `camelCase`.
+    pub fn matches(&self, name: &str) -> bool {
+        let name = name.trim_start_matches('_');
+        let first = match name.chars().next() {
+            Some(first) => first,
+            None => return true,
+        };
+
+        match self {
+            NameCasing::PascalCase => !first.is_lowercase() && !name.contains('_'),
+            NameCasing::CamelCase => !first.is_uppercase() && !name.contains('_'),
+            NameCasing::SnakeCase => !name.chars().any(|c| c.is_uppercase()),
+        }
+    }
+
+    /// Convert a name into this casing.
+    ///
+    /// Leading underscores are preserved, so `_private_fn` becomes `_privateFn` in `camelCase`.
+    pub fn convert(&self, name: &str) -> String {
+        let without_leading_underscores = name.trim_start_matches('_');
+        let leading_underscores = &name[..name.len() - without_leading_underscores.len()];
+
+        let words = split_words(without_leading_underscores);
+
+        let converted = match self {
+            NameCasing::PascalCase => words.iter().map(|word| capitalize(word)).collect(),
+            NameCasing::CamelCase => words
+                .iter()
+                .enumerate()
+                .map(|(idx, word)| {
+                    if idx == 0 {
+                        word.to_lowercase()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            NameCasing::SnakeCase => words
+                .iter()
+                .map(|word| word.to_lowercase())
+                .collect::<Vec<_>>()
+                .join("_"),
+        };
+
+        format!("{}{}", leading_underscores, converted)
+    }
+}
+
+impl Display for NameCasing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NameCasing::PascalCase => write!(f, "PascalCase"),
+            NameCasing::CamelCase => write!(f, "camelCase"),
+            NameCasing::SnakeCase => write!(f, "snake_case"),
+        }
+    }
+}
+
+/// Split a name into its words, at underscores and at lowercase to uppercase boundaries.
+///
+/// Runs of uppercase letters are kept together, so `HTTPClient` is split into `HTTP` and `Client`.
+fn split_words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = vec![];
+    let mut word = String::new();
+
+    for (idx, c) in chars.iter().enumerate() {
+        if *c == '_' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && !word.is_empty() {
+            let prev_is_upper = chars[idx - 1].is_uppercase();
+            let next_is_lower = chars.get(idx + 1).is_some_and(|next| next.is_lowercase());
+            if !prev_is_upper || next_is_lower {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+
+        word.push(*c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+/// Uppercase the first letter of a word, leaving the rest of it as is.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we can tell whether or not a name is written in a casing.
+    #[test]
+    fn matches_casing() {
+        assert!(NameCasing::PascalCase.matches("SomeType"));
+        assert!(NameCasing::PascalCase.matches("HTTPClient"));
+        assert!(!NameCasing::PascalCase.matches("someType"));
+        assert!(!NameCasing::PascalCase.matches("Some_Type"));
+
+        assert!(NameCasing::CamelCase.matches("someFunction"));
+        assert!(NameCasing::CamelCase.matches("_privateFn"));
+        assert!(NameCasing::CamelCase.matches("new"));
+        assert!(!NameCasing::CamelCase.matches("some_function"));
+        assert!(!NameCasing::CamelCase.matches("SomeFunction"));
+
+        assert!(NameCasing::SnakeCase.matches("some_function"));
+        assert!(!NameCasing::SnakeCase.matches("someFunction"));
+    }
+
+    /// Verify that we can convert names between casings.
+    #[test]
+    fn convert_casing() {
+        assert_eq!(NameCasing::PascalCase.convert("some_type"), "SomeType");
+        assert_eq!(NameCasing::PascalCase.convert("someType"), "SomeType");
+
+        assert_eq!(NameCasing::CamelCase.convert("get_value"), "getValue");
+        assert_eq!(NameCasing::CamelCase.convert("GetValue"), "getValue");
+        assert_eq!(NameCasing::CamelCase.convert("_private_fn"), "_privateFn");
+        assert_eq!(
+            NameCasing::CamelCase.convert("parse_HTTPRequest"),
+            "parseHTTPRequest"
+        );
+
+        assert_eq!(NameCasing::SnakeCase.convert("getValue"), "get_value");
+        assert_eq!(NameCasing::SnakeCase.convert("HTTPClient"), "http_client");
+    }
+}
@@ -0,0 +1,104 @@
+use std::str::FromStr;
+
+/// Which Swift functions get generated for Rust functions that return a `Result`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum SwiftResultFunctions {
+    /// Generate a Swift function that `throws` the `Err` value.
+    #[default]
+    Throwing,
+    /// Generate a Swift function that returns a `Result<T, Error>`.
+    ResultReturning,
+    /// Generate both a throwing Swift function and a Swift function that returns a
+    /// `Result<T, Error>`.
+    Both,
+}
+
+impl SwiftResultFunctions {
+    /// Whether or not the throwing Swift function should be public.
+    pub(crate) fn generates_throwing(&self) -> bool {
+        matches!(
+            self,
+            SwiftResultFunctions::Throwing | SwiftResultFunctions::Both
+        )
+    }
+
+    /// Whether or not a `Result<T, Error>` returning Swift function should be generated.
+    pub(crate) fn generates_result_returning(&self) -> bool {
+        matches!(
+            self,
+            SwiftResultFunctions::ResultReturning | SwiftResultFunctions::Both
+        )
+    }
+
+    /// The name of the `Result<T, Error>` returning variant of a throwing Swift function.
+    ///
+    /// "parse" -> "parseResult"
+    /// "parse_json" -> "parse_json_result"
+    pub(crate) fn result_returning_fn_name(swift_fn_name: &str) -> String {
+        if swift_fn_name.contains('_') {
+            format!("{}_result", swift_fn_name)
+        } else {
+            format!("{}Result", swift_fn_name)
+        }
+    }
+}
+
+impl FromStr for SwiftResultFunctions {
+    type Err = String;
+
+    /// "throwing", "result" or "both"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "throwing" => Ok(SwiftResultFunctions::Throwing),
+            "result" => Ok(SwiftResultFunctions::ResultReturning),
+            "both" => Ok(SwiftResultFunctions::Both),
+            _ => Err(format!(
+                r#"Invalid Swift result functions "{}". Expected "throwing", "result" or "both"."#,
+                s
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we can parse which Swift result functions to generate from strings.
+    #[test]
+    fn parse_swift_result_functions() {
+        assert_eq!(
+            SwiftResultFunctions::from_str("throwing").unwrap(),
+            SwiftResultFunctions::Throwing
+        );
+        assert_eq!(
+            SwiftResultFunctions::from_str("result").unwrap(),
+            SwiftResultFunctions::ResultReturning
+        );
+        assert_eq!(
+            SwiftResultFunctions::from_str("both").unwrap(),
+            SwiftResultFunctions::Both
+        );
+
+        assert!(SwiftResultFunctions::from_str("").is_err());
+        assert!(SwiftResultFunctions::from_str("throws").is_err());
+    }
+
+    /// Verify that the `Result` returning function's name follows the naming style of the
+    /// throwing function.
+    #[test]
+    fn result_returning_fn_name() {
+        assert_eq!(
+            SwiftResultFunctions::result_returning_fn_name("parse"),
+            "parseResult"
+        );
+        assert_eq!(
+            SwiftResultFunctions::result_returning_fn_name("parseJSON"),
+            "parseJSONResult"
+        );
+        assert_eq!(
+            SwiftResultFunctions::result_returning_fn_name("parse_json"),
+            "parse_json_result"
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A Swift language version, such as `5.4`.
+///
+/// Used to avoid generating Swift code that relies on language features that are not available
+/// in the Swift version that the generated code will be compiled with.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SwiftVersion {
+    /// The `5` in `5.4`.
+    pub major: u32,
+    /// The `4` in `5.4`.
+    pub minor: u32,
+}
+
+impl SwiftVersion {
+    /// Create a new Swift version.
+    pub const fn new(major: u32, minor: u32) -> Self {
+        SwiftVersion { major, minor }
+    }
+
+    /// Whether or not the `Sendable` protocol is available.
+    /// Introduced in Swift 5.5.
+    pub fn supports_sendable(&self) -> bool {
+        *self >= SwiftVersion::new(5, 5)
+    }
+
+    /// Whether or not the `@MainActor` attribute is available.
+    /// Introduced in Swift 5.5.
+    pub(crate) fn supports_main_actor(&self) -> bool {
+        *self >= SwiftVersion::new(5, 5)
+    }
+}
+
+impl Display for SwiftVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+impl FromStr for SwiftVersion {
+    type Err = String;
+
+    /// "5" -> 5.0
+    /// "5.4" -> 5.4
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!(r#"Invalid Swift version "{}". Expected "major.minor"."#, s);
+
+        let mut parts = s.trim().split('.');
+        let major = parts
+            .next()
+            .and_then(|major| major.parse().ok())
+            .ok_or_else(invalid)?;
+        let minor = match parts.next() {
+            Some(minor) => minor.parse().map_err(|_| invalid())?,
+            None => 0,
+        };
+
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+
+        Ok(SwiftVersion::new(major, minor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we can parse Swift versions from strings.
+    #[test]
+    fn parse_swift_version() {
+        assert_eq!(
+            SwiftVersion::from_str("5.4").unwrap(),
+            SwiftVersion::new(5, 4)
+        );
+        assert_eq!(
+            SwiftVersion::from_str("6").unwrap(),
+            SwiftVersion::new(6, 0)
+        );
+
+        assert!(SwiftVersion::from_str("").is_err());
+        assert!(SwiftVersion::from_str("5.x").is_err());
+        assert!(SwiftVersion::from_str("5.4.1").is_err());
+    }
+
+    /// Verify that we compare Swift versions by their major and then minor version.
+    #[test]
+    fn compare_swift_versions() {
+        assert!(SwiftVersion::new(5, 10) > SwiftVersion::new(5, 9));
+        assert!(SwiftVersion::new(6, 0) > SwiftVersion::new(5, 10));
+        assert!(!SwiftVersion::new(5, 4).supports_sendable());
+        assert!(SwiftVersion::new(5, 5).supports_sendable());
+        assert!(!SwiftVersion::new(5, 4).supports_main_actor());
+        assert!(SwiftVersion::new(5, 5).supports_main_actor());
+    }
+}
@@ -1,5 +1,12 @@
+mod duplicate_swift_member_names;
+mod embedded_swift;
+mod naming_convention;
 mod parse_error;
+mod parse_warning;
+mod reserved_swift_names;
+mod target_swift_version;
 pub(crate) use self::parse_error::*;
+pub use self::parse_warning::ParseWarning;
 
 pub(crate) struct ParseErrors {
     errors: Vec<ParseError>,
@@ -0,0 +1,132 @@
+use crate::codegen::CodegenConfig;
+use crate::errors::{ParseError, ParseErrors};
+use crate::parse::swift_member_signature;
+use crate::SwiftBridgeModule;
+use std::collections::HashSet;
+
+impl SwiftBridgeModule {
+    /// Return an error if any two of the module's functions would generate the same Swift
+    /// declaration once the [`CodegenConfig`] has renamed them.
+    ///
+    /// Parsing already rejects functions that collide under their Rust or `swift_name` names.
+    /// Options such as [`CodegenConfig::camel_case_swift_names`] and
+    /// [`CodegenConfig::getter_properties`] can introduce new collisions, such as `get_value`
+    /// and `getValue` both becoming `getValue`.
+    pub fn check_duplicate_swift_member_names(
+        &self,
+        config: &CodegenConfig,
+    ) -> Result<(), syn::Error> {
+        self.duplicate_swift_member_name_errors(config)
+            .combine_all()
+    }
+
+    fn duplicate_swift_member_name_errors(&self, config: &CodegenConfig) -> ParseErrors {
+        let mut errors = ParseErrors::new();
+        let mut seen = HashSet::new();
+
+        for function in &self.functions {
+            if !function.host_lang.is_rust() {
+                continue;
+            }
+
+            // A getter property `var value` collides with a method `func value()`, so both use the
+            // `value()` signature.
+            let name = if function.is_swift_initializer {
+                "init".to_string()
+            } else {
+                function
+                    .swift_getter_property_name(config)
+                    .unwrap_or_else(|| function.swift_fn_name(config))
+            };
+
+            let signature = swift_member_signature(function, &name);
+            if seen.insert(signature.clone()) {
+                continue;
+            }
+
+            let span = match &function.swift_name_override {
+                Some(swift_name) => swift_name.span(),
+                None => function.func.sig.ident.span(),
+            };
+            errors.push(ParseError::DuplicateSwiftMemberName { signature, span });
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::{CamelCaseSwiftNames, GetterProperties};
+    use crate::test_utils::parse_ok;
+    use quote::quote;
+
+    /// Verify that we reject functions whose names only collide after being converted to
+    /// camelCase.
+    #[test]
+    fn rejects_names_that_collide_after_camel_case() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn get_value(&self) -> u8;
+                    #[swift_bridge(swift_name = "getValue")]
+                    fn other_get_value(&self) -> u8;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let config = CodegenConfig::no_features_enabled();
+        assert!(module.check_duplicate_swift_member_names(&config).is_ok());
+
+        let mut config = CodegenConfig::no_features_enabled();
+        config.camel_case_swift_names = Some(CamelCaseSwiftNames::default());
+
+        let errors = module.duplicate_swift_member_name_errors(&config);
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::DuplicateSwiftMemberName { signature, .. } => {
+                assert_eq!(signature, "SomeType.getValue()");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we reject a getter property that collides with a method once getters are
+    /// generated as Swift properties.
+    #[test]
+    fn rejects_getter_property_that_collides_with_method() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(get(width))]
+                    fn get_width(&self) -> u32;
+                    fn width(&self) -> u32;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let config = CodegenConfig::no_features_enabled();
+        assert!(module.check_duplicate_swift_member_names(&config).is_ok());
+
+        let mut config = CodegenConfig::no_features_enabled();
+        config.getter_properties = Some(GetterProperties::default());
+
+        let errors = module.duplicate_swift_member_name_errors(&config);
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::DuplicateSwiftMemberName { signature, .. } => {
+                assert_eq!(signature, "SomeType.width()");
+            }
+            _ => panic!(),
+        };
+    }
+}
@@ -0,0 +1,153 @@
+use crate::bridged_type::{BridgeableType, BridgedType};
+use crate::codegen::CodegenConfig;
+use crate::errors::{ParseError, ParseErrors};
+use crate::parse::{SharedTypeDeclaration, TypeDeclaration};
+use crate::SwiftBridgeModule;
+use syn::{FnArg, ReturnType, Type};
+
+impl SwiftBridgeModule {
+    /// Return an error if the module uses a type or attribute whose generated Swift depends on
+    /// Foundation or Dispatch while [`CodegenConfig::embedded_swift`] is enabled.
+    pub fn check_embedded_swift(&self, config: &CodegenConfig) -> Result<(), syn::Error> {
+        self.embedded_swift_errors(config).combine_all()
+    }
+
+    fn embedded_swift_errors(&self, config: &CodegenConfig) -> ParseErrors {
+        let mut errors = ParseErrors::new();
+
+        if !config.embedded_swift {
+            return errors;
+        }
+
+        let mut push_if_uses_foundation = |ty: &Type| {
+            let uses_foundation = BridgedType::new_with_type(ty, &self.types)
+                .map(|bridged| bridged.uses_swift_foundation())
+                .unwrap_or(false);
+            if uses_foundation {
+                errors.push(ParseError::EmbeddedSwiftUnsupportedType { ty: ty.clone() });
+            }
+        };
+
+        for function in &self.functions {
+            for arg in function.func.sig.inputs.iter() {
+                if let FnArg::Typed(pat_ty) = arg {
+                    push_if_uses_foundation(&pat_ty.ty);
+                }
+            }
+            if let ReturnType::Type(_, ty) = &function.func.sig.output {
+                push_if_uses_foundation(ty);
+            }
+        }
+
+        for ty in self.types.types() {
+            if let TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) = ty {
+                for field in shared_struct.fields.normalized_fields() {
+                    push_if_uses_foundation(&field.ty);
+                }
+            }
+        }
+
+        for function in &self.functions {
+            if function.return_data {
+                errors.push(ParseError::EmbeddedSwiftUnsupportedAttribute {
+                    attribute: "return_data",
+                    span: function.func.sig.ident.span(),
+                });
+            }
+        }
+
+        for ty in self.types.types() {
+            if let TypeDeclaration::Opaque(opaque) = ty {
+                if opaque.attributes.dispatch_queue.is_some() {
+                    errors.push(ParseError::EmbeddedSwiftUnsupportedAttribute {
+                        attribute: "dispatch_queue",
+                        span: opaque.ty.span(),
+                    });
+                }
+            }
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse_ok;
+    use quote::quote;
+
+    /// Verify that we reject types that are represented by Foundation types when generating code
+    /// for Embedded Swift.
+    #[test]
+    fn rejects_foundation_types() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                struct SomeStruct {
+                    created_at: std::time::SystemTime,
+                    count: u32,
+                }
+
+                extern "Rust" {
+                    fn elapsed() -> Option<std::time::Duration>;
+                    fn payload(bytes: bytes::Bytes);
+                    fn count() -> u32;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let mut config = CodegenConfig::no_features_enabled();
+        config.embedded_swift = true;
+
+        let errors = module.embedded_swift_errors(&config);
+        assert_eq!(errors.len(), 3);
+        for error in errors.iter() {
+            assert!(matches!(
+                error,
+                ParseError::EmbeddedSwiftUnsupportedType { .. }
+            ));
+        }
+
+        config.embedded_swift = false;
+        assert!(module.check_embedded_swift(&config).is_ok());
+    }
+
+    /// Verify that we reject attributes whose generated Swift uses Foundation or Dispatch when
+    /// generating code for Embedded Swift.
+    #[test]
+    fn rejects_foundation_attributes() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(dispatch_queue = "com.example.queue")]
+                    type SomeType;
+
+                    #[swift_bridge(return_data)]
+                    fn some_function() -> Vec<u8>;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let mut config = CodegenConfig::no_features_enabled();
+        config.embedded_swift = true;
+
+        let errors = module.embedded_swift_errors(&config);
+        assert_eq!(errors.len(), 2);
+        match &errors[0] {
+            ParseError::EmbeddedSwiftUnsupportedAttribute { attribute, .. } => {
+                assert_eq!(*attribute, "return_data");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::EmbeddedSwiftUnsupportedAttribute { attribute, .. } => {
+                assert_eq!(*attribute, "dispatch_queue");
+            }
+            _ => panic!(),
+        };
+    }
+}
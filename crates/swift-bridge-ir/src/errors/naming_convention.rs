@@ -0,0 +1,142 @@
+use crate::codegen::{CodegenConfig, NameCasing};
+use crate::errors::ParseWarning;
+use crate::SwiftBridgeModule;
+
+impl SwiftBridgeModule {
+    /// Warn about the module's types and functions whose Swift names do not follow the
+    /// [`CodegenConfig::naming_convention`].
+    pub(super) fn naming_convention_warnings(&self, config: &CodegenConfig) -> Vec<ParseWarning> {
+        let mut warnings = vec![];
+
+        let naming_convention = match &config.naming_convention {
+            Some(naming_convention) => naming_convention,
+            None => return warnings,
+        };
+
+        let mut check = |swift_name: String, expected_casing: NameCasing| {
+            if !expected_casing.matches(&swift_name) {
+                warnings.push(ParseWarning::NamingConventionViolation {
+                    suggested_name: expected_casing.convert(&swift_name),
+                    swift_name,
+                    expected_casing,
+                });
+            }
+        };
+
+        for ty in self.types.types() {
+            check(ty.swift_name_string(), naming_convention.types);
+        }
+
+        for function in &self.functions {
+            // Initializers are always named `init` in Swift.
+            if function.is_swift_initializer {
+                continue;
+            }
+
+            let swift_name = function
+                .swift_getter_property_name(config)
+                .unwrap_or_else(|| function.swift_fn_name(config));
+            check(swift_name, naming_convention.functions);
+        }
+
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::NamingConvention;
+    use crate::test_utils::parse_ok;
+    use quote::quote;
+
+    /// Verify that we warn about a snake_case method name and suggest a camelCase one, but not
+    /// about names that follow the naming convention.
+    #[test]
+    fn snake_case_method_name() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn new() -> SomeType;
+                    fn get_value(&self) -> u8;
+                    fn reset(&self);
+                    #[swift_bridge(swift_name = "setValue")]
+                    fn set_value(&self, value: u8);
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let mut config = CodegenConfig::no_features_enabled();
+        config.naming_convention = Some(NamingConvention::default());
+
+        let warnings = module.parse_warnings(&config);
+        assert_eq!(
+            warnings,
+            vec![ParseWarning::NamingConventionViolation {
+                swift_name: "get_value".to_string(),
+                expected_casing: NameCasing::CamelCase,
+                suggested_name: "getValue".to_string(),
+            }]
+        );
+        assert!(warnings[0]
+            .to_string()
+            .contains(r#"is not camelCase. Consider renaming it to "getValue""#));
+
+        config.camel_case_swift_names = Some(Default::default());
+        assert!(module.parse_warnings(&config).is_empty());
+    }
+
+    /// Verify that we warn about types that do not follow the naming convention.
+    #[test]
+    fn type_names() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct some_struct {
+                    field: u8,
+                }
+
+                #[swift_bridge(swift_name = "RenamedEnum")]
+                enum some_enum {
+                    Variant,
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let mut config = CodegenConfig::no_features_enabled();
+        config.naming_convention = Some(NamingConvention::default());
+
+        assert_eq!(
+            module.parse_warnings(&config),
+            vec![ParseWarning::NamingConventionViolation {
+                swift_name: "some_struct".to_string(),
+                expected_casing: NameCasing::PascalCase,
+                suggested_name: "SomeStruct".to_string(),
+            }]
+        );
+    }
+
+    /// Verify that we do not check the casing of names unless a naming convention was configured.
+    #[test]
+    fn no_naming_convention() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn get_value() -> u8;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let config = CodegenConfig::no_features_enabled();
+        assert!(module.parse_warnings(&config).is_empty());
+    }
+}
@@ -1,4 +1,5 @@
-use proc_macro2::Ident;
+use crate::codegen::SwiftVersion;
+use proc_macro2::{Ident, Span};
 use quote::ToTokens;
 use syn::{Error, FnArg, Item, Receiver};
 use syn::{ForeignItemFn, ForeignItemType, LitStr};
@@ -38,14 +39,127 @@ pub(crate) enum ParseError {
     StructInvalidSwiftRepr { swift_repr_attr_value: LitStr },
     /// A struct was declared with an unrecognized attribute.
     StructUnrecognizedAttribute { attribute: Ident },
+    /// The number of names in a `#[swift_bridge(positional_names = [...])]` attribute does not
+    /// match the number of the struct's tuple fields.
+    StructPositionalNamesMismatch {
+        /// `positional_names`
+        attribute: Ident,
+        name_count: usize,
+        /// Zero if the struct is not a tuple struct.
+        field_count: usize,
+    },
+    /// A field that was annotated with `#[swift_bridge(error_description)]`,
+    /// `#[swift_bridge(failure_reason)]` or `#[swift_bridge(recovery_suggestion)]` is not a
+    /// `String`.
+    StructLocalizedErrorFieldNotString {
+        /// `error_description`, `failure_reason` or `recovery_suggestion`
+        attribute: Ident,
+        ty: Type,
+    },
+    /// A `#[swift_bridge(bytes)]` struct has a field that is not a primitive.
+    StructBytesFieldNotPrimitive { struct_ident: Ident, ty: Type },
+    /// A `#[swift_bridge(comparable)]` struct has a field whose Swift type is not `Comparable`.
+    StructFieldNotComparable { struct_ident: Ident, ty: Type },
+    /// A shared struct has a field whose type cannot be bridged.
+    /// Example: `struct Foo { bar: SomeUndeclaredType }`
+    StructFieldNotBridgeable {
+        struct_ident: Ident,
+        /// The field's name, or its index for tuple structs.
+        field_name: String,
+        ty: Type,
+    },
+    /// An extern "Rust" function returns a reference to a `#[swift_bridge(arc_mutex)]` type.
+    /// Swift holds these types as an `Arc<Mutex<T>>`, so they can only be returned by value.
+    ArcMutexReferenceReturned { ty: Type },
+    /// A `Cow<[T]>` was used somewhere other than the return type of an extern "Rust" function.
+    CowSliceNotReturnType { ty: Type },
+    /// A `Box<SomeSharedStruct>` was used outside of an `Option`.
+    /// Only `Option<Box<SomeSharedStruct>>` is currently supported.
+    BoxedSharedStructNotInOption { ty: Type },
     /// An enum was declared with an unrecognized attribute.
     EnumUnrecognizedAttribute { attribute: Ident },
+    /// A field of a `#[derive(PartialEq)]` enum has a type whose Swift representation can't be
+    /// compared, so we can't generate the enum's Swift `==`.
+    EnumFieldNotEquatable {
+        enum_ident: Ident,
+        variant: Ident,
+        ty: Type,
+    },
+    /// A variant of an enum that uses its discriminants as Swift raw values holds data.
+    /// For example, a `#[swift_bridge(comparable)]` enum.
+    EnumRawValueVariantHasData {
+        /// `comparable`
+        attribute: &'static str,
+        variant: Ident,
+    },
+    /// A variant of an enum that uses its discriminants as Swift raw values does not have an
+    /// integer discriminant.
+    EnumRawValueInvalidDiscriminant {
+        /// `comparable`
+        attribute: &'static str,
+        variant: Ident,
+    },
+    /// Two or more variants of an enum have the same discriminant.
+    /// Example: `enum Foo { A = 1, B = 1 }`
+    EnumDuplicateDiscriminant {
+        enum_ident: Ident,
+        value: i64,
+        /// The variants that share the discriminant, in declaration order.
+        variants: Vec<Ident>,
+    },
+    /// A variant of a `#[swift_bridge(case_iterable)]` or `#[swift_bridge(Identifiable)]` enum
+    /// holds data.
+    EnumUnitOnlyVariantHasData {
+        attribute: &'static str,
+        variant: Ident,
+    },
+    /// A variant of a `#[swift_bridge(option_set)]` enum holds data.
+    EnumOptionSetVariantHasData { variant: Ident },
+    /// A variant of a `#[swift_bridge(option_set)]` enum does not have a discriminant that is a
+    /// single bit of a `u32`.
+    EnumOptionSetInvalidDiscriminant { variant: Ident },
+    /// A variant of a `#[swift_bridge(option_set)]` enum uses the same bit as an earlier variant.
+    EnumOptionSetDuplicateBit { variant: Ident },
+    /// A generated Swift type or function would have a name that is in
+    /// [`crate::CodegenConfig::reserved_swift_names`].
+    ReservedSwiftName {
+        name: String,
+        /// The `swift_name = "..."` attribute value, or the Rust name if there isn't one.
+        span: Span,
+    },
+    /// A `#[swift_bridge(main_actor)]` type is used while targeting a Swift version that does not
+    /// have `@MainActor`, which was introduced in Swift 5.5.
+    MainActorUnsupportedSwiftVersion {
+        ty: Ident,
+        target_swift_version: SwiftVersion,
+    },
+    /// A type that is represented by a Foundation type in Swift, such as a `SystemTime`, is used
+    /// while generating code for Embedded Swift, which does not have Foundation.
+    EmbeddedSwiftUnsupportedType { ty: Type },
+    /// An attribute whose generated Swift uses Foundation or Dispatch, such as `return_data`, is
+    /// used while generating code for Embedded Swift.
+    EmbeddedSwiftUnsupportedAttribute {
+        attribute: &'static str,
+        /// The function or type that uses the attribute.
+        span: Span,
+    },
+    /// Two functions or methods would generate Swift declarations with the same name and argument
+    /// labels in the same Swift namespace.
+    DuplicateSwiftMemberName {
+        /// The colliding Swift signature, such as `SomeType.some_name(_:label:)`.
+        signature: String,
+        /// The `swift_name = "..."` attribute value, or the Rust name if there isn't one.
+        span: Span,
+    },
     /// There is no reason to use `swift_repr = "class"` on an empty struct.
     /// It's extra overhead with no advantages.
     EmptyStructHasSwiftReprClass {
         struct_ident: Ident,
         swift_repr_attr_value: LitStr,
     },
+    /// A struct that derives `Copy` has value semantics, so it can't use `swift_repr = "class"`,
+    /// which gives it reference semantics in Swift.
+    CopyStructWithClassRepr { struct_ident: Ident },
     /// See [`FunctionAttributeParseError`]
     FunctionAttribute(FunctionAttributeParseError),
     /// The function argument is a mutable reference to a Copy opaque type.
@@ -56,11 +170,18 @@ pub(crate) enum ParseError {
     InvalidModuleItem { item: Item },
     /// The associated_to attribute is used for only an associated method.
     InvalidAssociatedTo { self_: FnArg },
+    /// A function was associated with a shared type, but the only functions that shared types
+    /// support are synchronous extern "Rust" methods on `swift_repr = "struct"` structs.
+    /// The method can take `self: SomeStruct`, `self: &SomeStruct` or `self: &mut SomeStruct`.
+    UnsupportedSharedTypeMethod { fn_ident: Ident },
 }
 
 /// An error while parsing a function attribute.
 pub(crate) enum FunctionAttributeParseError {
     Identifiable(IdentifiableParseError),
+    Shared(SharedInstanceParseError),
+    ReturnData(ReturnDataParseError),
+    ReturnWarnings(ReturnWarningsParseError),
 }
 
 /// An error while parsing a function's `Identifiable` attribute.
@@ -71,6 +192,27 @@ pub(crate) enum IdentifiableParseError {
     MissingReturnType { fn_ident: Ident },
 }
 
+/// An error while parsing a function's `shared` attribute.
+pub(crate) enum SharedInstanceParseError {
+    /// A shared instance function must be associated to an opaque Rust type, take no arguments
+    /// and return a reference to that type.
+    InvalidSignature { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `return_data` attribute.
+pub(crate) enum ReturnDataParseError {
+    /// A `return_data` function must be a synchronous extern "Rust" function that returns a
+    /// `Vec<u8>` or a `Result<Vec<u8>, E>`.
+    InvalidSignature { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `return_warnings` attribute.
+pub(crate) enum ReturnWarningsParseError {
+    /// A `return_warnings` function must be a synchronous extern "Rust" function that returns a
+    /// `(T, Vec<W>)`.
+    InvalidSignature { fn_ident: Ident },
+}
+
 impl Into<syn::Error> for ParseError {
     fn into(self) -> Error {
         match self {
@@ -169,14 +311,215 @@ struct {struct_name};
                 );
                 Error::new_spanned(swift_repr_attr_value, message)
             }
+            ParseError::CopyStructWithClassRepr { struct_ident } => {
+                let message = format!(
+                    r#"Struct {struct_ident} derives `Copy` but has `swift_repr = "class"`, which would give it reference semantics in Swift.
+Either remove `Copy` from the struct's derives or use `swift_repr = "struct"`."#
+                );
+                Error::new_spanned(struct_ident, message)
+            }
+            ParseError::ArcMutexReferenceReturned { ty } => {
+                let message = format!(
+                    r#"Cannot return "{ty}". Types that use `#[swift_bridge(arc_mutex)]` can only be returned by value."#,
+                    ty = ty.to_token_stream()
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::CowSliceNotReturnType { ty } => {
+                let message = format!(
+                    r#"Type "{ty}" is not supported here. `Cow<[T]>` is currently only allowed as the return type of an extern "Rust" function."#,
+                    ty = ty.to_token_stream()
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::BoxedSharedStructNotInOption { ty } => {
+                let message = format!(
+                    r#"Type "{ty}" is not supported. A boxed shared struct can currently only be bridged as an `Option<{ty}>`."#,
+                    ty = ty.to_token_stream()
+                );
+                Error::new_spanned(ty, message)
+            }
             ParseError::StructUnrecognizedAttribute { attribute } => {
                 let message = format!(r#"Did not recognize struct attribute "{}"."#, attribute);
                 Error::new_spanned(attribute, message)
             }
+            ParseError::StructPositionalNamesMismatch {
+                attribute,
+                name_count,
+                field_count,
+            } => {
+                let message = format!(
+                    r#"Expected one positional name for each of the struct's {} tuple fields, but found {} names."#,
+                    field_count, name_count
+                );
+                Error::new_spanned(attribute, message)
+            }
+            ParseError::StructLocalizedErrorFieldNotString { attribute, ty } => {
+                let message = format!(
+                    r#"The "{}" attribute can only be used on a field of type "String", but the field has type "{}"."#,
+                    attribute,
+                    ty.to_token_stream()
+                );
+                Error::new_spanned(attribute, message)
+            }
+            ParseError::StructBytesFieldNotPrimitive { struct_ident, ty } => {
+                let message = format!(
+                    r#"Struct "{}" uses the "bytes" attribute, so its fields must be primitives such as "u32" or "bool", but found a field of type "{}"."#,
+                    struct_ident,
+                    ty.to_token_stream()
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::StructFieldNotComparable { struct_ident, ty } => {
+                let message = format!(
+                    r#"Struct "{}" uses the "comparable" attribute, so its fields must be numbers, comparable enums or comparable structs, but found a field of type "{}"."#,
+                    struct_ident,
+                    ty.to_token_stream()
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::StructFieldNotBridgeable {
+                struct_ident,
+                field_name,
+                ty,
+            } => {
+                let message = format!(
+                    r#"Field "{field_name}" of struct "{struct_name}" has type "{ty}", which cannot be bridged.
+Consider declaring "{ty}" as a shared struct or enum in the bridge module, or as an opaque type using `type {ty};`.
+"#,
+                    field_name = field_name,
+                    struct_name = struct_ident,
+                    ty = ty.to_token_stream().to_string()
+                );
+                Error::new_spanned(ty, message)
+            }
             ParseError::EnumUnrecognizedAttribute { attribute } => {
                 let message = format!(r#"Did not recognize enum attribute "{}"."#, attribute);
                 Error::new_spanned(attribute, message)
             }
+            ParseError::EnumFieldNotEquatable {
+                enum_ident,
+                variant,
+                ty,
+            } => {
+                let message = format!(
+                    r#"Variant "{variant}" of enum "{enum_ident}" holds a "{ty}", which cannot be compared in Swift, so the enum cannot derive PartialEq.
+Fields of a PartialEq enum must be primitives, Strings, enums that are Equatable in Swift or opaque Rust types that use `#[swift_bridge(Equatable)]`."#,
+                    ty = ty.to_token_stream()
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::EnumRawValueVariantHasData { attribute, variant } => {
+                let message = format!(
+                    r#"Variant "{}" of a {} enum cannot hold data."#,
+                    variant, attribute
+                );
+                Error::new_spanned(variant, message)
+            }
+            ParseError::EnumRawValueInvalidDiscriminant { attribute, variant } => {
+                let message = format!(
+                    r#"Variant "{variant}" of a {attribute} enum must have an integer discriminant.
+
+```
+#[swift_bridge({attribute})]
+enum SomeEnum {{
+    {variant} = 1,
+}}
+```
+"#,
+                    variant = variant,
+                    attribute = attribute
+                );
+                Error::new_spanned(variant, message)
+            }
+            ParseError::EnumDuplicateDiscriminant {
+                enum_ident,
+                value,
+                variants,
+            } => {
+                let names = variants
+                    .iter()
+                    .map(|variant| format!(r#""{}""#, variant))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let message = format!(
+                    r#"Variants {} of enum "{}" all have the discriminant {}. Each variant must have a unique discriminant."#,
+                    names, enum_ident, value
+                );
+                Error::new_spanned(&variants[1], message)
+            }
+            ParseError::EnumUnitOnlyVariantHasData { attribute, variant } => {
+                let message = format!(
+                    r#"Variant "{}" of a {} enum cannot hold data."#,
+                    variant, attribute
+                );
+                Error::new_spanned(variant, message)
+            }
+            ParseError::EnumOptionSetVariantHasData { variant } => {
+                let message = format!(
+                    r#"Variant "{}" of an option_set enum cannot hold data."#,
+                    variant
+                );
+                Error::new_spanned(variant, message)
+            }
+            ParseError::EnumOptionSetInvalidDiscriminant { variant } => {
+                let message = format!(
+                    r#"Variant "{variant}" of an option_set enum must have a discriminant with exactly one bit set.
+
+```
+#[swift_bridge(option_set)]
+enum SomeEnum {{
+    {variant} = 0b0100,
+}}
+```
+"#,
+                    variant = variant
+                );
+                Error::new_spanned(variant, message)
+            }
+            ParseError::EnumOptionSetDuplicateBit { variant } => {
+                let message = format!(
+                    r#"Variant "{}" uses a bit that is already used by another variant."#,
+                    variant
+                );
+                Error::new_spanned(variant, message)
+            }
+            ParseError::ReservedSwiftName { name, span } => {
+                let message = format!(
+                    r#"The Swift name "{}" is reserved. Consider using `#[swift_bridge(swift_name = "...")]` to give it a different Swift name."#,
+                    name
+                );
+                Error::new(span, message)
+            }
+            ParseError::MainActorUnsupportedSwiftVersion {
+                ty,
+                target_swift_version,
+            } => {
+                let message = format!(
+                    r#"Type {ty} uses `#[swift_bridge(main_actor)]`, but `@MainActor` requires Swift 5.5 and the target Swift version is {target_swift_version}."#
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::EmbeddedSwiftUnsupportedType { ty } => {
+                let message = format!(
+                    r#"Type "{ty}" is represented by a Foundation type in Swift, so it cannot be used when generating code for Embedded Swift."#,
+                    ty = ty.to_token_stream()
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::EmbeddedSwiftUnsupportedAttribute { attribute, span } => {
+                let message = format!(
+                    r#"`#[swift_bridge({attribute})]` generates Swift that uses Foundation or Dispatch, so it cannot be used when generating code for Embedded Swift."#
+                );
+                Error::new(span, message)
+            }
+            ParseError::DuplicateSwiftMemberName { signature, span } => {
+                let message = format!(
+                    r#"The Swift declaration {} is generated more than once. Consider using `#[swift_bridge(swift_name = "...")]` to give it a different Swift name."#,
+                    signature
+                );
+                Error::new(span, message)
+            }
             ParseError::FunctionAttribute(fn_attrib) => match fn_attrib {
                 FunctionAttributeParseError::Identifiable(identifiable) => match identifiable {
                     IdentifiableParseError::MustBeRefSelf { fn_ident } => {
@@ -194,6 +537,35 @@ struct {struct_name};
                         Error::new_spanned(fn_ident, message)
                     }
                 },
+                FunctionAttributeParseError::Shared(shared) => match shared {
+                    SharedInstanceParseError::InvalidSignature { fn_ident } => {
+                        let message = format!(
+                            r#"Shared instance function {} must be an associated function that takes no arguments and returns `&'static Self`."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::ReturnData(return_data) => match return_data {
+                    ReturnDataParseError::InvalidSignature { fn_ident } => {
+                        let message = format!(
+                            r#"Function {} with the `return_data` attribute must be a synchronous extern "Rust" function that returns `Vec<u8>` or `Result<Vec<u8>, E>`."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::ReturnWarnings(return_warnings) => {
+                    match return_warnings {
+                        ReturnWarningsParseError::InvalidSignature { fn_ident } => {
+                            let message = format!(
+                                r#"Function {} with the `return_warnings` attribute must be a synchronous extern "Rust" function that returns `(T, Vec<W>)`."#,
+                                fn_ident
+                            );
+                            Error::new_spanned(fn_ident, message)
+                        }
+                    }
+                }
             },
             ParseError::ArgCopyAndRefMut { arg } => {
                 let message =
@@ -209,6 +581,10 @@ struct {struct_name};
                     format!(r#"The associated_to attribute can only be used on static methods."#);
                 Error::new_spanned(self_, message)
             }
+            ParseError::UnsupportedSharedTypeMethod { fn_ident } => {
+                let message = r#"Shared types only support extern "Rust" methods that take `self: SomeStruct`, `self: &SomeStruct` or `self: &mut SomeStruct`, on structs that use `swift_repr = "struct"`."#;
+                Error::new_spanned(fn_ident, message)
+            }
         }
     }
 }
@@ -0,0 +1,313 @@
+use crate::codegen::{CodegenConfig, NameCasing};
+use crate::SwiftBridgeModule;
+use proc_macro2::Ident;
+use std::fmt::{Display, Formatter};
+
+/// A problem with a bridge module that does not prevent us from generating code for it.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ParseWarning {
+    /// A bridged function takes more arguments than [`CodegenConfig::max_function_arguments`].
+    ///
+    /// Platform ABIs only pass a handful of arguments in registers, so calls with many arguments
+    /// spill onto the stack.
+    TooManyFunctionArguments {
+        /// The name of the function.
+        function: Ident,
+        /// How many arguments the function takes, including its receiver.
+        argument_count: usize,
+        /// The maximum number of arguments that was configured.
+        max_arguments: usize,
+    },
+    /// A function's Swift name collided with another declaration, so
+    /// `#[swift_bridge(auto_suffix_swift_names)]` gave it a suffixed Swift name.
+    AutoSuffixedSwiftName {
+        /// The name of the function.
+        function: Ident,
+        /// The Swift name that the function was given.
+        swift_name: String,
+    },
+    /// A struct uses `swift_repr = "class"` even though all of its fields are `Copy` primitives.
+    ///
+    /// A Swift class is reference counted and heap allocated, which is unnecessary overhead for a
+    /// struct that is cheap to copy.
+    ClassReprStructWithOnlyPrimitiveFields {
+        /// The name of the struct.
+        struct_ident: Ident,
+    },
+    /// A bridge module does not declare any types or functions, so nothing gets generated for
+    /// it. This is usually a sign that its contents were accidentally placed somewhere else.
+    EmptyBridgeModule {
+        /// The name of the module.
+        module: Ident,
+    },
+    /// A bridged type or function has a Swift name that does not follow the
+    /// [`CodegenConfig::naming_convention`].
+    NamingConventionViolation {
+        /// The Swift name of the type or function.
+        swift_name: String,
+        /// The casing that the name was expected to be written in.
+        expected_casing: NameCasing,
+        /// The name converted into the expected casing.
+        suggested_name: String,
+    },
+}
+
+impl Display for ParseWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseWarning::TooManyFunctionArguments {
+                function,
+                argument_count,
+                max_arguments,
+            } => write!(
+                f,
+                r#""fn {}" takes {} arguments, which is more than the maximum of {}. Consider passing a shared struct that holds the arguments instead."#,
+                function, argument_count, max_arguments
+            ),
+            ParseWarning::AutoSuffixedSwiftName {
+                function,
+                swift_name,
+            } => write!(
+                f,
+                r#""fn {}" collides with another Swift declaration, so it was given the Swift name "{}". Consider using `#[swift_bridge(swift_name = "...")]` to pick a name yourself."#,
+                function, swift_name
+            ),
+            ParseWarning::ClassReprStructWithOnlyPrimitiveFields { struct_ident } => write!(
+                f,
+                r#"Struct "{}" only has primitive fields, so it is cheaper to pass as a Swift struct. Consider using `#[swift_bridge(swift_repr = "struct")]` instead of `swift_repr = "class"`."#,
+                struct_ident
+            ),
+            ParseWarning::EmptyBridgeModule { module } => write!(
+                f,
+                r#"Bridge module "{}" does not declare any bridged types or functions, so no Swift or C code will be generated for it. Did you mean to declare items inside of it?"#,
+                module
+            ),
+            ParseWarning::NamingConventionViolation {
+                swift_name,
+                expected_casing,
+                suggested_name,
+            } => write!(
+                f,
+                r#"Swift name "{}" is not {}. Consider renaming it to "{}"."#,
+                swift_name, expected_casing, suggested_name
+            ),
+        }
+    }
+}
+
+impl SwiftBridgeModule {
+    /// Look for problems with the module that do not prevent us from generating code for it.
+    pub fn parse_warnings(&self, config: &CodegenConfig) -> Vec<ParseWarning> {
+        let mut warnings = self.parse_warnings.clone();
+
+        for (function, swift_name) in &self.auto_suffixed_swift_names {
+            warnings.push(ParseWarning::AutoSuffixedSwiftName {
+                function: function.clone(),
+                swift_name: swift_name.clone(),
+            });
+        }
+
+        if let Some(max_arguments) = config.max_function_arguments {
+            for function in &self.functions {
+                let argument_count = function.func.sig.inputs.len();
+                if argument_count > max_arguments {
+                    warnings.push(ParseWarning::TooManyFunctionArguments {
+                        function: function.func.sig.ident.clone(),
+                        argument_count,
+                        max_arguments,
+                    });
+                }
+            }
+        }
+
+        warnings.extend(self.naming_convention_warnings(config));
+
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse_ok;
+    use quote::quote;
+
+    /// Verify that we warn about functions that take more than the maximum number of arguments.
+    #[test]
+    fn too_many_function_arguments() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn few_args(a: u8, b: u8);
+                    fn many_args(a: u8, b: u8, c: u8, d: u8);
+                    fn many_args_method(&self, a: u8, b: u8, c: u8);
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let mut config = CodegenConfig::no_features_enabled();
+        config.max_function_arguments = Some(3);
+
+        let warnings = module.parse_warnings(&config);
+        assert_eq!(warnings.len(), 2);
+
+        match &warnings[0] {
+            ParseWarning::TooManyFunctionArguments {
+                function,
+                argument_count,
+                max_arguments,
+            } => {
+                assert_eq!(function, "many_args");
+                assert_eq!(*argument_count, 4);
+                assert_eq!(*max_arguments, 3);
+            }
+            _ => panic!(),
+        };
+        match &warnings[1] {
+            ParseWarning::TooManyFunctionArguments {
+                function,
+                argument_count,
+                ..
+            } => {
+                assert_eq!(function, "many_args_method");
+                assert_eq!(*argument_count, 4);
+            }
+            _ => panic!(),
+        };
+        assert!(warnings[0]
+            .to_string()
+            .contains("Consider passing a shared struct"));
+    }
+
+    /// Verify that we do not warn about the number of arguments unless a maximum was configured.
+    #[test]
+    fn no_max_function_arguments() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn many_args(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8, g: u8, h: u8, i: u8);
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let config = CodegenConfig::no_features_enabled();
+        assert!(module.parse_warnings(&config).is_empty());
+    }
+
+    /// Verify that we warn about functions that were given a suffixed Swift name.
+    #[test]
+    fn auto_suffixed_swift_name() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            #[swift_bridge(auto_suffix_swift_names)]
+            mod ffi {
+                extern "Rust" {
+                    fn make();
+                    #[swift_bridge(swift_name = "make")]
+                    fn make_another();
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let config = CodegenConfig::no_features_enabled();
+        let warnings = module.parse_warnings(&config);
+
+        assert_eq!(
+            warnings,
+            vec![ParseWarning::AutoSuffixedSwiftName {
+                function: quote::format_ident!("make_another"),
+                swift_name: "make2".to_string(),
+            }]
+        );
+    }
+
+    /// Verify that we warn about `swift_repr = "class"` structs that only have primitive fields,
+    /// but not about ones that have non primitive fields.
+    #[test]
+    fn class_repr_struct_with_only_primitive_fields() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "class")]
+                struct Point {
+                    x: f64,
+                    y: f64,
+                    visible: bool,
+                }
+
+                #[swift_bridge(swift_repr = "class")]
+                struct Named {
+                    id: u32,
+                    name: String,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct Size {
+                    width: f64,
+                    height: f64,
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let config = CodegenConfig::no_features_enabled();
+        let warnings = module.parse_warnings(&config);
+
+        assert_eq!(
+            warnings,
+            vec![ParseWarning::ClassReprStructWithOnlyPrimitiveFields {
+                struct_ident: quote::format_ident!("Point"),
+            }]
+        );
+        assert!(warnings[0].to_string().contains(r#"swift_repr = "struct""#));
+    }
+
+    /// Verify that we warn about a bridge module that does not declare any bridged items, even if
+    /// it contains an empty extern block.
+    #[test]
+    fn empty_bridge_module() {
+        let config = CodegenConfig::no_features_enabled();
+
+        for tokens in [
+            quote! {
+                #[swift_bridge::bridge]
+                mod ffi {}
+            },
+            quote! {
+                #[swift_bridge::bridge]
+                mod ffi {
+                    extern "Rust" {}
+                }
+            },
+        ] {
+            let warnings = parse_ok(tokens).parse_warnings(&config);
+
+            assert_eq!(
+                warnings,
+                vec![ParseWarning::EmptyBridgeModule {
+                    module: quote::format_ident!("ffi"),
+                }]
+            );
+            assert!(warnings[0]
+                .to_string()
+                .contains("does not declare any bridged types or functions"));
+        }
+
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function();
+                }
+            }
+        };
+        assert!(parse_ok(tokens).parse_warnings(&config).is_empty());
+    }
+}
@@ -0,0 +1,136 @@
+use crate::codegen::CodegenConfig;
+use crate::errors::{ParseError, ParseErrors};
+use crate::parse::{SharedTypeDeclaration, TypeDeclaration};
+use crate::SwiftBridgeModule;
+use proc_macro2::Span;
+
+impl SwiftBridgeModule {
+    /// Return an error if any of the module's generated Swift types or functions would have a
+    /// name that is in [`CodegenConfig::reserved_swift_names`].
+    ///
+    /// This lets a project forbid names beyond Swift's keywords, such as single letter type names
+    /// that would shadow generic parameters.
+    pub fn check_reserved_swift_names(&self, config: &CodegenConfig) -> Result<(), syn::Error> {
+        self.reserved_swift_name_errors(config).combine_all()
+    }
+
+    fn reserved_swift_name_errors(&self, config: &CodegenConfig) -> ParseErrors {
+        let mut errors = ParseErrors::new();
+
+        if config.reserved_swift_names.is_empty() {
+            return errors;
+        }
+
+        let mut check = |name: String, span: Span| {
+            if config.reserved_swift_names.contains(&name) {
+                errors.push(ParseError::ReservedSwiftName { name, span });
+            }
+        };
+
+        for ty in self.types.types() {
+            match ty {
+                TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                    let span = match &shared_struct.swift_name {
+                        Some(swift_name) => swift_name.span(),
+                        None => shared_struct.name.span(),
+                    };
+                    check(shared_struct.swift_name_string(), span);
+                }
+                TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
+                    let span = match &shared_enum.swift_name {
+                        Some(swift_name) => swift_name.span(),
+                        None => shared_enum.name.span(),
+                    };
+                    check(shared_enum.swift_name_string(), span);
+                }
+                TypeDeclaration::Opaque(opaque) => {
+                    check(opaque.ty.to_string(), opaque.ty.span());
+                }
+            }
+        }
+
+        for function in &self.functions {
+            let span = match &function.swift_name_override {
+                Some(swift_name) => swift_name.span(),
+                None => function.func.sig.ident.span(),
+            };
+            check(function.swift_fn_name(config), span);
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse_ok;
+    use quote::quote;
+
+    /// Verify that we reject Swift names that are in a custom reserved list.
+    #[test]
+    fn rejects_reserved_swift_names() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                struct T;
+
+                #[swift_bridge(swift_name = "U")]
+                enum SomeEnum {
+                    Variant
+                }
+
+                extern "Rust" {
+                    type V;
+                    type AllowedType;
+
+                    #[swift_bridge(swift_name = "x")]
+                    fn some_function();
+                    fn allowed_function();
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let mut config = CodegenConfig::no_features_enabled();
+        config.reserved_swift_names = vec![
+            "T".to_string(),
+            "U".to_string(),
+            "V".to_string(),
+            "x".to_string(),
+            "SomeEnum".to_string(),
+            "some_function".to_string(),
+        ];
+
+        let errors = module.reserved_swift_name_errors(&config);
+        let names: Vec<&str> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::ReservedSwiftName { name, .. } => name.as_str(),
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(names, vec!["T", "U", "V", "x"]);
+
+        assert!(module.check_reserved_swift_names(&config).is_err());
+    }
+
+    /// Verify that no names are reserved by default.
+    #[test]
+    fn no_reserved_swift_names() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                struct T;
+
+                extern "Rust" {
+                    type V;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let config = CodegenConfig::no_features_enabled();
+        assert!(module.check_reserved_swift_names(&config).is_ok());
+    }
+}
@@ -0,0 +1,79 @@
+use crate::codegen::CodegenConfig;
+use crate::errors::{ParseError, ParseErrors};
+use crate::parse::TypeDeclaration;
+use crate::SwiftBridgeModule;
+
+impl SwiftBridgeModule {
+    /// Return an error if the module uses a Swift language feature that is not available in the
+    /// [`CodegenConfig::target_swift_version`].
+    pub fn check_target_swift_version(&self, config: &CodegenConfig) -> Result<(), syn::Error> {
+        self.target_swift_version_errors(config).combine_all()
+    }
+
+    fn target_swift_version_errors(&self, config: &CodegenConfig) -> ParseErrors {
+        let mut errors = ParseErrors::new();
+
+        let target_swift_version = match config.target_swift_version {
+            Some(target_swift_version) => target_swift_version,
+            None => return errors,
+        };
+
+        if !target_swift_version.supports_main_actor() {
+            for ty in self.types.types() {
+                if let TypeDeclaration::Opaque(opaque) = ty {
+                    if opaque.attributes.main_actor {
+                        errors.push(ParseError::MainActorUnsupportedSwiftVersion {
+                            ty: opaque.ty.clone(),
+                            target_swift_version,
+                        });
+                    }
+                }
+            }
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::SwiftVersion;
+    use crate::test_utils::parse_ok;
+    use quote::quote;
+
+    /// Verify that we reject `#[swift_bridge(main_actor)]` types when targeting a Swift version
+    /// that does not have `@MainActor`.
+    #[test]
+    fn rejects_main_actor_before_swift_5_5() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(main_actor)]
+                    type SomeType;
+                    type OtherType;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let mut config = CodegenConfig::no_features_enabled();
+        config.target_swift_version = Some(SwiftVersion::new(5, 4));
+
+        let errors = module.target_swift_version_errors(&config);
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::MainActorUnsupportedSwiftVersion { ty, .. } => {
+                assert_eq!(ty, "SomeType");
+            }
+            _ => panic!(),
+        };
+
+        config.target_swift_version = Some(SwiftVersion::new(5, 5));
+        assert!(module.check_target_swift_version(&config).is_ok());
+
+        config.target_swift_version = None;
+        assert!(module.check_target_swift_version(&config).is_ok());
+    }
+}
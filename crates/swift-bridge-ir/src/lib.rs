@@ -8,14 +8,18 @@
 #![deny(missing_docs)]
 
 use proc_macro2::Ident;
-use syn::{Path, Visibility};
+use syn::{LitStr, Path, Visibility};
 
-use crate::bridge_module_attributes::CfgAttr;
+use crate::bridge_module_attributes::{CfgAttr, FfiVisibility};
 use crate::parse::TypeDeclarations;
 use crate::parsed_extern_fn::ParsedExternFn;
 
 pub use self::bridge_macro_attributes::{SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs};
-pub use self::codegen::CodegenConfig;
+pub use self::codegen::{
+    CamelCaseSwiftNames, CodegenConfig, GetterProperties, NameCasing, NamingConvention,
+    SwiftResultFunctions, SwiftVersion,
+};
+pub use self::errors::ParseWarning;
 
 mod errors;
 mod parse;
@@ -63,6 +67,16 @@ pub struct SwiftBridgeModule {
     functions: Vec<ParsedExternFn>,
     swift_bridge_path: Path,
     cfg_attrs: Vec<CfgAttr>,
+    /// Functions that were given a suffixed Swift name because of
+    /// `#[swift_bridge(auto_suffix_swift_names)]`, along with the name that they were given.
+    auto_suffixed_swift_names: Vec<(Ident, String)>,
+    /// Warnings that were found while parsing the module.
+    parse_warnings: Vec<ParseWarning>,
+    /// The Rust visibility of the generated `extern "C"` functions.
+    ffi_visibility: FfiVisibility,
+    /// `#[swift_bridge(rust_ffi_file = "...")]`, the name of the file in `OUT_DIR` that holds
+    /// the generated `extern "C"` functions.
+    rust_ffi_file: Option<LitStr>,
 }
 
 impl SwiftBridgeModule {
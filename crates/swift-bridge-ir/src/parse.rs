@@ -1,20 +1,27 @@
-use crate::bridge_module_attributes::CfgAttr;
-use crate::bridged_type::BridgedType;
-use crate::errors::{ParseError, ParseErrors};
-use crate::parse::parse_enum::SharedEnumDeclarationParser;
-use crate::parse::parse_extern_mod::ForeignModParser;
-use crate::parse::parse_struct::SharedStructDeclarationParser;
+use crate::bridge_module_attributes::{CfgAttr, FfiVisibility, ModuleSwiftBridgeAttr};
+use crate::bridged_type::bridgeable_boxed_struct::find_unsupported_boxed_shared_struct;
+use crate::bridged_type::bridgeable_cow_slice::{find_cow_slice, BridgeableCowSlice};
+use crate::bridged_type::{BridgeableType, BridgedType};
+use crate::errors::{ParseError, ParseErrors, ParseWarning};
+use crate::parse::parse_enum::{push_non_equatable_enum_field_errors, SharedEnumDeclarationParser};
+use crate::parse::parse_extern_mod::{push_duplicate_swift_member_name_errors, ForeignModParser};
+use crate::parse::parse_struct::{
+    push_bytes_struct_field_errors, push_non_comparable_struct_field_errors,
+    push_unbridgeable_struct_field_errors, SharedStructDeclarationParser,
+};
 use crate::SwiftBridgeModule;
-use proc_macro2::TokenTree;
+use proc_macro2::{Delimiter, TokenTree};
 use quote::{quote, ToTokens};
+use std::ops::Deref;
 use syn::parse::{Parse, ParseStream};
-use syn::{Item, ItemMod, Token};
+use syn::{FnArg, GenericArgument, Item, ItemMod, LitStr, PathArguments, ReturnType, Token, Type};
 
 mod parse_enum;
 mod parse_extern_mod;
 mod parse_struct;
 
 mod type_declarations;
+pub(crate) use self::parse_extern_mod::swift_member_signature;
 pub(crate) use self::type_declarations::*;
 
 impl Parse for SwiftBridgeModule {
@@ -63,6 +70,11 @@ impl Parse for SwiftBridgeModuleAndErrors {
             let mut type_declarations = TypeDeclarations::default();
             let mut unresolved_types = vec![];
             let mut cfg_attrs = vec![];
+            let mut parse_warnings = vec![];
+            let mut auto_suffix_swift_names = false;
+            let mut ffi_visibility = FfiVisibility::default();
+            let mut rust_ffi_file = None;
+            let mut extern_block_idx = 0;
 
             for attr in item_mod.attrs {
                 match attr.path.to_token_stream().to_string().as_str() {
@@ -70,6 +82,19 @@ impl Parse for SwiftBridgeModuleAndErrors {
                         let cfg: CfgAttr = syn::parse2(attr.tokens)?;
                         cfg_attrs.push(cfg);
                     }
+                    "swift_bridge" if is_parenthesized(&attr.tokens) => {
+                        match syn::parse2(attr.tokens)? {
+                            ModuleSwiftBridgeAttr::AutoSuffixSwiftNames => {
+                                auto_suffix_swift_names = true;
+                            }
+                            ModuleSwiftBridgeAttr::FfiVisibility(visibility) => {
+                                ffi_visibility = visibility;
+                            }
+                            ModuleSwiftBridgeAttr::RustFfiFile(file) => {
+                                rust_ffi_file = Some(file);
+                            }
+                        }
+                    }
                     _ => {}
                 };
             }
@@ -82,13 +107,16 @@ impl Parse for SwiftBridgeModuleAndErrors {
                             type_declarations: &mut type_declarations,
                             functions: &mut functions,
                             unresolved_types: &mut unresolved_types,
+                            extern_block_idx,
                         }
                         .parse(foreign_mod)?;
+                        extern_block_idx += 1;
                     }
                     Item::Struct(item_struct) => {
                         let shared_struct = SharedStructDeclarationParser {
                             item_struct,
                             errors: &mut errors,
+                            warnings: &mut parse_warnings,
                         }
                         .parse()?;
                         type_declarations.insert(
@@ -124,6 +152,82 @@ impl Parse for SwiftBridgeModuleAndErrors {
                 });
             }
 
+            for function in functions.iter() {
+                let sig = &function.func.sig;
+                let arg_types = sig.inputs.iter().filter_map(|arg| match arg {
+                    FnArg::Typed(pat_ty) => Some(pat_ty.ty.deref()),
+                    FnArg::Receiver(_) => None,
+                });
+                let return_type = match &sig.output {
+                    ReturnType::Type(_, ty) => Some(ty.deref()),
+                    ReturnType::Default => None,
+                };
+
+                for ty in arg_types.clone().chain(return_type) {
+                    if let Some(ty) = find_unsupported_boxed_shared_struct(ty, &type_declarations) {
+                        errors.push(ParseError::BoxedSharedStructNotInOption { ty: ty.clone() });
+                    }
+                }
+
+                // A `Cow<[T]>` can be returned from Rust, but can't be used anywhere else.
+                let return_type_cow = return_type.and_then(|ty| {
+                    let is_returned_cow = function.host_lang.is_rust()
+                        && BridgeableCowSlice::from_type(ty, &type_declarations).is_some();
+                    if is_returned_cow {
+                        None
+                    } else {
+                        find_cow_slice(ty, &type_declarations)
+                    }
+                });
+                let arg_cows = arg_types.filter_map(|ty| find_cow_slice(ty, &type_declarations));
+                for ty in arg_cows.chain(return_type_cow) {
+                    errors.push(ParseError::CowSliceNotReturnType { ty: ty.clone() });
+                }
+
+                if function.host_lang.is_rust() {
+                    let arc_mutex_ref =
+                        return_type.and_then(|ty| find_arc_mutex_reference(ty, &type_declarations));
+                    if let Some(ty) = arc_mutex_ref {
+                        errors.push(ParseError::ArcMutexReferenceReturned { ty: ty.clone() });
+                    }
+                }
+            }
+
+            for ty in type_declarations.types() {
+                if let TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) = ty {
+                    push_unbridgeable_struct_field_errors(
+                        shared_struct,
+                        &type_declarations,
+                        &mut errors,
+                    );
+                    push_bytes_struct_field_errors(shared_struct, &type_declarations, &mut errors);
+                    push_non_comparable_struct_field_errors(
+                        shared_struct,
+                        &type_declarations,
+                        &mut errors,
+                    );
+                }
+                if let TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) = ty {
+                    push_non_equatable_enum_field_errors(
+                        shared_enum,
+                        &type_declarations,
+                        &mut errors,
+                    );
+                }
+            }
+
+            if functions.is_empty() && type_declarations.types().is_empty() {
+                parse_warnings.push(ParseWarning::EmptyBridgeModule {
+                    module: module_name.clone(),
+                });
+            }
+
+            let auto_suffixed_swift_names = push_duplicate_swift_member_name_errors(
+                &mut functions,
+                auto_suffix_swift_names,
+                &mut errors,
+            );
+
             let module = SwiftBridgeModule {
                 name: module_name,
                 vis,
@@ -131,6 +235,10 @@ impl Parse for SwiftBridgeModuleAndErrors {
                 functions,
                 swift_bridge_path: syn::parse2(quote! { swift_bridge }).unwrap(),
                 cfg_attrs,
+                auto_suffixed_swift_names,
+                parse_warnings,
+                ffi_visibility,
+                rust_ffi_file,
             };
             Ok(SwiftBridgeModuleAndErrors { module, errors })
         } else {
@@ -142,6 +250,52 @@ impl Parse for SwiftBridgeModuleAndErrors {
     }
 }
 
+// Whether an attribute's tokens are a parenthesized group, such as `(auto_suffix_swift_names)`.
+/// Find a reference to a `#[swift_bridge(arc_mutex)]` type within the type.
+///
+/// Swift holds an `Arc<Mutex<T>>` for these types, so there is no way to give it a `&T`.
+fn find_arc_mutex_reference<'a>(ty: &'a Type, types: &TypeDeclarations) -> Option<&'a Type> {
+    match ty {
+        Type::Reference(reference) => {
+            let is_arc_mutex = match reference.elem.deref() {
+                Type::Path(path) => matches!(
+                    types.get_with_type_path(path),
+                    Some(TypeDeclaration::Opaque(opaque)) if opaque.attributes.arc_mutex
+                ),
+                _ => false,
+            };
+            if is_arc_mutex {
+                return Some(ty);
+            }
+
+            find_arc_mutex_reference(&reference.elem, types)
+        }
+        Type::Path(path) => {
+            let last = path.path.segments.last()?;
+            match &last.arguments {
+                PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+                    GenericArgument::Type(inner) => find_arc_mutex_reference(inner, types),
+                    _ => None,
+                }),
+                _ => None,
+            }
+        }
+        Type::Tuple(tuple) => tuple
+            .elems
+            .iter()
+            .find_map(|elem| find_arc_mutex_reference(elem, types)),
+        _ => None,
+    }
+}
+
+fn is_parenthesized(tokens: &proc_macro2::TokenStream) -> bool {
+    let mut tokens = tokens.clone().into_iter();
+    match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Group(group)), None) => group.delimiter() == Delimiter::Parenthesis,
+        _ => false,
+    }
+}
+
 // Used to fast-forward our attribute parsing to the next attribute when we've run into an
 // issue parsing the current attribute.
 fn move_input_cursor_to_next_comma(input: ParseStream) {
@@ -163,6 +317,34 @@ fn move_input_cursor_to_next_comma(input: ParseStream) {
     }
 }
 
+// Parses the comma separated protocol names from a `conforms_to = "Protocol, AnotherProtocol"`
+// attribute, erroring if any of them is not a legal Swift identifier.
+fn parse_conforms_to(protocols: &LitStr) -> syn::Result<Vec<String>> {
+    protocols
+        .value()
+        .split(',')
+        .map(|protocol| {
+            let protocol = protocol.trim();
+
+            let mut chars = protocol.chars();
+            let is_identifier = chars
+                .next()
+                .map(|c| c.is_alphabetic() || c == '_')
+                .unwrap_or(false)
+                && chars.all(|c| c.is_alphanumeric() || c == '_');
+
+            if is_identifier {
+                Ok(protocol.to_string())
+            } else {
+                Err(syn::Error::new_spanned(
+                    protocols,
+                    format!(r#""{}" is not a valid Swift protocol name."#, protocol),
+                ))
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -188,6 +370,54 @@ mod tests {
         };
     }
 
+    /// Verify that we can parse the `ffi_visibility` module attribute.
+    #[test]
+    fn parse_module_ffi_visibility() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {}
+        };
+        assert_eq!(parse_ok(tokens).ffi_visibility, FfiVisibility::Pub);
+
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            #[swift_bridge(ffi_visibility = "crate")]
+            mod foo {}
+        };
+        assert_eq!(parse_ok(tokens).ffi_visibility, FfiVisibility::Crate);
+    }
+
+    /// Verify that we can parse the `rust_ffi_file` module attribute.
+    #[test]
+    fn parse_module_rust_ffi_file() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            #[swift_bridge(rust_ffi_file = "some_ffi.rs")]
+            mod foo {}
+        };
+
+        let module = parse_ok(tokens);
+        assert_eq!(module.rust_ffi_file.unwrap().value(), "some_ffi.rs");
+    }
+
+    /// Verify that we get an error for an unknown `ffi_visibility`.
+    #[test]
+    fn error_if_unknown_ffi_visibility() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            #[swift_bridge(ffi_visibility = "private")]
+            mod foo {}
+        };
+
+        let error = syn::parse2::<SwiftBridgeModuleAndErrors>(tokens)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            r#"Unknown ffi_visibility "private". Expected "pub" or "crate"."#
+        );
+    }
+
     /// Verify that we get an error when parsing an unsupported module item, such as a
     /// `use` statement.
     #[test]
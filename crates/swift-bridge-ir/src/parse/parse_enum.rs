@@ -1,6 +1,8 @@
-use crate::bridged_type::{EnumVariant, SharedEnum, StructFields};
-use crate::errors::ParseErrors;
-use syn::ItemEnum;
+use crate::bridged_type::{swift_field_equality, EnumVariant, SharedEnum, StructFields};
+use crate::errors::{ParseError, ParseErrors};
+use crate::parse::TypeDeclarations;
+use proc_macro2::Ident;
+use syn::{Expr, ItemEnum, Lit};
 
 use self::enum_attributes::SharedEnumAllAttributes;
 
@@ -24,22 +26,225 @@ impl<'a> SharedEnumDeclarationParser<'a> {
             let variant = EnumVariant {
                 name: v.ident,
                 fields: StructFields::from_syn_fields(v.fields),
+                discriminant: v.discriminant.map(|(_eq, expr)| expr),
             };
             variants.push(variant);
         }
 
+        // Both of these attributes use the variants' discriminants as the Swift enum's raw values.
+        let raw_value_attribute = if attribs.swift_bridge.objc {
+            Some("objc")
+        } else if attribs.swift_bridge.comparable {
+            Some("comparable")
+        } else {
+            None
+        };
+        if let Some(attribute) = raw_value_attribute {
+            validate_raw_value_variants(self.errors, &variants, attribute);
+        }
+        if attribs.swift_bridge.case_iterable {
+            validate_unit_only_variants(self.errors, &variants, "case_iterable");
+        }
+        if attribs.swift_bridge.identifiable {
+            validate_unit_only_variants(self.errors, &variants, "Identifiable");
+        }
+        if attribs.swift_bridge.option_set {
+            validate_option_set_variants(self.errors, &variants);
+        } else {
+            validate_unique_discriminants(self.errors, &item_enum.ident, &variants);
+        }
+
         let shared_enum = SharedEnum {
             name: item_enum.ident,
             variants,
             already_declared: attribs.swift_bridge.already_declared,
             swift_name: attribs.swift_bridge.swift_name,
             derive: attribs.derive,
+            case_iterable: attribs.swift_bridge.case_iterable,
+            identifiable: attribs.swift_bridge.identifiable,
+            comparable: attribs.swift_bridge.comparable,
+            conforms_to: attribs.swift_bridge.conforms_to,
+            custom_string_convertible: attribs.swift_bridge.custom_string_convertible,
+            error_domain: attribs.swift_bridge.error_domain,
+            from_str: attribs.swift_bridge.from_str,
+            objc: attribs.swift_bridge.objc,
+            option_set: attribs.swift_bridge.option_set,
         };
 
         Ok(shared_enum)
     }
 }
 
+/// Every variant of a `comparable` or `objc` enum must be a unit variant with an integer
+/// discriminant, since the generated Swift enum uses the discriminants as its raw values.
+fn validate_raw_value_variants(
+    errors: &mut ParseErrors,
+    variants: &[EnumVariant],
+    attribute: &'static str,
+) {
+    for variant in variants {
+        if !variant.fields.is_empty() {
+            errors.push(ParseError::EnumRawValueVariantHasData {
+                attribute,
+                variant: variant.name.clone(),
+            });
+            continue;
+        }
+
+        if variant.integer_discriminant().is_none() {
+            errors.push(ParseError::EnumRawValueInvalidDiscriminant {
+                attribute,
+                variant: variant.name.clone(),
+            });
+        }
+    }
+}
+
+/// No two variants can have the same discriminant, since the discriminant would then map to more
+/// than one variant.
+///
+/// Variants without an explicit discriminant follow Rust's rules and use the previous variant's
+/// discriminant plus one. `option_set` enums are checked by [`validate_option_set_variants`].
+fn validate_unique_discriminants(
+    errors: &mut ParseErrors,
+    enum_ident: &Ident,
+    variants: &[EnumVariant],
+) {
+    let mut discriminants: Vec<(i64, Vec<Ident>)> = vec![];
+    let mut next = Some(0);
+
+    for variant in variants {
+        let value = match &variant.discriminant {
+            Some(_) => variant.integer_discriminant(),
+            None => next,
+        };
+        next = value.and_then(|value| value.checked_add(1));
+
+        // We can't evaluate discriminants that aren't integer literals, such as constants.
+        let value = match value {
+            Some(value) => value,
+            None => continue,
+        };
+
+        match discriminants
+            .iter_mut()
+            .find(|(existing, _)| *existing == value)
+        {
+            Some((_, names)) => names.push(variant.name.clone()),
+            None => discriminants.push((value, vec![variant.name.clone()])),
+        }
+    }
+
+    for (value, names) in discriminants {
+        if names.len() > 1 {
+            errors.push(ParseError::EnumDuplicateDiscriminant {
+                enum_ident: enum_ident.clone(),
+                value,
+                variants: names,
+            });
+        }
+    }
+}
+
+/// Every variant of a `case_iterable` or `Identifiable` enum must be a unit variant, since Swift
+/// can only list the cases of an enum whose cases hold no data, and only synthesizes `Hashable` for
+/// such enums.
+fn validate_unit_only_variants(
+    errors: &mut ParseErrors,
+    variants: &[EnumVariant],
+    attribute: &'static str,
+) {
+    for variant in variants {
+        if !variant.fields.is_empty() {
+            errors.push(ParseError::EnumUnitOnlyVariantHasData {
+                attribute,
+                variant: variant.name.clone(),
+            });
+        }
+    }
+}
+
+/// Every variant of an `option_set` enum must be a unit variant whose discriminant occupies
+/// a single bit of a `u32` that no other variant is using.
+fn validate_option_set_variants(errors: &mut ParseErrors, variants: &[EnumVariant]) {
+    let mut used_bits: u32 = 0;
+
+    for variant in variants {
+        if !variant.fields.is_empty() {
+            errors.push(ParseError::EnumOptionSetVariantHasData {
+                variant: variant.name.clone(),
+            });
+            continue;
+        }
+
+        let bit = match option_set_discriminant_bit(variant.discriminant.as_ref()) {
+            Some(bit) => bit,
+            None => {
+                errors.push(ParseError::EnumOptionSetInvalidDiscriminant {
+                    variant: variant.name.clone(),
+                });
+                continue;
+            }
+        };
+
+        if used_bits & bit != 0 {
+            errors.push(ParseError::EnumOptionSetDuplicateBit {
+                variant: variant.name.clone(),
+            });
+        }
+        used_bits |= bit;
+    }
+}
+
+/// Push an error for every field of a `#[derive(PartialEq)]` enum whose type can't be compared in
+/// Swift.
+///
+/// This happens after the entire module has been parsed, since a field's type might be declared
+/// further down in the module.
+pub(crate) fn push_non_equatable_enum_field_errors(
+    shared_enum: &SharedEnum,
+    types: &TypeDeclarations,
+    errors: &mut ParseErrors,
+) {
+    if !shared_enum.derive.partial_eq {
+        return;
+    }
+
+    for variant in shared_enum.variants.iter() {
+        for field in variant.fields.normalized_fields() {
+            if swift_field_equality(&field.ty, "lhs", "rhs", types).is_some() {
+                continue;
+            }
+
+            errors.push(ParseError::EnumFieldNotEquatable {
+                enum_ident: shared_enum.name.clone(),
+                variant: variant.name.clone(),
+                ty: field.ty,
+            });
+        }
+    }
+}
+
+/// `Variant = 4` -> Some(4)
+/// `Variant = 3` -> None, since more than one bit is set.
+/// `Variant` -> None
+fn option_set_discriminant_bit(discriminant: Option<&Expr>) -> Option<u32> {
+    let lit = match discriminant? {
+        Expr::Lit(expr_lit) => &expr_lit.lit,
+        _ => return None,
+    };
+    let bit = match lit {
+        Lit::Int(lit_int) => lit_int.base10_parse::<u32>().ok()?,
+        _ => return None,
+    };
+
+    if bit.count_ones() == 1 {
+        Some(bit)
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::bridged_type::StructFields;
@@ -184,6 +389,384 @@ mod tests {
         };
     }
 
+    /// Verify that we can parse the `#[swift_bridge(option_set)]` attribute along with each
+    /// variant's discriminant.
+    #[test]
+    fn option_set_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(option_set)]
+                enum Permissions {
+                    Read = 1,
+                    Write = 2,
+                    Execute = 0b100,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.option_set);
+        assert_eq!(
+            ty.variants[2]
+                .discriminant
+                .as_ref()
+                .unwrap()
+                .to_token_stream()
+                .to_string(),
+            "0b100"
+        );
+    }
+
+    /// Verify that we push errors for `option_set` enums whose variants have data, or whose
+    /// discriminants are missing, are not a single bit or reuse a bit.
+    #[test]
+    fn option_set_invalid_bit_layout() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(option_set)]
+                enum Permissions {
+                    Read = 1,
+                    Write = 1,
+                    Execute = 3,
+                    Delete,
+                    Rename(u8),
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
+
+        match &errors[0] {
+            ParseError::EnumOptionSetDuplicateBit { variant } => {
+                assert_eq!(variant, "Write");
+            }
+            _ => panic!(),
+        };
+        for (idx, expected) in [(1, "Execute"), (2, "Delete")] {
+            match &errors[idx] {
+                ParseError::EnumOptionSetInvalidDiscriminant { variant } => {
+                    assert_eq!(variant, expected);
+                }
+                _ => panic!(),
+            };
+        }
+        match &errors[3] {
+            ParseError::EnumOptionSetVariantHasData { variant } => {
+                assert_eq!(variant, "Rename");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we push an error naming every variant that shares a discriminant, including
+    /// variants whose discriminant is implicitly one more than the previous variant's.
+    #[test]
+    fn duplicate_discriminants() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Status {
+                    Ok = 1,
+                    Success = 1,
+                    Pending = 0,
+                    Waiting,
+                    Failed = 5,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::EnumDuplicateDiscriminant {
+                enum_ident,
+                value,
+                variants,
+            } => {
+                assert_eq!(enum_ident, "Status");
+                assert_eq!(*value, 1);
+                assert_eq!(variants.len(), 3);
+                assert_eq!(variants[0], "Ok");
+                assert_eq!(variants[1], "Success");
+                assert_eq!(variants[2], "Waiting");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(comparable)]` attribute along with each
+    /// variant's integer discriminant.
+    #[test]
+    fn comparable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(comparable)]
+                enum Priority {
+                    Low = -1,
+                    High = 0x10,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.comparable);
+        assert_eq!(ty.variants[0].integer_discriminant(), Some(-1));
+        assert_eq!(ty.variants[1].integer_discriminant(), Some(16));
+    }
+
+    /// Verify that we push errors for `comparable` enums whose variants have data or do not have
+    /// an integer discriminant.
+    #[test]
+    fn comparable_without_integer_discriminants() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(comparable)]
+                enum Priority {
+                    Low = 1,
+                    Medium,
+                    High(u8),
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::EnumRawValueInvalidDiscriminant { attribute, variant } => {
+                assert_eq!(*attribute, "comparable");
+                assert_eq!(variant, "Medium");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::EnumRawValueVariantHasData { attribute, variant } => {
+                assert_eq!(*attribute, "comparable");
+                assert_eq!(variant, "High");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(case_iterable)]` attribute.
+    #[test]
+    fn case_iterable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(case_iterable)]
+                enum Weekday {
+                    Monday,
+                    Tuesday,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.case_iterable);
+    }
+
+    /// Verify that we push an error for each variant of a `case_iterable` enum that holds data.
+    #[test]
+    fn case_iterable_variant_with_data() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(case_iterable)]
+                enum Shape {
+                    Empty,
+                    Circle(f64),
+                    Square { side: f64 },
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        for (idx, expected) in vec!["Circle", "Square"].into_iter().enumerate() {
+            match &errors[idx] {
+                ParseError::EnumUnitOnlyVariantHasData { attribute, variant } => {
+                    assert_eq!(*attribute, "case_iterable");
+                    assert_eq!(variant, expected);
+                }
+                _ => panic!(),
+            };
+        }
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(Identifiable)]` attribute.
+    #[test]
+    fn identifiable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Identifiable)]
+                enum Weekday {
+                    Monday,
+                    Tuesday,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.identifiable);
+    }
+
+    /// Verify that we push an error for each variant of an `Identifiable` enum that holds data.
+    #[test]
+    fn identifiable_variant_with_data() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Identifiable)]
+                enum Shape {
+                    Empty,
+                    Circle(f64),
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::EnumUnitOnlyVariantHasData { attribute, variant } => {
+                assert_eq!(*attribute, "Identifiable");
+                assert_eq!(variant, "Circle");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(from_str)]` attribute.
+    #[test]
+    fn from_str_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(from_str)]
+                enum Color {
+                    Red,
+                    Green,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.from_str);
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(custom_string_convertible)]` attribute.
+    #[test]
+    fn custom_string_convertible_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(custom_string_convertible)]
+                enum Shape {
+                    Circle { radius: f64 },
+                    Empty,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.custom_string_convertible);
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(error_domain = "...")]` attribute.
+    #[test]
+    fn error_domain_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(error_domain = "com.example")]
+                enum SomeError {
+                    Variant,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert_eq!(ty.error_domain.as_ref().unwrap().value(), "com.example");
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(objc)]` attribute.
+    #[test]
+    fn objc_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(objc)]
+                enum Direction {
+                    Up = 0,
+                    Down = 1,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.objc);
+        assert!(ty.has_swift_raw_values());
+    }
+
+    /// Verify that we push errors for `objc` enums whose variants have data or do not have an
+    /// integer discriminant.
+    #[test]
+    fn objc_without_integer_discriminants() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(objc)]
+                enum Direction {
+                    Up,
+                    Down(u8),
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::EnumRawValueInvalidDiscriminant { attribute, variant } => {
+                assert_eq!(*attribute, "objc");
+                assert_eq!(variant, "Up");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::EnumRawValueVariantHasData { attribute, variant } => {
+                assert_eq!(*attribute, "objc");
+                assert_eq!(variant, "Down");
+            }
+            _ => panic!(),
+        };
+    }
+
     /// Verify that we can parse #[derive(Debug)] on enums
     #[test]
     fn derive_debug() {
@@ -202,4 +785,99 @@ mod tests {
         let ty = module.types.types()[0].unwrap_shared_enum();
         assert!(ty.derive.debug);
     }
+
+    /// Verify that we can parse #[derive(Clone)] on enums
+    #[test]
+    fn derive_clone() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(Clone)]
+                enum Foo {
+                    Variant1(u8)
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.derive.clone);
+        assert!(!ty.derive.debug);
+    }
+
+    /// Verify that we can parse #[derive(PartialEq)] on enums
+    #[test]
+    fn derive_partial_eq() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(PartialEq)]
+                enum Foo {
+                    Variant1(u8, String, Bar),
+                    Variant2 { field: SomeType }
+                }
+
+                enum Bar {
+                    Variant
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(Equatable)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("Foo").unwrap().unwrap_shared_enum();
+        assert!(ty.derive.partial_eq);
+        assert!(!ty.derive.clone);
+    }
+
+    /// Verify that we push an error for every field of a #[derive(PartialEq)] enum that can't be
+    /// compared in Swift.
+    #[test]
+    fn error_if_partial_eq_enum_field_not_equatable() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(PartialEq)]
+                enum Foo {
+                    Variant1(u8, SomeType),
+                    Variant2 { field: Bar }
+                }
+
+                enum Bar {
+                    Variant(u8)
+                }
+
+                extern "Rust" {
+                    type SomeType;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        let mut not_equatable: Vec<(String, String)> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::EnumFieldNotEquatable { variant, ty, .. } => {
+                    (variant.to_string(), ty.to_token_stream().to_string())
+                }
+                _ => panic!(),
+            })
+            .collect();
+        not_equatable.sort();
+        assert_eq!(
+            not_equatable,
+            vec![
+                ("Variant1".to_string(), "SomeType".to_string()),
+                ("Variant2".to_string(), "Bar".to_string()),
+            ]
+        );
+    }
 }
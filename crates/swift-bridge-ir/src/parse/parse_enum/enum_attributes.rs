@@ -1,6 +1,6 @@
 use crate::bridged_type::DeriveAttrs;
 use crate::errors::ParseError;
-use crate::parse::move_input_cursor_to_next_comma;
+use crate::parse::{move_input_cursor_to_next_comma, parse_conforms_to};
 use proc_macro2::Ident;
 use quote::ToTokens;
 use syn::parse::{Parse, ParseStream};
@@ -28,6 +28,8 @@ impl SharedEnumAllAttributes {
                     for arg in args.into_iter() {
                         match arg.get_ident().unwrap().to_string().as_str() {
                             "Debug" => attributes.derive.debug = true,
+                            "Clone" => attributes.derive.clone = true,
+                            "PartialEq" => attributes.derive.partial_eq = true,
                             _ => todo!("Unsupported derive macro; please see https://github.com/chinedufn/swift-bridge/issues/190#issuecomment-1463234027"),
                         }
                     }
@@ -48,7 +50,16 @@ impl SharedEnumAllAttributes {
 
 pub(super) enum EnumAttr {
     AlreadyDeclared,
+    CaseIterable,
+    Comparable,
     Error(ParseError),
+    ConformsTo(Vec<String>),
+    CustomStringConvertible,
+    ErrorDomain(LitStr),
+    FromStr,
+    Identifiable,
+    Objc,
+    OptionSet,
     SwiftName(LitStr),
 }
 
@@ -56,6 +67,15 @@ pub(super) enum EnumAttr {
 pub(super) struct SharedEnumSwiftBridgeAttributes {
     pub errors: Vec<ParseError>,
     pub already_declared: bool,
+    pub case_iterable: bool,
+    pub comparable: bool,
+    pub conforms_to: Vec<String>,
+    pub custom_string_convertible: bool,
+    pub error_domain: Option<LitStr>,
+    pub from_str: bool,
+    pub identifiable: bool,
+    pub objc: bool,
+    pub option_set: bool,
     pub swift_name: Option<LitStr>,
 }
 
@@ -63,7 +83,16 @@ impl SharedEnumSwiftBridgeAttributes {
     pub(super) fn store_attrib(&mut self, attrib: EnumAttr) -> syn::Result<()> {
         match attrib {
             EnumAttr::AlreadyDeclared => self.already_declared = true,
+            EnumAttr::CaseIterable => self.case_iterable = true,
+            EnumAttr::Comparable => self.comparable = true,
+            EnumAttr::ConformsTo(protocols) => self.conforms_to = protocols,
+            EnumAttr::CustomStringConvertible => self.custom_string_convertible = true,
             EnumAttr::Error(error) => self.errors.push(error),
+            EnumAttr::ErrorDomain(domain) => self.error_domain = Some(domain),
+            EnumAttr::FromStr => self.from_str = true,
+            EnumAttr::Identifiable => self.identifiable = true,
+            EnumAttr::Objc => self.objc = true,
+            EnumAttr::OptionSet => self.option_set = true,
             EnumAttr::SwiftName(name) => self.swift_name = Some(name),
         };
         Ok(())
@@ -91,6 +120,24 @@ impl Parse for EnumAttr {
 
         let attr = match key.to_string().as_str() {
             "already_declared" => EnumAttr::AlreadyDeclared,
+            "case_iterable" => EnumAttr::CaseIterable,
+            "comparable" => EnumAttr::Comparable,
+            "conforms_to" => {
+                input.parse::<Token![=]>()?;
+
+                EnumAttr::ConformsTo(parse_conforms_to(&input.parse()?)?)
+            }
+            "custom_string_convertible" => EnumAttr::CustomStringConvertible,
+            "error_domain" => {
+                input.parse::<Token![=]>()?;
+
+                let domain = input.parse()?;
+                EnumAttr::ErrorDomain(domain)
+            }
+            "from_str" => EnumAttr::FromStr,
+            "Identifiable" => EnumAttr::Identifiable,
+            "objc" => EnumAttr::Objc,
+            "option_set" => EnumAttr::OptionSet,
             "swift_name" => {
                 input.parse::<Token![=]>()?;
 
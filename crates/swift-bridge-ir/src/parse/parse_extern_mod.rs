@@ -1,16 +1,19 @@
 use self::argument_attributes::ArgumentAttributes;
 pub(crate) use self::opaque_type_attributes::OpaqueTypeAllAttributes;
 use crate::bridged_type::{
-    bridgeable_type_from_fn_arg, pat_type_pat_is_self, BridgeableType, BridgedType,
+    bridgeable_type_from_fn_arg, pat_type_pat_is_self, BridgeableType, BridgedType, StructSwiftRepr,
+};
+use crate::errors::{
+    FunctionAttributeParseError, IdentifiableParseError, ParseError, ParseErrors,
+    ReturnDataParseError, ReturnWarningsParseError, SharedInstanceParseError,
 };
-use crate::errors::{FunctionAttributeParseError, IdentifiableParseError, ParseError, ParseErrors};
 use crate::parse::parse_extern_mod::function_attributes::FunctionAttributes;
 use crate::parse::parse_extern_mod::generics::GenericOpaqueType;
 use crate::parse::type_declarations::{
-    OpaqueForeignTypeDeclaration, TypeDeclaration, TypeDeclarations,
+    OpaqueForeignTypeDeclaration, SharedTypeDeclaration, TypeDeclaration, TypeDeclarations,
 };
 use crate::parse::{HostLang, OpaqueRustTypeGenerics};
-use crate::parsed_extern_fn::fn_arg_is_mutable_reference;
+use crate::parsed_extern_fn::{fn_arg_is_mutable_reference, value_and_warning_types};
 use crate::ParsedExternFn;
 use proc_macro2::Ident;
 use quote::{format_ident, ToTokens};
@@ -33,6 +36,8 @@ pub(super) struct ForeignModParser<'a> {
     pub type_declarations: &'a mut TypeDeclarations,
     pub functions: &'a mut Vec<ParsedExternFn>,
     pub unresolved_types: &'a mut Vec<Type>,
+    /// The index of this foreign module among all of the foreign modules in the `mod` module.
+    pub extern_block_idx: usize,
 }
 
 impl<'a> ForeignModParser<'a> {
@@ -134,6 +139,24 @@ impl<'a> ForeignModParser<'a> {
                         is_swift_failable_initializer,
                     )?;
 
+                    if let Some(TypeDeclaration::Shared(shared)) = associated_type.as_ref() {
+                        let is_struct_method = match shared {
+                            SharedTypeDeclaration::Struct(shared_struct) => {
+                                shared_struct.swift_repr == StructSwiftRepr::Structure
+                                    && host_lang.is_rust()
+                                    && func.sig.asyncness.is_none()
+                                    && func.sig.receiver().is_some()
+                            }
+                            SharedTypeDeclaration::Enum(_) => false,
+                        };
+                        if !is_struct_method {
+                            self.errors.push(ParseError::UnsupportedSharedTypeMethod {
+                                fn_ident: func.sig.ident.clone(),
+                            });
+                            continue;
+                        }
+                    }
+
                     if attributes.is_swift_identifiable {
                         let args = &func.sig.inputs;
 
@@ -171,6 +194,60 @@ impl<'a> ForeignModParser<'a> {
                             ));
                         }
                     }
+                    if attributes.is_swift_shared_instance {
+                        let returns_ref_to_associated_type =
+                            match (&associated_type, &func.sig.output) {
+                                (
+                                    Some(TypeDeclaration::Opaque(opaque)),
+                                    ReturnType::Type(_, ty),
+                                ) => match ty.deref() {
+                                    Type::Reference(reference) => {
+                                        opaque.host_lang.is_rust()
+                                            && reference.mutability.is_none()
+                                            && opaque.ty
+                                                == reference.elem.to_token_stream().to_string()
+                                    }
+                                    _ => false,
+                                },
+                                _ => false,
+                            };
+
+                        if !func.sig.inputs.is_empty() || !returns_ref_to_associated_type {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Shared(
+                                    SharedInstanceParseError::InvalidSignature {
+                                        fn_ident: func.sig.ident.clone(),
+                                    },
+                                ),
+                            ));
+                        }
+                    }
+                    if attributes.return_data
+                        && (!host_lang.is_rust()
+                            || func.sig.asyncness.is_some()
+                            || !returns_byte_vec_or_result_of_byte_vec(&func.sig.output))
+                    {
+                        self.errors.push(ParseError::FunctionAttribute(
+                            FunctionAttributeParseError::ReturnData(
+                                ReturnDataParseError::InvalidSignature {
+                                    fn_ident: func.sig.ident.clone(),
+                                },
+                            ),
+                        ));
+                    }
+                    if attributes.return_warnings
+                        && (!host_lang.is_rust()
+                            || func.sig.asyncness.is_some()
+                            || value_and_warning_types(&func.sig.output).is_none())
+                    {
+                        self.errors.push(ParseError::FunctionAttribute(
+                            FunctionAttributeParseError::ReturnWarnings(
+                                ReturnWarningsParseError::InvalidSignature {
+                                    fn_ident: func.sig.ident.clone(),
+                                },
+                            ),
+                        ));
+                    }
                     let mut argument_labels: HashMap<Ident, LitStr> = HashMap::new();
                     for arg in func.sig.inputs.iter() {
                         let is_mutable_ref = fn_arg_is_mutable_reference(arg);
@@ -232,20 +309,41 @@ impl<'a> ForeignModParser<'a> {
                         }
                     }
 
+                    // The `call` methods of a `#[swift_bridge(callable)]` type are generated as
+                    // `callAsFunction` so that Swift can call instances directly.
+                    let swift_name_override = match (&attributes.swift_name, &associated_type) {
+                        (None, Some(TypeDeclaration::Opaque(opaque)))
+                            if host_lang.is_rust()
+                                && opaque.attributes.callable
+                                && func.sig.ident == "call" =>
+                        {
+                            Some(LitStr::new("callAsFunction", func.sig.ident.span()))
+                        }
+                        _ => attributes.swift_name,
+                    };
+
                     let func = ParsedExternFn {
                         func,
                         associated_type,
                         is_swift_initializer: attributes.is_swift_initializer,
                         is_swift_failable_initializer: is_swift_failable_initializer,
                         is_swift_identifiable: attributes.is_swift_identifiable,
+                        is_swift_shared_instance: attributes.is_swift_shared_instance,
                         host_lang,
                         rust_name_override: attributes.rust_name,
-                        swift_name_override: attributes.swift_name,
+                        swift_name_override,
                         return_into: attributes.return_into,
                         return_with: attributes.return_with,
+                        return_data: attributes.return_data,
+                        return_warnings: attributes.return_warnings,
                         args_into: attributes.args_into,
                         get_field: attributes.get_field,
+                        set_cell_field: attributes.set_cell_field,
+                        available: attributes.available,
+                        discardable_result: attributes.discardable_result,
+                        spi: attributes.spi,
                         argument_labels: argument_labels,
+                        extern_block_idx: self.extern_block_idx,
                     };
                     self.functions.push(func);
                 }
@@ -408,6 +506,114 @@ Otherwise we use a more general error that says that your argument is invalid.
     }
 }
 
+/// Push an error for every extern "Rust" function or method whose generated Swift declaration
+/// would have the same name and argument labels as an earlier one in the same Swift namespace.
+///
+/// Free functions share the module's namespace, while a type's methods, associated functions and
+/// initializers share that type's namespace. This catches collisions that are introduced by
+/// `#[swift_bridge(swift_name = "...")]`, since the Rust names are always unique.
+///
+/// When `auto_suffix` is set (`#[swift_bridge(auto_suffix_swift_names)]` on the module) a colliding
+/// function is instead given the first free Swift name out of `name2`, `name3`, ... and is returned
+/// alongside its new name so that we can warn about it. Initializers can't be renamed, so they
+/// still error.
+///
+/// This happens after the entire module has been parsed, since functions that are declared in
+/// different extern blocks can collide.
+///
+/// Names that only collide once a [`crate::codegen::CodegenConfig`] renames them, such as when
+/// converting them to camelCase, are caught by
+/// [`SwiftBridgeModule::check_duplicate_swift_member_names`].
+pub(crate) fn push_duplicate_swift_member_name_errors(
+    functions: &mut [ParsedExternFn],
+    auto_suffix: bool,
+    errors: &mut ParseErrors,
+) -> Vec<(Ident, String)> {
+    let mut seen = HashSet::new();
+    let mut auto_suffixed = vec![];
+
+    for function in functions.iter_mut() {
+        if !function.host_lang.is_rust() {
+            continue;
+        }
+
+        let name = if function.is_swift_initializer {
+            "init".to_string()
+        } else {
+            match &function.swift_name_override {
+                Some(swift_name) => swift_name.value(),
+                None => function.func.sig.ident.to_string(),
+            }
+        };
+
+        let signature = swift_member_signature(function, &name);
+        if seen.insert(signature.clone()) {
+            continue;
+        }
+
+        let span = match &function.swift_name_override {
+            Some(swift_name) => swift_name.span(),
+            None => function.func.sig.ident.span(),
+        };
+
+        if !auto_suffix || function.is_swift_initializer {
+            errors.push(ParseError::DuplicateSwiftMemberName { signature, span });
+            continue;
+        }
+
+        let mut suffix = 2;
+        let suffixed_name = loop {
+            let suffixed_name = format!("{}{}", name, suffix);
+            if seen.insert(swift_member_signature(function, &suffixed_name)) {
+                break suffixed_name;
+            }
+            suffix += 1;
+        };
+
+        function.swift_name_override = Some(LitStr::new(&suffixed_name, span));
+        auto_suffixed.push((function.func.sig.ident.clone(), suffixed_name));
+    }
+
+    auto_suffixed
+}
+
+/// The Swift declaration that a function generates, such as `SomeType.some_name(_:label:)`.
+pub(crate) fn swift_member_signature(function: &ParsedExternFn, name: &str) -> String {
+    let namespace = function
+        .associated_type
+        .as_ref()
+        .map(|ty| ty.swift_name_string());
+
+    let mut labels = "".to_string();
+    for arg in function.func.sig.inputs.iter() {
+        let pat_ty = match arg {
+            FnArg::Typed(pat_ty) if !pat_type_pat_is_self(pat_ty) => pat_ty,
+            _ => continue,
+        };
+
+        let arg_name = format_ident!("{}", pat_ty.pat.to_token_stream().to_string());
+        match function.argument_labels.get(&arg_name) {
+            Some(label) => labels += &format!("{}:", label.value()),
+            None => labels += "_:",
+        }
+    }
+
+    match namespace {
+        Some(namespace) => format!("{}.{}({})", namespace, name, labels),
+        None => format!("{}({})", name, labels),
+    }
+}
+
+/// Whether or not a function returns a `Vec<u8>` or a `Result<Vec<u8>, E>`.
+fn returns_byte_vec_or_result_of_byte_vec(output: &ReturnType) -> bool {
+    let ty = match output {
+        ReturnType::Type(_, ty) => ty.to_token_stream().to_string(),
+        ReturnType::Default => return false,
+    };
+
+    ty == "Vec < u8 >" || (ty.starts_with("Result < Vec < u8 > ,") && ty.ends_with('>'))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::errors::ParseError;
@@ -617,6 +823,67 @@ mod tests {
         }
     }
 
+    /// Verify that we push an error for function arguments and return types that use a boxed
+    /// shared struct outside of an `Option`.
+    #[test]
+    fn error_if_function_uses_boxed_shared_struct_not_in_option() {
+        let tokens = quote! {
+            mod foo {
+                #[swift_bridge(swift_repr = "struct")]
+                struct TreeNode {
+                    left: Option<Box<TreeNode>>,
+                }
+
+                extern "Rust" {
+                    fn a (node: Box<TreeNode>);
+                    fn b () -> Box<TreeNode>;
+                    fn c (node: Option<Box<TreeNode>>) -> Option<Box<TreeNode>>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::BoxedSharedStructNotInOption { ty } => {
+                    assert_eq!(ty.to_token_stream().to_string(), "Box < TreeNode >");
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push an error for a `Cow<[T]>` anywhere other than the return type of an
+    /// extern "Rust" function.
+    #[test]
+    fn error_if_cow_slice_not_rust_return_type() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a (arg: Cow<'static, [u8]>);
+                    fn b () -> Option<Cow<'static, [u8]>>;
+                    fn c () -> Cow<'static, [u8]>;
+                }
+
+                extern "Swift" {
+                    fn d () -> Cow<'static, [u8]>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::CowSliceNotReturnType { ty } => {
+                    assert_eq!(ty.to_token_stream().to_string(), "Cow < 'static , [u8] >");
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
     /// Verify that a freestanding function can return a declared type.
     #[test]
     fn freestanding_function_return_declared_type() {
@@ -644,8 +911,8 @@ mod tests {
                     type Foo;
 
                     fn a () -> Bar;
-                    fn a () -> &Bar;
-                    fn a () -> &mut Bar;
+                    fn b () -> &Bar;
+                    fn c () -> &mut Bar;
                 }
             }
         };
@@ -834,6 +1101,57 @@ mod tests {
         );
     }
 
+    /// Verify that we can parse the `HashableByGetters` attribute.
+    #[test]
+    fn parse_hashable_by_getters_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(HashableByGetters)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .hashable_by_getters,
+            true
+        );
+    }
+
+    /// Verify that we can parse the `Clone` attribute.
+    #[test]
+    fn parse_clone_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Clone)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .clone
+        );
+    }
+
     /// Verify that we can parse the `equatable` attribute.
     #[test]
     fn parse_equatable_attribute() {
@@ -860,6 +1178,99 @@ mod tests {
         );
     }
 
+    /// Verify that we can parse the `AddAssign` and `SubAssign` attributes.
+    #[test]
+    fn parse_compound_assignment_attributes() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(AddAssign, SubAssign)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert!(ty.attributes.add_assign);
+        assert!(ty.attributes.sub_assign);
+    }
+
+    /// Verify that we can parse the `Neg` attribute.
+    #[test]
+    fn parse_neg_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Neg)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert!(ty.attributes.neg);
+    }
+
+    /// Verify that we can parse the `main_actor` attribute.
+    #[test]
+    fn parse_main_actor_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(main_actor)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert!(ty.attributes.main_actor);
+    }
+
+    /// Verify that the `call` methods of a `callable` type get `callAsFunction` as their Swift
+    /// name, unless they have their own `swift_name`.
+    #[test]
+    fn parse_callable_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(callable)]
+                    type SomeType;
+
+                    fn call(&self);
+                    #[swift_bridge(swift_name = "invoke")]
+                    fn call(&self, arg: u8);
+                    fn other(&self);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert!(ty.attributes.callable);
+
+        let swift_names: Vec<_> = module
+            .functions
+            .iter()
+            .map(|f| f.swift_name_override.as_ref().map(|name| name.value()))
+            .collect();
+        assert_eq!(
+            swift_names,
+            vec![
+                Some("callAsFunction".to_string()),
+                Some("invoke".to_string()),
+                None
+            ]
+        );
+    }
+
     /// Verify that we can parse the `copy` attribute.
     #[test]
     fn parse_copy_attribute() {
@@ -935,6 +1346,278 @@ mod tests {
         );
     }
 
+    /// Verify that we can parse `#[doc(alias = "...")]` attributes from an extern "Rust" opaque
+    /// type.
+    #[test]
+    fn parse_opaque_rust_type_doc_alias() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    /// Some comment
+                    #[doc(alias = "FirstAlias")]
+                    #[doc(alias = "SecondAlias")]
+                    type AnotherType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("AnotherType").unwrap().unwrap_opaque();
+        assert_eq!(ty.attributes.doc_aliases, vec!["FirstAlias", "SecondAlias"]);
+        assert_eq!(ty.attributes.doc_comment.as_ref().unwrap(), " Some comment");
+    }
+
+    /// Verify that we return an error instead of panicking if a doc alias is not a string.
+    #[test]
+    fn error_if_doc_alias_is_not_a_string() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[doc(alias = 5)]
+                    type AnotherType;
+                }
+            }
+        };
+
+        let err = syn::parse2::<SwiftBridgeModule>(tokens).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            r#"doc alias must be a string, such as #[doc(alias = "SomeAlias")]"#
+        );
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(arc_mutex)]` attribute from an opaque Rust
+    /// type.
+    #[test]
+    fn parse_opaque_rust_type_arc_mutex() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(arc_mutex)]
+                    type Counter;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("Counter").unwrap().unwrap_opaque();
+        assert!(ty.attributes.arc_mutex);
+    }
+
+    /// Verify that we return an error if `arc_mutex` is combined with an attribute whose
+    /// generated functions don't go through the type's mutex.
+    #[test]
+    fn error_if_arc_mutex_combined_with_incompatible_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(arc_mutex, Hashable)]
+                    type Counter;
+                }
+            }
+        };
+
+        let error = syn::parse2::<SwiftBridgeModule>(tokens).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "`arc_mutex` cannot be combined with `Hashable`"
+        );
+    }
+
+    /// Verify that we push an error if a function returns a reference to an `arc_mutex` type.
+    #[test]
+    fn error_if_arc_mutex_type_returned_by_reference() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(arc_mutex)]
+                    type Counter;
+
+                    fn a(&self) -> &Counter;
+                    fn b() -> Option<&'static Counter>;
+                    fn c() -> Counter;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::ArcMutexReferenceReturned { ty } => {
+                    assert!(ty.to_token_stream().to_string().ends_with("Counter"));
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(deref = "...")]` attribute from an opaque
+    /// Rust type.
+    #[test]
+    fn parse_opaque_rust_type_deref() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(deref = "Inner")]
+                    type Wrapper;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("Wrapper").unwrap().unwrap_opaque();
+        assert_eq!(ty.attributes.deref.as_ref().unwrap(), "Inner");
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(btree_map(K, V))]` attribute from an
+    /// extern "Rust" opaque type.
+    #[test]
+    fn parse_opaque_rust_type_btree_map() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(btree_map(u32, f64))]
+                    type Scores;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("Scores").unwrap().unwrap_opaque();
+        let btree_map = ty.attributes.btree_map.as_ref().unwrap();
+        assert_eq!(btree_map.key.to_token_stream().to_string(), "u32");
+        assert_eq!(btree_map.value.to_token_stream().to_string(), "f64");
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(counter(T))]` attribute from an extern "Rust"
+    /// opaque type.
+    #[test]
+    fn parse_opaque_rust_type_counter() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(counter(u64))]
+                    type Visits;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("Visits").unwrap().unwrap_opaque();
+        let counter = ty.attributes.counter.as_ref().unwrap();
+        assert_eq!(counter.to_token_stream().to_string(), "u64");
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(index(Idx, Output))]` attribute from an
+    /// extern "Rust" opaque type.
+    #[test]
+    fn parse_opaque_rust_type_index() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(index(usize, f64))]
+                    type Samples;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("Samples").unwrap().unwrap_opaque();
+        let index = ty.attributes.index.as_ref().unwrap();
+        assert_eq!(index.index.to_token_stream().to_string(), "usize");
+        assert_eq!(index.output.to_token_stream().to_string(), "f64");
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(error_chain)]` attribute from an
+    /// extern "Rust" opaque type.
+    #[test]
+    fn parse_opaque_rust_type_error_chain() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(error_chain)]
+                    type SomeError;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("SomeError").unwrap().unwrap_opaque();
+        assert!(ty.attributes.error_chain);
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(dispatch_queue = "...")]` attribute from an
+    /// extern "Rust" opaque type.
+    #[test]
+    fn parse_opaque_rust_type_dispatch_queue() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(dispatch_queue = "com.example.queue")]
+                    type AnotherType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("AnotherType").unwrap().unwrap_opaque();
+        assert_eq!(
+            ty.attributes.dispatch_queue.as_deref(),
+            Some("com.example.queue")
+        );
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(conforms_to = "...")]` attribute from an
+    /// extern "Rust" opaque type.
+    #[test]
+    fn parse_opaque_rust_type_conforms_to() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(conforms_to = "MyProtocol, AnotherProtocol")]
+                    type AnotherType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("AnotherType").unwrap().unwrap_opaque();
+        assert_eq!(
+            ty.attributes.conforms_to,
+            vec!["MyProtocol".to_string(), "AnotherProtocol".to_string()]
+        );
+    }
+
+    /// Verify that we return an error if a `conforms_to` protocol is not a legal identifier.
+    #[test]
+    fn error_if_conforms_to_protocol_is_not_an_identifier() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(conforms_to = "MyProtocol, 2D Shape")]
+                    type AnotherType;
+                }
+            }
+        };
+
+        let error = syn::parse2::<SwiftBridgeModule>(tokens).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            r#""2D Shape" is not a valid Swift protocol name."#
+        );
+    }
+
     /// Verify that we push errors for unknown arguments in a function
     #[test]
     fn error_args_into_arg_not_found_in_function() {
@@ -991,4 +1674,184 @@ mod tests {
             }
         }
     }
+
+    /// Verify that we push an error for functions on shared types other than extern "Rust" methods
+    /// on `swift_repr = "struct"` structs.
+    #[test]
+    fn error_if_unsupported_shared_type_method() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: u8,
+                }
+
+                #[swift_bridge(swift_repr = "class")]
+                struct SomeClass {
+                    field: u8,
+                }
+
+                extern "Rust" {
+                    fn a(self: &SomeStruct);
+                    fn b(self: SomeStruct);
+                    fn c(self: &mut SomeClass);
+                    #[swift_bridge(associated_to = SomeStruct)]
+                    fn d() -> u8;
+                    fn e(self: &mut SomeStruct);
+                }
+
+                extern "Swift" {
+                    fn f(self: &mut SomeStruct);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 3);
+
+        let mut fn_idents: Vec<String> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::UnsupportedSharedTypeMethod { fn_ident } => fn_ident.to_string(),
+                _ => panic!(),
+            })
+            .collect();
+        fn_idents.sort();
+        assert_eq!(fn_idents, vec!["c", "d", "f"]);
+    }
+
+    /// Verify that we push an error when a function and a method, or two methods, would generate
+    /// the same Swift declaration.
+    #[test]
+    fn error_if_duplicate_swift_member_name() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(associated_to = SomeType)]
+                    fn make(arg: u8) -> SomeType;
+                    #[swift_bridge(swift_name = "make")]
+                    fn make_from(&self, arg: u8) -> SomeType;
+
+                    fn first(&self);
+                    #[swift_bridge(swift_name = "first")]
+                    fn second(&mut self);
+
+                    fn free_function();
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(swift_name = "free_function")]
+                    fn another_free_function();
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        let signatures: Vec<&str> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::DuplicateSwiftMemberName { signature, .. } => signature.as_str(),
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(
+            signatures,
+            vec!["SomeType.make(_:)", "SomeType.first()", "free_function()"]
+        );
+    }
+
+    /// Verify that `#[swift_bridge(auto_suffix_swift_names)]` gives colliding functions distinct
+    /// suffixed Swift names instead of pushing an error.
+    #[test]
+    fn auto_suffix_duplicate_swift_member_names() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            #[swift_bridge(auto_suffix_swift_names)]
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn first(&self);
+                    #[swift_bridge(swift_name = "first")]
+                    fn second(&self);
+                    #[swift_bridge(swift_name = "first")]
+                    fn third(&self);
+
+                    fn free_function();
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(swift_name = "free_function")]
+                    fn another_free_function();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let swift_names: Vec<(String, Option<String>)> = module
+            .functions
+            .iter()
+            .map(|f| {
+                (
+                    f.func.sig.ident.to_string(),
+                    f.swift_name_override.as_ref().map(|name| name.value()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            swift_names,
+            vec![
+                ("first".to_string(), None),
+                ("second".to_string(), Some("first2".to_string())),
+                ("third".to_string(), Some("first3".to_string())),
+                ("free_function".to_string(), None),
+                (
+                    "another_free_function".to_string(),
+                    Some("free_function2".to_string())
+                ),
+            ]
+        );
+    }
+
+    /// Verify that functions with the same Swift name do not collide when they are in different
+    /// Swift namespaces or have different argument labels.
+    #[test]
+    fn no_error_if_swift_member_names_are_distinct() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                extern "Rust" {
+                    type AnotherType;
+
+                    fn name(&self) -> u8;
+                }
+
+                extern "Rust" {
+                    type SomeType;
+
+                    fn name(&self) -> u8;
+                    #[swift_bridge(swift_name = "name")]
+                    fn name_with_label(&self, #[swift_bridge(label = "prefix")] prefix: u8) -> u8;
+                    #[swift_bridge(swift_name = "name")]
+                    fn free_name() -> u8;
+                }
+
+                extern "Swift" {
+                    #[swift_bridge(swift_name = "name")]
+                    fn swift_name() -> u8;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 0);
+        assert_eq!(errors.len(), 0);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::parsed_extern_fn::{GetField, GetFieldDirect, GetFieldWith};
+use crate::parsed_extern_fn::{GetField, GetFieldCell, GetFieldDirect, GetFieldWith};
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
 use syn::{LitStr, Path, Token};
@@ -6,14 +6,21 @@ use syn::{LitStr, Path, Token};
 #[derive(Default)]
 pub(super) struct FunctionAttributes {
     pub associated_to: Option<Ident>,
+    pub available: Option<LitStr>,
+    pub discardable_result: bool,
     pub is_swift_initializer: bool,
     pub is_swift_identifiable: bool,
+    pub is_swift_shared_instance: bool,
     pub rust_name: Option<LitStr>,
     pub swift_name: Option<LitStr>,
     pub return_into: bool,
     pub return_with: Option<Path>,
+    pub return_data: bool,
+    pub return_warnings: bool,
     pub args_into: Option<Vec<Ident>>,
     pub get_field: Option<GetField>,
+    pub set_cell_field: Option<Ident>,
+    pub spi: Option<LitStr>,
 }
 
 impl FunctionAttributes {
@@ -22,6 +29,9 @@ impl FunctionAttributes {
             FunctionAttr::AssociatedTo(ident) => {
                 self.associated_to = Some(ident);
             }
+            FunctionAttr::Available(platforms) => self.available = Some(platforms),
+            FunctionAttr::DiscardableResult => self.discardable_result = true,
+            FunctionAttr::Spi(group) => self.spi = Some(group),
             FunctionAttr::Init => self.is_swift_initializer = true,
             FunctionAttr::RustName(name) => {
                 self.rust_name = Some(name);
@@ -35,29 +45,46 @@ impl FunctionAttributes {
             FunctionAttr::ReturnWith(path) => {
                 self.return_with = Some(path);
             }
+            FunctionAttr::ReturnData => self.return_data = true,
+            FunctionAttr::ReturnWarnings => self.return_warnings = true,
             FunctionAttr::ArgsInto(args) => self.args_into = Some(args),
             FunctionAttr::Identifiable => {
                 self.is_swift_identifiable = true;
             }
+            FunctionAttr::Shared => {
+                self.is_swift_shared_instance = true;
+            }
             FunctionAttr::GetField(get_field) => self.get_field = Some(GetField::Direct(get_field)),
             FunctionAttr::GetFieldWith(get_field) => {
                 self.get_field = Some(GetField::With(get_field))
             }
+            FunctionAttr::GetFieldCell(get_field) => {
+                self.get_field = Some(GetField::Cell(get_field))
+            }
+            FunctionAttr::SetFieldCell(field_name) => self.set_cell_field = Some(field_name),
         }
     }
 }
 
 pub(super) enum FunctionAttr {
     AssociatedTo(Ident),
+    Available(LitStr),
+    DiscardableResult,
+    Spi(LitStr),
     SwiftName(LitStr),
     RustName(LitStr),
     Init,
     Identifiable,
+    Shared,
     ReturnInto,
     ReturnWith(Path),
+    ReturnData,
+    ReturnWarnings,
     ArgsInto(Vec<Ident>),
     GetField(GetFieldDirect),
     GetFieldWith(GetFieldWith),
+    GetFieldCell(GetFieldCell),
+    SetFieldCell(Ident),
 }
 
 impl Parse for FunctionAttributes {
@@ -87,6 +114,15 @@ impl Parse for FunctionAttr {
 
                 FunctionAttr::AssociatedTo(value)
             }
+            "available" => {
+                input.parse::<Token![=]>()?;
+                FunctionAttr::Available(input.parse()?)
+            }
+            "discardable_result" => FunctionAttr::DiscardableResult,
+            "spi" => {
+                input.parse::<Token![=]>()?;
+                FunctionAttr::Spi(input.parse()?)
+            }
             "swift_name" => {
                 input.parse::<Token![=]>()?;
                 let value: LitStr = input.parse()?;
@@ -95,6 +131,7 @@ impl Parse for FunctionAttr {
             }
             "init" => FunctionAttr::Init,
             "Identifiable" => FunctionAttr::Identifiable,
+            "shared" => FunctionAttr::Shared,
             // TODO: Right before we release 0.2.0 we should remove this
             //  "into_return_type" variant since it is deprecated.
             //
@@ -103,6 +140,8 @@ impl Parse for FunctionAttr {
                 input.parse::<Token![=]>()?;
                 FunctionAttr::ReturnWith(input.parse()?)
             }
+            "return_data" => FunctionAttr::ReturnData,
+            "return_warnings" => FunctionAttr::ReturnWarnings,
             "rust_name" => {
                 input.parse::<Token![=]>()?;
                 let value: LitStr = input.parse()?;
@@ -149,6 +188,20 @@ impl Parse for FunctionAttr {
                     path,
                 })
             }
+            "get_cell" => {
+                let content;
+                syn::parenthesized!(content in input);
+
+                let field_name = content.parse::<Ident>()?;
+
+                FunctionAttr::GetFieldCell(GetFieldCell { field_name })
+            }
+            "set_cell" => {
+                let content;
+                syn::parenthesized!(content in input);
+
+                FunctionAttr::SetFieldCell(content.parse::<Ident>()?)
+            }
             _ => {
                 let attrib = key.to_string();
                 Err(syn::Error::new_spanned(
@@ -164,7 +217,10 @@ impl Parse for FunctionAttr {
 
 #[cfg(test)]
 mod tests {
-    use crate::errors::{FunctionAttributeParseError, IdentifiableParseError, ParseError};
+    use crate::errors::{
+        FunctionAttributeParseError, IdentifiableParseError, ParseError, ReturnDataParseError,
+        ReturnWarningsParseError, SharedInstanceParseError,
+    };
     use crate::test_utils::{parse_errors, parse_ok};
     use quote::{quote, ToTokens};
 
@@ -257,6 +313,26 @@ mod tests {
         assert_eq!(module.functions.len(), 1,);
     }
 
+    /// Verify that we can parse the discardable_result attribute.
+    #[test]
+    fn parse_discardable_result_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(discardable_result)]
+                    fn some_function () -> Result<u8, u8>;
+
+                    fn another_function () -> Result<u8, u8>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.functions[0].discardable_result);
+        assert!(!module.functions[1].discardable_result);
+    }
+
     /// Verify that we can parse an init function.
     #[test]
     fn initializer() {
@@ -535,6 +611,35 @@ mod tests {
         }
     }
 
+    /// Verify that we can parse the `get_cell` and `set_cell` attributes.
+    #[test]
+    fn parses_get_cell_and_set_cell_attributes() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Foo;
+
+                    #[swift_bridge(get_cell(count))]
+                    fn count(&self) -> i32;
+
+                    #[swift_bridge(set_cell(count))]
+                    fn set_count(&self, count: i32);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let funcs = &module.functions;
+        let field = funcs[0].get_field.as_ref().unwrap().unwrap_cell();
+        assert_eq!(field.field_name, "count");
+        assert_eq!(funcs[1].set_cell_field.as_ref().unwrap(), "count");
+
+        assert!(funcs[0].uses_cell_field());
+        assert!(funcs[1].uses_cell_field());
+    }
+
     /// Verify that we can parse a function that has multiple swift_bridge attributes.
     #[test]
     fn parses_multiple_function_swift_bridge_attributes() {
@@ -555,4 +660,190 @@ mod tests {
         assert_eq!(func.args_into.as_ref().unwrap().len(), 1);
         assert_eq!(func.return_into, true);
     }
+
+    /// Verify that we can parse the `shared` attribute.
+    #[test]
+    fn parse_shared_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(associated_to = SomeType, shared)]
+                    fn shared() -> &'static SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.functions[0].is_swift_shared_instance);
+    }
+
+    /// Verify that we push a parse error if we put a `shared` attribute on a function that does
+    /// not take zero arguments and return a reference to its associated type.
+    #[test]
+    fn error_if_shared_attribute_on_invalid_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(shared)]
+                    fn a() -> &'static SomeType;
+
+                    #[swift_bridge(associated_to = SomeType, shared)]
+                    fn b(arg: u8) -> &'static SomeType;
+
+                    #[swift_bridge(associated_to = SomeType, shared)]
+                    fn c() -> SomeType;
+
+                    #[swift_bridge(shared)]
+                    fn d(&self) -> &SomeType;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 4);
+
+        for (idx, expected) in vec!["a", "b", "c", "d"].into_iter().enumerate() {
+            match &errors[idx] {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Shared(
+                    SharedInstanceParseError::InvalidSignature { fn_ident },
+                )) => {
+                    assert_eq!(fn_ident, expected);
+                }
+                _ => panic!(),
+            };
+        }
+    }
+
+    /// Verify that we can parse the `return_data` attribute.
+    #[test]
+    fn parse_return_data_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(return_data)]
+                    fn some_function() -> Result<Vec<u8>, String>;
+
+                    #[swift_bridge(return_data)]
+                    fn another_function() -> Vec<u8>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.functions[0].return_data);
+        assert!(module.functions[1].return_data);
+    }
+
+    /// Verify that we push a parse error if we put a `return_data` attribute on a function that
+    /// does not return a `Vec<u8>` or a `Result<Vec<u8>, E>`.
+    #[test]
+    fn error_if_return_data_attribute_on_invalid_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(return_data)]
+                    fn a() -> Vec<u32>;
+
+                    #[swift_bridge(return_data)]
+                    fn b() -> Result<u8, String>;
+
+                    #[swift_bridge(return_data)]
+                    async fn c() -> Vec<u8>;
+                }
+
+                extern "Swift" {
+                    #[swift_bridge(return_data)]
+                    fn d() -> Vec<u8>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 4);
+
+        for (idx, expected) in vec!["a", "b", "c", "d"].into_iter().enumerate() {
+            match &errors[idx] {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::ReturnData(
+                    ReturnDataParseError::InvalidSignature { fn_ident },
+                )) => {
+                    assert_eq!(fn_ident, expected);
+                }
+                _ => panic!(),
+            };
+        }
+    }
+
+    /// Verify that we can parse the `return_warnings` attribute.
+    #[test]
+    fn parse_return_warnings_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(return_warnings)]
+                    fn some_function() -> (u32, Vec<SomeWarning>);
+
+                    fn another_function() -> (u32, Vec<SomeWarning>);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.functions[0].return_warnings);
+        assert!(!module.functions[1].return_warnings);
+    }
+
+    /// Verify that we push a parse error if we put a `return_warnings` attribute on a function
+    /// that does not return a `(T, Vec<W>)`.
+    #[test]
+    fn error_if_return_warnings_attribute_on_invalid_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(return_warnings)]
+                    fn a() -> Vec<u32>;
+
+                    #[swift_bridge(return_warnings)]
+                    fn b() -> (u32, u32);
+
+                    #[swift_bridge(return_warnings)]
+                    async fn c() -> (u32, Vec<u32>);
+                }
+
+                extern "Swift" {
+                    #[swift_bridge(return_warnings)]
+                    fn d() -> (u32, Vec<u32>);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 4);
+
+        for (idx, expected) in vec!["a", "b", "c", "d"].into_iter().enumerate() {
+            match &errors[idx] {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::ReturnWarnings(
+                    ReturnWarningsParseError::InvalidSignature { fn_ident },
+                )) => {
+                    assert_eq!(fn_ident, expected);
+                }
+                _ => panic!(),
+            };
+        }
+    }
 }
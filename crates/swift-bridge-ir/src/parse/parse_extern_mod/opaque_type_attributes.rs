@@ -1,9 +1,9 @@
-use crate::parse::OpaqueCopy;
+use crate::parse::{parse_conforms_to, OpaqueBTreeMap, OpaqueCopy, OpaqueIndex};
 use proc_macro2::Ident;
 use quote::ToTokens;
 use std::ops::Deref;
 use syn::parse::{Parse, ParseStream};
-use syn::{Attribute, LitInt, Meta};
+use syn::{Attribute, LitInt, LitStr, Meta, NestedMeta, Type};
 
 #[derive(Default, Clone)]
 pub(crate) struct OpaqueTypeAllAttributes {
@@ -12,26 +12,84 @@ pub(crate) struct OpaqueTypeAllAttributes {
     // TODO: Use this to generate doc comment for the generated Swift type.
     #[allow(unused)]
     pub doc_comment: Option<String>,
+    /// Aliases from `#[doc(alias = "...")]` attributes.
+    /// These get emitted as keywords in the generated Swift class's doc comment.
+    pub doc_aliases: Vec<String>,
 }
 
 #[derive(Default, Clone)]
 pub(crate) struct OpaqueTypeSwiftBridgeAttributes {
+    /// `#[swift_bridge(AddAssign)]`
+    /// The type implements `AddAssign<&Self>`, and the generated Swift class gets a `+=` operator.
+    pub add_assign: bool,
     /// Whether or not the `#[swift_bridge(already_declared)]` attribute was present on the type.
     /// If it was, we won't generate Swift and C type declarations for this type, since we
     /// will elsewhere.
     pub already_declared: bool,
+    /// `#[swift_bridge(arc_mutex)]`
+    /// The type is shared with Swift as an `Arc<Mutex<T>>`, and method calls lock the mutex.
+    pub arc_mutex: bool,
+    /// `#[swift_bridge(btree_map(K, V))]`
+    /// The type implements `AsRef<BTreeMap<K, V>>`, and the generated Swift class gets a
+    /// `range(start, end)` method that returns the map's entries within that range.
+    pub btree_map: Option<Box<OpaqueBTreeMap>>,
+    /// `#[swift_bridge(callable)]`
+    /// The type acts as a function, and its `call` methods are generated as Swift
+    /// `callAsFunction` methods so that Swift can call instances directly.
+    pub callable: bool,
+    /// `#[swift_bridge(Clone)]`
+    /// The type implements `Clone`, and the generated Swift class gets an `init(copying:)`
+    /// initializer that clones another instance.
+    pub clone: bool,
     /// `#[swift_bridge(Copy(...)]`
     /// Describes the type's Copy semantics.
     pub copy: Option<OpaqueCopy>,
+    /// `#[swift_bridge(counter(u32))]`
+    /// The type implements `AddAssign<u32>`, `SubAssign<u32>` and `AsRef<u32>`, and the generated
+    /// Swift class gets `increment()` and `decrement()` methods and a `value` property.
+    pub counter: Option<Box<Type>>,
+    /// `#[swift_bridge(conforms_to = "SomeProtocol, AnotherProtocol")]`
+    /// Swift protocols that the generated Swift class conforms to. The user implements them.
+    pub conforms_to: Vec<String>,
     /// `#[swift_bridge(declare_generic)]`
     /// Used to declare a generic type.
     pub declare_generic: bool,
+    /// `#[swift_bridge(deref = "Inner")]`
+    /// The opaque Rust type that this type implements `std::ops::Deref` for.
+    /// The generated Swift class forwards calls to the `&self` methods of that type.
+    pub deref: Option<Ident>,
+    /// `#[swift_bridge(dispatch_queue = "com.example.queue")]`
+    /// The label of the dispatch queue that the type must be used on.
+    /// The generated Swift methods check this before calling into Rust.
+    pub dispatch_queue: Option<String>,
     /// `#[swift_bridge(Equatable)]`
     /// Used to determine if Equatable need to be implemented.
     pub equatable: bool,
+    /// `#[swift_bridge(error_chain)]`
+    /// The type implements `std::error::Error`, and the generated Swift class's `description`
+    /// includes the error's entire `source()` chain.
+    pub error_chain: bool,
     /// `#[swift_bridge(Hashable)]`
     /// Used to determine if Hashable need to be implemented.
     pub hashable: bool,
+    /// `#[swift_bridge(HashableByGetters)]`
+    /// The generated Swift class's `==` and `hash(into:)` compare and combine the values that
+    /// the type's `#[swift_bridge(get(...))]` getters return, instead of calling into Rust.
+    pub hashable_by_getters: bool,
+    /// `#[swift_bridge(index(usize, f64))]`
+    /// The type implements `std::ops::Index`, and the generated Swift class gets a read-only
+    /// `subscript` that calls it.
+    pub index: Option<Box<OpaqueIndex>>,
+    /// `#[swift_bridge(main_actor)]`
+    /// The generated Swift class's methods and initializers are isolated to the `@MainActor`.
+    pub main_actor: bool,
+    /// `#[swift_bridge(Neg)]`
+    /// `&Self` implements `Neg<Output = Self>`, and the generated Swift class gets a prefix `-`
+    /// operator.
+    pub neg: bool,
+    /// `#[swift_bridge(SubAssign)]`
+    /// The type implements `SubAssign<&Self>`, and the generated Swift class gets a `-=` operator.
+    pub sub_assign: bool,
 }
 
 impl OpaqueTypeAllAttributes {
@@ -44,19 +102,43 @@ impl OpaqueTypeAllAttributes {
             match attribute_name.as_str() {
                 "doc" => {
                     let meta = attr.parse_meta()?;
-                    let doc = match meta {
+                    match meta {
                         Meta::NameValue(name_val) => match name_val.lit {
-                            syn::Lit::Str(comment) => comment.value(),
+                            syn::Lit::Str(comment) => {
+                                attributes.doc_comment = Some(comment.value());
+                            }
                             _ => {
                                 todo!("Push parse error that doc attribute is in incorrect format")
                             }
                         },
+                        // #[doc(alias = "...")]
+                        Meta::List(list) => {
+                            for nested in list.nested.iter() {
+                                match nested {
+                                    NestedMeta::Meta(Meta::NameValue(name_val))
+                                        if name_val.path.is_ident("alias") =>
+                                    {
+                                        match &name_val.lit {
+                                            syn::Lit::Str(alias) => {
+                                                attributes.doc_aliases.push(alias.value());
+                                            }
+                                            lit => {
+                                                return Err(syn::Error::new_spanned(
+                                                    lit,
+                                                    "doc alias must be a string, such as \
+                                                     #[doc(alias = \"SomeAlias\")]",
+                                                ));
+                                            }
+                                        }
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        }
                         _ => {
                             todo!("Push parse error that doc attribute is in incorrect format")
                         }
                     };
-
-                    attributes.doc_comment = Some(doc);
                 }
                 "swift_bridge" => {
                     attributes.swift_bridge = attr.parse_args()?;
@@ -70,28 +152,89 @@ impl OpaqueTypeAllAttributes {
 }
 
 impl OpaqueTypeSwiftBridgeAttributes {
+    /// The first attribute that can't be used alongside `#[swift_bridge(arc_mutex)]`, if any.
+    ///
+    /// These attributes generate functions that treat the pointer that Swift holds as a pointer
+    /// to the type itself, but an `arc_mutex` type's pointer is to its `Mutex<T>`.
+    fn arc_mutex_incompatible_attribute(&self) -> Option<&'static str> {
+        if !self.arc_mutex {
+            return None;
+        }
+
+        let incompatible = [
+            (self.add_assign, "AddAssign"),
+            (self.btree_map.is_some(), "btree_map"),
+            (self.clone, "Clone"),
+            (self.counter.is_some(), "counter"),
+            (self.deref.is_some(), "deref"),
+            (self.equatable, "Equatable"),
+            (self.error_chain, "error_chain"),
+            (self.hashable, "Hashable"),
+            (self.index.is_some(), "index"),
+            (self.neg, "Neg"),
+            (self.sub_assign, "SubAssign"),
+        ];
+        incompatible
+            .iter()
+            .find(|(enabled, _)| *enabled)
+            .map(|(_, name)| *name)
+    }
+
     pub(super) fn store_attrib(&mut self, attrib: OpaqueTypeAttr) {
         match attrib {
+            OpaqueTypeAttr::AddAssign => self.add_assign = true,
             OpaqueTypeAttr::AlreadyDeclared => self.already_declared = true,
+            OpaqueTypeAttr::ArcMutex => self.arc_mutex = true,
+            OpaqueTypeAttr::BTreeMap(btree_map) => self.btree_map = Some(btree_map),
+            OpaqueTypeAttr::Callable => self.callable = true,
+            OpaqueTypeAttr::Clone => self.clone = true,
+            OpaqueTypeAttr::ConformsTo(protocols) => self.conforms_to = protocols,
             OpaqueTypeAttr::Copy { size } => self.copy = Some(OpaqueCopy { size_bytes: size }),
+            OpaqueTypeAttr::Counter(value) => self.counter = Some(value),
             OpaqueTypeAttr::DeclareGeneric => self.declare_generic = true,
+            OpaqueTypeAttr::Deref(target) => {
+                self.deref = Some(Ident::new(&target.value(), target.span()))
+            }
+            OpaqueTypeAttr::DispatchQueue(label) => self.dispatch_queue = Some(label.value()),
             OpaqueTypeAttr::Equatable => self.equatable = true,
+            OpaqueTypeAttr::ErrorChain => self.error_chain = true,
             OpaqueTypeAttr::Hashable => self.hashable = true,
+            OpaqueTypeAttr::HashableByGetters => self.hashable_by_getters = true,
+            OpaqueTypeAttr::Index(index) => self.index = Some(index),
+            OpaqueTypeAttr::MainActor => self.main_actor = true,
+            OpaqueTypeAttr::Neg => self.neg = true,
+            OpaqueTypeAttr::SubAssign => self.sub_assign = true,
         }
     }
 }
 
 pub(crate) enum OpaqueTypeAttr {
+    AddAssign,
     AlreadyDeclared,
+    ArcMutex,
+    BTreeMap(Box<OpaqueBTreeMap>),
+    Callable,
+    Clone,
+    ConformsTo(Vec<String>),
     Copy { size: usize },
+    Counter(Box<Type>),
     DeclareGeneric,
+    Deref(LitStr),
+    DispatchQueue(LitStr),
     Equatable,
+    ErrorChain,
     Hashable,
+    HashableByGetters,
+    Index(Box<OpaqueIndex>),
+    MainActor,
+    Neg,
+    SubAssign,
 }
 
 impl Parse for OpaqueTypeSwiftBridgeAttributes {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut attributes = OpaqueTypeSwiftBridgeAttributes::default();
+        let span = input.span();
 
         let punctuated =
             syn::punctuated::Punctuated::<OpaqueTypeAttr, syn::Token![,]>::parse_terminated(input)?;
@@ -100,6 +243,13 @@ impl Parse for OpaqueTypeSwiftBridgeAttributes {
             attributes.store_attrib(attr);
         }
 
+        if let Some(attribute) = attributes.arc_mutex_incompatible_attribute() {
+            return Err(syn::Error::new(
+                span,
+                format!("`arc_mutex` cannot be combined with `{}`", attribute),
+            ));
+        }
+
         Ok(attributes)
     }
 }
@@ -109,7 +259,25 @@ impl Parse for OpaqueTypeAttr {
         let key: Ident = input.parse()?;
 
         let attrib = match key.to_string().as_str() {
+            "AddAssign" => OpaqueTypeAttr::AddAssign,
             "already_declared" => OpaqueTypeAttr::AlreadyDeclared,
+            "arc_mutex" => OpaqueTypeAttr::ArcMutex,
+            // btree_map(u32, String)
+            "btree_map" => {
+                let content;
+                syn::parenthesized!(content in input);
+
+                let key = content.parse()?;
+                content.parse::<syn::Token![,]>()?;
+                let value = content.parse()?;
+                OpaqueTypeAttr::BTreeMap(Box::new(OpaqueBTreeMap { key, value }))
+            }
+            "callable" => OpaqueTypeAttr::Callable,
+            "Clone" => OpaqueTypeAttr::Clone,
+            "conforms_to" => {
+                input.parse::<syn::Token![=]>()?;
+                OpaqueTypeAttr::ConformsTo(parse_conforms_to(&input.parse()?)?)
+            }
             // Copy(10)
             "Copy" => {
                 let content;
@@ -121,9 +289,39 @@ impl Parse for OpaqueTypeAttr {
                     size: size.to_string().parse().unwrap(),
                 }
             }
+            // counter(u32)
+            "counter" => {
+                let content;
+                syn::parenthesized!(content in input);
+
+                OpaqueTypeAttr::Counter(Box::new(content.parse()?))
+            }
             "declare_generic" => OpaqueTypeAttr::DeclareGeneric,
+            "deref" => {
+                input.parse::<syn::Token![=]>()?;
+                OpaqueTypeAttr::Deref(input.parse()?)
+            }
+            "dispatch_queue" => {
+                input.parse::<syn::Token![=]>()?;
+                OpaqueTypeAttr::DispatchQueue(input.parse()?)
+            }
             "Equatable" => OpaqueTypeAttr::Equatable,
+            "error_chain" => OpaqueTypeAttr::ErrorChain,
             "Hashable" => OpaqueTypeAttr::Hashable,
+            "HashableByGetters" => OpaqueTypeAttr::HashableByGetters,
+            // index(usize, f64)
+            "index" => {
+                let content;
+                syn::parenthesized!(content in input);
+
+                let index = content.parse()?;
+                content.parse::<syn::Token![,]>()?;
+                let output = content.parse()?;
+                OpaqueTypeAttr::Index(Box::new(OpaqueIndex { index, output }))
+            }
+            "main_actor" => OpaqueTypeAttr::MainActor,
+            "Neg" => OpaqueTypeAttr::Neg,
+            "SubAssign" => OpaqueTypeAttr::SubAssign,
             _ => {
                 let attrib = key.to_string();
                 Err(syn::Error::new_spanned(
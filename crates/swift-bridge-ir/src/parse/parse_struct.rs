@@ -1,23 +1,40 @@
+use crate::bridged_type::bridgeable_boxed_struct::find_unsupported_boxed_shared_struct;
+use crate::bridged_type::bridgeable_cow_slice::find_cow_slice;
+use crate::bridged_type::shared_struct::{
+    LocalizedErrorFields, NamedStructField, NormalizedStructFieldAccessor, StructDerives,
+};
 use crate::bridged_type::{
-    shared_struct::StructDerives, SharedStruct, StructFields, StructSwiftRepr,
+    BridgedType, CustomBridgedType, SharedStruct, SharedType, StdLibType, StructFields,
+    StructSwiftRepr,
 };
-use crate::errors::{ParseError, ParseErrors};
-use crate::parse::move_input_cursor_to_next_comma;
+use crate::errors::{ParseError, ParseErrors, ParseWarning};
+use crate::parse::{move_input_cursor_to_next_comma, parse_conforms_to, TypeDeclarations};
 use proc_macro2::Ident;
 use quote::ToTokens;
 use syn::parse::{Parse, ParseStream};
-use syn::{ItemStruct, LitStr, Meta, Token};
+use syn::{Field, Fields, ItemStruct, LitStr, Meta, Token, Type};
 
 pub(crate) struct SharedStructDeclarationParser<'a> {
     pub item_struct: ItemStruct,
     pub errors: &'a mut ParseErrors,
+    pub warnings: &'a mut Vec<ParseWarning>,
 }
 
 enum StructAttr {
     SwiftRepr((StructSwiftRepr, LitStr)),
     SwiftName(LitStr),
+    CustomReflectable,
+    /// `positional_names = ["x", "y"]`
+    PositionalNames {
+        attribute: Ident,
+        names: Vec<LitStr>,
+    },
     Error(StructAttrParseError),
     AlreadyDeclared,
+    Bytes,
+    Comparable,
+    ErrorDomain(LitStr),
+    ConformsTo(Vec<String>),
 }
 
 enum StructAttrParseError {
@@ -30,6 +47,12 @@ struct StructAttribs {
     swift_repr: Option<(StructSwiftRepr, LitStr)>,
     swift_name: Option<LitStr>,
     already_declared: bool,
+    custom_reflectable: bool,
+    bytes: bool,
+    comparable: bool,
+    error_domain: Option<LitStr>,
+    conforms_to: Vec<String>,
+    positional_names: Option<(Ident, Vec<LitStr>)>,
     derives: StructDerives,
 }
 
@@ -38,6 +61,7 @@ impl Default for StructDerives {
         StructDerives {
             copy: false,
             clone: false,
+            hash: false,
         }
     }
 }
@@ -77,6 +101,33 @@ impl Parse for StructAttr {
                 StructAttr::SwiftName(name)
             }
             "already_declared" => StructAttr::AlreadyDeclared,
+            "custom_reflectable" => StructAttr::CustomReflectable,
+            "bytes" => StructAttr::Bytes,
+            "comparable" => StructAttr::Comparable,
+            "error_domain" => {
+                input.parse::<Token![=]>()?;
+
+                let domain = input.parse()?;
+                StructAttr::ErrorDomain(domain)
+            }
+            "conforms_to" => {
+                input.parse::<Token![=]>()?;
+
+                StructAttr::ConformsTo(parse_conforms_to(&input.parse()?)?)
+            }
+            "positional_names" => {
+                input.parse::<Token![=]>()?;
+
+                let content;
+                syn::bracketed!(content in input);
+                let names =
+                    syn::punctuated::Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+
+                StructAttr::PositionalNames {
+                    attribute: key,
+                    names: names.into_iter().collect(),
+                }
+            }
             _ => {
                 move_input_cursor_to_next_comma(input);
                 StructAttr::Error(StructAttrParseError::UnrecognizedAttribute(key))
@@ -124,6 +175,24 @@ impl<'a> SharedStructDeclarationParser<'a> {
                             StructAttr::AlreadyDeclared => {
                                 attribs.already_declared = true;
                             }
+                            StructAttr::CustomReflectable => {
+                                attribs.custom_reflectable = true;
+                            }
+                            StructAttr::Bytes => {
+                                attribs.bytes = true;
+                            }
+                            StructAttr::Comparable => {
+                                attribs.comparable = true;
+                            }
+                            StructAttr::ErrorDomain(domain) => {
+                                attribs.error_domain = Some(domain);
+                            }
+                            StructAttr::ConformsTo(protocols) => {
+                                attribs.conforms_to = protocols;
+                            }
+                            StructAttr::PositionalNames { attribute, names } => {
+                                attribs.positional_names = Some((attribute, names));
+                            }
                         };
                     }
                 }
@@ -133,6 +202,7 @@ impl<'a> SharedStructDeclarationParser<'a> {
                             match derive.to_token_stream().to_string().as_str() {
                                 "Copy" => attribs.derives.copy = true,
                                 "Clone" => attribs.derives.clone = true,
+                                "Hash" => attribs.derives.hash = true,
                                 _ => {}
                             }
                         }
@@ -149,7 +219,10 @@ impl<'a> SharedStructDeclarationParser<'a> {
             }
         }
 
-        let swift_repr = if item_struct.fields.len() == 0 {
+        let mut item_fields = item_struct.fields;
+        let phantom_data_fields = take_phantom_data_fields(&mut item_fields);
+
+        let swift_repr = if item_fields.len() == 0 {
             if let Some((swift_repr, lit_str)) = attribs.swift_repr {
                 if swift_repr == StructSwiftRepr::Class {
                     self.errors.push(ParseError::EmptyStructHasSwiftReprClass {
@@ -170,19 +243,281 @@ impl<'a> SharedStructDeclarationParser<'a> {
             StructSwiftRepr::Structure
         };
 
+        if swift_repr == StructSwiftRepr::Class && attribs.derives.copy {
+            self.errors.push(ParseError::CopyStructWithClassRepr {
+                struct_ident: item_struct.ident.clone(),
+            });
+        }
+
+        let localized_error = parse_localized_error_fields(item_fields.iter(), self.errors)?;
+
+        let mut fields = StructFields::from_syn_fields(item_fields);
+
+        // Primitives don't need to be declared in the bridge module, so we can check for them
+        // before all of the module's types have been parsed.
+        if swift_repr == StructSwiftRepr::Class
+            && fields
+                .normalized_fields()
+                .iter()
+                .all(|field| is_primitive(&field.ty, &TypeDeclarations::default()))
+        {
+            self.warnings
+                .push(ParseWarning::ClassReprStructWithOnlyPrimitiveFields {
+                    struct_ident: item_struct.ident.clone(),
+                });
+        }
+
+        if let Some((attribute, names)) = attribs.positional_names {
+            match &mut fields {
+                StructFields::Unnamed(unnamed) if unnamed.len() == names.len() => {
+                    for (field, name) in unnamed.iter_mut().zip(names) {
+                        field.swift_name = Some(name.value());
+                    }
+                }
+                StructFields::Unnamed(unnamed) => {
+                    self.errors.push(ParseError::StructPositionalNamesMismatch {
+                        attribute,
+                        name_count: names.len(),
+                        field_count: unnamed.len(),
+                    });
+                }
+                StructFields::Named(_) | StructFields::Unit => {
+                    self.errors.push(ParseError::StructPositionalNamesMismatch {
+                        attribute,
+                        name_count: names.len(),
+                        field_count: 0,
+                    });
+                }
+            }
+        }
+
         let shared_struct = SharedStruct {
             name: item_struct.ident,
             swift_repr,
-            fields: StructFields::from_syn_fields(item_struct.fields),
+            fields,
             swift_name: attribs.swift_name,
             already_declared: attribs.already_declared,
+            custom_reflectable: attribs.custom_reflectable,
+            bytes: attribs.bytes,
+            comparable: attribs.comparable,
             derives: attribs.derives,
+            localized_error,
+            error_domain: attribs.error_domain,
+            conforms_to: attribs.conforms_to,
+            phantom_data_fields,
         };
 
         Ok(shared_struct)
     }
 }
 
+/// Remove a struct's named `PhantomData<T>` fields and return them.
+///
+/// `PhantomData` is only a type level marker, so it is not passed over FFI. The marker is
+/// recreated when the struct is converted back into its Rust representation.
+fn take_phantom_data_fields(fields: &mut Fields) -> Vec<NamedStructField> {
+    let named = match fields {
+        Fields::Named(named) => named,
+        Fields::Unnamed(_) | Fields::Unit => return vec![],
+    };
+
+    let mut phantom_data_fields = vec![];
+    let mut kept = syn::punctuated::Punctuated::new();
+
+    for field in std::mem::take(&mut named.named) {
+        if is_phantom_data(&field.ty) {
+            phantom_data_fields.push(NamedStructField {
+                name: field.ident.unwrap(),
+                ty: field.ty,
+            });
+        } else {
+            kept.push(field);
+        }
+    }
+
+    named.named = kept;
+    phantom_data_fields
+}
+
+/// `PhantomData<T>`, `std::marker::PhantomData<T>` or `core::marker::PhantomData<T>`
+fn is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "PhantomData")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// Parse the `#[swift_bridge(error_description)]`, `#[swift_bridge(failure_reason)]` and
+/// `#[swift_bridge(recovery_suggestion)]` field attributes.
+fn parse_localized_error_fields<'a>(
+    fields: impl Iterator<Item = &'a Field>,
+    errors: &mut ParseErrors,
+) -> syn::Result<LocalizedErrorFields> {
+    let mut localized_error = LocalizedErrorFields::default();
+
+    for (idx, field) in fields.enumerate() {
+        for attr in field.attrs.iter() {
+            if attr.path.to_token_stream().to_string() != "swift_bridge" {
+                continue;
+            }
+
+            let keys = attr.parse_args_with(
+                syn::punctuated::Punctuated::<Ident, Token![,]>::parse_terminated,
+            )?;
+            for key in keys {
+                let localized_error_field = match key.to_string().as_str() {
+                    "error_description" => &mut localized_error.error_description,
+                    "failure_reason" => &mut localized_error.failure_reason,
+                    "recovery_suggestion" => &mut localized_error.recovery_suggestion,
+                    _ => {
+                        errors.push(ParseError::StructUnrecognizedAttribute { attribute: key });
+                        continue;
+                    }
+                };
+
+                if field.ty.to_token_stream().to_string() != "String" {
+                    errors.push(ParseError::StructLocalizedErrorFieldNotString {
+                        attribute: key,
+                        ty: field.ty.clone(),
+                    });
+                    continue;
+                }
+
+                *localized_error_field = Some(idx);
+            }
+        }
+    }
+
+    Ok(localized_error)
+}
+
+/// Push an error for every field of the struct whose type cannot be bridged.
+///
+/// This happens after the entire module has been parsed, since a field's type might be declared
+/// further down in the module.
+pub(crate) fn push_unbridgeable_struct_field_errors(
+    shared_struct: &SharedStruct,
+    types: &TypeDeclarations,
+    errors: &mut ParseErrors,
+) {
+    for field in shared_struct.fields.normalized_fields() {
+        if let Some(ty) = find_unsupported_boxed_shared_struct(&field.ty, types) {
+            errors.push(ParseError::BoxedSharedStructNotInOption { ty: ty.clone() });
+            continue;
+        }
+        if let Some(ty) = find_cow_slice(&field.ty, types) {
+            errors.push(ParseError::CowSliceNotReturnType { ty: ty.clone() });
+            continue;
+        }
+        if BridgedType::new_with_type(&field.ty, types).is_some() {
+            continue;
+        }
+
+        let field_name = match &field.accessor {
+            NormalizedStructFieldAccessor::Named(name) => name.to_string(),
+            NormalizedStructFieldAccessor::Unnamed(idx) => idx.to_string(),
+        };
+        errors.push(ParseError::StructFieldNotBridgeable {
+            struct_ident: shared_struct.name.clone(),
+            field_name,
+            ty: field.ty,
+        });
+    }
+}
+
+/// Push an error for every field of a `#[swift_bridge(bytes)]` struct that is not a primitive.
+///
+/// The struct's bytes are a copy of its FFI representation, so its fields cannot hold pointers
+/// such as a `String`'s.
+pub(crate) fn push_bytes_struct_field_errors(
+    shared_struct: &SharedStruct,
+    types: &TypeDeclarations,
+    errors: &mut ParseErrors,
+) {
+    if !shared_struct.bytes {
+        return;
+    }
+
+    for field in shared_struct.fields.normalized_fields() {
+        if is_primitive(&field.ty, types) {
+            continue;
+        }
+
+        errors.push(ParseError::StructBytesFieldNotPrimitive {
+            struct_ident: shared_struct.name.clone(),
+            ty: field.ty,
+        });
+    }
+}
+
+/// Push an error for every field of a `#[swift_bridge(comparable)]` struct whose Swift type is not
+/// `Comparable`.
+pub(crate) fn push_non_comparable_struct_field_errors(
+    shared_struct: &SharedStruct,
+    types: &TypeDeclarations,
+    errors: &mut ParseErrors,
+) {
+    if !shared_struct.comparable {
+        return;
+    }
+
+    for field in shared_struct.fields.normalized_fields() {
+        if is_swift_comparable(&field.ty, types) {
+            continue;
+        }
+
+        errors.push(ParseError::StructFieldNotComparable {
+            struct_ident: shared_struct.name.clone(),
+            ty: field.ty,
+        });
+    }
+}
+
+/// Whether or not the type's Swift representation is `Comparable`.
+///
+/// Numbers, `comparable` enums and `comparable` structs are `Comparable`. Swift's `Bool` is not.
+fn is_swift_comparable(ty: &Type, types: &TypeDeclarations) -> bool {
+    match BridgedType::new_with_type(ty, types) {
+        Some(BridgedType::StdLib(std_lib_type)) => {
+            !matches!(std_lib_type, StdLibType::Bool) && is_primitive(ty, types)
+        }
+        Some(BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum)))) => {
+            shared_enum.comparable
+        }
+        Some(BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(
+            shared_struct,
+        )))) => shared_struct.comparable,
+        _ => false,
+    }
+}
+
+/// Whether or not the type is a `Copy` number or `bool`.
+fn is_primitive(ty: &Type, types: &TypeDeclarations) -> bool {
+    matches!(
+        BridgedType::new_with_type(ty, types),
+        Some(BridgedType::StdLib(
+            StdLibType::U8
+                | StdLibType::I8
+                | StdLibType::U16
+                | StdLibType::I16
+                | StdLibType::U32
+                | StdLibType::I32
+                | StdLibType::U64
+                | StdLibType::I64
+                | StdLibType::Usize
+                | StdLibType::Isize
+                | StdLibType::F32
+                | StdLibType::F64
+                | StdLibType::Bool
+        ))
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -263,6 +598,48 @@ mod tests {
         };
     }
 
+    /// Verify that we push an error that names the field and its type if a shared struct has a
+    /// field whose type cannot be bridged.
+    #[test]
+    fn error_if_field_type_not_bridgeable() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Foo {
+                    bar: u8,
+                    baz: SomeUndeclaredType,
+                    declared_later: Buzz,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct Buzz(Option<AnotherUndeclaredType>);
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        let expected = [
+            ("Foo", "baz", "SomeUndeclaredType"),
+            ("Buzz", "0", "Option < AnotherUndeclaredType >"),
+        ];
+        for (idx, (expected_struct, expected_field, expected_ty)) in expected.iter().enumerate() {
+            match &errors[idx] {
+                ParseError::StructFieldNotBridgeable {
+                    struct_ident,
+                    field_name,
+                    ty,
+                } => {
+                    assert_eq!(struct_ident, expected_struct);
+                    assert_eq!(field_name, expected_field);
+                    assert_eq!(ty.to_token_stream().to_string(), *expected_ty);
+                }
+                _ => panic!(),
+            };
+        }
+    }
+
     /// Verify that we push an error if a struct with no fields has it's swift_repr set to "class",
     /// since there is no advantage to bearing that extra overhead.
     #[test]
@@ -298,6 +675,32 @@ mod tests {
         }
     }
 
+    /// Verify that we push an error if a struct that derives `Copy` has its swift_repr set to
+    /// "class", since a `Copy` struct has value semantics.
+    #[test]
+    fn error_if_copy_struct_swift_repr_set_to_class() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(Copy, Clone)]
+                #[swift_bridge(swift_repr = "class")]
+                struct Foo {
+                    field: String,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::CopyStructWithClassRepr { struct_ident } => {
+                assert_eq!(struct_ident, "Foo");
+            }
+            _ => panic!(),
+        };
+    }
+
     /// Verify that we can parse a struct with a named field.
     #[test]
     fn parse_struct_with_named_u8_field() {
@@ -325,6 +728,34 @@ mod tests {
         };
     }
 
+    /// Verify that we remove `PhantomData` fields from a struct's bridged fields.
+    #[test]
+    fn parse_struct_with_phantom_data_field() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Foo {
+                    bar: u8,
+                    marker: std::marker::PhantomData<super::Marker>
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        match &ty.fields {
+            StructFields::Named(fields) => {
+                assert_eq!(fields.len(), 1);
+                assert_eq!(fields[0].name, "bar");
+            }
+            _ => panic!(),
+        };
+        assert_eq!(ty.phantom_data_fields.len(), 1);
+        assert_eq!(ty.phantom_data_fields[0].name, "marker");
+    }
+
     /// Verify that we parse the swift_name = "..."
     #[test]
     fn parse_swift_name_attribute() {
@@ -351,7 +782,7 @@ mod tests {
                 #[derive(Copy, Clone)]
                 struct Foo;
 
-                #[derive(Clone)]
+                #[derive(Clone, Hash)]
                 struct Bar;
             }
         };
@@ -362,11 +793,13 @@ mod tests {
 
         assert_eq!(ty.derives.copy, true);
         assert_eq!(ty.derives.clone, true);
+        assert_eq!(ty.derives.hash, false);
 
         let ty2 = module.types.types()[1].unwrap_shared_struct();
 
         assert_eq!(ty2.derives.copy, false);
         assert_eq!(ty2.derives.clone, true);
+        assert_eq!(ty2.derives.hash, true);
     }
 
     /// Verify that we properly parse multiple comma separated struct attributes.
@@ -429,6 +862,286 @@ mod tests {
         assert!(ty.already_declared);
     }
 
+    /// Verify that we can parse the `custom_reflectable` attribute.
+    #[test]
+    fn parses_struct_custom_reflectable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", custom_reflectable)]
+                struct SomeType {
+                    field: u8
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert!(ty.custom_reflectable);
+    }
+
+    /// Verify that we can parse the `bytes` attribute.
+    #[test]
+    fn parses_struct_bytes_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", bytes)]
+                struct SomeType {
+                    field: u8
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert!(ty.bytes);
+    }
+
+    /// Verify that we can parse the `error_domain` attribute.
+    #[test]
+    fn parses_struct_error_domain_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", error_domain = "com.example")]
+                struct SomeType {
+                    field: u8
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert_eq!(ty.error_domain.as_ref().unwrap().value(), "com.example");
+    }
+
+    /// Verify that we push an error if a `bytes` struct has a field that is not a primitive.
+    #[test]
+    fn error_if_bytes_struct_field_not_primitive() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", bytes)]
+                struct SomeType {
+                    id: u32,
+                    name: String,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::StructBytesFieldNotPrimitive { struct_ident, ty } => {
+                assert_eq!(struct_ident, "SomeType");
+                assert_eq!(ty.to_token_stream().to_string(), "String");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `comparable` attribute.
+    #[test]
+    fn parse_comparable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", comparable)]
+                struct SomeType {
+                    major: u32,
+                    minor: u32,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert!(ty.comparable);
+    }
+
+    /// Verify that we push an error for each field of a `comparable` struct whose Swift type is
+    /// not `Comparable`.
+    #[test]
+    fn error_if_comparable_struct_field_not_comparable() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", comparable)]
+                struct SomeType {
+                    id: u32,
+                    enabled: bool,
+                    other: OtherType,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct OtherType {
+                    id: u32,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        let tys: Vec<String> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::StructFieldNotComparable { struct_ident, ty } => {
+                    assert_eq!(struct_ident, "SomeType");
+                    ty.to_token_stream().to_string()
+                }
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(tys, vec!["bool", "OtherType"]);
+    }
+
+    /// Verify that we push an error for a boxed shared struct field that is not wrapped in an
+    /// `Option`, while allowing `Option<Box<SharedStruct>>`.
+    #[test]
+    fn error_if_boxed_shared_struct_field_not_in_option() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct TreeNode {
+                    left: Option<Box<TreeNode>>,
+                    right: Box<TreeNode>,
+                    children: Vec<Box<TreeNode>>,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        let tys: Vec<String> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::BoxedSharedStructNotInOption { ty } => ty.to_token_stream().to_string(),
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(tys, vec!["Box < TreeNode >", "Box < TreeNode >"]);
+    }
+
+    /// Verify that we can parse the `positional_names` attribute.
+    #[test]
+    fn parses_struct_positional_names_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", positional_names = ["x", "y"])]
+                struct SomeType(f64, f64);
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        let swift_names: Vec<String> = ty
+            .fields
+            .normalized_fields()
+            .into_iter()
+            .map(|field| field.swift_name)
+            .collect();
+        assert_eq!(swift_names, vec!["x", "y"]);
+    }
+
+    /// Verify that we push an error if the number of positional names does not match the number
+    /// of tuple fields.
+    #[test]
+    fn error_if_positional_names_count_mismatch() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", positional_names = ["x"])]
+                struct SomeType(f64, f64);
+
+                #[swift_bridge(swift_repr = "struct", positional_names = ["x"])]
+                struct AnotherType {
+                    x: f64
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        for (idx, expected_field_count) in [2, 0].into_iter().enumerate() {
+            match &errors[idx] {
+                ParseError::StructPositionalNamesMismatch {
+                    attribute,
+                    name_count,
+                    field_count,
+                } => {
+                    assert_eq!(attribute, "positional_names");
+                    assert_eq!(*name_count, 1);
+                    assert_eq!(*field_count, expected_field_count);
+                }
+                _ => panic!(),
+            };
+        }
+    }
+
+    /// Verify that we can parse the `LocalizedError` field attributes.
+    #[test]
+    fn parses_struct_localized_error_field_attributes() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeError {
+                    code: u32,
+                    #[swift_bridge(error_description)]
+                    message: String,
+                    #[swift_bridge(recovery_suggestion)]
+                    hint: String,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert_eq!(ty.localized_error.error_description, Some(1));
+        assert_eq!(ty.localized_error.failure_reason, None);
+        assert_eq!(ty.localized_error.recovery_suggestion, Some(2));
+    }
+
+    /// Verify that we push an error if a `LocalizedError` field attribute is used on a field
+    /// that is not a `String`.
+    #[test]
+    fn error_if_localized_error_field_not_string() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeError {
+                    #[swift_bridge(failure_reason)]
+                    code: u32,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::StructLocalizedErrorFieldNotString { attribute, ty } => {
+                assert_eq!(attribute, "failure_reason");
+                assert_eq!(ty.to_token_stream().to_string(), "u32");
+            }
+            _ => panic!(),
+        };
+    }
+
     /// Verify that we return an error if an attribute isn't recognized.
     #[test]
     fn error_if_attribute_unrecognized() {
@@ -58,6 +58,7 @@ impl TypeDeclaration {
                 reference,
                 mutable,
                 has_swift_bridge_copy_annotation: opaque.attributes.copy.is_some(),
+                arc_mutex: opaque.attributes.arc_mutex,
                 generics: opaque.generics.clone(),
             }),
             _ => None,
@@ -77,6 +78,11 @@ impl OpaqueForeignTypeDeclaration {
     pub(crate) fn ffi_repr_type_tokens(&self) -> TokenStream {
         if self.attributes.copy.is_some() {
             self.ffi_copy_repr_ident().to_token_stream()
+        } else if self.attributes.arc_mutex {
+            let ty_name = &self.ty;
+            quote::quote! {
+                *const std::sync::Mutex<super::#ty_name>
+            }
         } else {
             let ty_name = &self.ty;
             quote::quote! {
@@ -96,6 +102,14 @@ impl OpaqueForeignTypeDeclaration {
         )
     }
 
+    /// The C FFI link name of the function used to create another owned handle to an
+    /// `#[swift_bridge(arc_mutex)]` type.
+    ///
+    /// "__swift_bridge__$SomeType$_share"
+    pub(crate) fn share_rust_opaque_type_ffi_name(&self) -> String {
+        format!("{}${}$_share", SWIFT_BRIDGE_PREFIX, self.ty)
+    }
+
     /// The C FFI link name of the function used to free memory for this opaque Rust type.
     ///
     /// For `type SomeType<u32>` this would be:
@@ -177,6 +191,24 @@ impl OpaqueForeignTypeDeclaration {
     }
 }
 
+/// `#[swift_bridge(btree_map(K, V))]`
+#[derive(Clone)]
+pub(crate) struct OpaqueBTreeMap {
+    /// The map's key type, such as the `K` in `BTreeMap<K, V>`.
+    pub(crate) key: Type,
+    /// The map's value type, such as the `V` in `BTreeMap<K, V>`.
+    pub(crate) value: Type,
+}
+
+/// `#[swift_bridge(index(Idx, Output))]`
+#[derive(Clone)]
+pub(crate) struct OpaqueIndex {
+    /// The index type, such as the `Idx` in `impl Index<Idx>`.
+    pub(crate) index: Type,
+    /// The type that indexing returns, such as the `Output` in `impl Index<Idx, Output = Output>`.
+    pub(crate) output: Type,
+}
+
 #[derive(Copy, Clone)]
 pub(crate) struct OpaqueCopy {
     /// The size of the opaque type, in bytes.
@@ -258,6 +290,26 @@ impl TypeDeclaration {
             _ => None,
         }
     }
+
+    pub(crate) fn as_shared_struct(&self) -> Option<&SharedStruct> {
+        match self {
+            TypeDeclaration::Shared(SharedTypeDeclaration::Struct(s)) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// The name of the type in Swift.
+    pub(crate) fn swift_name_string(&self) -> String {
+        match self {
+            TypeDeclaration::Opaque(opaque) => opaque.ty.to_string(),
+            TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                shared_struct.swift_name_string()
+            }
+            TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
+                shared_enum.swift_name_string()
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1,8 +1,11 @@
 use crate::bridged_type::boxed_fn::BridgeableBoxedFnOnce;
-use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType, StdLibType};
+use crate::bridged_type::{
+    pat_type_pat_is_self, BridgeableType, BridgedType, SharedStruct, StdLibType,
+};
+use crate::codegen::CodegenConfig;
 use crate::parse::{HostLang, SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
 use crate::SWIFT_BRIDGE_PREFIX;
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use quote::{quote, quote_spanned, ToTokens};
 use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
@@ -66,6 +69,9 @@ pub(crate) struct ParsedExternFn {
     /// Whether or not this function should be used for the associated type's Swift
     /// `Identifiable` protocol implementation.
     pub is_swift_identifiable: bool,
+    /// Whether or not this function returns a `&'static` singleton that should be exposed as a
+    /// Swift `static let` on the associated type.
+    pub is_swift_shared_instance: bool,
     pub rust_name_override: Option<syn::LitStr>,
     pub swift_name_override: Option<syn::LitStr>,
     /// If true, we call `.into()` on the expression that the function returns before returning it.
@@ -81,6 +87,14 @@ pub(crate) struct ParsedExternFn {
     /// ```
     pub return_into: bool,
     pub return_with: Option<Path>,
+    /// `#[swift_bridge(return_data)]`
+    /// Swift returns the `Vec<u8>` that this function returns, or that its `Result` holds on
+    /// success, as a Foundation `Data` that owns the Rust `Vec`'s buffer.
+    pub return_data: bool,
+    /// `#[swift_bridge(return_warnings)]`
+    /// Swift returns the `(T, Vec<W>)` that this function returns as a `ValueWithWarnings<T, W>`
+    /// that holds the value and an array of the warnings.
+    pub return_warnings: bool,
     /// Call `.into()` before passing this argument to the function that handles it.
     ///
     /// ```no_run,ignore
@@ -96,12 +110,42 @@ pub(crate) struct ParsedExternFn {
     pub args_into: Option<Vec<Ident>>,
     /// Get one of the associated type's fields
     pub get_field: Option<GetField>,
+    /// Set the value inside of one of the associated type's `Cell<T>` fields.
+    ///
+    /// ```no_run,ignore
+    /// // Declaration
+    /// #[swift_bridge(set_cell(count))]
+    /// fn set_count(&self, count: i32);
+    ///
+    /// // Approximate generated code
+    /// extern "C" fn set_count(this: *mut SomeType, count: i32) {
+    ///     (unsafe { &*this }).count.set(count)
+    /// }
+    /// ```
+    pub set_cell_field: Option<Ident>,
+    /// `#[swift_bridge(available = "iOS 15.0, macOS 12.0")]`
+    /// The platforms that the generated Swift function is available on, which get emitted as an
+    /// `@available(iOS 15.0, macOS 12.0, *)` attribute.
+    pub available: Option<LitStr>,
+    /// `#[swift_bridge(discardable_result)]`
+    /// Mark the generated Swift function `@discardableResult`, so that ignoring its return value
+    /// does not produce a warning.
+    pub discardable_result: bool,
+    /// `#[swift_bridge(spi = "GroupName")]`
+    /// The generated Swift function is emitted as `@_spi(GroupName)`, so that only modules that
+    /// import this module using `@_spi(GroupName) import` can use it.
+    pub spi: Option<LitStr>,
     pub argument_labels: HashMap<Ident, LitStr>,
+    /// The index of the `extern "..." { ... }` block within the bridge module that this function
+    /// was declared in.
+    /// Used to group the generated Swift methods into one extension per extern block.
+    pub extern_block_idx: usize,
 }
 
 pub(crate) enum GetField {
     Direct(GetFieldDirect),
     With(GetFieldWith),
+    Cell(GetFieldCell),
 }
 
 pub struct GetFieldDirect {
@@ -117,6 +161,12 @@ pub struct GetFieldWith {
     pub(crate) path: Path,
 }
 
+/// `#[swift_bridge(get_cell(field))]`
+/// Gets a copy of the value inside of a `Cell<T>` field.
+pub struct GetFieldCell {
+    pub(crate) field_name: Ident,
+}
+
 #[cfg(test)]
 impl GetField {
     pub(crate) fn unwrap_direct(&self) -> &GetFieldDirect {
@@ -132,6 +182,13 @@ impl GetField {
             _ => panic!(),
         }
     }
+
+    pub(crate) fn unwrap_cell(&self) -> &GetFieldCell {
+        match self {
+            GetField::Cell(d) => d,
+            _ => panic!(),
+        }
+    }
 }
 
 impl ParsedExternFn {
@@ -139,6 +196,141 @@ impl ParsedExternFn {
         self.func.sig.receiver().is_some()
     }
 
+    /// Some if this is a method on a shared struct.
+    pub(crate) fn associated_shared_struct(&self) -> Option<&SharedStruct> {
+        self.associated_type.as_ref()?.as_shared_struct()
+    }
+
+    /// Whether or not this is a `self: &mut SomeStruct` method on a shared struct, which Swift
+    /// exposes as a `mutating func`.
+    /// Shared struct methods that take `self: SomeStruct` or `self: &SomeStruct` are passed a copy
+    /// of the struct instead.
+    pub(crate) fn mutates_shared_struct(&self) -> bool {
+        self.associated_shared_struct().is_some() && self.self_mutability().is_some()
+    }
+
+    /// Whether or not this is an extern "Rust" function that returns a `&'static str`.
+    ///
+    /// The string outlives any Swift value, so Swift copies it into a `String` instead of handing
+    /// out a `RustStr` whose lifetime it would have to reason about.
+    pub(crate) fn returns_static_str(&self) -> bool {
+        if !self.host_lang.is_rust() || self.sig.asyncness.is_some() {
+            return false;
+        }
+
+        match &self.sig.output {
+            ReturnType::Type(_, ty) => match ty.deref() {
+                Type::Reference(ty_ref) => {
+                    ty_ref.lifetime.as_ref().map(|l| l.ident == "static") == Some(true)
+                        && ty_ref.elem.to_token_stream().to_string() == "str"
+                }
+                _ => false,
+            },
+            ReturnType::Default => false,
+        }
+    }
+
+    /// Whether or not this is an extern "Rust" function that returns a `&str` that borrows from one
+    /// of its arguments, such as `fn longest<'a>(&self, a: &'a str, b: &'a str) -> &'a str`.
+    ///
+    /// Swift only lends its strings to Rust for the duration of the call, so Swift copies the
+    /// returned `&str` into a `String` before the argument that it points into goes away.
+    pub(crate) fn returns_str_borrowed_from_args(&self) -> bool {
+        if !self.host_lang.is_rust() || self.sig.asyncness.is_some() {
+            return false;
+        }
+
+        let ty_ref = match &self.sig.output {
+            ReturnType::Type(_, ty) => match ty.deref() {
+                Type::Reference(ty_ref) if ty_ref.elem.to_token_stream().to_string() == "str" => {
+                    ty_ref
+                }
+                _ => return false,
+            },
+            ReturnType::Default => return false,
+        };
+
+        match ty_ref.lifetime.as_ref() {
+            Some(lifetime) if lifetime.ident == "static" => false,
+            Some(lifetime) => self.sig.inputs.iter().any(|arg| match arg {
+                FnArg::Typed(pat_ty) if !pat_type_pat_is_self(pat_ty) => {
+                    type_contains_lifetime(&pat_ty.ty, lifetime)
+                }
+                _ => false,
+            }),
+            // Without a `self` argument an elided lifetime can only come from a borrowed argument.
+            None => {
+                !self.is_method()
+                    && self.sig.inputs.iter().any(|arg| match arg {
+                        FnArg::Typed(pat_ty) => {
+                            pat_ty.ty.to_token_stream().to_string().contains('&')
+                        }
+                        FnArg::Receiver(_) => false,
+                    })
+            }
+        }
+    }
+
+    /// Whether or not Swift copies the `&str` that this function returns into a `String`.
+    pub(crate) fn copies_returned_str(&self) -> bool {
+        self.returns_static_str() || self.returns_str_borrowed_from_args()
+    }
+
+    /// Whether or not this is an extern "Rust" method on an opaque Rust class that returns a view
+    /// that borrows from `self`, such as a `&str` or a `&[T]`.
+    ///
+    /// These methods get a Swift overload that passes the view to a closure and keeps `self` alive
+    /// with `withExtendedLifetime` until the closure returns, so that the Rust value that the view
+    /// points into can't be freed while the view is in use.
+    pub(crate) fn returns_borrowed_view_of_self(&self) -> bool {
+        fn is_borrowed_view(ty: &Type) -> bool {
+            match ty {
+                Type::Reference(ty_ref) => match ty_ref.elem.deref() {
+                    Type::Slice(_) => true,
+                    elem => elem.to_token_stream().to_string() == "str",
+                },
+                Type::Path(path) => {
+                    let last = path.path.segments.last().unwrap();
+                    match &last.arguments {
+                        syn::PathArguments::AngleBracketed(args) if last.ident == "Option" => {
+                            match args.args.first() {
+                                Some(syn::GenericArgument::Type(inner)) => is_borrowed_view(inner),
+                                _ => false,
+                            }
+                        }
+                        _ => false,
+                    }
+                }
+                _ => false,
+            }
+        }
+
+        let is_opaque_rust_class_method = match self.associated_type.as_ref() {
+            Some(TypeDeclaration::Opaque(ty)) => {
+                ty.host_lang.is_rust() && ty.attributes.copy.is_none()
+            }
+            _ => false,
+        };
+        if !is_opaque_rust_class_method
+            || !self.host_lang.is_rust()
+            || self.sig.asyncness.is_some()
+            || self.self_reference().is_none()
+            || self.copies_returned_str()
+        {
+            return false;
+        }
+
+        match &self.sig.output {
+            ReturnType::Type(_, ty) => is_borrowed_view(ty),
+            ReturnType::Default => false,
+        }
+    }
+
+    /// Whether or not this function reads or writes one of its associated type's `Cell<T>` fields.
+    pub fn uses_cell_field(&self) -> bool {
+        matches!(self.get_field, Some(GetField::Cell(_))) || self.set_cell_field.is_some()
+    }
+
     pub fn self_reference(&self) -> Option<(Token![&], Option<Lifetime>)> {
         match self.func.sig.receiver()? {
             FnArg::Receiver(receiver) => receiver.reference.clone(),
@@ -443,6 +635,17 @@ impl ParsedExternFn {
                     .unwrap()
                     .ty
             )
+        } else if let Some(shared_struct) = self.associated_shared_struct() {
+            let maybe_pointer = if self.mutates_shared_struct() {
+                "*"
+            } else {
+                ""
+            };
+            format!(
+                "struct {}{} this",
+                shared_struct.ffi_name_string(),
+                maybe_pointer
+            )
         } else {
             "void* self".to_string()
         };
@@ -452,13 +655,83 @@ impl ParsedExternFn {
 }
 
 impl ParsedExternFn {
+    /// The name of the function on the Swift side.
+    ///
+    /// This is the `#[swift_bridge(swift_name = "...")]` if there is one, otherwise it is the
+    /// Rust function's name, converted to camelCase if the config enables it.
+    pub(crate) fn swift_fn_name(&self, config: &CodegenConfig) -> String {
+        if let Some(swift_name) = &self.swift_name_override {
+            return swift_name.value();
+        }
+
+        let fn_name = self.func.sig.ident.to_string();
+        match &config.camel_case_swift_names {
+            Some(camel_case) => camel_case.to_camel_case(&fn_name),
+            None => fn_name,
+        }
+    }
+
+    /// The `@available(...)` attribute for the generated Swift function, or `None` if the
+    /// function does not have a `#[swift_bridge(available = "...")]` attribute.
+    ///
+    /// `"iOS 15.0, macOS 12.0"` -> `@available(iOS 15.0, macOS 12.0, *)`
+    pub(crate) fn swift_available_attribute(&self) -> Option<String> {
+        let platforms = self.available.as_ref()?.value();
+        let platforms = platforms
+            .split(',')
+            .map(|platform| platform.trim())
+            .filter(|platform| !platform.is_empty() && *platform != "*")
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Some(format!("@available({}, *)", platforms))
+    }
+
+    /// The `@_spi(...)` attribute for the generated Swift function, or `None` if the function
+    /// does not have a `#[swift_bridge(spi = "...")]` attribute.
+    pub(crate) fn swift_spi_attribute(&self) -> Option<String> {
+        let group = self.spi.as_ref()?.value();
+        Some(format!("@_spi({})", group.trim()))
+    }
+
+    /// The name of the Swift computed property that this getter is generated as, or `None` if
+    /// it is generated as a Swift method.
+    ///
+    /// Only synchronous `#[swift_bridge(get(...))]` style methods that take no arguments besides
+    /// `&self` can become properties.
+    pub(crate) fn swift_getter_property_name(&self, config: &CodegenConfig) -> Option<String> {
+        let getter_properties = config.getter_properties.as_ref()?;
+
+        if self.get_field.is_none()
+            || !self.is_method()
+            || self.func.sig.inputs.len() != 1
+            || self.func.sig.asyncness.is_some()
+        {
+            return None;
+        }
+
+        if let Some(swift_name) = &self.swift_name_override {
+            return Some(swift_name.value());
+        }
+
+        let fn_name = self.func.sig.ident.to_string();
+        let property_name = getter_properties.property_name(&fn_name);
+        Some(match &config.camel_case_swift_names {
+            Some(camel_case) => camel_case.to_camel_case(property_name),
+            None => property_name.to_string(),
+        })
+    }
+
     pub fn link_name(&self) -> String {
         let host_type = self
             .associated_type
             .as_ref()
             .map(|h| {
                 match h {
-                    TypeDeclaration::Shared(_) => {
+                    TypeDeclaration::Shared(SharedTypeDeclaration::Struct(h)) => {
+                        format!("${}", h.name)
+                    }
+                    TypeDeclaration::Shared(SharedTypeDeclaration::Enum(_)) => {
                         //
                         todo!()
                     }
@@ -521,7 +794,10 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
             .as_ref()
             .map(|h| {
                 match h {
-                    TypeDeclaration::Shared(_) => {
+                    TypeDeclaration::Shared(SharedTypeDeclaration::Struct(h)) => {
+                        format!("{}_", h.name)
+                    }
+                    TypeDeclaration::Shared(SharedTypeDeclaration::Enum(_)) => {
                         //
                         todo!()
                     }
@@ -580,13 +856,10 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
         for (idx, fn_once) in self.args_filtered_to_boxed_fns(types) {
             let arg_name = self.arg_name_at_idx(idx).unwrap();
 
-            if fn_once.params.is_empty() && fn_once.ret.is_null() {
-                initializers += &format!(
-                "{maybe_space}let cb{idx} = __private__RustFnOnceCallbackNoArgsNoRet(ptr: {arg_name});"
-            );
-            } else {
-                initializers += &format!("{maybe_space}let cb{idx} = __private__RustFnOnceCallback{maybe_associated_ty}${fn_name}$param{idx}(ptr: {arg_name});");
-            }
+            let callback_class =
+                fn_once.swift_callback_class_name(maybe_associated_ty, fn_name, idx);
+            initializers +=
+                &format!("{maybe_space}let cb{idx} = {callback_class}(ptr: {arg_name});");
 
             maybe_space = " ";
         }
@@ -674,6 +947,59 @@ pub(crate) fn fn_arg_is_mutable_reference(fn_arg: &FnArg) -> bool {
     }
 }
 
+/// The `T` and `W` in a `(T, Vec<W>)` return type.
+pub(crate) fn value_and_warning_types(output: &ReturnType) -> Option<(&Type, &Type)> {
+    let tuple = match output {
+        ReturnType::Type(_, ty) => match ty.deref() {
+            Type::Tuple(tuple) if tuple.elems.len() == 2 => tuple,
+            _ => return None,
+        },
+        ReturnType::Default => return None,
+    };
+
+    let warnings = match &tuple.elems[1] {
+        Type::Path(path) => path.path.segments.last()?,
+        _ => return None,
+    };
+    if warnings.ident != "Vec" {
+        return None;
+    }
+    let warning = match &warnings.arguments {
+        syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            syn::GenericArgument::Type(ty) => ty,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some((&tuple.elems[0], warning))
+}
+
+/// Whether or not the lifetime, such as the `'a` in `&'a str`, appears anywhere within the type.
+fn type_contains_lifetime(ty: &Type, lifetime: &Lifetime) -> bool {
+    fn tokens_contain_lifetime(tokens: TokenStream, lifetime: &Lifetime) -> bool {
+        let mut tokens = tokens.into_iter().peekable();
+
+        while let Some(token) = tokens.next() {
+            let is_lifetime = match token {
+                TokenTree::Punct(punct) => {
+                    punct.as_char() == '\''
+                        && matches!(tokens.peek(), Some(TokenTree::Ident(ident)) if *ident == lifetime.ident)
+                }
+                TokenTree::Group(group) => tokens_contain_lifetime(group.stream(), lifetime),
+                _ => false,
+            };
+            if is_lifetime {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    tokens_contain_lifetime(ty.to_token_stream(), lifetime)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,9 @@
+use crate::bridge_module_attributes::FfiVisibility;
 use crate::bridged_type::BridgedType;
 use crate::parse::{HostLang, OpaqueCopy, TypeDeclaration, TypeDeclarations};
-use crate::parsed_extern_fn::{GetField, GetFieldDirect, GetFieldWith, ParsedExternFn};
+use crate::parsed_extern_fn::{
+    GetField, GetFieldCell, GetFieldDirect, GetFieldWith, ParsedExternFn,
+};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use std::collections::HashMap;
@@ -29,6 +32,7 @@ impl ParsedExternFn {
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
         custom_type_definitions: &mut HashMap<String, TokenStream>,
+        ffi_visibility: FfiVisibility,
     ) -> TokenStream {
         let link_name = self.link_name();
 
@@ -53,7 +57,7 @@ impl ParsedExternFn {
                 if !is_async {
                     quote! {
                         #[export_name = #link_name]
-                        pub extern "C" fn #prefixed_fn_name ( #params ) #ret {
+                        #ffi_visibility extern "C" fn #prefixed_fn_name ( #params ) #ret {
                             #call_fn
                         }
                     }
@@ -89,7 +93,7 @@ impl ParsedExternFn {
 
                     quote! {
                         #[export_name = #link_name]
-                        pub extern "C" fn #prefixed_fn_name (
+                        #ffi_visibility extern "C" fn #prefixed_fn_name (
                             callback_wrapper: *mut std::ffi::c_void,
                             callback: extern "C" fn(*mut std::ffi::c_void #maybe_return_ty) -> (),
                             #params
@@ -110,9 +114,23 @@ impl ParsedExternFn {
                 }
             }
             HostLang::Swift => {
-                quote! {
-                    #[link_name = #link_name]
-                    fn #prefixed_fn_name ( #params ) #ret;
+                if self.sig.asyncness.is_some() {
+                    let maybe_return_ty =
+                        self.maybe_async_rust_fn_return_ty(swift_bridge_path, types);
+
+                    quote! {
+                        #[link_name = #link_name]
+                        fn #prefixed_fn_name (
+                            callback_wrapper: *mut std::ffi::c_void,
+                            callback: extern "C" fn(*mut std::ffi::c_void #maybe_return_ty) -> (),
+                            #params
+                        );
+                    }
+                } else {
+                    quote! {
+                        #[link_name = #link_name]
+                        fn #prefixed_fn_name ( #params ) #ret;
+                    }
                 }
             }
         }
@@ -139,7 +157,7 @@ impl ParsedExternFn {
         };
 
         let mut call_fn = if self.is_method() {
-            self.call_method_tokens(&call_fn)
+            self.call_method_tokens(&call_fn, &call_args, swift_bridge_path)
         } else {
             self.call_function_tokens(&call_fn)
         };
@@ -147,7 +165,7 @@ impl ParsedExternFn {
         let return_ty = self.return_ty_built_in(types).unwrap();
 
         if self.return_into {
-            call_fn = return_ty.rust_expression_into(&call_fn);
+            call_fn = return_ty.rust_expression_into(&call_fn, types);
         }
 
         if let Some(return_with) = self.return_with.as_ref() {
@@ -171,11 +189,48 @@ impl ParsedExternFn {
     }
 
     /// Generate tokens for calling a method.
-    fn call_method_tokens(&self, call_fn: &TokenStream) -> TokenStream {
+    fn call_method_tokens(
+        &self,
+        call_fn: &TokenStream,
+        call_args: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        if self.associated_shared_struct().is_some() && !self.mutates_shared_struct() {
+            // Swift passes a copy of the struct's FFI representation, so nothing is written back.
+            return quote! {
+                this.into_rust_repr().#call_fn
+            };
+        }
+        if self.mutates_shared_struct() {
+            // Swift passes a pointer to the struct's FFI representation. We move it out, call the
+            // method and then write the mutated struct back so that Swift can read it.
+            return quote! {
+                {
+                    let mut this_val = unsafe { std::ptr::read(this) }.into_rust_repr();
+                    let val = this_val.#call_fn;
+                    unsafe { std::ptr::write(this, this_val.into_ffi_repr()) };
+                    val
+                }
+            };
+        }
+
         let this = if self.is_copy_method_on_opaque_type() {
             quote! {
                 this.into_rust_repr()
             }
+        } else if self.is_arc_mutex_method_on_opaque_type() {
+            // The mutex stays locked until the end of the method call.
+            if self.self_reference().is_some() {
+                let maybe_mut = self.self_mutability();
+
+                quote! {
+                    (& #maybe_mut * #swift_bridge_path::arc_mutex_support::lock(unsafe { & *this }))
+                }
+            } else {
+                quote! {
+                    ( unsafe { std::sync::Arc::from_raw(this) } )
+                }
+            }
         } else {
             if let Some(reference) = self.self_reference() {
                 let maybe_ref = reference.0;
@@ -191,6 +246,12 @@ impl ParsedExternFn {
             }
         };
 
+        if let Some(field_name) = self.set_cell_field.as_ref() {
+            return quote! {
+                #this . #field_name . set( #call_args )
+            };
+        }
+
         match &self.get_field {
             Some(GetField::Direct(get_direct)) => {
                 let GetFieldDirect {
@@ -213,6 +274,12 @@ impl ParsedExternFn {
                    super::#path ( #maybe_ref #maybe_mut #this . #field_name )
                 }
             }
+            Some(GetField::Cell(get_cell)) => {
+                let GetFieldCell { field_name } = get_cell;
+                quote! {
+                   #this . #field_name . get()
+                }
+            }
             None => {
                 quote! {
                         #this.#call_fn
@@ -251,6 +318,14 @@ impl ParsedExternFn {
         self.maybe_copy_descriptor().is_some()
     }
 
+    /// Whether or not this is a method on a type that is using `#[swift_bridge(arc_mutex)]`
+    pub(crate) fn is_arc_mutex_method_on_opaque_type(&self) -> bool {
+        match self.associated_type.as_ref() {
+            Some(TypeDeclaration::Opaque(ty)) => ty.attributes.arc_mutex,
+            _ => false,
+        }
+    }
+
     /// Describes the "..." in a `#[swift_bridge(Copy(...))]`
     pub(crate) fn maybe_copy_descriptor(&self) -> Option<OpaqueCopy> {
         match self.associated_type.as_ref()? {
@@ -336,6 +411,7 @@ mod tests {
                 &module.swift_bridge_path,
                 &module.types,
                 &mut HashMap::new(),
+                module.ffi_visibility,
             ),
             &expected_fn,
         );
@@ -1,5 +1,5 @@
 use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType};
-use crate::parse::{HostLang, TypeDeclaration, TypeDeclarations};
+use crate::parse::{HostLang, SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
 use crate::parsed_extern_fn::ParsedExternFn;
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
@@ -89,8 +89,17 @@ impl ParsedExternFn {
                         .get(&bridged_type.to_token_stream().to_string())
                         .unwrap()
                     {
-                        TypeDeclaration::Shared(_) => {
-                            todo!("Support methods on shared types.")
+                        TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                            let ffi_name =
+                                shared_struct.type_name_with_swift_bridge_prefix(swift_bridge_path);
+                            if self.mutates_shared_struct() {
+                                quote! { *mut #ffi_name }
+                            } else {
+                                quote! { #ffi_name }
+                            }
+                        }
+                        TypeDeclaration::Shared(SharedTypeDeclaration::Enum(_)) => {
+                            todo!("Support methods on shared enums.")
                         }
                         TypeDeclaration::Opaque(opaque) => opaque.ffi_repr_type_tokens(),
                     };
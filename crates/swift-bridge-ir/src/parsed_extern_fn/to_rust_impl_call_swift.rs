@@ -1,3 +1,4 @@
+use crate::bridge_module_attributes::FfiVisibility;
 use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType};
 use crate::parse::{SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
 use crate::parsed_extern_fn::ParsedExternFn;
@@ -59,17 +60,43 @@ impl ParsedExternFn {
         let call_args = self.to_call_rust_args(swift_bridge_path, types);
         let linked_fn_name = self.extern_swift_linked_fn_new();
 
+        if sig.asyncness.is_some() {
+            let inner =
+                self.await_swift_async_fn(&linked_fn_name, &call_args, swift_bridge_path, types);
+
+            return quote! {
+                pub async fn #fn_name(#params) #ret {
+                    #inner
+                }
+            };
+        }
+
         let mut inner = quote! {
             unsafe { #linked_fn_name(#call_args) }
         };
 
         if let Some(built_in) = BridgedType::new_with_return_type(&sig.output, types) {
-            inner = built_in.convert_ffi_expression_to_rust_type(
-                &inner,
-                sig.output.span(),
-                swift_bridge_path,
-                types,
-            );
+            if built_in.is_result() {
+                // A `Result`'s FFI representation gets inspected more than once, so we store it
+                // instead of calling the Swift function again each time.
+                let converted = built_in.convert_ffi_expression_to_rust_type(
+                    &quote! { val },
+                    sig.output.span(),
+                    swift_bridge_path,
+                    types,
+                );
+                inner = quote! {
+                    let val = #inner;
+                    #converted
+                };
+            } else {
+                inner = built_in.convert_ffi_expression_to_rust_type(
+                    &inner,
+                    sig.output.span(),
+                    swift_bridge_path,
+                    types,
+                );
+            }
         } else {
             todo!("Push to ParsedErrors")
         }
@@ -81,6 +108,61 @@ impl ParsedExternFn {
         }
     }
 
+    /// Call a Swift `async` function and await the value that it passes to its completion callback.
+    ///
+    /// The future is created inside of a block so that the raw `callback_wrapper` pointer isn't
+    /// held across the `.await`, which would make the returned future `!Send`.
+    fn await_swift_async_fn(
+        &self,
+        linked_fn_name: &Ident,
+        call_args: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let sig = &self.func.sig;
+        let built_in = BridgedType::new_with_return_type(&sig.output, types).unwrap();
+        let ffi_ret = built_in.to_ffi_compatible_rust_type(swift_bridge_path, types);
+
+        let (maybe_val_param, val) = if self
+            .maybe_async_rust_fn_return_ty(swift_bridge_path, types)
+            .is_some()
+        {
+            (quote! { , val: #ffi_ret }, quote! { val })
+        } else {
+            (quote! {}, quote! { () })
+        };
+
+        let awaited = if built_in.is_null() {
+            quote! { fut.await }
+        } else {
+            let converted = built_in.convert_ffi_expression_to_rust_type(
+                &quote! { val },
+                sig.output.span(),
+                swift_bridge_path,
+                types,
+            );
+            quote! {
+                let val = fut.await;
+                #converted
+            }
+        };
+
+        quote! {
+            extern "C" fn on_complete(callback_wrapper: *mut std::ffi::c_void #maybe_val_param) {
+                unsafe {
+                    swift_bridge::async_support::SwiftFuture::<#ffi_ret>::complete(callback_wrapper, #val)
+                }
+            }
+
+            let fut = {
+                let (fut, callback_wrapper) = swift_bridge::async_support::SwiftFuture::<#ffi_ret>::new();
+                unsafe { #linked_fn_name(callback_wrapper, on_complete, #call_args) };
+                fut
+            };
+            #awaited
+        }
+    }
+
     /// #\[export_name = "__swift_bridge__$SomeType$some_method$param1"]
     /// pub extern "C" fn SomeType_some_method_param1(boxed_fn: *mut dyn FnOnce(u8) -> (), arg0: u8) {
     ///     unsafe { Box::from_raw(boxed_fn) }(arg0)
@@ -93,13 +175,14 @@ impl ParsedExternFn {
         &self,
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
+        ffi_visibility: FfiVisibility,
     ) -> TokenStream {
         let sig = &self.func.sig;
         let fn_name = &sig.ident;
 
         let mut boxed_fn_support = vec![];
         for (idx, boxed_fn) in self.args_filtered_to_boxed_fns(types) {
-            if boxed_fn.does_not_have_params_or_return() {
+            if boxed_fn.uses_no_args_no_ret_fn_once_class() {
                 continue;
             }
 
@@ -144,9 +227,7 @@ impl ParsedExternFn {
             let arg_name = self.arg_name_tokens_at_idx(idx).unwrap();
             let arg_name = Ident::new(&format!("{}_{}", fn_name, arg_name), arg_name.span());
 
-            let call_boxed_fn = quote! {
-                unsafe { Box::from_raw(#arg_name)(#(#call_args),*) }
-            };
+            let call_boxed_fn = boxed_fn.call_ffi_boxed_fn(&arg_name, &call_args);
             let call_boxed_fn = boxed_fn.ret.convert_rust_expression_to_ffi_type(
                 &call_boxed_fn,
                 swift_bridge_path,
@@ -156,14 +237,14 @@ impl ParsedExternFn {
             );
             let call_boxed_fn = quote! {
                 #[export_name = #call_boxed_fn_link_name]
-                pub extern "C" fn #boxed_fn_name(#arg_name: #boxed_fn_ffi_repr #maybe_params) #maybe_ret {
+                #ffi_visibility extern "C" fn #boxed_fn_name(#arg_name: #boxed_fn_ffi_repr #maybe_params) #maybe_ret {
                     #call_boxed_fn
                 }
             };
 
             let free_boxed_fn = quote! {
                 #[export_name = #free_boxed_fn_link_name]
-                pub extern "C" fn #free_boxed_fn_name(#arg_name: #boxed_fn_ffi_repr) {
+                #ffi_visibility extern "C" fn #free_boxed_fn_name(#arg_name: #boxed_fn_ffi_repr) {
                     let _ = unsafe { Box::from_raw(#arg_name) };
                 }
             };
@@ -1,6 +1,6 @@
 use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType, TypePosition};
 use crate::parse::TypeDeclarations;
-use crate::parsed_extern_fn::ParsedExternFn;
+use crate::parsed_extern_fn::{value_and_warning_types, ParsedExternFn};
 use quote::{format_ident, ToTokens};
 use std::ops::Deref;
 use syn::{FnArg, Path, ReturnType, Type};
@@ -145,11 +145,44 @@ impl ParsedExternFn {
         args.join(", ")
     }
 
+    /// The Swift types of the `T` and `W` that a `#[swift_bridge(return_warnings)]` function
+    /// returns in its `(T, Vec<W>)`.
+    pub(crate) fn swift_value_and_warning_types(
+        &self,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> Option<(String, String)> {
+        if !self.return_warnings {
+            return None;
+        }
+
+        let (value, warning) = value_and_warning_types(&self.func.sig.output)?;
+        let to_swift_type = |ty: &Type| {
+            BridgedType::new_with_type(ty, types).map(|ty| {
+                ty.to_swift_type(
+                    TypePosition::FnReturn(self.host_lang),
+                    types,
+                    swift_bridge_path,
+                )
+            })
+        };
+
+        Some((to_swift_type(value)?, to_swift_type(warning)?))
+    }
+
     pub fn to_swift_return_type(
         &self,
         types: &TypeDeclarations,
         swift_bridge_path: &Path,
     ) -> String {
+        if self.copies_returned_str() {
+            return " -> String".to_string();
+        }
+        if let Some((value, warning)) = self.swift_value_and_warning_types(types, swift_bridge_path)
+        {
+            return format!(" -> ValueWithWarnings<{}, {}>", value, warning);
+        }
+
         match &self.func.sig.output {
             ReturnType::Default => "".to_string(),
             ReturnType::Type(_, ty) => {
@@ -160,7 +193,17 @@ impl ParsedExternFn {
                         }
                     }
 
-                    let maybe_throws = if built_in.is_result() { "throws " } else { "" };
+                    // Swift functions that return a `Result` are wrapped in a function that
+                    // catches the error and returns the `Result`'s FFI representation.
+                    let maybe_throws = if built_in.is_result() && self.host_lang.is_rust() {
+                        "throws "
+                    } else {
+                        ""
+                    };
+
+                    if self.return_data {
+                        return format!(" {}-> Data", maybe_throws);
+                    }
 
                     format!(
                         " {}-> {}",
@@ -178,14 +221,60 @@ impl ParsedExternFn {
         }
     }
 
+    // fn foo (&self, arg1: u8, #[swift_bridge(label = "bar")] arg2: u32)
+    //  becomes
+    //  - arg1, bar: arg2
+    //
+    /// The arguments for calling the generated Swift function from another Swift function that
+    /// has the same parameters.
+    pub fn to_swift_forwarding_call_args(&self) -> String {
+        self.to_swift_forwarding_call_args_mapped(|arg_name| arg_name.to_string())
+    }
+
+    /// The arguments for calling the generated Swift function from another Swift function,
+    /// where `map_arg` converts each of the calling function's argument names into the value that
+    /// gets passed along.
+    pub fn to_swift_forwarding_call_args_mapped(&self, map_arg: impl Fn(&str) -> String) -> String {
+        let mut args = vec![];
+
+        for arg in self.func.sig.inputs.iter() {
+            let pat_ty = match arg {
+                FnArg::Receiver(_) => continue,
+                FnArg::Typed(pat_ty) if pat_type_pat_is_self(pat_ty) => continue,
+                FnArg::Typed(pat_ty) => pat_ty,
+            };
+
+            let arg_name = pat_ty.pat.to_token_stream().to_string();
+            let arg_value = map_arg(&arg_name);
+            let arg = match self.argument_labels.get(&format_ident!("{}", arg_name)) {
+                Some(argument_label) if argument_label.value() != "_" => {
+                    format!("{}: {}", argument_label.value(), arg_value)
+                }
+                _ => arg_value,
+            };
+
+            args.push(arg);
+        }
+
+        args.join(", ")
+    }
+
     fn push_receiver_as_arg(&self, args: &mut Vec<String>, is_reference: bool) {
         let arg = if self.is_copy_method_on_opaque_type() {
             "self.bytes"
+        } else if self.mutates_shared_struct() {
+            // The mutating func passes its struct's FFI representation to Rust by pointer, so
+            // that Rust can write the mutated struct back into it.
+            "&ffiSelf"
+        } else if self.associated_shared_struct().is_some() {
+            "self.intoFfiRepr()"
         } else {
+            // Trap instead of using freed memory if the value was already consumed by a previous
+            // call to a method that takes `self` by value.
             if is_reference {
-                "ptr"
+                "unconsumedPtr"
             } else {
-                "{isOwned = false; return ptr;}()"
+                "{precondition(isOwned, \"Attempted to use a value that was already consumed.\"); isOwned = false; return ptr;}()"
             }
         };
         args.push(arg.to_string());
@@ -344,12 +433,12 @@ mod tests {
 
         assert_eq!(
             functions[0].to_swift_call_args(true, false, &module.types, &module.swift_bridge_path),
-            "{isOwned = false; return ptr;}()"
+            "{precondition(isOwned, \"Attempted to use a value that was already consumed.\"); isOwned = false; return ptr;}()"
         );
 
         assert_eq!(
             functions[1].to_swift_call_args(true, false, &module.types, &module.swift_bridge_path),
-            "{isOwned = false; return ptr;}()"
+            "{precondition(isOwned, \"Attempted to use a value that was already consumed.\"); isOwned = false; return ptr;}()"
         );
 
         assert_eq!(
@@ -0,0 +1,28 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=duplicate-swift-member-name.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SomeType;
+
+        #[swift_bridge(associated_to = SomeType)]
+        fn make(arg: u8) -> SomeType;
+        #[swift_bridge(swift_name = "make")]
+        fn make_from(&self, arg: u8) -> SomeType;
+    }
+}
+
+struct SomeType;
+
+impl SomeType {
+    fn make(_arg: u8) -> Self {
+        SomeType
+    }
+
+    fn make_from(&self, _arg: u8) -> Self {
+        SomeType
+    }
+}
+
+fn main() {}
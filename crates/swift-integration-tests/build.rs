@@ -11,8 +11,9 @@ fn main() {
         println!("cargo:rerun-if-changed={}", path.to_str().unwrap());
     }
 
-    swift_bridge_build::parse_bridges(bridges)
-        .write_all_concatenated(out_dir, env!("CARGO_PKG_NAME"));
+    let generated = swift_bridge_build::parse_bridges(bridges);
+    generated.write_rust_ffi_files(std::env::var("OUT_DIR").unwrap());
+    generated.write_all_concatenated(out_dir, env!("CARGO_PKG_NAME"));
 }
 
 fn read_files_recursive(dir: PathBuf, files: &mut Vec<PathBuf>) {
@@ -29,6 +29,11 @@ mod ffi {
         #[swift_bridg(init)]
         fn new() -> TestRustAsyncSelf;
         async fn reflect_u16(&self, arg: u16) -> u16;
+        async fn checked_divide_u16(
+            &self,
+            dividend: u16,
+            divisor: u16,
+        ) -> Result<u16, AsyncResultErrEnum>;
     }
 
     extern "Rust" {
@@ -71,6 +76,22 @@ mod ffi {
         async fn rust_async_func_return_result_null_and_transparent_enum(
             succeed: bool,
         ) -> Result<(), AsyncResultErrEnum>;
+        async fn rust_async_func_return_result_u32_and_transparent_enum(
+            succeed: bool,
+        ) -> Result<u32, AsyncResultErrEnum>;
+        async fn rust_async_func_return_result_u32_and_boxed_error(
+            succeed: bool,
+        ) -> Result<u32, Box<dyn std::error::Error + Send + Sync>>;
+    }
+
+    extern "Rust" {
+        async fn rust_async_awaits_swift_async_throwing_fn(
+            value: u32,
+        ) -> Result<u32, AsyncResultErrEnum>;
+    }
+
+    extern "Swift" {
+        async fn swift_async_halve_even_number(value: u32) -> Result<u32, AsyncResultErrEnum>;
     }
 }
 
@@ -98,6 +119,19 @@ impl TestRustAsyncSelf {
     async fn reflect_u16(&self, arg: u16) -> u16 {
         arg
     }
+
+    async fn checked_divide_u16(
+        &self,
+        dividend: u16,
+        divisor: u16,
+    ) -> Result<u16, ffi::AsyncResultErrEnum> {
+        dividend
+            .checked_div(divisor)
+            .ok_or(ffi::AsyncResultErrEnum::UnnamedFields(
+                "division by zero".to_string(),
+                dividend as i32,
+            ))
+    }
 }
 
 pub struct AsyncResultOpaqueRustType1(u32);
@@ -194,3 +228,28 @@ async fn rust_async_func_return_result_null_opaque_rust(
         Err(AsyncResultOpaqueRustType2(111))
     }
 }
+
+async fn rust_async_func_return_result_u32_and_transparent_enum(
+    succeed: bool,
+) -> Result<u32, ffi::AsyncResultErrEnum> {
+    if succeed {
+        Ok(123)
+    } else {
+        Err(ffi::AsyncResultErrEnum::NamedFields { value: 456 })
+    }
+}
+
+async fn rust_async_func_return_result_u32_and_boxed_error(
+    succeed: bool,
+) -> Result<u32, Box<dyn std::error::Error + Send + Sync>> {
+    let digits = if succeed { "123" } else { "12a" };
+
+    Ok(digits.parse::<u32>()?)
+}
+
+/// Verify that Rust can await a Swift `async throws` function.
+async fn rust_async_awaits_swift_async_throwing_fn(
+    value: u32,
+) -> Result<u32, ffi::AsyncResultErrEnum> {
+    ffi::swift_async_halve_even_number(value).await
+}
@@ -1,3 +1,6 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
 #[swift_bridge::bridge]
 mod ffi {
     extern "Swift" {
@@ -32,6 +35,16 @@ mod ffi {
             &self,
             callback: Box<dyn FnOnce(u16) -> u16>,
         ) -> u16;
+        fn method_with_fn_progress_callback(
+            &self,
+            steps: u8,
+            fail: bool,
+            progress: Box<dyn Fn(u8)>,
+        ) -> Result<u8, CallbackTestProcessError>;
+    }
+
+    enum CallbackTestProcessError {
+        Failed,
     }
 
     // TODO
@@ -136,4 +149,29 @@ fn test_callbacks_rust_calls_swift() {
     ffi::swift_func_takes_callback_with_result_arg(Box::new(|result| {
         assert_eq!(result.unwrap().val(), 555)
     }));
+
+    test_fn_progress_callback_with_result(&swift_callback_tester);
+}
+
+/// Verify that Swift can call a `Box<dyn Fn>` progress callback multiple times before returning
+/// a `Result`, and that the callback is freed on both the `Ok` and the `Err` path.
+fn test_fn_progress_callback_with_result(swift_callback_tester: &ffi::SwiftMethodCallbackTester) {
+    for fail in [false, true] {
+        let progress = Rc::new(RefCell::new(vec![]));
+
+        let progress_clone = progress.clone();
+        let result = swift_callback_tester.method_with_fn_progress_callback(
+            3,
+            fail,
+            Box::new(move |step| progress_clone.borrow_mut().push(step)),
+        );
+
+        if fail {
+            assert!(matches!(result, Err(ffi::CallbackTestProcessError::Failed)));
+        } else {
+            assert!(matches!(result, Ok(3)));
+        }
+        assert_eq!(*progress.borrow(), vec![1, 2, 3]);
+        assert_eq!(Rc::strong_count(&progress), 1);
+    }
 }
@@ -1,3 +1,10 @@
 mod already_declared;
+mod case_iterable;
+mod comparable;
+mod custom_string_convertible;
 mod derive;
+mod from_str;
+mod identifiable;
+mod objc;
+mod option_set;
 mod swift_name;
@@ -0,0 +1,21 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(case_iterable)]
+    enum CaseIterableWeekday {
+        Wednesday,
+        Monday,
+        Friday,
+        Tuesday,
+    }
+
+    extern "Rust" {
+        fn case_iterable_weekday_index(day: CaseIterableWeekday) -> u8;
+    }
+}
+
+use ffi::CaseIterableWeekday;
+
+/// The index of the variant in the enum's declaration.
+fn case_iterable_weekday_index(day: CaseIterableWeekday) -> u8 {
+    day as u8
+}
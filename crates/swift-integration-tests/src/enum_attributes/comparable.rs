@@ -0,0 +1,25 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(comparable)]
+    enum ComparablePriority {
+        Low = -1,
+        Medium = 5,
+        High = 10,
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    struct PrioritizedTask {
+        id: u32,
+        priority: ComparablePriority,
+    }
+
+    extern "Rust" {
+        fn comparable_priority_highest() -> ComparablePriority;
+    }
+}
+
+use ffi::ComparablePriority;
+
+fn comparable_priority_highest() -> ComparablePriority {
+    ComparablePriority::High
+}
@@ -0,0 +1,24 @@
+/// Verify that a `#[swift_bridge(custom_string_convertible)]` enum's Swift `description` lists its
+/// variant's fields.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/custom_string_convertible_attribute_codegen_tests.rs
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(custom_string_convertible)]
+    enum DescribedShape {
+        Circle { radius: f64 },
+        Rectangle(u32, u32),
+        Labeled(String),
+        Empty,
+    }
+
+    extern "Rust" {
+        fn described_shape_circle(radius: f64) -> DescribedShape;
+    }
+}
+
+use ffi::DescribedShape;
+
+fn described_shape_circle(radius: f64) -> DescribedShape {
+    DescribedShape::Circle { radius }
+}
@@ -4,4 +4,20 @@ mod ffi {
     enum DeriveDebugEnum {
         Variant,
     }
+
+    #[derive(PartialEq)]
+    enum DerivePartialEqEnum {
+        Number(i32),
+        Text(String),
+        Point { x: u8, y: u8 },
+        Empty,
+    }
+
+    extern "Rust" {
+        fn rust_make_derive_partial_eq_enum_text(text: &str) -> DerivePartialEqEnum;
+    }
+}
+
+fn rust_make_derive_partial_eq_enum_text(text: &str) -> ffi::DerivePartialEqEnum {
+    ffi::DerivePartialEqEnum::Text(text.to_string())
 }
@@ -0,0 +1,25 @@
+/// Verify that we can construct a `#[swift_bridge(from_str)]` enum from a Swift `String`.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/from_str_attribute_codegen_tests.rs
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(from_str)]
+    enum FromStrColor {
+        Red,
+        Green,
+    }
+}
+
+use ffi::FromStrColor;
+
+impl std::str::FromStr for FromStrColor {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "red" => Ok(FromStrColor::Red),
+            "green" => Ok(FromStrColor::Green),
+            _ => Err(()),
+        }
+    }
+}
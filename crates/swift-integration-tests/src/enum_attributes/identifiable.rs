@@ -0,0 +1,24 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(Identifiable)]
+    enum IdentifiableSortOrder {
+        Newest,
+        Oldest,
+        MostPopular,
+    }
+
+    extern "Rust" {
+        fn identifiable_sort_order_label(order: IdentifiableSortOrder) -> String;
+    }
+}
+
+use ffi::IdentifiableSortOrder;
+
+fn identifiable_sort_order_label(order: IdentifiableSortOrder) -> String {
+    match order {
+        IdentifiableSortOrder::Newest => "Newest first",
+        IdentifiableSortOrder::Oldest => "Oldest first",
+        IdentifiableSortOrder::MostPopular => "Most popular",
+    }
+    .to_string()
+}
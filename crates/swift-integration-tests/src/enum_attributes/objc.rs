@@ -0,0 +1,21 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(objc)]
+    enum ObjcDirection {
+        Up = 1,
+        Down = 2,
+    }
+
+    extern "Rust" {
+        fn objc_direction_reverse(direction: ObjcDirection) -> ObjcDirection;
+    }
+}
+
+use ffi::ObjcDirection;
+
+fn objc_direction_reverse(direction: ObjcDirection) -> ObjcDirection {
+    match direction {
+        ObjcDirection::Up => ObjcDirection::Down,
+        ObjcDirection::Down => ObjcDirection::Up,
+    }
+}
@@ -0,0 +1,19 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(option_set)]
+    enum OptionSetPermissions {
+        Read = 1,
+        Write = 2,
+        Execute = 4,
+    }
+
+    extern "Rust" {
+        fn option_set_read_and_write_raw_value() -> u32;
+    }
+}
+
+use ffi::OptionSetPermissions;
+
+fn option_set_read_and_write_raw_value() -> u32 {
+    OptionSetPermissions::Read as u32 | OptionSetPermissions::Write as u32
+}
@@ -13,6 +13,8 @@ mod ffi {
         fn len(&self) -> usize;
 
         fn as_slice(&self) -> &[u8];
+
+        fn into_parts(self) -> (usize, u8);
     }
 
     extern "Rust" {
@@ -54,6 +56,10 @@ impl ARustStack {
         self.stack.pop();
     }
 
+    fn into_parts(self) -> (usize, u8) {
+        (self.stack.len(), self.stack.last().copied().unwrap_or(0))
+    }
+
     fn as_ptr(&self) -> *const u8 {
         self.stack.as_ptr()
     }
@@ -0,0 +1,34 @@
+/// Verify that Swift can link against the functions of a bridge module whose generated
+/// `extern "C"` functions are `pub(crate)`.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/ffi_visibility_attribute_codegen_tests.rs
+#[swift_bridge::bridge]
+#[swift_bridge(ffi_visibility = "crate")]
+mod ffi {
+    extern "Rust" {
+        type CrateVisibleFfiCounter;
+
+        #[swift_bridge(init)]
+        fn new(count: u32) -> CrateVisibleFfiCounter;
+
+        fn count(&self) -> u32;
+
+        fn crate_visible_ffi_double(value: u32) -> u32;
+    }
+}
+
+pub struct CrateVisibleFfiCounter(u32);
+
+impl CrateVisibleFfiCounter {
+    fn new(count: u32) -> Self {
+        CrateVisibleFfiCounter(count)
+    }
+
+    fn count(&self) -> u32 {
+        self.0
+    }
+}
+
+fn crate_visible_ffi_double(value: u32) -> u32 {
+    value * 2
+}
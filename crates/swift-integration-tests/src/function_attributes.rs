@@ -1,8 +1,12 @@
 mod args_into;
+mod available;
 mod get;
+mod get_cell;
 mod get_with;
 mod identifiable;
 mod return_into;
 mod return_with;
 mod rust_name;
+mod shared;
+mod spi;
 mod swift_name;
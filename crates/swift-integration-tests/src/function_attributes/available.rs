@@ -0,0 +1,15 @@
+/// Verify that we can use `#[swift_bridge(available = "...")]` on a Rust function.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/function_attribute_codegen_tests.rs
+///   - available
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(available = "macOS 11.0, iOS 14.0")]
+        fn test_available_attribute_double(value: u32) -> u32;
+    }
+}
+
+fn test_available_attribute_double(value: u32) -> u32 {
+    value * 2
+}
@@ -0,0 +1,29 @@
+use std::cell::Cell;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SomeTypeGetCell;
+
+        #[swift_bridge(init)]
+        fn new() -> SomeTypeGetCell;
+
+        #[swift_bridge(get_cell(my_cell_i32))]
+        fn my_cell_i32(&self) -> i32;
+
+        #[swift_bridge(set_cell(my_cell_i32))]
+        fn set_my_cell_i32(&self, value: i32);
+    }
+}
+
+pub struct SomeTypeGetCell {
+    my_cell_i32: Cell<i32>,
+}
+
+impl SomeTypeGetCell {
+    fn new() -> SomeTypeGetCell {
+        SomeTypeGetCell {
+            my_cell_i32: Cell::new(5),
+        }
+    }
+}
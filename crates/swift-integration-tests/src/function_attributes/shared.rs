@@ -0,0 +1,35 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Verify that we can use `#[swift_bridge(shared)]` to expose a Rust singleton to Swift.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/function_attribute_codegen_tests.rs
+///   - shared_instance
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SharedInstanceCounter;
+
+        #[swift_bridge(associated_to = SharedInstanceCounter, shared)]
+        fn shared() -> &'static SharedInstanceCounter;
+
+        fn increment(&self) -> u32;
+    }
+}
+
+static SHARED_INSTANCE_COUNTER: SharedInstanceCounter = SharedInstanceCounter {
+    count: AtomicU32::new(0),
+};
+
+pub struct SharedInstanceCounter {
+    count: AtomicU32,
+}
+
+impl SharedInstanceCounter {
+    fn shared() -> &'static SharedInstanceCounter {
+        &SHARED_INSTANCE_COUNTER
+    }
+
+    fn increment(&self) -> u32 {
+        self.count.fetch_add(1, Ordering::SeqCst) + 1
+    }
+}
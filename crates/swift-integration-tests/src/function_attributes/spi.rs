@@ -0,0 +1,15 @@
+/// Verify that we can use `#[swift_bridge(spi = "...")]` on a Rust function.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/function_attribute_codegen_tests.rs
+///   - spi
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(spi = "SpiTests")]
+        fn test_spi_attribute_triple(value: u32) -> u32;
+    }
+}
+
+fn test_spi_attribute_triple(value: u32) -> u32 {
+    value * 3
+}
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_string_map_u32(map: HashMap<String, u32>) -> HashMap<String, u32>;
+        fn rust_string_map_get(map: HashMap<String, i64>, key: &str) -> Option<i64>;
+        fn rust_sum_string_map_values(map: HashMap<String, u64>) -> u64;
+        fn rust_make_string_map(len: u32) -> HashMap<String, u32>;
+    }
+
+    extern "Rust" {
+        type StringMapValue;
+
+        #[swift_bridge(init)]
+        fn new(name: &str) -> StringMapValue;
+        fn name(&self) -> String;
+
+        fn rust_reflect_string_map_opaque(
+            map: HashMap<String, StringMapValue>,
+        ) -> HashMap<String, StringMapValue>;
+        fn rust_make_string_map_opaque(len: u32) -> HashMap<String, StringMapValue>;
+    }
+
+    extern "Rust" {
+        type IntMapRecord;
+
+        #[swift_bridge(init)]
+        fn new(name: &str) -> IntMapRecord;
+        fn name(&self) -> String;
+
+        fn rust_reflect_int_map_u32(map: HashMap<u32, u32>) -> HashMap<u32, u32>;
+        fn rust_reflect_int_map_i64_f64(map: HashMap<i64, f64>) -> HashMap<i64, f64>;
+        fn rust_reflect_int_map_opaque(
+            map: HashMap<u32, IntMapRecord>,
+        ) -> HashMap<u32, IntMapRecord>;
+        fn rust_make_int_map_opaque(len: u32) -> HashMap<u32, IntMapRecord>;
+    }
+}
+
+fn rust_reflect_string_map_u32(map: HashMap<String, u32>) -> HashMap<String, u32> {
+    map
+}
+
+fn rust_string_map_get(map: HashMap<String, i64>, key: &str) -> Option<i64> {
+    map.get(key).copied()
+}
+
+fn rust_sum_string_map_values(map: HashMap<String, u64>) -> u64 {
+    map.values().sum()
+}
+
+/// Create a map of "key-{idx}" to `idx` for every index below `len`.
+fn rust_make_string_map(len: u32) -> HashMap<String, u32> {
+    (0..len).map(|idx| (format!("key-{}", idx), idx)).collect()
+}
+
+pub struct StringMapValue {
+    name: String,
+}
+
+impl StringMapValue {
+    fn new(name: &str) -> Self {
+        StringMapValue {
+            name: name.to_string(),
+        }
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+fn rust_reflect_string_map_opaque(
+    map: HashMap<String, StringMapValue>,
+) -> HashMap<String, StringMapValue> {
+    map
+}
+
+/// Create a map of "key-{idx}" to a `StringMapValue` named "value-{idx}" for every index below
+/// `len`.
+fn rust_make_string_map_opaque(len: u32) -> HashMap<String, StringMapValue> {
+    (0..len)
+        .map(|idx| {
+            let value = StringMapValue::new(&format!("value-{}", idx));
+            (format!("key-{}", idx), value)
+        })
+        .collect()
+}
+
+pub struct IntMapRecord {
+    name: String,
+}
+
+impl IntMapRecord {
+    fn new(name: &str) -> Self {
+        IntMapRecord {
+            name: name.to_string(),
+        }
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+fn rust_reflect_int_map_u32(map: HashMap<u32, u32>) -> HashMap<u32, u32> {
+    map
+}
+
+fn rust_reflect_int_map_i64_f64(map: HashMap<i64, f64>) -> HashMap<i64, f64> {
+    map
+}
+
+fn rust_reflect_int_map_opaque(map: HashMap<u32, IntMapRecord>) -> HashMap<u32, IntMapRecord> {
+    map
+}
+
+/// Create a map of `idx` to an `IntMapRecord` named "record-{idx}" for every index below `len`.
+fn rust_make_int_map_opaque(len: u32) -> HashMap<u32, IntMapRecord> {
+    (0..len)
+        .map(|idx| (idx, IntMapRecord::new(&format!("record-{}", idx))))
+        .collect()
+}
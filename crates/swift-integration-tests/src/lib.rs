@@ -4,11 +4,14 @@ mod import_opaque_swift_class;
 mod async_function;
 mod boxed_functions;
 mod conditional_compilation;
+mod ffi_visibility;
 mod generics;
+mod hash_map;
 mod option;
 mod pointer;
 mod primitive;
 mod result;
+mod rust_ffi_file;
 mod rust_function_uses_opaque_swift_type;
 mod shared_types;
 mod single_representation_type_elision;
@@ -1,4 +1,19 @@
 mod already_declared;
+mod arc_mutex;
+mod btree_map;
+mod callable;
+mod clone;
+mod compound_assignment;
+mod conforms_to;
 mod copy;
+mod counter;
+mod deref;
+mod dispatch_queue;
+mod doc_alias;
 mod equatable;
+mod error_chain;
 mod hashable;
+mod hashable_by_getters;
+mod index;
+mod main_actor;
+mod neg;
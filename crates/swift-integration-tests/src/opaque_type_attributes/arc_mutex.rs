@@ -0,0 +1,48 @@
+/// Verify that we can use `#[swift_bridge(arc_mutex)]` on an opaque Rust type.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_type_arc_mutex
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(arc_mutex)]
+        type ArcMutexCounter;
+
+        #[swift_bridge(init)]
+        fn new(start: u32) -> ArcMutexCounter;
+
+        fn increment(&mut self);
+        fn value(&self) -> u32;
+        fn add_from(&mut self, other: &ArcMutexCounter);
+    }
+
+    extern "Rust" {
+        fn arc_mutex_counter_strong_count(counter: ArcMutexCounter) -> usize;
+    }
+}
+
+use std::sync::{Arc, Mutex};
+
+pub struct ArcMutexCounter(u32);
+
+impl ArcMutexCounter {
+    fn new(start: u32) -> Arc<Mutex<Self>> {
+        Arc::new(Mutex::new(ArcMutexCounter(start)))
+    }
+
+    fn increment(&mut self) {
+        self.0 += 1;
+    }
+
+    fn value(&self) -> u32 {
+        self.0
+    }
+
+    fn add_from(&mut self, other: &ArcMutexCounter) {
+        self.0 += other.0;
+    }
+}
+
+fn arc_mutex_counter_strong_count(counter: Arc<Mutex<ArcMutexCounter>>) -> usize {
+    Arc::strong_count(&counter)
+}
@@ -0,0 +1,36 @@
+use std::collections::BTreeMap;
+
+/// Verify that we can use `#[swift_bridge(btree_map(K, V))]` on an opaque Rust type.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_type_btree_map
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(btree_map(u32, u32))]
+        type BTreeMapScores;
+
+        #[swift_bridge(init)]
+        fn new() -> BTreeMapScores;
+
+        fn insert(&mut self, key: u32, value: u32);
+    }
+}
+
+pub struct BTreeMapScores(BTreeMap<u32, u32>);
+
+impl BTreeMapScores {
+    fn new() -> Self {
+        BTreeMapScores(BTreeMap::new())
+    }
+
+    fn insert(&mut self, key: u32, value: u32) {
+        self.0.insert(key, value);
+    }
+}
+
+impl AsRef<BTreeMap<u32, u32>> for BTreeMapScores {
+    fn as_ref(&self) -> &BTreeMap<u32, u32> {
+        &self.0
+    }
+}
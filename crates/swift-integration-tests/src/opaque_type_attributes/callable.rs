@@ -0,0 +1,29 @@
+/// Verify that we can use `#[swift_bridge(callable)]` to call an opaque Rust type as a function
+/// from Swift.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_type_callable
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(callable)]
+        type CallableMultiplier;
+
+        #[swift_bridge(init)]
+        fn new(factor: i64) -> CallableMultiplier;
+
+        fn call(&self, value: i64) -> i64;
+    }
+}
+
+pub struct CallableMultiplier(Box<dyn Fn(i64) -> i64>);
+
+impl CallableMultiplier {
+    fn new(factor: i64) -> Self {
+        CallableMultiplier(Box::new(move |value| value * factor))
+    }
+
+    fn call(&self, value: i64) -> i64 {
+        (self.0)(value)
+    }
+}
@@ -0,0 +1,36 @@
+/// Verify that we can use `#[swift_bridge(Clone)]` on an opaque Rust type.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_clone_type
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Clone)]
+        type CloneNameList;
+
+        #[swift_bridge(init)]
+        fn new() -> CloneNameList;
+
+        fn push(&mut self, name: &str);
+        fn len(&self) -> usize;
+    }
+}
+
+#[derive(Clone)]
+pub struct CloneNameList {
+    names: Vec<String>,
+}
+
+impl CloneNameList {
+    fn new() -> Self {
+        CloneNameList { names: vec![] }
+    }
+
+    fn push(&mut self, name: &str) {
+        self.names.push(name.to_string());
+    }
+
+    fn len(&self) -> usize {
+        self.names.len()
+    }
+}
@@ -0,0 +1,41 @@
+/// Verify that we can use `#[swift_bridge(AddAssign, SubAssign)]` on an opaque Rust type.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_type_compound_assignment
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(AddAssign, SubAssign, Clone)]
+        type CompoundAssignCounter;
+
+        #[swift_bridge(init)]
+        fn new(count: i64) -> CompoundAssignCounter;
+
+        fn count(&self) -> i64;
+    }
+}
+
+#[derive(Clone)]
+pub struct CompoundAssignCounter(i64);
+
+impl CompoundAssignCounter {
+    fn new(count: i64) -> Self {
+        CompoundAssignCounter(count)
+    }
+
+    fn count(&self) -> i64 {
+        self.0
+    }
+}
+
+impl std::ops::AddAssign<&CompoundAssignCounter> for CompoundAssignCounter {
+    fn add_assign(&mut self, rhs: &CompoundAssignCounter) {
+        self.0 += rhs.0;
+    }
+}
+
+impl std::ops::SubAssign<&CompoundAssignCounter> for CompoundAssignCounter {
+    fn sub_assign(&mut self, rhs: &CompoundAssignCounter) {
+        self.0 -= rhs.0;
+    }
+}
@@ -0,0 +1,31 @@
+/// Verify that we can use `#[swift_bridge(conforms_to = "...")]` to make an opaque Rust type
+/// conform to Swift protocols that are implemented in Swift.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/conforms_to_attribute_codegen_tests.rs
+///   - conforms_to_opaque_rust_type
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(conforms_to = "ConformsToTestHasValue")]
+        type ConformsToTestType;
+
+        #[swift_bridge(init)]
+        fn new(value: u32) -> ConformsToTestType;
+
+        fn value(&self) -> u32;
+    }
+}
+
+pub struct ConformsToTestType {
+    value: u32,
+}
+
+impl ConformsToTestType {
+    fn new(value: u32) -> Self {
+        ConformsToTestType { value }
+    }
+
+    fn value(&self) -> u32 {
+        self.value
+    }
+}
@@ -0,0 +1,40 @@
+/// Verify that we can use `#[swift_bridge(counter(u32))]` on an opaque Rust type.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_type_counter
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(counter(u32))]
+        type PageVisitCounter;
+
+        #[swift_bridge(init)]
+        fn new() -> PageVisitCounter;
+    }
+}
+
+pub struct PageVisitCounter(u32);
+
+impl PageVisitCounter {
+    fn new() -> Self {
+        PageVisitCounter(0)
+    }
+}
+
+impl std::ops::AddAssign<u32> for PageVisitCounter {
+    fn add_assign(&mut self, rhs: u32) {
+        self.0 += rhs;
+    }
+}
+
+impl std::ops::SubAssign<u32> for PageVisitCounter {
+    fn sub_assign(&mut self, rhs: u32) {
+        self.0 -= rhs;
+    }
+}
+
+impl AsRef<u32> for PageVisitCounter {
+    fn as_ref(&self) -> &u32 {
+        &self.0
+    }
+}
@@ -0,0 +1,49 @@
+/// Verify that we can use `#[swift_bridge(deref = "...")]` on an opaque Rust type.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_type_deref
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type DerefInner;
+
+        fn inner_value(&self) -> u32;
+        fn add_to_inner_value(&self, amount: u32) -> u32;
+    }
+
+    extern "Rust" {
+        #[swift_bridge(deref = "DerefInner")]
+        type DerefWrapper;
+
+        #[swift_bridge(init)]
+        fn new(value: u32) -> DerefWrapper;
+    }
+}
+
+pub struct DerefInner(u32);
+
+impl DerefInner {
+    fn inner_value(&self) -> u32 {
+        self.0
+    }
+
+    fn add_to_inner_value(&self, amount: u32) -> u32 {
+        self.0 + amount
+    }
+}
+
+pub struct DerefWrapper(DerefInner);
+
+impl DerefWrapper {
+    fn new(value: u32) -> Self {
+        DerefWrapper(DerefInner(value))
+    }
+}
+
+impl std::ops::Deref for DerefWrapper {
+    type Target = DerefInner;
+
+    fn deref(&self) -> &DerefInner {
+        &self.0
+    }
+}
@@ -0,0 +1,29 @@
+/// Verify that we can use `#[swift_bridge(dispatch_queue = "...")]` on an opaque Rust type.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_type_dispatch_queue
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        // XCTest runs tests on the main thread, which uses the main dispatch queue.
+        #[swift_bridge(dispatch_queue = "com.apple.main-thread")]
+        type RustDispatchQueueType;
+
+        #[swift_bridge(init)]
+        fn new() -> RustDispatchQueueType;
+
+        fn value(&self) -> u8;
+    }
+}
+
+pub struct RustDispatchQueueType(u8);
+
+impl RustDispatchQueueType {
+    fn new() -> Self {
+        RustDispatchQueueType(9)
+    }
+
+    fn value(&self) -> u8 {
+        self.0
+    }
+}
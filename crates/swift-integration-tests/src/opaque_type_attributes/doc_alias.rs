@@ -0,0 +1,28 @@
+/// Verify that we can use `#[doc(alias = "...")]` on an opaque Rust type.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_type_doc_alias
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[doc(alias = "RustDocAliasTypeAlias")]
+        type RustDocAliasType;
+
+        #[swift_bridge(init)]
+        fn new() -> RustDocAliasType;
+
+        fn value(&self) -> u8;
+    }
+}
+
+pub struct RustDocAliasType(u8);
+
+impl RustDocAliasType {
+    fn new() -> Self {
+        RustDocAliasType(7)
+    }
+
+    fn value(&self) -> u8 {
+        self.0
+    }
+}
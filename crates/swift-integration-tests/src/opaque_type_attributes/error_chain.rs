@@ -0,0 +1,56 @@
+/// Verify that we can use `#[swift_bridge(error_chain)]` on an opaque Rust error type.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_type_error_chain
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(error_chain)]
+        type ErrorChainTestError;
+
+        fn rust_func_return_error_chain_error() -> Result<(), ErrorChainTestError>;
+    }
+}
+
+/// An error that is caused by a `ParseIntError`, which in turn is caused by nothing.
+#[derive(Debug)]
+pub struct ErrorChainTestError {
+    source: ConfigError,
+}
+
+#[derive(Debug)]
+struct ConfigError {
+    source: std::num::ParseIntError,
+}
+
+impl std::fmt::Display for ErrorChainTestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to start server")
+    }
+}
+
+impl std::error::Error for ErrorChainTestError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid port")
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+fn rust_func_return_error_chain_error() -> Result<(), ErrorChainTestError> {
+    let source = "not a port".parse::<u16>().unwrap_err();
+
+    Err(ErrorChainTestError {
+        source: ConfigError { source },
+    })
+}
@@ -0,0 +1,31 @@
+use Clone;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(HashableByGetters)]
+        type HashableByGettersType;
+
+        #[swift_bridge(init)]
+        fn new(id: u32, name: &str) -> HashableByGettersType;
+
+        #[swift_bridge(get(id))]
+        fn id(&self) -> u32;
+        #[swift_bridge(get_with(&name = Clone::clone))]
+        fn name(&self) -> String;
+    }
+}
+
+pub struct HashableByGettersType {
+    id: u32,
+    name: String,
+}
+
+impl HashableByGettersType {
+    fn new(id: u32, name: &str) -> Self {
+        HashableByGettersType {
+            id,
+            name: name.to_string(),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+/// Verify that we can use `#[swift_bridge(index(Idx, Output))]` on an opaque Rust type.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_type_index
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(index(usize, i32))]
+        type IndexSamples;
+
+        #[swift_bridge(init)]
+        fn new() -> IndexSamples;
+    }
+}
+
+pub struct IndexSamples(Vec<i32>);
+
+impl IndexSamples {
+    fn new() -> Self {
+        IndexSamples(vec![10, -20, 30])
+    }
+}
+
+impl std::ops::Index<usize> for IndexSamples {
+    type Output = i32;
+
+    fn index(&self, index: usize) -> &i32 {
+        &self.0[index]
+    }
+}
@@ -0,0 +1,33 @@
+/// Verify that we can use `#[swift_bridge(main_actor)]` on an opaque Rust type.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_type_main_actor
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(main_actor)]
+        type MainActorCounter;
+
+        #[swift_bridge(init)]
+        fn new() -> MainActorCounter;
+
+        fn count(&self) -> u32;
+        fn increment(&mut self);
+    }
+}
+
+pub struct MainActorCounter(u32);
+
+impl MainActorCounter {
+    fn new() -> Self {
+        MainActorCounter(0)
+    }
+
+    fn count(&self) -> u32 {
+        self.0
+    }
+
+    fn increment(&mut self) {
+        self.0 += 1;
+    }
+}
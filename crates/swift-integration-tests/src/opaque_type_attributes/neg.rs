@@ -0,0 +1,44 @@
+/// Verify that we can use `#[swift_bridge(Neg)]` on an opaque Rust type.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/opaque_rust_type_codegen_tests.rs
+///   - extern_rust_type_neg
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Neg)]
+        type NegVector2;
+
+        #[swift_bridge(init)]
+        fn new(x: f64, y: f64) -> NegVector2;
+
+        fn x(&self) -> f64;
+        fn y(&self) -> f64;
+    }
+}
+
+pub struct NegVector2 {
+    x: f64,
+    y: f64,
+}
+
+impl NegVector2 {
+    fn new(x: f64, y: f64) -> Self {
+        NegVector2 { x, y }
+    }
+
+    fn x(&self) -> f64 {
+        self.x
+    }
+
+    fn y(&self) -> f64 {
+        self.y
+    }
+}
+
+impl std::ops::Neg for &NegVector2 {
+    type Output = NegVector2;
+
+    fn neg(self) -> NegVector2 {
+        NegVector2::new(-self.x, -self.y)
+    }
+}
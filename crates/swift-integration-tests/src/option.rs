@@ -1,6 +1,7 @@
 //! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/option_codegen_tests.rs
 
 use ffi::OptTestOpaqueSwiftType;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 #[swift_bridge::bridge]
 mod ffi {
@@ -35,6 +36,13 @@ mod ffi {
         field: u8,
     }
 
+    #[swift_bridge(swift_repr = "struct")]
+    struct OptionBoxTreeNode {
+        value: u32,
+        left: Option<Box<OptionBoxTreeNode>>,
+        right: Option<Box<OptionBoxTreeNode>>,
+    }
+
     extern "Rust" {
         type OptTestOpaqueRustType;
         type OptTestOpaqueRefRustType;
@@ -42,10 +50,13 @@ mod ffi {
         #[swift_bridge(init)]
         fn new(field: u8) -> OptTestOpaqueRustType;
         fn field(self: &OptTestOpaqueRustType) -> u8;
+        fn set_field(self: &mut OptTestOpaqueRustType, field: u8);
 
         #[swift_bridge(associated_to = OptTestOpaqueRefRustType)]
         fn new(field: u8) -> OptTestOpaqueRefRustType;
         fn field_ref(self: &OptTestOpaqueRefRustType) -> Option<&OptTestOpaqueRustType>;
+        fn field_mut(self: &mut OptTestOpaqueRefRustType) -> Option<&mut OptTestOpaqueRustType>;
+        fn clear_field(self: &mut OptTestOpaqueRefRustType);
     }
 
     extern "Rust" {
@@ -82,11 +93,19 @@ mod ffi {
         fn rust_reflect_option_f64(arg: Option<f64>) -> Option<f64>;
         fn rust_reflect_option_bool(arg: Option<bool>) -> Option<bool>;
 
+        fn rust_reflect_option_duration(arg: Option<Duration>) -> Option<Duration>;
+        fn rust_reflect_option_system_time(arg: Option<SystemTime>) -> Option<SystemTime>;
+
+        fn rust_reflect_option_char(arg: Option<char>) -> Option<char>;
+
         fn rust_reflect_option_string(arg: Option<String>) -> Option<String>;
         fn rust_create_option_static_str() -> Option<&'static str>;
         fn rust_reflect_option_str(arg: Option<&str>) -> Option<&str>;
 
         fn rust_reflect_option_vector_rust_type(arg: Option<Vec<u16>>) -> Option<Vec<u16>>;
+        fn rust_reflect_option_vector_opaque_rust_type(
+            arg: Option<Vec<OptTestOpaqueRustType>>,
+        ) -> Option<Vec<OptTestOpaqueRustType>>;
 
         fn rust_reflect_option_opaque_rust_type(
             arg: Option<OptTestOpaqueRustType>,
@@ -123,6 +142,9 @@ mod ffi {
             arg: Option<OptionStruct>,
         ) -> Option<OptionStruct>;
 
+        fn rust_create_option_box_tree() -> OptionBoxTreeNode;
+        fn rust_sum_option_box_tree(tree: OptionBoxTreeNode) -> u32;
+
         fn test_rust_calls_swift_option_primitive();
     }
 
@@ -143,6 +165,11 @@ mod ffi {
         fn swift_reflect_option_f64(arg: Option<f64>) -> Option<f64>;
         fn swift_reflect_option_bool(arg: Option<bool>) -> Option<bool>;
 
+        fn swift_reflect_option_duration(arg: Option<Duration>) -> Option<Duration>;
+        fn swift_reflect_option_system_time(arg: Option<SystemTime>) -> Option<SystemTime>;
+
+        fn swift_reflect_option_char(arg: Option<char>) -> Option<char>;
+
         fn swift_reflect_option_string(arg: Option<String>) -> Option<String>;
         // TODO: Change to `swift_reflect_option_str` once we support Swift returning `-> &str`
         fn swift_arg_option_str(arg: Option<&str>) -> bool;
@@ -194,6 +221,25 @@ fn test_rust_calls_swift_option_primitive() {
     assert_eq!(ffi::swift_reflect_option_bool(Some(false)), Some(false));
     assert_eq!(ffi::swift_reflect_option_bool(None), None);
 
+    let duration = Duration::from_millis(1500);
+    assert_eq!(
+        ffi::swift_reflect_option_duration(Some(duration)),
+        Some(duration)
+    );
+    assert_eq!(ffi::swift_reflect_option_duration(None), None);
+
+    let time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    assert_eq!(
+        ffi::swift_reflect_option_system_time(Some(time)),
+        Some(time)
+    );
+    assert_eq!(ffi::swift_reflect_option_system_time(None), None);
+
+    assert_eq!(ffi::swift_reflect_option_char(Some('a')), Some('a'));
+    assert_eq!(ffi::swift_reflect_option_char(Some('🎉')), Some('🎉'));
+    assert_eq!(ffi::swift_reflect_option_char(Some('\0')), Some('\0'));
+    assert_eq!(ffi::swift_reflect_option_char(None), None);
+
     assert_eq!(ffi::swift_reflect_option_string(None), None);
     assert_eq!(
         ffi::swift_reflect_option_string(Some("hello".to_string())),
@@ -221,6 +267,10 @@ impl OptTestOpaqueRustType {
     fn field(&self) -> u8 {
         self.field
     }
+
+    fn set_field(&mut self, field: u8) {
+        self.field = field;
+    }
 }
 
 pub struct OptTestOpaqueRefRustType {
@@ -237,6 +287,14 @@ impl OptTestOpaqueRefRustType {
     fn field_ref(&self) -> Option<&OptTestOpaqueRustType> {
         self.field.as_ref()
     }
+
+    fn field_mut(&mut self) -> Option<&mut OptTestOpaqueRustType> {
+        self.field.as_mut()
+    }
+
+    fn clear_field(&mut self) {
+        self.field = None;
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -283,6 +341,18 @@ mod reflect_primitives {
     pub fn rust_reflect_option_bool(arg: Option<bool>) -> Option<bool> { arg }
 }
 
+fn rust_reflect_option_duration(arg: Option<Duration>) -> Option<Duration> {
+    arg
+}
+
+fn rust_reflect_option_system_time(arg: Option<SystemTime>) -> Option<SystemTime> {
+    arg
+}
+
+fn rust_reflect_option_char(arg: Option<char>) -> Option<char> {
+    arg
+}
+
 fn rust_reflect_option_string(arg: Option<String>) -> Option<String> {
     arg
 }
@@ -298,6 +368,12 @@ fn rust_reflect_option_vector_rust_type(arg: Option<Vec<u16>>) -> Option<Vec<u16
     arg
 }
 
+fn rust_reflect_option_vector_opaque_rust_type(
+    arg: Option<Vec<OptTestOpaqueRustType>>,
+) -> Option<Vec<OptTestOpaqueRustType>> {
+    arg
+}
+
 fn rust_reflect_option_opaque_rust_type(
     arg: Option<OptTestOpaqueRustType>,
 ) -> Option<OptTestOpaqueRustType> {
@@ -350,6 +426,34 @@ fn rust_reflect_option_struct_with_no_data(
     arg
 }
 
+/// Creates a tree where the root (1) has a left child (2) with its own left child (4),
+/// and a right child (3).
+fn rust_create_option_box_tree() -> ffi::OptionBoxTreeNode {
+    let leaf = |value| ffi::OptionBoxTreeNode {
+        value,
+        left: None,
+        right: None,
+    };
+
+    ffi::OptionBoxTreeNode {
+        value: 1,
+        left: Some(Box::new(ffi::OptionBoxTreeNode {
+            value: 2,
+            left: Some(Box::new(leaf(4))),
+            right: None,
+        })),
+        right: Some(Box::new(leaf(3))),
+    }
+}
+fn rust_sum_option_box_tree(tree: ffi::OptionBoxTreeNode) -> u32 {
+    tree.value
+        + tree.left.map(|l| rust_sum_option_box_tree(*l)).unwrap_or(0)
+        + tree
+            .right
+            .map(|r| rust_sum_option_box_tree(*r))
+            .unwrap_or(0)
+}
+
 #[derive(PartialEq)]
 struct FailableInitType;
 
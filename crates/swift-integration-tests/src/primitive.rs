@@ -1,3 +1,6 @@
+use std::num::Saturating;
+use std::time::Duration;
+
 #[swift_bridge::bridge]
 mod ffi {
     extern "Rust" {
@@ -14,6 +17,12 @@ mod ffi {
         fn rust_double_f32(arg: f32) -> f32;
         fn rust_double_f64(arg: f64) -> f64;
         fn rust_negate_bool(arg: bool) -> bool;
+
+        fn rust_double_saturating_u8(arg: Saturating<u8>) -> Saturating<u8>;
+
+        fn rust_add_durations(a: Duration, b: Duration) -> Duration;
+        fn rust_saturating_sub_durations(a: Duration, b: Duration) -> Duration;
+        fn rust_duration_is_shorter(a: Duration, b: Duration) -> bool;
     }
 
     extern "Swift" {
@@ -28,6 +37,8 @@ mod ffi {
         fn swift_double_f32(arg: f32) -> f32;
         fn swift_double_f64(arg: f64) -> f64;
         fn swift_negate_bool(arg: bool) -> bool;
+
+        fn swift_reflect_saturating_u8(arg: Saturating<u8>) -> Saturating<u8>;
     }
 }
 
@@ -44,6 +55,11 @@ fn test_rust_calls_swift_primitives() {
     assert_eq!(ffi::swift_double_f64(5.), 10.);
     assert_eq!(ffi::swift_negate_bool(true), false);
     assert_eq!(ffi::swift_negate_bool(false), true);
+
+    assert_eq!(
+        ffi::swift_reflect_saturating_u8(Saturating(5)) + Saturating(u8::MAX),
+        Saturating(u8::MAX)
+    );
 }
 
 fn rust_double_u8(arg: u8) -> u8 {
@@ -89,3 +105,19 @@ fn rust_double_f64(arg: f64) -> f64 {
 fn rust_negate_bool(arg: bool) -> bool {
     !arg
 }
+
+fn rust_double_saturating_u8(arg: Saturating<u8>) -> Saturating<u8> {
+    arg + arg
+}
+
+fn rust_add_durations(a: Duration, b: Duration) -> Duration {
+    a + b
+}
+
+fn rust_saturating_sub_durations(a: Duration, b: Duration) -> Duration {
+    a.saturating_sub(b)
+}
+
+fn rust_duration_is_shorter(a: Duration, b: Duration) -> bool {
+    a < b
+}
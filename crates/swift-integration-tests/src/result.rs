@@ -1,5 +1,7 @@
 //! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/result_codegen_tests.rs
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 #[swift_bridge::bridge]
 mod ffi {
     struct UnitStruct;
@@ -84,11 +86,160 @@ mod ffi {
         ) -> Result<Vec<ResultTestOpaqueRustType>, ResultTestOpaqueRustType>;
     }
 
+    enum ResultTestQueryError {
+        NotFound,
+        InvalidLimit(u32),
+    }
+    extern "Rust" {
+        fn rust_func_return_result_of_vec_opaque_transparent_enum(
+            limit: u32,
+        ) -> Result<Vec<ResultTestOpaqueRustType>, ResultTestQueryError>;
+    }
+
+    #[swift_bridge(error_domain = "com.swift-bridge.result-tests")]
+    enum ResultTestDomainError {
+        NotFound = 404,
+        Timeout,
+    }
+    extern "Rust" {
+        fn rust_func_return_result_with_error_domain(
+            code: u16,
+        ) -> Result<(), ResultTestDomainError>;
+    }
+
     extern "Rust" {
         fn rust_func_return_result_tuple_transparent_enum(
             succeed: bool,
         ) -> Result<(i32, ResultTestOpaqueRustType, String), ResultTransparentEnum>;
     }
+
+    extern "Rust" {
+        fn rust_func_return_result_unit_unit(succeed: bool) -> Result<(), ()>;
+        fn rust_func_takes_result_unit_unit(arg: Result<(), ()>) -> bool;
+    }
+
+    extern "Rust" {
+        type ResultTestSession;
+        type ResultTestSessionError;
+
+        fn rust_func_return_result_session(
+            succeed: bool,
+        ) -> Result<ResultTestSession, ResultTestSessionError>;
+        fn id(self: &ResultTestSession) -> u32;
+        fn code(self: &ResultTestSessionError) -> u32;
+
+        fn result_test_session_live_count() -> usize;
+    }
+
+    #[derive(Clone, PartialEq)]
+    enum ResultTestValidationError {
+        Empty,
+        TooLong(u32),
+        ContainsWhitespace,
+    }
+
+    extern "Rust" {
+        fn rust_func_validate_username(name: &str) -> Result<(), Vec<ResultTestValidationError>>;
+    }
+
+    extern "Rust" {
+        type ResultTestLazyError;
+
+        fn rust_func_return_result_lazy_error(succeed: bool) -> Result<u32, ResultTestLazyError>;
+        fn result_test_lazy_error_constructed_count() -> usize;
+    }
+
+    enum ResultTestIoError {
+        Eof,
+    }
+
+    extern "Rust" {
+        type ResultTestReader;
+
+        #[swift_bridge(init)]
+        fn new() -> ResultTestReader;
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, ResultTestIoError>;
+    }
+
+    enum ResultTestParseError {
+        InvalidNumber,
+        Overflow,
+    }
+
+    extern "Rust" {
+        fn rust_func_add_parsed_numbers(
+            first: &str,
+            second: &str,
+        ) -> Result<u8, ResultTestParseError>;
+
+        #[swift_bridge(return_into)]
+        fn rust_func_parse_u8_into_error(number: &str) -> Result<u8, ResultTestParseError>;
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    struct ResultTestSyntaxError {
+        token: String,
+        position: u32,
+    }
+
+    extern "Rust" {
+        fn rust_func_parse_keyword(input: &str) -> Result<u32, ResultTestSyntaxError<'_>>;
+    }
+
+    enum ResultTestDbError {
+        NotConnected,
+    }
+
+    extern "Rust" {
+        type ResultTestUser;
+
+        fn id(&self) -> u32;
+
+        fn rust_func_find_user(
+            connected: bool,
+            id: u32,
+        ) -> Result<Option<ResultTestUser>, ResultTestDbError>;
+    }
+
+    enum ResultTestSwiftError {
+        Odd(u32),
+        Zero,
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_throwing_functions();
+    }
+
+    extern "Swift" {
+        fn swift_func_halve_even_number(value: u32) -> Result<u32, ResultTestSwiftError>;
+        fn swift_func_check_non_zero(value: u32) -> Result<(), ResultTestSwiftError>;
+        fn swift_func_check_fits_in_u8(value: u32) -> Result<(), ResultTestParseError>;
+    }
+
+    extern "Rust" {
+        type ResultTestItemStream;
+        type ResultTestStreamItem;
+
+        #[swift_bridge(init)]
+        fn new(ids: Vec<u32>) -> ResultTestItemStream;
+        fn next(self: &mut ResultTestItemStream) -> Option<ResultTestStreamItem>;
+
+        fn id(self: &ResultTestStreamItem) -> u32;
+        fn result(self: &ResultTestStreamItem) -> Result<String, String>;
+    }
+
+    extern "Rust" {
+        type ResultTestRetryingClient;
+
+        #[swift_bridge(init)]
+        fn new(failures_before_success: u32) -> ResultTestRetryingClient;
+        fn call(self: &ResultTestRetryingClient, attempts: &mut u32) -> Result<u32, String>;
+    }
+
+    extern "Rust" {
+        #[swift_bridge(return_data)]
+        fn rust_func_return_result_of_data(succeed: bool) -> Result<Vec<u8>, ResultTestIoError>;
+    }
 }
 
 fn rust_func_takes_result_string(arg: Result<String, String>) {
@@ -238,6 +389,24 @@ fn rust_func_return_result_of_vec_opaque(
     ])
 }
 
+fn rust_func_return_result_of_vec_opaque_transparent_enum(
+    limit: u32,
+) -> Result<Vec<ResultTestOpaqueRustType>, ffi::ResultTestQueryError> {
+    match limit {
+        0 => Err(ffi::ResultTestQueryError::NotFound),
+        limit if limit > 100 => Err(ffi::ResultTestQueryError::InvalidLimit(limit)),
+        limit => Ok((0..limit).map(ResultTestOpaqueRustType::new).collect()),
+    }
+}
+
+fn rust_func_return_result_with_error_domain(code: u16) -> Result<(), ffi::ResultTestDomainError> {
+    match code {
+        404 => Err(ffi::ResultTestDomainError::NotFound),
+        405 => Err(ffi::ResultTestDomainError::Timeout),
+        _ => Ok(()),
+    }
+}
+
 fn rust_func_return_result_tuple_transparent_enum(
     succeed: bool,
 ) -> Result<(i32, ResultTestOpaqueRustType, String), ffi::ResultTransparentEnum> {
@@ -247,3 +416,324 @@ fn rust_func_return_result_tuple_transparent_enum(
         Err(ffi::ResultTransparentEnum::NamedField { data: -123 })
     }
 }
+
+fn rust_func_return_result_unit_unit(succeed: bool) -> Result<(), ()> {
+    if succeed {
+        Ok(())
+    } else {
+        Err(())
+    }
+}
+
+fn rust_func_takes_result_unit_unit(arg: Result<(), ()>) -> bool {
+    arg.is_ok()
+}
+
+pub struct ResultTestUser {
+    id: u32,
+}
+
+impl ResultTestUser {
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+/// Look up a user that may not exist, failing if there is no database connection.
+fn rust_func_find_user(
+    connected: bool,
+    id: u32,
+) -> Result<Option<ResultTestUser>, ffi::ResultTestDbError> {
+    if !connected {
+        return Err(ffi::ResultTestDbError::NotConnected);
+    }
+
+    if id <= 3 {
+        Ok(Some(ResultTestUser { id }))
+    } else {
+        Ok(None)
+    }
+}
+
+pub struct ResultTestReader {
+    data: Vec<u8>,
+    position: usize,
+}
+
+impl ResultTestReader {
+    fn new() -> Self {
+        ResultTestReader {
+            data: vec![1, 2, 3, 4, 5],
+            position: 0,
+        }
+    }
+
+    /// Fill the buffer with the next unread bytes, returning the number of bytes that were read.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, ffi::ResultTestIoError> {
+        let remaining = &self.data[self.position..];
+        if remaining.is_empty() {
+            return Err(ffi::ResultTestIoError::Eof);
+        }
+
+        let count = remaining.len().min(buf.len());
+        buf[..count].copy_from_slice(&remaining[..count]);
+        self.position += count;
+
+        Ok(count)
+    }
+}
+
+impl From<std::num::ParseIntError> for ffi::ResultTestParseError {
+    fn from(_: std::num::ParseIntError) -> Self {
+        ffi::ResultTestParseError::InvalidNumber
+    }
+}
+
+fn parse_number(number: &str) -> Result<u8, ffi::ResultTestParseError> {
+    Ok(number.parse::<u8>()?)
+}
+
+/// Returns the `ParseIntError` itself, which `#[swift_bridge(return_into)]` converts into a
+/// `ResultTestParseError`.
+fn rust_func_parse_u8_into_error(number: &str) -> Result<u8, std::num::ParseIntError> {
+    number.parse::<u8>()
+}
+
+/// Composes several fallible calls using `?`, so that an error from any of them is returned to
+/// Swift.
+fn rust_func_add_parsed_numbers(
+    first: &str,
+    second: &str,
+) -> Result<u8, ffi::ResultTestParseError> {
+    let first = parse_number(first)?;
+    let second = parse_number(second)?;
+
+    first
+        .checked_add(second)
+        .ok_or(ffi::ResultTestParseError::Overflow)
+}
+
+/// A syntax error that borrows the unexpected token from the parsed input.
+pub struct BorrowedSyntaxError<'a> {
+    token: &'a str,
+    position: usize,
+}
+
+impl BorrowedSyntaxError<'_> {
+    fn into_owned(self) -> ffi::ResultTestSyntaxError {
+        ffi::ResultTestSyntaxError {
+            token: self.token.to_string(),
+            position: self.position as u32,
+        }
+    }
+}
+
+/// Parses a keyword into its numeric value. The error borrows from `input`, so it gets copied
+/// into a `ResultTestSyntaxError` before it is returned to Swift.
+fn rust_func_parse_keyword(input: &str) -> Result<u32, BorrowedSyntaxError<'_>> {
+    let trimmed = input.trim_start();
+    let position = input.len() - trimmed.len();
+
+    match trimmed {
+        "one" => Ok(1),
+        "two" => Ok(2),
+        token => Err(BorrowedSyntaxError { token, position }),
+    }
+}
+
+/// The number of `ResultTestSession`s and `ResultTestSessionError`s that have not been dropped,
+/// used to confirm that Swift frees both the `Ok` and the `Err` values.
+static SESSION_LIVE_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+fn result_test_session_live_count() -> usize {
+    SESSION_LIVE_COUNT.load(Ordering::SeqCst)
+}
+
+pub struct ResultTestSession {
+    id: u32,
+}
+
+impl ResultTestSession {
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl Drop for ResultTestSession {
+    fn drop(&mut self) {
+        SESSION_LIVE_COUNT.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+pub struct ResultTestSessionError {
+    code: u32,
+}
+
+impl ResultTestSessionError {
+    fn code(&self) -> u32 {
+        self.code
+    }
+}
+
+impl Drop for ResultTestSessionError {
+    fn drop(&mut self) {
+        SESSION_LIVE_COUNT.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn rust_func_return_result_session(
+    succeed: bool,
+) -> Result<ResultTestSession, ResultTestSessionError> {
+    SESSION_LIVE_COUNT.fetch_add(1, Ordering::SeqCst);
+
+    if succeed {
+        Ok(ResultTestSession { id: 7 })
+    } else {
+        Err(ResultTestSessionError { code: 500 })
+    }
+}
+
+/// Returns every rule that the username breaks, instead of only the first one.
+fn rust_func_validate_username(name: &str) -> Result<(), Vec<ffi::ResultTestValidationError>> {
+    let mut errors = vec![];
+
+    if name.is_empty() {
+        errors.push(ffi::ResultTestValidationError::Empty);
+    }
+    if name.len() > 8 {
+        errors.push(ffi::ResultTestValidationError::TooLong(name.len() as u32));
+    }
+    if name.contains(char::is_whitespace) {
+        errors.push(ffi::ResultTestValidationError::ContainsWhitespace);
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// The number of `ResultTestLazyError`s that have been constructed, used to confirm that
+/// returning `Ok` never constructs the error type.
+static LAZY_ERROR_CONSTRUCTED_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+fn result_test_lazy_error_constructed_count() -> usize {
+    LAZY_ERROR_CONSTRUCTED_COUNT.load(Ordering::SeqCst)
+}
+
+pub struct ResultTestLazyError;
+
+impl ResultTestLazyError {
+    fn new() -> Self {
+        LAZY_ERROR_CONSTRUCTED_COUNT.fetch_add(1, Ordering::SeqCst);
+        ResultTestLazyError
+    }
+}
+
+fn rust_func_return_result_lazy_error(succeed: bool) -> Result<u32, ResultTestLazyError> {
+    if succeed {
+        Ok(123)
+    } else {
+        Err(ResultTestLazyError::new())
+    }
+}
+
+/// Verify that Rust receives an `Err` when a Swift function throws.
+fn test_rust_calls_swift_throwing_functions() {
+    assert_eq!(ffi::swift_func_halve_even_number(10).ok(), Some(5));
+    match ffi::swift_func_halve_even_number(7) {
+        Err(ffi::ResultTestSwiftError::Odd(7)) => {}
+        _ => panic!(),
+    };
+
+    assert!(ffi::swift_func_check_non_zero(1).is_ok());
+    match ffi::swift_func_check_non_zero(0) {
+        Err(ffi::ResultTestSwiftError::Zero) => {}
+        _ => panic!(),
+    };
+
+    assert!(ffi::swift_func_check_fits_in_u8(255).is_ok());
+    match ffi::swift_func_check_fits_in_u8(256) {
+        Err(ffi::ResultTestParseError::Overflow) => {}
+        _ => panic!(),
+    };
+}
+
+/// Yields one item per id, where each item carries its own `Result`. Odd ids fail, but the stream
+/// keeps going so that Swift can handle each error without ending the iteration.
+pub struct ResultTestItemStream {
+    ids: std::vec::IntoIter<u32>,
+}
+
+impl ResultTestItemStream {
+    fn new(ids: Vec<u32>) -> Self {
+        ResultTestItemStream {
+            ids: ids.into_iter(),
+        }
+    }
+
+    fn next(&mut self) -> Option<ResultTestStreamItem> {
+        let id = self.ids.next()?;
+        let result = if id % 2 == 0 {
+            Ok(format!("item {}", id))
+        } else {
+            Err(format!("item {} failed", id))
+        };
+
+        Some(ResultTestStreamItem { id, result })
+    }
+}
+
+pub struct ResultTestStreamItem {
+    id: u32,
+    result: Result<String, String>,
+}
+
+impl ResultTestStreamItem {
+    fn id(&self) -> u32 {
+        self.id
+    }
+
+    fn result(&self) -> Result<String, String> {
+        self.result.clone()
+    }
+}
+
+/// Fails `failures_before_success` times before succeeding, giving up after
+/// `RETRYING_CLIENT_MAX_ATTEMPTS` attempts.
+pub struct ResultTestRetryingClient {
+    failures_before_success: u32,
+}
+
+const RETRYING_CLIENT_MAX_ATTEMPTS: u32 = 3;
+
+impl ResultTestRetryingClient {
+    fn new(failures_before_success: u32) -> Self {
+        ResultTestRetryingClient {
+            failures_before_success,
+        }
+    }
+
+    /// Writes the number of attempts that were made into `attempts`.
+    fn call(&self, attempts: &mut u32) -> Result<u32, String> {
+        *attempts = 0;
+
+        while *attempts < RETRYING_CLIENT_MAX_ATTEMPTS {
+            *attempts += 1;
+            if *attempts > self.failures_before_success {
+                return Ok(200);
+            }
+        }
+
+        Err(format!("Gave up after {} attempts", attempts))
+    }
+}
+
+fn rust_func_return_result_of_data(succeed: bool) -> Result<Vec<u8>, ffi::ResultTestIoError> {
+    if succeed {
+        Ok(vec![1, 2, 3, 255])
+    } else {
+        Err(ffi::ResultTestIoError::Eof)
+    }
+}
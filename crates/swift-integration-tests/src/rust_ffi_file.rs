@@ -0,0 +1,35 @@
+/// Verify that Swift can call the functions of a bridge module whose generated `extern "C"`
+/// functions are written to a separate file by the build script.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/rust_ffi_file_attribute_codegen_tests.rs
+#[swift_bridge::bridge]
+#[swift_bridge(rust_ffi_file = "rust_ffi_file_test_ffi.rs")]
+mod ffi {
+    extern "Rust" {
+        type RustFfiFileCounter;
+
+        #[swift_bridge(init)]
+        fn new(count: u32) -> RustFfiFileCounter;
+
+        fn increment(&mut self) -> u32;
+
+        fn rust_ffi_file_triple(value: u32) -> u32;
+    }
+}
+
+pub struct RustFfiFileCounter(u32);
+
+impl RustFfiFileCounter {
+    fn new(count: u32) -> Self {
+        RustFfiFileCounter(count)
+    }
+
+    fn increment(&mut self) -> u32 {
+        self.0 += 1;
+        self.0
+    }
+}
+
+fn rust_ffi_file_triple(value: u32) -> u32 {
+    value * 3
+}
@@ -66,6 +66,32 @@ mod ffi {
             arg: EnumWithGenericOpaqueRust,
         ) -> EnumWithGenericOpaqueRust;
     }
+
+    enum RecursiveExpr {
+        Number(i64),
+        Negate(Box<RecursiveExpr>),
+        Add {
+            left: Box<RecursiveExpr>,
+            right: Box<RecursiveExpr>,
+        },
+    }
+
+    extern "Rust" {
+        fn reflect_recursive_expr(arg: RecursiveExpr) -> RecursiveExpr;
+        fn evaluate_recursive_expr(arg: RecursiveExpr) -> i64;
+    }
+
+    enum EnumWithHybridRawValues {
+        Idle = 10,
+        Running(u32),
+        Done,
+    }
+
+    extern "Rust" {
+        fn reflect_enum_with_hybrid_raw_values(
+            arg: EnumWithHybridRawValues,
+        ) -> EnumWithHybridRawValues;
+    }
 }
 
 fn reflect_enum_with_no_data(arg: ffi::EnumWithNoData) -> ffi::EnumWithNoData {
@@ -107,3 +133,23 @@ fn reflect_enum_with_generic_opaque_type(
 ) -> ffi::EnumWithGenericOpaqueRust {
     arg
 }
+
+fn reflect_recursive_expr(arg: ffi::RecursiveExpr) -> ffi::RecursiveExpr {
+    arg
+}
+
+fn evaluate_recursive_expr(arg: ffi::RecursiveExpr) -> i64 {
+    match arg {
+        ffi::RecursiveExpr::Number(number) => number,
+        ffi::RecursiveExpr::Negate(expr) => -evaluate_recursive_expr(*expr),
+        ffi::RecursiveExpr::Add { left, right } => {
+            evaluate_recursive_expr(*left) + evaluate_recursive_expr(*right)
+        }
+    }
+}
+
+fn reflect_enum_with_hybrid_raw_values(
+    arg: ffi::EnumWithHybridRawValues,
+) -> ffi::EnumWithHybridRawValues {
+    arg
+}
@@ -22,6 +22,67 @@ mod ffi {
         field: String,
     }
 
+    #[swift_bridge(swift_repr = "struct")]
+    struct StructReprStructWithOptionalStringField {
+        id: u8,
+        name: String,
+        nickname: Option<String>,
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    struct StructReprStructWithPhantomDataField {
+        value: u32,
+        unit: std::marker::PhantomData<super::PhantomDataUnitMarker>,
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    struct StructReprStructWithMutatingMethods {
+        count: u32,
+        label: String,
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    struct StructReprStructBudget {
+        remaining: u32,
+        name: String,
+    }
+
+    // Declared before `StructReprStructPoint` to verify that the nested struct gets declared
+    // first in the C header.
+    #[swift_bridge(swift_repr = "struct")]
+    struct StructReprStructLine {
+        start: StructReprStructPoint,
+        end: StructReprStructPoint,
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    struct StructReprStructPoint {
+        x: f64,
+        y: f64,
+    }
+
+    extern "Rust" {
+        fn swift_calls_rust_make_line(length: f64) -> StructReprStructLine;
+        fn swift_calls_rust_translate_line(
+            line: StructReprStructLine,
+            dx: f64,
+            dy: f64,
+        ) -> StructReprStructLine;
+    }
+
+    extern "Rust" {
+        fn increment(self: &mut StructReprStructWithMutatingMethods, by: u32) -> u32;
+        fn append_to_label(self: &mut StructReprStructWithMutatingMethods, suffix: &str);
+    }
+
+    extern "Rust" {
+        fn spend(
+            self: StructReprStructBudget,
+            amount: u32,
+        ) -> Result<StructReprStructBudget, String>;
+        fn remaining_after(self: &StructReprStructBudget, amount: u32) -> Result<u32, String>;
+    }
+
     extern "Rust" {
         fn test_rust_calls_swift();
 
@@ -34,6 +95,14 @@ mod ffi {
         fn swift_calls_rust_tuple_struct(
             arg: StructReprStructTupleStruct,
         ) -> StructReprStructTupleStruct;
+
+        fn swift_calls_rust_struct_repr_struct_optional_string_field(
+            arg: StructReprStructWithOptionalStringField,
+        ) -> StructReprStructWithOptionalStringField;
+
+        fn swift_calls_rust_struct_repr_struct_phantom_data_field(
+            arg: StructReprStructWithPhantomDataField,
+        ) -> StructReprStructWithPhantomDataField;
     }
 
     extern "Swift" {
@@ -64,12 +133,89 @@ fn swift_calls_rust_struct_repr_struct_one_primitive_field(
     arg
 }
 
+impl ffi::StructReprStructWithMutatingMethods {
+    /// Add to the count and return the new count.
+    fn increment(&mut self, by: u32) -> u32 {
+        self.count += by;
+        self.count
+    }
+
+    fn append_to_label(&mut self, suffix: &str) {
+        self.label.push_str(suffix);
+    }
+}
+
+impl ffi::StructReprStructBudget {
+    /// Return the budget that is left after spending the amount, or describe the shortfall if the
+    /// amount is more than what remains.
+    fn spend(mut self, amount: u32) -> Result<Self, String> {
+        if amount > self.remaining {
+            return Err(format!("short by {}", amount - self.remaining));
+        }
+
+        self.remaining -= amount;
+        Ok(self)
+    }
+
+    fn remaining_after(&self, amount: u32) -> Result<u32, String> {
+        self.remaining
+            .checked_sub(amount)
+            .ok_or_else(|| format!("{} cannot cover {}", self.name, amount))
+    }
+}
+
 fn swift_calls_rust_tuple_struct(
     arg: ffi::StructReprStructTupleStruct,
 ) -> ffi::StructReprStructTupleStruct {
     arg
 }
 
+/// Returns the struct with its `id` incremented, so that Swift can verify that the struct made a
+/// full round trip through Rust.
+fn swift_calls_rust_struct_repr_struct_optional_string_field(
+    mut arg: ffi::StructReprStructWithOptionalStringField,
+) -> ffi::StructReprStructWithOptionalStringField {
+    arg.id += 1;
+    arg
+}
+
+/// Used to tag `StructReprStructWithPhantomDataField` at the type level.
+pub struct PhantomDataUnitMarker;
+
+/// Returns the struct with its `value` doubled.
+fn swift_calls_rust_struct_repr_struct_phantom_data_field(
+    arg: ffi::StructReprStructWithPhantomDataField,
+) -> ffi::StructReprStructWithPhantomDataField {
+    ffi::StructReprStructWithPhantomDataField {
+        value: arg.value * 2,
+        unit: arg.unit,
+    }
+}
+
+/// A horizontal line that starts at the origin.
+fn swift_calls_rust_make_line(length: f64) -> ffi::StructReprStructLine {
+    ffi::StructReprStructLine {
+        start: ffi::StructReprStructPoint { x: 0., y: 0. },
+        end: ffi::StructReprStructPoint { x: length, y: 0. },
+    }
+}
+
+fn swift_calls_rust_translate_line(
+    line: ffi::StructReprStructLine,
+    dx: f64,
+    dy: f64,
+) -> ffi::StructReprStructLine {
+    let translate = |point: ffi::StructReprStructPoint| ffi::StructReprStructPoint {
+        x: point.x + dx,
+        y: point.y + dy,
+    };
+
+    ffi::StructReprStructLine {
+        start: translate(line.start),
+        end: translate(line.end),
+    }
+}
+
 #[deny(unused)]
 mod tests {
     use super::ffi;
@@ -8,3 +8,126 @@
 // - Create SliceTests.swift
 //   - Add Swift test verifying that we can use an Array<SliceTestOpaqueRustType> as a slice
 //   - Add Swift test verifying that we can use a RustVec<SliceTestOpaqueRustType> as a slice
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_sum_jagged_slice(arg: &[&[i32]]) -> i32;
+        fn rust_jagged_slice_inner_lengths(arg: &[&[i32]]) -> Vec<u32>;
+    }
+
+    extern "Rust" {
+        fn rust_sum_bytes(bytes: &[u8]) -> u64;
+    }
+
+    extern "Rust" {
+        type SharedBytesHolder;
+
+        fn rust_sum_bytes_from_data(data: Bytes) -> u64;
+        fn rust_hold_bytes_tail(data: bytes::Bytes) -> SharedBytesHolder;
+        fn sum(&self) -> u64;
+        fn shares_buffer(&self) -> bool;
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    struct SlicePoint {
+        x: i32,
+        y: i32,
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    struct SliceLabel {
+        text: String,
+    }
+
+    extern "Rust" {
+        fn rust_sum_slice_points(points: &[SlicePoint]) -> SlicePoint;
+        fn rust_join_slice_labels(labels: &[SliceLabel]) -> String;
+    }
+
+    extern "Rust" {
+        type CowSliceHolder;
+
+        #[swift_bridge(init)]
+        fn new() -> CowSliceHolder;
+
+        fn borrowed_bytes(&self) -> Cow<'_, [u8]>;
+        fn owned_bytes(&self) -> Cow<[u8]>;
+    }
+}
+
+use bytes::Bytes;
+use std::borrow::Cow;
+
+fn rust_sum_jagged_slice(arg: &[&[i32]]) -> i32 {
+    arg.iter().map(|inner| inner.iter().sum::<i32>()).sum()
+}
+
+fn rust_jagged_slice_inner_lengths(arg: &[&[i32]]) -> Vec<u32> {
+    arg.iter().map(|inner| inner.len() as u32).collect()
+}
+
+fn rust_sum_bytes(bytes: &[u8]) -> u64 {
+    bytes.iter().map(|byte| *byte as u64).sum()
+}
+
+fn rust_sum_bytes_from_data(data: Bytes) -> u64 {
+    data.iter().map(|byte| *byte as u64).sum()
+}
+
+fn rust_hold_bytes_tail(data: Bytes) -> SharedBytesHolder {
+    let tail = data.slice(1..);
+    SharedBytesHolder { data, tail }
+}
+
+/// Holds a `Bytes` that was passed from Swift along with a slice of it, so that Swift can verify
+/// that both keep pointing at the original buffer.
+pub struct SharedBytesHolder {
+    data: Bytes,
+    tail: Bytes,
+}
+
+impl SharedBytesHolder {
+    fn sum(&self) -> u64 {
+        self.tail.iter().map(|byte| *byte as u64).sum()
+    }
+
+    fn shares_buffer(&self) -> bool {
+        self.tail.as_ptr() == unsafe { self.data.as_ptr().add(1) }
+    }
+}
+
+fn rust_sum_slice_points(points: &[ffi::SlicePoint]) -> ffi::SlicePoint {
+    ffi::SlicePoint {
+        x: points.iter().map(|point| point.x).sum(),
+        y: points.iter().map(|point| point.y).sum(),
+    }
+}
+
+fn rust_join_slice_labels(labels: &[ffi::SliceLabel]) -> String {
+    labels
+        .iter()
+        .map(|label| label.text.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub struct CowSliceHolder {
+    bytes: Vec<u8>,
+}
+
+impl CowSliceHolder {
+    fn new() -> Self {
+        CowSliceHolder {
+            bytes: vec![1, 2, 3],
+        }
+    }
+
+    fn borrowed_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(&self.bytes)
+    }
+
+    fn owned_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Owned(self.bytes.clone())
+    }
+}
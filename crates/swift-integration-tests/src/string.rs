@@ -4,6 +4,18 @@ mod ffi {
         fn run_string_tests();
 
         fn create_string(str: &str) -> String;
+
+        fn rust_static_str_color_name(code: u8) -> &'static str;
+
+        type StringTestPicker;
+        #[swift_bridge(init)]
+        fn new(min_len: usize) -> StringTestPicker;
+        fn longest<'a>(&self, a: &'a str, b: &'a str) -> &'a str;
+        fn append_summary(&self, out: &mut String);
+        fn rust_trim_str(text: &str) -> &str;
+
+        fn rust_make_boxed_str(repeat: u8) -> Box<str>;
+        fn rust_reflect_boxed_str(arg: Box<str>) -> Box<str>;
     }
 
     extern "Swift" {
@@ -26,3 +38,47 @@ fn run_string_tests() {
 fn create_string(str: &str) -> String {
     str.to_string()
 }
+
+fn rust_static_str_color_name(code: u8) -> &'static str {
+    match code {
+        0 => "red",
+        1 => "green",
+        _ => "unknown",
+    }
+}
+
+pub struct StringTestPicker {
+    min_len: usize,
+}
+
+impl StringTestPicker {
+    fn new(min_len: usize) -> Self {
+        StringTestPicker { min_len }
+    }
+
+    /// Returns the longer of the two strings, or `a` if `b` is shorter than `min_len`.
+    fn longest<'a>(&self, a: &'a str, b: &'a str) -> &'a str {
+        if b.len() > a.len() && b.len() >= self.min_len {
+            b
+        } else {
+            a
+        }
+    }
+
+    /// Appends a description of this picker to the end of `out`.
+    fn append_summary(&self, out: &mut String) {
+        out.push_str(&format!("picks strings of at least {} bytes", self.min_len));
+    }
+}
+
+fn rust_trim_str(text: &str) -> &str {
+    text.trim()
+}
+
+fn rust_make_boxed_str(repeat: u8) -> Box<str> {
+    "ab".repeat(repeat as usize).into_boxed_str()
+}
+
+fn rust_reflect_boxed_str(arg: Box<str>) -> Box<str> {
+    arg
+}
@@ -1,3 +1,8 @@
 mod already_declared;
+mod bytes;
+mod comparable;
+mod custom_reflectable;
 mod derive;
+mod localized_error;
+mod positional_names;
 mod swift_name;
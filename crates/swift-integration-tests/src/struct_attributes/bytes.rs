@@ -0,0 +1,23 @@
+/// Verify that structs with the `bytes` attribute can be serialized to and from bytes in Swift.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/transparent_struct_codegen_tests.rs
+///   - shared_struct_bytes_attribute
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", bytes)]
+    struct BytesTestCacheEntry {
+        id: u64,
+        score: f32,
+        pinned: bool,
+    }
+
+    extern "Rust" {
+        fn rust_reflect_bytes_test_cache_entry(arg: BytesTestCacheEntry) -> BytesTestCacheEntry;
+    }
+}
+
+use ffi::BytesTestCacheEntry;
+
+fn rust_reflect_bytes_test_cache_entry(arg: BytesTestCacheEntry) -> BytesTestCacheEntry {
+    arg
+}
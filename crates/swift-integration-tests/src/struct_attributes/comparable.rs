@@ -0,0 +1,19 @@
+/// Verify that shared structs with the `comparable` attribute are ordered by their fields in
+/// declaration order.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/comparable_attribute_codegen_tests.rs
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(comparable)]
+    enum ComparableTestChannel {
+        Beta = 0,
+        Stable = 1,
+    }
+
+    #[swift_bridge(swift_repr = "struct", comparable)]
+    struct ComparableTestVersion {
+        major: u32,
+        minor: u32,
+        channel: ComparableTestChannel,
+    }
+}
@@ -0,0 +1,27 @@
+/// Verify that shared structs with the `custom_reflectable` attribute expose their fields
+/// through Swift's `Mirror`.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/custom_reflectable_attribute_codegen_tests.rs
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", custom_reflectable)]
+    struct CustomReflectableStruct {
+        field1: u8,
+        field2: bool,
+    }
+
+    #[swift_bridge(swift_repr = "struct", custom_reflectable)]
+    struct CustomReflectableTupleStruct(u32, u8);
+
+    extern "Rust" {
+        fn rust_reflect_custom_reflectable_struct(
+            arg: CustomReflectableStruct,
+        ) -> CustomReflectableStruct;
+    }
+}
+
+use ffi::CustomReflectableStruct;
+
+fn rust_reflect_custom_reflectable_struct(arg: CustomReflectableStruct) -> CustomReflectableStruct {
+    arg
+}
@@ -25,4 +25,16 @@ mod ffi {
     struct StructDeriveClone3 {
         field: String,
     }
+
+    #[swift_bridge(swift_repr = "struct")]
+    #[derive(Hash)]
+    struct StructDeriveHash {
+        id: u32,
+        enabled: bool,
+        score: Option<i64>,
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    #[derive(Hash)]
+    struct StructDeriveHashTuple(u32, i32);
 }
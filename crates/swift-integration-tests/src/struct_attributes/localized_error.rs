@@ -0,0 +1,33 @@
+/// Verify that a struct whose fields use the `error_description`, `failure_reason` and
+/// `recovery_suggestion` attributes is a Swift `LocalizedError`.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/transparent_struct_codegen_tests.rs
+///   - shared_struct_localized_error_field_attributes
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct LocalizedErrorTestError {
+        code: u32,
+        #[swift_bridge(error_description)]
+        message: String,
+        #[swift_bridge(failure_reason)]
+        reason: String,
+        #[swift_bridge(recovery_suggestion)]
+        suggestion: String,
+    }
+
+    extern "Rust" {
+        fn rust_func_return_localized_error(code: u32) -> Result<(), LocalizedErrorTestError>;
+    }
+}
+
+use ffi::LocalizedErrorTestError;
+
+fn rust_func_return_localized_error(code: u32) -> Result<(), LocalizedErrorTestError> {
+    Err(LocalizedErrorTestError {
+        code,
+        message: "The file could not be saved.".to_string(),
+        reason: "The disk is full.".to_string(),
+        suggestion: "Free up some space and try again.".to_string(),
+    })
+}
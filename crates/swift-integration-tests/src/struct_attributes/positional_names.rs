@@ -0,0 +1,19 @@
+/// Verify that tuple structs with the `positional_names` attribute expose their fields to Swift
+/// using the given names.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/positional_names_attribute_codegen_tests.rs
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", positional_names = ["x", "y"])]
+    struct PositionalNamesPoint(f64, f64);
+
+    extern "Rust" {
+        fn rust_swap_positional_names_point(arg: PositionalNamesPoint) -> PositionalNamesPoint;
+    }
+}
+
+use ffi::PositionalNamesPoint;
+
+fn rust_swap_positional_names_point(arg: PositionalNamesPoint) -> PositionalNamesPoint {
+    PositionalNamesPoint(arg.1, arg.0)
+}
@@ -9,6 +9,14 @@ mod ffi {
     struct TupleTestStruct {
         field: u8,
     }
+    enum TupleTestPartialError {
+        InvalidNumber(String),
+    }
+    #[swift_bridge(swift_repr = "struct")]
+    #[derive(Clone)]
+    struct TupleTestSkippedNumber {
+        position: u32,
+    }
     extern "Rust" {
         type TupleTestOpaqueRustType;
         #[swift_bridge(init)]
@@ -21,6 +29,9 @@ mod ffi {
         fn rust_reflect_tuple_f64_and_usize_and_bool(
             tuple: (f64, usize, bool),
         ) -> (f64, usize, bool);
+        fn rust_sum_valid_numbers(numbers: &str) -> (u32, Option<TupleTestPartialError>);
+        #[swift_bridge(return_warnings)]
+        fn rust_sum_numbers_with_warnings(numbers: &str) -> (u32, Vec<TupleTestSkippedNumber>);
     }
     extern "Swift" {
         fn swift_reflect_tuple_primitives(arg: (i32, u32)) -> (i32, u32);
@@ -61,6 +72,44 @@ fn rust_reflect_tuple_f64_and_usize_and_bool(tuple: (f64, usize, bool)) -> (f64,
     tuple
 }
 
+/// Sum the comma separated numbers that can be parsed, and report the first one that can't be.
+fn rust_sum_valid_numbers(numbers: &str) -> (u32, Option<ffi::TupleTestPartialError>) {
+    let mut sum = 0;
+    let mut error = None;
+
+    for number in numbers.split(',') {
+        match number.parse::<u32>() {
+            Ok(number) => sum += number,
+            Err(_) => {
+                if error.is_none() {
+                    error = Some(ffi::TupleTestPartialError::InvalidNumber(
+                        number.to_string(),
+                    ));
+                }
+            }
+        }
+    }
+
+    (sum, error)
+}
+
+/// Sum the comma separated numbers that can be parsed, with a warning for each one that can't be.
+fn rust_sum_numbers_with_warnings(numbers: &str) -> (u32, Vec<ffi::TupleTestSkippedNumber>) {
+    let mut sum = 0;
+    let mut warnings = vec![];
+
+    for (position, number) in numbers.split(',').enumerate() {
+        match number.parse::<u32>() {
+            Ok(number) => sum += number,
+            Err(_) => warnings.push(ffi::TupleTestSkippedNumber {
+                position: position as u32,
+            }),
+        }
+    }
+
+    (sum, warnings)
+}
+
 fn test_rust_calls_swift_tuples() {
     let val = ffi::swift_reflect_tuple_primitives((-123, 123));
     assert_eq!(val.0, -123);
@@ -1,3 +1,5 @@
+use std::time::{Duration, SystemTime};
+
 #[swift_bridge::bridge]
 mod ffi {
     enum TransparentEnumInsideVecT {
@@ -5,6 +7,22 @@ mod ffi {
         VariantB,
     }
 
+    #[derive(Clone)]
+    enum DataEnumInsideVecT {
+        Circle(f64),
+        Rectangle { width: f64, height: f64 },
+        Label(String),
+        Empty,
+    }
+
+    #[swift_bridge(swift_repr = "struct", conforms_to = "Codable")]
+    #[derive(Clone)]
+    struct CodableRecordInsideVecT {
+        id: u32,
+        score: f64,
+        active: bool,
+    }
+
     extern "Rust" {
         type ARustTypeInsideVecT;
 
@@ -12,6 +30,15 @@ mod ffi {
         fn new(text: &str) -> ARustTypeInsideVecT;
 
         fn text(&self) -> &str;
+        fn append_copies(&self, out: &mut Vec<ARustTypeInsideVecT>, count: u8);
+    }
+
+    extern "Rust" {
+        fn rust_append_to_vec_u32(out: &mut Vec<u32>, count: u32);
+    }
+
+    extern "Rust" {
+        fn rust_make_vec_u32_with_capacity(len: u32, capacity: u32) -> Vec<u32>;
     }
 
     extern "Rust" {
@@ -20,12 +47,42 @@ mod ffi {
         ) -> Vec<ARustTypeInsideVecT>;
     }
 
+    extern "Rust" {
+        fn rust_reflect_vec_option_opaque_rust_type(
+            arg: Vec<Option<ARustTypeInsideVecT>>,
+        ) -> Vec<Option<ARustTypeInsideVecT>>;
+    }
+
     extern "Rust" {
         fn rust_reflect_vec_transparent_enum(
             arg: Vec<TransparentEnumInsideVecT>,
         ) -> Vec<TransparentEnumInsideVecT>;
     }
 
+    extern "Rust" {
+        fn rust_reflect_vec_data_enum(arg: Vec<DataEnumInsideVecT>) -> Vec<DataEnumInsideVecT>;
+    }
+
+    extern "Rust" {
+        fn rust_make_vec_codable_records(count: u32) -> Vec<CodableRecordInsideVecT>;
+        fn rust_reflect_vec_codable_records(
+            arg: Vec<CodableRecordInsideVecT>,
+        ) -> Vec<CodableRecordInsideVecT>;
+    }
+
+    extern "Rust" {
+        fn rust_reflect_vec_duration(arg: Vec<Duration>) -> Vec<Duration>;
+        fn rust_reflect_vec_system_time(arg: Vec<SystemTime>) -> Vec<SystemTime>;
+    }
+
+    extern "Rust" {
+        type VecTestSentence;
+
+        #[swift_bridge(init)]
+        fn new(text: &str) -> VecTestSentence;
+        fn words(&self) -> Vec<&str>;
+    }
+
     extern "Rust" {
         fn run_vec_tests();
     }
@@ -63,14 +120,83 @@ impl ARustTypeInsideVecT {
     fn text(&self) -> &str {
         &self.text
     }
+
+    /// Push `count` copies of this value onto the vector.
+    fn append_copies(&self, out: &mut Vec<ARustTypeInsideVecT>, count: u8) {
+        for _ in 0..count {
+            out.push(ARustTypeInsideVecT::new(&self.text));
+        }
+    }
+}
+
+/// Push the numbers `0..count` onto the end of the vector.
+fn rust_append_to_vec_u32(out: &mut Vec<u32>, count: u32) {
+    out.extend(0..count);
+}
+
+/// Create a vector holding the numbers `0..len` with room for at least `capacity` elements.
+fn rust_make_vec_u32_with_capacity(len: u32, capacity: u32) -> Vec<u32> {
+    let mut vec = Vec::with_capacity(capacity as usize);
+    vec.extend(0..len);
+    vec
 }
 
 fn rust_reflect_vec_opaque_rust_type(arg: Vec<ARustTypeInsideVecT>) -> Vec<ARustTypeInsideVecT> {
     arg
 }
 
+fn rust_reflect_vec_option_opaque_rust_type(
+    arg: Vec<Option<ARustTypeInsideVecT>>,
+) -> Vec<Option<ARustTypeInsideVecT>> {
+    arg
+}
+
 fn rust_reflect_vec_transparent_enum(
     arg: Vec<ffi::TransparentEnumInsideVecT>,
 ) -> Vec<ffi::TransparentEnumInsideVecT> {
     arg
 }
+
+fn rust_reflect_vec_data_enum(arg: Vec<ffi::DataEnumInsideVecT>) -> Vec<ffi::DataEnumInsideVecT> {
+    arg
+}
+
+fn rust_make_vec_codable_records(count: u32) -> Vec<ffi::CodableRecordInsideVecT> {
+    (0..count)
+        .map(|id| ffi::CodableRecordInsideVecT {
+            id,
+            score: id as f64 * 1.5,
+            active: id % 2 == 0,
+        })
+        .collect()
+}
+
+fn rust_reflect_vec_codable_records(
+    arg: Vec<ffi::CodableRecordInsideVecT>,
+) -> Vec<ffi::CodableRecordInsideVecT> {
+    arg
+}
+
+fn rust_reflect_vec_duration(arg: Vec<Duration>) -> Vec<Duration> {
+    arg
+}
+
+fn rust_reflect_vec_system_time(arg: Vec<SystemTime>) -> Vec<SystemTime> {
+    arg
+}
+
+pub struct VecTestSentence {
+    text: String,
+}
+
+impl VecTestSentence {
+    fn new(text: &str) -> Self {
+        VecTestSentence {
+            text: text.to_string(),
+        }
+    }
+
+    fn words(&self) -> Vec<&str> {
+        self.text.split_whitespace().collect()
+    }
+}
@@ -0,0 +1,73 @@
+//! Support for opaque Rust types that use `#[swift_bridge(arc_mutex)]`.
+//!
+//! Swift holds a pointer to the `Mutex<T>` inside of an `Arc<Mutex<T>>`, and every method call
+//! locks the mutex for the duration of the call.
+
+use std::cell::RefCell;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Mutex, MutexGuard};
+
+thread_local! {
+    /// The addresses of the mutexes that are currently locked by bridged calls on this thread.
+    static HELD_LOCKS: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Lock a mutex that is shared with Swift.
+///
+/// A `std::sync::Mutex` deadlocks if the thread that holds the lock tries to lock it again.
+/// This can happen if a Rust method calls back into Swift and Swift calls another method on the
+/// same value, or if the same value is passed as both `self` and an argument.
+/// We detect this and abort instead of deadlocking.
+///
+/// This is called from `extern "C"` functions, which must not unwind, so we abort the process
+/// rather than panicking.
+pub fn lock<T>(mutex: &Mutex<T>) -> ArcMutexGuard<'_, T> {
+    let address = mutex as *const Mutex<T> as usize;
+
+    let is_reentrant = HELD_LOCKS.with(|held| held.borrow().contains(&address));
+    if is_reentrant {
+        eprintln!(
+            "Re-entrant call on a #[swift_bridge(arc_mutex)] value that is already locked by this thread."
+        );
+        std::process::abort();
+    }
+
+    let guard = mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    HELD_LOCKS.with(|held| held.borrow_mut().push(address));
+
+    ArcMutexGuard { guard, address }
+}
+
+/// Holds the lock for a mutex that is shared with Swift, and keeps track of the fact that the
+/// current thread holds it.
+pub struct ArcMutexGuard<'a, T> {
+    guard: MutexGuard<'a, T>,
+    address: usize,
+}
+
+impl<'a, T> Deref for ArcMutexGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<'a, T> DerefMut for ArcMutexGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+impl<'a, T> Drop for ArcMutexGuard<'a, T> {
+    fn drop(&mut self) {
+        HELD_LOCKS.with(|held| {
+            let mut held = held.borrow_mut();
+            if let Some(idx) = held.iter().rposition(|address| *address == self.address) {
+                held.remove(idx);
+            }
+        });
+    }
+}
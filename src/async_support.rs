@@ -2,6 +2,8 @@ use once_cell::sync::Lazy;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::mpsc::{Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
 
 #[doc(hidden)]
 pub static ASYNC_RUNTIME: Lazy<TokioRuntime> = Lazy::new(|| {
@@ -50,3 +52,67 @@ impl TokioRuntime {
         });
     }
 }
+
+/// A future that resolves once a Swift `async` function that was called from Rust completes.
+///
+/// Swift gets handed a pointer to the future's shared state along with a callback, and calls the
+/// callback with the function's return value once it finishes.
+#[doc(hidden)]
+pub struct SwiftFuture<T> {
+    state: Arc<Mutex<SwiftFutureState<T>>>,
+}
+
+struct SwiftFutureState<T> {
+    value: Option<T>,
+    waker: Option<Waker>,
+}
+
+// The value is only ever written once by the Swift callback and then moved out by the future,
+// so it is never accessed from two threads at the same time.
+unsafe impl<T> Send for SwiftFuture<T> {}
+
+#[doc(hidden)]
+impl<T> SwiftFuture<T> {
+    /// Create the future along with the `callback_wrapper` pointer that gets passed to Swift.
+    pub fn new() -> (Self, *mut std::ffi::c_void) {
+        let state = Arc::new(Mutex::new(SwiftFutureState {
+            value: None,
+            waker: None,
+        }));
+        let callback_wrapper = Arc::into_raw(state.clone()) as *mut std::ffi::c_void;
+
+        (SwiftFuture { state }, callback_wrapper)
+    }
+
+    /// Resolve the future with the value that the Swift function returned.
+    ///
+    /// # Safety
+    ///
+    /// `callback_wrapper` must have been created by `SwiftFuture::<T>::new` and can only be
+    /// completed once.
+    pub unsafe fn complete(callback_wrapper: *mut std::ffi::c_void, value: T) {
+        let state = Arc::from_raw(callback_wrapper as *const Mutex<SwiftFutureState<T>>);
+        let mut state = state.lock().unwrap();
+
+        state.value = Some(value);
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Future for SwiftFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+
+        match state.value.take() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
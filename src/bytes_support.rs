@@ -0,0 +1,45 @@
+use std::ffi::c_void;
+
+/// A Swift `Data` that was passed to Rust.
+///
+/// Swift retains the `Data`'s storage before passing it to Rust, and `release` gives up that
+/// retain. This has the same layout as the `__private__SwiftBytes` C struct.
+#[repr(C)]
+pub struct FfiSwiftBytes {
+    owner: *mut c_void,
+    start: *const u8,
+    len: usize,
+    release: extern "C" fn(*mut c_void),
+}
+
+impl FfiSwiftBytes {
+    /// Wrap the Swift buffer in a `Bytes` without copying it.
+    ///
+    /// The Swift storage is released once the `Bytes` and all of its clones have been dropped.
+    pub fn into_bytes(self) -> bytes::Bytes {
+        bytes::Bytes::from_owner(SwiftBytesOwner(self))
+    }
+}
+
+struct SwiftBytesOwner(FfiSwiftBytes);
+
+// Swift's `NSData` is immutable and its retain count is thread safe, so the buffer can be read
+// and released from any thread.
+unsafe impl Send for SwiftBytesOwner {}
+unsafe impl Sync for SwiftBytesOwner {}
+
+impl AsRef<[u8]> for SwiftBytesOwner {
+    fn as_ref(&self) -> &[u8] {
+        if self.0.len == 0 {
+            return &[];
+        }
+
+        unsafe { std::slice::from_raw_parts(self.0.start, self.0.len) }
+    }
+}
+
+impl Drop for SwiftBytesOwner {
+    fn drop(&mut self) {
+        (self.0.release)(self.0.owner)
+    }
+}
@@ -6,7 +6,10 @@ pub use swift_bridge_macro::bridge;
 
 mod std_bridge;
 
-pub use self::std_bridge::{option, result, string};
+pub use self::std_bridge::{hash_map, option, result, string};
+
+#[doc(hidden)]
+pub mod arc_mutex_support;
 
 #[doc(hidden)]
 #[cfg(feature = "async")]
@@ -15,6 +18,10 @@ pub mod async_support;
 #[doc(hidden)]
 pub mod boxed_fn_support;
 
+#[doc(hidden)]
+#[cfg(feature = "bytes")]
+pub mod bytes_support;
+
 #[doc(hidden)]
 pub mod copy_support;
 
@@ -45,8 +52,54 @@ impl<T> FfiSlice<T> {
 
     /// Get a reference to the slice that this FfiSlice points to.
     pub fn as_slice(&self) -> &'static [T] {
+        if self.len == 0 {
+            return &[];
+        }
+
         unsafe { std::slice::from_raw_parts(self.start, self.len) }
     }
+
+    /// Create an FfiSlice that takes ownership of the Vec's elements.
+    ///
+    /// The elements are leaked, so the receiver is responsible for freeing them.
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        let slice = Box::leak(vec.into_boxed_slice());
+        FfiSlice::from_slice(slice)
+    }
+
+    /// Get a mutable reference to the slice that this FfiSlice points to.
+    pub fn as_mut_slice(&self) -> &'static mut [T] {
+        if self.len == 0 {
+            return &mut [];
+        }
+
+        unsafe { std::slice::from_raw_parts_mut(self.start as *mut T, self.len) }
+    }
+}
+
+impl<T> FfiSlice<FfiSlice<T>> {
+    /// Get references to each of the inner slices that this FfiSlice of FfiSlice's points to.
+    pub fn as_jagged_slices(&self) -> Vec<&'static [T]> {
+        self.as_slice()
+            .iter()
+            .map(|slice| slice.as_slice())
+            .collect()
+    }
+}
+
+/// Called by the generated functions that free opaque Rust types.
+///
+/// When the `debug-log-frees` feature is enabled this logs the type and address of the freed
+/// value in debug builds, which helps with matching up allocations and frees when debugging
+/// memory leaks. Otherwise it does nothing.
+#[doc(hidden)]
+#[inline(always)]
+pub fn debug_log_free<T>(type_name: &str, ptr: *const T) {
+    #[cfg(all(feature = "debug-log-frees", debug_assertions))]
+    eprintln!("swift-bridge: free {} {:p}", type_name, ptr);
+
+    #[cfg(not(all(feature = "debug-log-frees", debug_assertions)))]
+    let _ = (type_name, ptr);
 }
 
 // The code generation automatically implements this for all shared structs.
@@ -102,3 +155,17 @@ pub trait SharedEnum {
 pub extern "C" fn __swift_bridge__null_pointer() -> *const std::ffi::c_void {
     std::ptr::null()
 }
+
+// Frees a slice of pointers that was created using `FfiSlice::from_vec`, such as the slice that
+// Swift receives when Rust passes it a `Vec<Option<SomeOpaqueRustType>>`.
+//
+// The pointers themselves are not freed, since Swift takes ownership of them.
+#[export_name = "__swift_bridge__$free_ffi_slice_of_pointers"]
+#[doc(hidden)]
+pub extern "C" fn free_ffi_slice_of_pointers(slice: FfiSlice<*mut std::ffi::c_void>) {
+    let slice =
+        std::ptr::slice_from_raw_parts_mut(slice.start as *mut *mut std::ffi::c_void, slice.len);
+    unsafe {
+        let _ = Box::from_raw(slice);
+    }
+}
@@ -2,6 +2,7 @@
 //! crates/swift-bridge-build/src/generate_core/*
 #![allow(missing_docs)]
 
+pub mod hash_map;
 pub mod option;
 pub mod result;
 mod rust_vec;
@@ -0,0 +1,251 @@
+use crate::FfiSlice;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A `HashMap<String, V>` that is passed over FFI as three buffers, so that the whole map can be
+/// transferred without an FFI call for each entry.
+///
+/// When Swift passes a map to Rust the buffers are owned by Swift and only live for the duration
+/// of the call, so Rust copies them into a `HashMap`.
+///
+/// When Rust passes a map to Swift the buffers are leaked, and Swift frees them using
+/// `__swift_bridge__$FfiStringMap$free_{V}` after copying them into a `Dictionary`.
+///
+/// Opaque Rust type values are passed as boxed pointers, one per entry. Whichever side receives
+/// the map takes ownership of the values, while the buffers are freed using
+/// `__swift_bridge__$FfiStringMap$free_pointers`.
+#[repr(C)]
+pub struct FfiStringMap<V> {
+    /// The UTF-8 bytes of every key, one after another.
+    pub key_bytes: FfiSlice<u8>,
+    /// The length in bytes of each key.
+    pub key_lengths: FfiSlice<usize>,
+    /// The value of each key, in the same order as the keys.
+    pub values: FfiSlice<V>,
+}
+
+impl<V: Copy + 'static> FfiStringMap<V> {
+    /// Leak the map's keys and values into buffers that Swift will free.
+    pub fn from_hash_map(map: HashMap<String, V>) -> Self {
+        Self::from_hash_map_with(map, |value| value)
+    }
+
+    /// Leak the map's keys into buffers that Swift will free, converting each value into its FFI
+    /// representation.
+    pub fn from_hash_map_with<T>(map: HashMap<String, T>, mut to_ffi: impl FnMut(T) -> V) -> Self {
+        let mut key_bytes = Vec::with_capacity(map.keys().map(|key| key.len()).sum());
+        let mut key_lengths = Vec::with_capacity(map.len());
+        let mut values = Vec::with_capacity(map.len());
+
+        for (key, value) in map {
+            key_bytes.extend_from_slice(key.as_bytes());
+            key_lengths.push(key.len());
+            values.push(to_ffi(value));
+        }
+
+        FfiStringMap {
+            key_bytes: FfiSlice::from_vec(key_bytes),
+            key_lengths: FfiSlice::from_vec(key_lengths),
+            values: FfiSlice::from_vec(values),
+        }
+    }
+
+    /// Copy the buffers into a `HashMap`.
+    pub fn to_hash_map(&self) -> HashMap<String, V> {
+        self.to_hash_map_with(|value| value)
+    }
+
+    /// Copy the buffers into a `HashMap`, converting each value from its FFI representation.
+    pub fn to_hash_map_with<T>(&self, mut from_ffi: impl FnMut(V) -> T) -> HashMap<String, T> {
+        let key_bytes = self.key_bytes.as_slice();
+        let mut offset = 0;
+
+        self.key_lengths
+            .as_slice()
+            .iter()
+            .zip(self.values.as_slice())
+            .map(|(len, value)| {
+                let key = &key_bytes[offset..offset + len];
+                offset += len;
+
+                (String::from_utf8_lossy(key).into_owned(), from_ffi(*value))
+            })
+            .collect()
+    }
+
+    /// Free buffers that were created by [`FfiStringMap::from_hash_map`].
+    fn free(self) {
+        unsafe {
+            free_leaked_slice(self.key_bytes);
+            free_leaked_slice(self.key_lengths);
+            free_leaked_slice(self.values);
+        }
+    }
+}
+
+/// A `HashMap<K, V>` with integer keys that is passed over FFI as a buffer of keys and a buffer of
+/// values.
+///
+/// A map has no order, so the only guarantee is that the value at each index belongs to the key
+/// at the same index.
+///
+/// When Rust passes a map to Swift the buffers are leaked, and Swift frees them using
+/// `__swift_bridge__$FfiIntMap$free_keys_{K}` and `__swift_bridge__$FfiIntMap$free_values_{V}`
+/// after copying them into a `Dictionary`.
+#[repr(C)]
+pub struct FfiIntMap<K, V> {
+    /// Every key in the map.
+    pub keys: FfiSlice<K>,
+    /// The value of each key, in the same order as the keys.
+    pub values: FfiSlice<V>,
+}
+
+impl<K: Copy + Eq + Hash + 'static, V: Copy + 'static> FfiIntMap<K, V> {
+    /// Leak the map's keys and values into buffers that Swift will free.
+    pub fn from_hash_map(map: HashMap<K, V>) -> Self {
+        Self::from_hash_map_with(map, |value| value)
+    }
+
+    /// Leak the map's keys into a buffer that Swift will free, converting each value into its FFI
+    /// representation.
+    pub fn from_hash_map_with<T>(map: HashMap<K, T>, mut to_ffi: impl FnMut(T) -> V) -> Self {
+        let (keys, values): (Vec<K>, Vec<V>) = map
+            .into_iter()
+            .map(|(key, value)| (key, to_ffi(value)))
+            .unzip();
+
+        FfiIntMap {
+            keys: FfiSlice::from_vec(keys),
+            values: FfiSlice::from_vec(values),
+        }
+    }
+
+    /// Copy the buffers into a `HashMap`.
+    pub fn to_hash_map(&self) -> HashMap<K, V> {
+        self.to_hash_map_with(|value| value)
+    }
+
+    /// Copy the buffers into a `HashMap`, converting each value from its FFI representation.
+    pub fn to_hash_map_with<T>(&self, mut from_ffi: impl FnMut(V) -> T) -> HashMap<K, T> {
+        self.keys
+            .as_slice()
+            .iter()
+            .zip(self.values.as_slice())
+            .map(|(key, value)| (*key, from_ffi(*value)))
+            .collect()
+    }
+}
+
+unsafe fn free_leaked_slice<T>(slice: FfiSlice<T>) {
+    let slice = std::ptr::slice_from_raw_parts_mut(slice.start as *mut T, slice.len);
+    drop(Box::from_raw(slice));
+}
+
+use macro_::{int_map_key_externs, int_map_value_externs, string_map_externs};
+
+string_map_externs!(u8);
+string_map_externs!(u16);
+string_map_externs!(u32);
+string_map_externs!(u64);
+string_map_externs!(usize);
+
+string_map_externs!(i8);
+string_map_externs!(i16);
+string_map_externs!(i32);
+string_map_externs!(i64);
+string_map_externs!(isize);
+
+string_map_externs!(f32);
+string_map_externs!(f64);
+
+string_map_externs!(bool);
+
+int_map_key_externs!(u8);
+int_map_key_externs!(u16);
+int_map_key_externs!(u32);
+int_map_key_externs!(u64);
+int_map_key_externs!(usize);
+
+int_map_key_externs!(i8);
+int_map_key_externs!(i16);
+int_map_key_externs!(i32);
+int_map_key_externs!(i64);
+int_map_key_externs!(isize);
+
+int_map_value_externs!(u8);
+int_map_value_externs!(u16);
+int_map_value_externs!(u32);
+int_map_value_externs!(u64);
+int_map_value_externs!(usize);
+
+int_map_value_externs!(i8);
+int_map_value_externs!(i16);
+int_map_value_externs!(i32);
+int_map_value_externs!(i64);
+int_map_value_externs!(isize);
+
+int_map_value_externs!(f32);
+int_map_value_externs!(f64);
+
+int_map_value_externs!(bool);
+
+// Frees a map of opaque Rust type values that was passed from Rust to Swift.
+//
+// The values themselves are not freed, since Swift takes ownership of them.
+#[export_name = "__swift_bridge__$FfiStringMap$free_pointers"]
+#[doc(hidden)]
+pub extern "C" fn free_string_map_of_pointers(map: FfiStringMap<*mut std::ffi::c_void>) {
+    map.free()
+}
+
+// Frees the values of an integer keyed map of opaque Rust type values that was passed from Rust
+// to Swift.
+//
+// The values themselves are not freed, since Swift takes ownership of them.
+#[export_name = "__swift_bridge__$FfiIntMap$free_values_pointers"]
+#[doc(hidden)]
+pub extern "C" fn free_int_map_values_of_pointers(values: FfiSlice<*mut std::ffi::c_void>) {
+    unsafe { free_leaked_slice(values) }
+}
+
+mod macro_ {
+    macro_rules! string_map_externs {
+        ($ty:ty) => {
+            const _: () = {
+                #[export_name = concat!("__swift_bridge__$FfiStringMap$free_", stringify!($ty))]
+                #[doc(hidden)]
+                pub extern "C" fn _free(map: crate::hash_map::FfiStringMap<$ty>) {
+                    map.free()
+                }
+            };
+        };
+    }
+
+    macro_rules! int_map_key_externs {
+        ($ty:ty) => {
+            const _: () = {
+                #[export_name = concat!("__swift_bridge__$FfiIntMap$free_keys_", stringify!($ty))]
+                #[doc(hidden)]
+                pub extern "C" fn _free_keys(keys: crate::FfiSlice<$ty>) {
+                    unsafe { free_leaked_slice(keys) }
+                }
+            };
+        };
+    }
+
+    macro_rules! int_map_value_externs {
+        ($ty:ty) => {
+            const _: () = {
+                #[export_name = concat!("__swift_bridge__$FfiIntMap$free_values_", stringify!($ty))]
+                #[doc(hidden)]
+                pub extern "C" fn _free_values(values: crate::FfiSlice<$ty>) {
+                    unsafe { free_leaked_slice(values) }
+                }
+            };
+        };
+    }
+
+    pub(super) use int_map_key_externs;
+    pub(super) use int_map_value_externs;
+    pub(super) use string_map_externs;
+}
@@ -41,6 +41,13 @@ mod macro_ {
                     vec.len()
                 }
 
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$capacity")]
+                #[doc(hidden)]
+                pub extern "C" fn _capacity(vec: *mut Vec<$ty>) -> usize {
+                    let vec = unsafe { &*vec };
+                    vec.capacity()
+                }
+
                 #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$push")]
                 #[doc(hidden)]
                 pub extern "C" fn _push(vec: *mut Vec<$ty>, val: $ty) {